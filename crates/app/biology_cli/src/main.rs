@@ -1,15 +1,32 @@
 use biology::{templates, Nomenclature, Sex};
-use biology::anatomy::body::Body;
+use biology::demography::{CarryingCapacity, Census, DEFAULT_NEEDS};
 use biology::ecosystem::environment::{Climate, Environment, Terrain};
+use biology::ecosystem::food_web::FoodWeb;
 use biology::ecosystem::interactions::Interaction;
 use biology::ecosystem::population::Population;
+use biology::ecosystem::resources::ResourceKind;
+use biology::simulation::{
+    Aging, Births, Competition, InteractionEffects, Metabolism, Mortality, Predation, Replenishment,
+    Simulation, Weather,
+};
 use biology::nomenclature::nomenclature::NomenclatureComponents;
 use biology::vitals::blood_pressure::BloodPressure;
 use biology::vitals::live_vitals::LiveVitals;
 use biology::vitals::vital_status::VitalStatus;
 use biology::vitals::vital_types::{Celsius, BPM};
-use biology_animalia::{Human, NameParts};
-use core_shared::Centimeters;
+use biology::Organism;
+use biology_animalia::{Human, Mammal, NameParts};
+use core_shared::{Centimeters, Identifier, Kilograms, MetersPerSecond};
+use std::any::Any;
+
+// Mammals in the desert give birth to their own species.
+fn same_species(parent: &dyn Organism, id: Identifier) -> Option<Box<dyn Organism>> {
+    let parent: &dyn Any = parent;
+    let mammal = parent.downcast_ref::<Mammal>()?;
+    let mut newborn = Mammal::new(mammal.species.clone());
+    newborn.id = id;
+    Some(Box::new(newborn))
+}
 
 fn main() {
 
@@ -29,46 +46,108 @@ fn main() {
     println!("{} {}", stephen.first_name().unwrap(), stephen.last_name().unwrap());
 
     // Create an ecosystem with environmental context
-    let desert_environment = Environment {
-        climate: Climate::Desert,
-        terrain: Terrain::Plains,
-        altitude: Centimeters(42800),
-        humidity: 0.14,
-    };
+    let desert_environment = Environment::new(
+        Climate::Desert,
+        Terrain::Plains,
+        Centimeters(42800),
+        0.14,
+    );
 
     let mut desert_ecosystem = Population::new(desert_environment);
 
-    // Add members to the ecosystem
-    desert_ecosystem.add_member(Body::new(&stephen));
+    // The population owns its members; keep the ids to refer to them.
+    let stephen_id = stephen.id;
+    let coyote = Mammal::new("Canis latrans".to_string())
+        .with_body_mass(Kilograms(14))
+        .with_top_speed(MetersPerSecond(18));
+    let jackrabbit = Mammal::new("Lepus californicus".to_string())
+        .with_body_mass(Kilograms(2))
+        .with_top_speed(MetersPerSecond(20));
+    let coyote_id = coyote.id;
+    let jackrabbit_id = jackrabbit.id;
+
+    for member in [coyote, jackrabbit] {
+        desert_ecosystem.insert(member).expect("new mammals have unique ids");
+    }
+    desert_ecosystem.insert(stephen).expect("stephen is not yet a member");
 
     // Add some ecological interactions
-    desert_ecosystem.add_interaction(Interaction::Predation {
-        predator_id: 0,
-        prey_id: 10,
-    });
-
-    desert_ecosystem.add_interaction(Interaction::Competition {
-        competitor1_id: 0,
-        competitor2_id: 1,
-        resource: "Food resources".to_string(),
-    });
-
-    println!("Forest Ecosystem:");
+    desert_ecosystem
+        .add_interaction(Interaction::Predation {
+            predator_id: coyote_id,
+            prey_id: jackrabbit_id,
+        })
+        .expect("both are members");
+
+    desert_ecosystem
+        .add_interaction(Interaction::Competition {
+            competitor1_id: coyote_id,
+            competitor2_id: stephen_id,
+            resource: ResourceKind::FoodBiomass,
+        })
+        .expect("both are members");
+
+    println!("Desert Ecosystem:");
     println!("Environment: {:?}", desert_ecosystem.environment);
     println!("Population size: {}", desert_ecosystem.size());
-    println!("Interactions: {} recorded", desert_ecosystem.interactions.len());
+    println!("Interactions: {} recorded", desert_ecosystem.interactions().len());
     println!("");
 
-    for body in &desert_ecosystem.members {
-        body.display_summary();
+    let food_web = FoodWeb::from_population(&desert_ecosystem);
+    for (species, level) in food_web.trophic_levels() {
+        println!("  {}: trophic level {:.1}", species, level);
+    }
+    println!("Connectance: {:.2}", food_web.connectance());
+    println!();
+
+    for member in desert_ecosystem.members() {
+        println!("{}", member.summary());
         println!("");
     }
 
+    let stephen = desert_ecosystem
+        .get_as::<Human>(stephen_id)
+        .expect("stephen is a member");
+
     println!(
         "{} : {} ({})",
         stephen.id,
         stephen.display_name(),
         stephen.sex
     );
+    println!();
+
+    // What happens to the desert over 100 simulated days?
+    let mut simulation = Simulation::new(desert_ecosystem, 42)
+        .with_rule(Aging)
+        .with_rule(Replenishment)
+        .with_rule(Weather::new())
+        .with_rule(Predation::default())
+        .with_rule(Competition::default())
+        .with_rule(InteractionEffects::default())
+        .with_rule(Metabolism::default())
+        .with_rule(Births::new(same_species).with_maturity(30))
+        .with_rule(Mortality::default());
+
+    for report in simulation.run(100) {
+        if report.births() + report.deaths() > 0 {
+            println!(
+                "Day {}: {} born, {} died, {} alive",
+                report.tick,
+                report.births(),
+                report.deaths(),
+                report.alive
+            );
+        }
+    }
+    println!("After {} days: {} members", simulation.tick(), simulation.population().size());
+    for (kind, pool) in simulation.population().environment.resources.iter() {
+        println!("  {}: {:.1} of {:.1}", kind, pool.amount, pool.capacity);
+    }
+    if let Some(capacity) = CarryingCapacity::of_population(simulation.population(), &DEFAULT_NEEDS) {
+        println!("Carrying capacity: {:.0} members (limited by {})", capacity.members, capacity.limiting);
+    }
+    let census = Census::of_simulation(&simulation);
+    println!("Living: {}, mean age {:.0} days", census.living(), census.mean_age().unwrap_or(0.0));
 
 }
@@ -1,10 +1,14 @@
-use core_shared::Direction;
+use std::fmt::{Display, Formatter};
+use core_shared::{Direction, Millimeters};
 use crate::anatomy::arm_measurements::ArmMeasurements;
+use crate::anatomy::fin_measurements::FinMeasurements;
 use crate::anatomy::leg_measurements::LegMeasurements;
 use crate::anatomy::limb_status::LimbStatus;
+use crate::anatomy::wing_measurements::WingMeasurements;
 
 // The new, more powerful Appendage enum
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type", rename_all = "snake_case"))]
 pub enum Appendage {
     Arm {
         side: Direction,
@@ -16,11 +20,151 @@ pub enum Appendage {
         measurements: LegMeasurements,
         status: LimbStatus,
     },
+    Wing {
+        side: Direction,
+        measurements: WingMeasurements,
+        status: LimbStatus,
+    },
+    Fin {
+        position: FinPosition,
+        measurements: FinMeasurements,
+        status: LimbStatus,
+    },
+    // An insect's feelers, in pairs.
+    Antenna {
+        side: Direction,
+        length: Millimeters,
+        status: LimbStatus,
+    },
+    // A mollusc's tentacles or a cephalopod's arms.
+    Tentacle {
+        side: Direction,
+        length: Millimeters,
+        status: LimbStatus,
+    },
     // We could later add variants with different data, for example:
     // Tail { length_cm: u32, status: Status },
 }
 
+/// Which kind of appendage, without its data; used to count and describe body plans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AppendageKind {
+    Arm,
+    Leg,
+    Wing,
+    Fin,
+    Antenna,
+    Tentacle,
+}
+
+impl AppendageKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AppendageKind::Arm => "arm",
+            AppendageKind::Leg => "leg",
+            AppendageKind::Wing => "wing",
+            AppendageKind::Fin => "fin",
+            AppendageKind::Antenna => "antenna",
+            AppendageKind::Tentacle => "tentacle",
+        }
+    }
+
+    pub fn plural(&self) -> &'static str {
+        match self {
+            AppendageKind::Arm => "arms",
+            AppendageKind::Leg => "legs",
+            AppendageKind::Wing => "wings",
+            AppendageKind::Fin => "fins",
+            AppendageKind::Antenna => "antennae",
+            AppendageKind::Tentacle => "tentacles",
+        }
+    }
+}
+
+/// Where a fin sits on a fish; pectoral and pelvic fins come in pairs, the rest are single.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum FinPosition {
+    // Behind the gills, for steering and braking.
+    Pectoral(Direction),
+    // On the belly, for balance.
+    Pelvic(Direction),
+    // Along the back, keeping the fish upright.
+    Dorsal,
+    // Under the tail, for stability.
+    Anal,
+    // The tail fin, which drives the fish forward.
+    Caudal,
+}
+
+impl FinPosition {
+    pub fn side(&self) -> Option<Direction> {
+        match self {
+            FinPosition::Pectoral(side) | FinPosition::Pelvic(side) => Some(*side),
+            FinPosition::Dorsal | FinPosition::Anal | FinPosition::Caudal => None,
+        }
+    }
+}
+
+impl Display for FinPosition {
+    // "Left Pectoral", "Caudal"
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FinPosition::Pectoral(side) => write!(f, "{:?} Pectoral", side),
+            FinPosition::Pelvic(side) => write!(f, "{:?} Pelvic", side),
+            FinPosition::Dorsal => write!(f, "Dorsal"),
+            FinPosition::Anal => write!(f, "Anal"),
+            FinPosition::Caudal => write!(f, "Caudal"),
+        }
+    }
+}
+
 impl Appendage {
+    pub fn kind(&self) -> AppendageKind {
+        match self {
+            Appendage::Arm { .. } => AppendageKind::Arm,
+            Appendage::Leg { .. } => AppendageKind::Leg,
+            Appendage::Wing { .. } => AppendageKind::Wing,
+            Appendage::Fin { .. } => AppendageKind::Fin,
+            Appendage::Antenna { .. } => AppendageKind::Antenna,
+            Appendage::Tentacle { .. } => AppendageKind::Tentacle,
+        }
+    }
+
+    pub fn status(&self) -> &LimbStatus {
+        match self {
+            Appendage::Arm { status, .. }
+            | Appendage::Leg { status, .. }
+            | Appendage::Wing { status, .. }
+            | Appendage::Fin { status, .. }
+            | Appendage::Antenna { status, .. }
+            | Appendage::Tentacle { status, .. } => status,
+        }
+    }
+
+    pub fn status_mut(&mut self) -> &mut LimbStatus {
+        match self {
+            Appendage::Arm { status, .. }
+            | Appendage::Leg { status, .. }
+            | Appendage::Wing { status, .. }
+            | Appendage::Fin { status, .. }
+            | Appendage::Antenna { status, .. }
+            | Appendage::Tentacle { status, .. } => status,
+        }
+    }
+
+    // `None` for appendages on the midline, such as a dorsal or tail fin.
+    pub fn side(&self) -> Option<Direction> {
+        match self {
+            Appendage::Arm { side, .. }
+            | Appendage::Leg { side, .. }
+            | Appendage::Wing { side, .. }
+            | Appendage::Antenna { side, .. }
+            | Appendage::Tentacle { side, .. } => Some(*side),
+            Appendage::Fin { position, .. } => position.side(),
+        }
+    }
+
     // This method creates a detailed string description of a single anatomy.
     pub fn description(&self) -> String {
         match self {
@@ -44,6 +188,32 @@ impl Appendage {
                     side, status, measurements.inseam.0, measurements.quad.0
                 )
             }
+            Appendage::Wing {
+                side,
+                measurements,
+                status,
+            } => {
+                format!(
+                    "\t- {:?} Wing ({:?}): Length: {}cm, Chord: {}cm",
+                    side, status, measurements.length.0, measurements.chord.0
+                )
+            }
+            Appendage::Fin {
+                position,
+                measurements,
+                status,
+            } => {
+                format!(
+                    "\t- {} Fin ({:?}): Length: {}cm, Base: {}cm",
+                    position, status, measurements.length.0, measurements.base.0
+                )
+            }
+            Appendage::Antenna { side, length, status } => {
+                format!("\t- {:?} Antenna ({:?}): Length: {}mm", side, status, length.0)
+            }
+            Appendage::Tentacle { side, length, status } => {
+                format!("\t- {:?} Tentacle ({:?}): Length: {}mm", side, status, length.0)
+            }
         }
     }
 }
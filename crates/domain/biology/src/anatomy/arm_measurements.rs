@@ -1,6 +1,7 @@
 use core_shared::Centimeters;
 
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArmMeasurements {
     pub upper_length: Centimeters,
     pub triceps_length: Centimeters,
@@ -20,3 +21,16 @@ impl Default for ArmMeasurements {
         }
     }
 }
+
+impl ArmMeasurements {
+    pub fn scaled(&self, factor: f64) -> ArmMeasurements {
+        let scale = |length: Centimeters| Centimeters((length.0 as f64 * factor).round() as u32);
+        ArmMeasurements {
+            upper_length: scale(self.upper_length),
+            triceps_length: scale(self.triceps_length),
+            span: scale(self.span),
+            flexed_bicep: scale(self.flexed_bicep),
+            unflexed_bicep: scale(self.unflexed_bicep),
+        }
+    }
+}
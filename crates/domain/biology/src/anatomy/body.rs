@@ -1,15 +1,19 @@
-use crate::Summarizable;
+use crate::{Nomenclature, Summarizable};
 
 pub struct Body<'a> {
     pub entity: &'a dyn Summarizable,
+    pub nomenclature: &'a dyn Nomenclature,
 }
 
 impl<'a> Body<'a> {
-    pub fn new(entity: &'a impl Summarizable) -> Self {
-        Self { entity: { entity } }
+    pub fn new<T: Summarizable + Nomenclature>(entity: &'a T) -> Self {
+        Self {
+            entity,
+            nomenclature: entity,
+        }
     }
 
     pub fn display_summary(&self) {
         println!("{}", self.entity.summary());
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,30 @@
+use core_shared::Centimeters;
+
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FinMeasurements {
+    // Base to tip, along the longest ray.
+    pub length: Centimeters,
+    // Where the fin joins the body, front to back.
+    pub base: Centimeters,
+}
+
+impl Default for FinMeasurements {
+    // A trout-sized pectoral fin.
+    fn default() -> Self {
+        Self {
+            length: Centimeters(4),
+            base: Centimeters(2),
+        }
+    }
+}
+
+impl FinMeasurements {
+    pub fn scaled(&self, factor: f64) -> FinMeasurements {
+        let scale = |length: Centimeters| Centimeters((length.0 as f64 * factor).round() as u32);
+        FinMeasurements {
+            length: scale(self.length),
+            base: scale(self.base),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use core_shared::Centimeters;
 
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LegMeasurements {
     pub inseam: Centimeters,
     pub quad: Centimeters,
@@ -14,3 +15,13 @@ impl Default for LegMeasurements {
         }
     }
 }
+
+impl LegMeasurements {
+    pub fn scaled(&self, factor: f64) -> LegMeasurements {
+        let scale = |length: Centimeters| Centimeters((length.0 as f64 * factor).round() as u32);
+        LegMeasurements {
+            inseam: scale(self.inseam),
+            quad: scale(self.quad),
+        }
+    }
+}
@@ -1,8 +1,18 @@
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
 pub enum LimbStatus {
     #[default]
     Intact,
     Injured(String),
     Amputated,
     Prosthetic,
+    // Never formed; a congenital limb difference.
+    Absent,
+}
+
+impl LimbStatus {
+    // Amputated and absent limbs are missing; injured and prosthetic ones are attached.
+    pub fn is_missing(&self) -> bool {
+        matches!(self, LimbStatus::Amputated | LimbStatus::Absent)
+    }
 }
@@ -1,5 +1,7 @@
 pub mod body;
 pub mod appendage;
 pub mod arm_measurements;
+pub mod fin_measurements;
 pub mod limb_status;
-pub mod leg_measurements;
\ No newline at end of file
+pub mod leg_measurements;
+pub mod wing_measurements;
@@ -0,0 +1,38 @@
+use core_shared::Centimeters;
+
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WingMeasurements {
+    // Shoulder to wingtip, for one wing.
+    pub length: Centimeters,
+    // Leading to trailing edge, at the widest point.
+    pub chord: Centimeters,
+}
+
+impl Default for WingMeasurements {
+    // A pigeon-sized wing.
+    fn default() -> Self {
+        Self {
+            length: Centimeters(32),
+            chord: Centimeters(12),
+        }
+    }
+}
+
+impl WingMeasurements {
+    pub fn scaled(&self, factor: f64) -> WingMeasurements {
+        let scale = |length: Centimeters| Centimeters((length.0 as f64 * factor).round() as u32);
+        WingMeasurements {
+            length: scale(self.length),
+            chord: scale(self.chord),
+        }
+    }
+
+    // Length over chord; long, narrow wings (albatrosses) soar, short, broad ones (pheasants) burst.
+    pub fn aspect_ratio(&self) -> f64 {
+        if self.chord.0 == 0 {
+            return 0.0;
+        }
+        self.length.0 as f64 / self.chord.0 as f64
+    }
+}
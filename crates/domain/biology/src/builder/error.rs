@@ -0,0 +1,54 @@
+use std::fmt::{Display, Formatter};
+
+/// One thing wrong with a builder's settings.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuildProblem {
+    /// The species name is empty.
+    MissingSpecies,
+    /// A name was set but is blank; holds which one, e.g. "common name".
+    BlankName(&'static str),
+    /// Vitals no living organism could have, e.g. a diastolic pressure above the systolic.
+    ImplausibleVitals(String),
+    /// A field set by a type's own builder fails that type's checks.
+    Invalid {
+        field: &'static str,
+        reason: String,
+    },
+}
+
+impl Display for BuildProblem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildProblem::MissingSpecies => write!(f, "no species given"),
+            BuildProblem::BlankName(which) => write!(f, "the {} is blank", which),
+            BuildProblem::ImplausibleVitals(reason) => write!(f, "implausible vitals: {}", reason),
+            BuildProblem::Invalid { field, reason } => write!(f, "invalid {}: {}", field, reason),
+        }
+    }
+}
+
+/// Every problem found when a builder was built; never empty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuildError {
+    pub problems: Vec<BuildProblem>,
+}
+
+impl BuildError {
+    // Ok if nothing is wrong, otherwise an error listing it all.
+    pub fn check(problems: Vec<BuildProblem>) -> Result<(), BuildError> {
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(BuildError { problems })
+        }
+    }
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let problems: Vec<String> = self.problems.iter().map(|problem| problem.to_string()).collect();
+        write!(f, "cannot build: {}", problems.join("; "))
+    }
+}
+
+impl std::error::Error for BuildError {}
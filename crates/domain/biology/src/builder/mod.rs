@@ -0,0 +1,16 @@
+//! Building organisms of any kingdom with validation.
+//!
+//! - `organism_builder` → `OrganismBuilder`, the settings every organism
+//!   shares, `OrganismBuilderExt`, its setters for builders that extend
+//!   it, and `OrganismParts`, what it builds
+//! - `error` → `BuildError`, listing every `BuildProblem` found
+//!
+//! Kingdom crates wrap an `OrganismBuilder` in a builder of their own
+//! (`Mammal::builder`, `Plant::builder` and so on for every type) that adds
+//! the type's fields and checks. See the module README.md for detailed guidance.
+
+pub mod error;
+pub mod organism_builder;
+
+pub use error::{BuildError, BuildProblem};
+pub use organism_builder::{vital_problems, OrganismBuilder, OrganismBuilderExt, OrganismParts};
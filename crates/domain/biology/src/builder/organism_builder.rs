@@ -0,0 +1,231 @@
+use core_shared::{Identifier, Kilograms, MetersPerSecond};
+use crate::anatomy::appendage::{Appendage, AppendageKind};
+use crate::builder::error::{BuildError, BuildProblem};
+use crate::classification::sex::Sex;
+use crate::ecosystem::habitat::HabitatPreference;
+use crate::patterns::structural_patterns::AnatomyAnalyzer;
+use crate::templates::anatomy::AnatomyTemplate;
+use crate::vitals::live_vitals::LiveVitals;
+use crate::vitals::vital_status::VitalStatus;
+
+/// The settings every organism shares, whatever its kingdom.
+///
+/// On its own it builds `OrganismParts`; organism types wrap it in a
+/// builder of their own, which gets the shared setters by implementing
+/// `OrganismBuilderExt`, adds its own fields and checks, and assembles
+/// the organism from the parts.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let parts = OrganismBuilder::new("Vulpes vulpes")
+///     .common_name("Red fox")
+///     .sex(Sex::Female)
+///     .habitat(HabitatPreference::on(&[Terrain::Forest, Terrain::Plains]))
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct OrganismBuilder {
+    id: Identifier,
+    species: String,
+    common_name: Option<String>,
+    name: Option<String>,
+    sex: Sex,
+    vitals: VitalStatus,
+    body_mass: Option<Kilograms>,
+    top_speed: Option<MetersPerSecond>,
+    appendages: Vec<Appendage>,
+    habitat: Option<HabitatPreference>,
+}
+
+/// What an `OrganismBuilder` builds: the shared fields, checked and ready to move into an organism.
+#[derive(Debug, Clone)]
+pub struct OrganismParts {
+    pub id: Identifier,
+    pub species: String,
+    pub common_name: Option<String>,
+    pub name: Option<String>,
+    pub sex: Sex,
+    pub vitals: VitalStatus,
+    pub body_mass: Option<Kilograms>,
+    pub top_speed: Option<MetersPerSecond>,
+    pub appendages: Vec<Appendage>,
+    pub habitat: Option<HabitatPreference>,
+}
+
+impl OrganismBuilder {
+    pub fn new(species: &str) -> Self {
+        Self {
+            id: Identifier::new(),
+            species: species.to_string(),
+            common_name: None,
+            name: None,
+            sex: Sex::Unknown,
+            vitals: VitalStatus::Alive(LiveVitals::default()),
+            body_mass: None,
+            top_speed: None,
+            appendages: Vec::new(),
+            habitat: None,
+        }
+    }
+
+    pub fn species(&self) -> &str {
+        &self.species
+    }
+
+    // The vitals set so far, for extending builders' own checks.
+    pub fn vital_status(&self) -> &VitalStatus {
+        &self.vitals
+    }
+
+    // Whether a common name was set, for types that keep none.
+    pub fn has_common_name(&self) -> bool {
+        self.common_name.is_some()
+    }
+
+    // Everything wrong with the shared settings; extending builders add their own.
+    pub fn problems(&self) -> Vec<BuildProblem> {
+        let mut problems = Vec::new();
+        if self.species.trim().is_empty() {
+            problems.push(BuildProblem::MissingSpecies);
+        }
+        for (which, name) in [("common name", &self.common_name), ("name", &self.name)] {
+            if name.as_ref().is_some_and(|name| name.trim().is_empty()) {
+                problems.push(BuildProblem::BlankName(which));
+            }
+        }
+        problems.extend(vital_problems(&self.vitals));
+        problems
+    }
+
+    // Appendages of kinds the type never has, e.g. arms on a bird; `what` names the type.
+    pub fn unexpected_appendages(&self, allowed: &[AppendageKind], what: &str) -> Vec<BuildProblem> {
+        self.appendage_kinds()
+            .into_iter()
+            .filter(|kind| !allowed.contains(kind))
+            .map(|kind| BuildProblem::Invalid {
+                field: "appendages",
+                reason: format!("{} has no {}", what, kind.plural()),
+            })
+            .collect()
+    }
+
+    pub fn build(self) -> Result<OrganismParts, BuildError> {
+        BuildError::check(self.problems())?;
+        Ok(self.into_parts())
+    }
+
+    // The parts without any checks, for builders that have already run `problems`.
+    pub fn into_parts(self) -> OrganismParts {
+        OrganismParts {
+            id: self.id,
+            species: self.species,
+            common_name: self.common_name,
+            name: self.name,
+            sex: self.sex,
+            vitals: self.vitals,
+            body_mass: self.body_mass,
+            top_speed: self.top_speed,
+            appendages: self.appendages,
+            habitat: self.habitat,
+        }
+    }
+}
+
+// Readings no living organism could have, whatever its species.
+pub fn vital_problems(vitals: &VitalStatus) -> Vec<BuildProblem> {
+    let VitalStatus::Alive(vitals) = vitals else {
+        return Vec::new();
+    };
+    let (systolic, diastolic) = (vitals.blood_pressure.0, vitals.blood_pressure.1);
+    let mut problems = Vec::new();
+    if (systolic == 0) != (diastolic == 0) {
+        problems.push(BuildProblem::ImplausibleVitals(format!(
+            "blood pressure {}/{} is missing a reading",
+            systolic, diastolic
+        )));
+    } else if diastolic >= systolic && systolic > 0 {
+        problems.push(BuildProblem::ImplausibleVitals(format!(
+            "diastolic pressure {} is not below systolic {}",
+            diastolic, systolic
+        )));
+    }
+    problems
+}
+
+// Lets extending builders check the body plan so far, e.g. count its wings.
+impl AnatomyAnalyzer for OrganismBuilder {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
+    }
+}
+
+/// The shared setters, for `OrganismBuilder` and every builder that wraps one.
+///
+/// Implementors only say where their `OrganismBuilder` is.
+pub trait OrganismBuilderExt: Sized {
+    fn organism_builder(&mut self) -> &mut OrganismBuilder;
+
+    // Keeps an existing identity, e.g. when rebuilding a saved organism.
+    fn id(mut self, id: Identifier) -> Self {
+        self.organism_builder().id = id;
+        self
+    }
+
+    fn common_name(mut self, common_name: &str) -> Self {
+        self.organism_builder().common_name = Some(common_name.to_string());
+        self
+    }
+
+    fn name(mut self, name: &str) -> Self {
+        self.organism_builder().name = Some(name.to_string());
+        self
+    }
+
+    fn sex(mut self, sex: Sex) -> Self {
+        self.organism_builder().sex = sex;
+        self
+    }
+
+    fn vitals(mut self, vitals: VitalStatus) -> Self {
+        self.organism_builder().vitals = vitals;
+        self
+    }
+
+    fn body_mass(mut self, body_mass: Kilograms) -> Self {
+        self.organism_builder().body_mass = Some(body_mass);
+        self
+    }
+
+    fn top_speed(mut self, top_speed: MetersPerSecond) -> Self {
+        self.organism_builder().top_speed = Some(top_speed);
+        self
+    }
+
+    // Replaces the appendages with the template's two arms and two legs.
+    fn anatomy_template(mut self, template: &AnatomyTemplate) -> Self {
+        self.organism_builder().appendages = template.appendages();
+        self
+    }
+
+    fn appendages(mut self, appendages: Vec<Appendage>) -> Self {
+        self.organism_builder().appendages = appendages;
+        self
+    }
+
+    fn appendage(mut self, appendage: Appendage) -> Self {
+        self.organism_builder().appendages.push(appendage);
+        self
+    }
+
+    fn habitat(mut self, habitat: HabitatPreference) -> Self {
+        self.organism_builder().habitat = Some(habitat);
+        self
+    }
+}
+
+impl OrganismBuilderExt for OrganismBuilder {
+    fn organism_builder(&mut self) -> &mut OrganismBuilder {
+        self
+    }
+}
@@ -20,4 +20,28 @@
 /// ```
 pub trait Mobility {
     fn describe_locomotion(&self) -> String;
+
+    // The medium the organism moves through, used to cost movement across terrain.
+    fn locomotion(&self) -> Locomotion {
+        Locomotion::Terrestrial
+    }
+}
+
+/// How an organism gets from place to place.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Locomotion {
+    // Walks, runs or climbs over land.
+    #[default]
+    Terrestrial,
+    // Swims; cannot leave the water.
+    Aquatic,
+    // Flies over any terrain.
+    Aerial,
+    // At home both in shallow water and on land.
+    Amphibious,
+    // Burrows through soil.
+    Fossorial,
+    // Fixed in one place.
+    Sessile,
 }
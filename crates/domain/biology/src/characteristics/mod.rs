@@ -4,16 +4,19 @@
 //! Files are organized by domain concept, not trait name:
 //!
 //! - `structural` → `Anatomy` trait
-//! - `locomotion` → `Mobility` trait
+//! - `locomotion` → `Mobility` trait and the `Locomotion` modes
 //! - `presentation` → `Summarizable` trait
+//! - `organism` → `Organism` trait
 //!
 //! See the module README.md for detailed guidance.
 
 pub mod structural;
 pub mod locomotion;
 pub mod presentation;
+pub mod organism;
 
 // Re-export the main traits for convenience
 pub use structural::Anatomy;
-pub use locomotion::Mobility;
+pub use locomotion::{Locomotion, Mobility};
 pub use presentation::Summarizable;
+pub use organism::Organism;
@@ -0,0 +1,91 @@
+//! Identity characteristics of organisms.
+//!
+//! This module defines the trait that lets a population own and look up
+//! organisms of any concrete type.
+
+use std::any::Any;
+use core_shared::{Identifier, Kilograms, MetersPerSecond};
+use crate::ecosystem::environment::Environment;
+use crate::genetics::genome::Genome;
+use crate::vitals::post_mortem_report::PostMortemReport;
+use crate::vitals::vital_status::VitalStatus;
+use crate::vitals::vital_types::Celsius;
+use crate::characteristics::locomotion::Mobility;
+use crate::{Nomenclature, Sex, Summarizable};
+
+/// An individual organism that can live in a population.
+///
+/// This trait combines identity (`id`), classification (`species`, `sex`) and
+/// state (`vitals`) with the naming, presentation and mobility traits every
+/// organism already implements. Populations store members as `Box<dyn Organism>`;
+/// because `Any` is a supertrait, a member can be downcast back to its
+/// concrete type when needed.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use biology::characteristics::organism::Organism;
+///
+/// impl Organism for Animal {
+///     fn id(&self) -> Identifier { self.id }
+///     fn species(&self) -> String { self.species.clone() }
+///     fn vitals(&self) -> &VitalStatus { &self.vitals }
+///     fn vitals_mut(&mut self) -> &mut VitalStatus { &mut self.vitals }
+/// }
+///
+/// let member: &dyn Organism = population.get(id).unwrap();
+/// let animal = (member as &dyn Any).downcast_ref::<Animal>();
+/// ```
+pub trait Organism: Any + Nomenclature + Summarizable + Mobility {
+    fn id(&self) -> Identifier;
+    fn species(&self) -> String;
+    fn vitals(&self) -> &VitalStatus;
+    fn vitals_mut(&mut self) -> &mut VitalStatus;
+
+    fn sex(&self) -> Sex {
+        Sex::Unknown
+    }
+
+    // Physical measures used by simulation rules; `None` when not recorded.
+    fn body_mass(&self) -> Option<Kilograms> {
+        None
+    }
+
+    fn top_speed(&self) -> Option<MetersPerSecond> {
+        None
+    }
+
+    // The typical lifespan of the organism's species in days, if known; `Mortality` prefers it to its own.
+    fn lifespan(&self) -> Option<u32> {
+        None
+    }
+
+    // The organism's genes, if it carries a modelled genome.
+    fn genome(&self) -> Option<&Genome> {
+        None
+    }
+
+    // Whether the organism can live in the environment at all; fish check the water's salinity.
+    fn tolerates(&self, _environment: &Environment) -> bool {
+        true
+    }
+
+    // Whether the organism makes its own food, like a plant; producers are the base of a food web.
+    fn is_producer(&self) -> bool {
+        false
+    }
+
+    // An ectotherm's body temperature in the environment; `None` for animals that make their own heat.
+    fn body_temperature_in(&self, _environment: &Environment) -> Option<Celsius> {
+        None
+    }
+
+    fn is_alive(&self) -> bool {
+        matches!(self.vitals(), VitalStatus::Alive(_))
+    }
+
+    // Marks the organism deceased; a human also closes its medical record with the report.
+    fn record_death(&mut self, report: PostMortemReport) {
+        *self.vitals_mut() = VitalStatus::Deceased(report);
+    }
+}
@@ -1,4 +1,5 @@
 #![allow(dead_code)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
 pub enum Kingdom {
     Animalia,
     Plantae,
@@ -14,3 +15,8 @@ pub struct Species {
     pub name: &'static str,
     pub scientific_name: &'static str,
 }
+
+// True if `name` is the species itself or its genus: "Canis" covers "Canis latrans".
+pub(crate) fn covers(name: &str, species: &str) -> bool {
+    species == name || species.strip_prefix(name).is_some_and(|rest| rest.starts_with(' '))
+}
@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+use crate::ecosystem::environment::Environment;
+use crate::ecosystem::population::Population;
+use crate::ecosystem::resources::ResourceKind;
+
+/// What each member consumes per tick by default: the `Competition` rule's demand.
+pub const DEFAULT_NEEDS: [(ResourceKind, f64); 2] = [(ResourceKind::FoodBiomass, 1.0), (ResourceKind::Water, 1.0)];
+
+/// How many members an environment can sustain indefinitely.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CarryingCapacity {
+    pub members: f64,
+    // The resource that runs out first.
+    pub limiting: ResourceKind,
+}
+
+impl CarryingCapacity {
+    /// Estimates K from the renewable supply of each needed resource.
+    ///
+    /// A pool can sustain as many members as its regeneration per tick
+    /// covers, each taking `need` of it; the scarcest resource sets the
+    /// capacity. While the `Weather` rule runs, regeneration is scaled by
+    /// the current rainfall (water) and growing conditions (food biomass).
+    /// `None` when nothing is needed.
+    pub fn estimate(environment: &Environment, needs: &[(ResourceKind, f64)]) -> Option<Self> {
+        Self::limit(&supply(environment), needs)
+    }
+
+    // The population's environment, or the sum over its landscape's cells if it has one.
+    pub fn of_population(population: &Population, needs: &[(ResourceKind, f64)]) -> Option<Self> {
+        let Some(landscape) = population.landscape() else {
+            return Self::estimate(&population.environment, needs);
+        };
+
+        let mut total = BTreeMap::new();
+        for (_, cell) in landscape.cells() {
+            for (kind, amount) in supply(cell) {
+                *total.entry(kind).or_insert(0.0) += amount;
+            }
+        }
+        Self::limit(&total, needs)
+    }
+
+    fn limit(supply: &BTreeMap<ResourceKind, f64>, needs: &[(ResourceKind, f64)]) -> Option<Self> {
+        needs
+            .iter()
+            .filter(|(_, need)| *need > 0.0)
+            .map(|&(kind, need)| CarryingCapacity {
+                members: supply.get(&kind).copied().unwrap_or(0.0) / need,
+                limiting: kind,
+            })
+            .min_by(|a, b| a.members.total_cmp(&b.members))
+    }
+}
+
+// Sustainable yield per tick of each resource.
+fn supply(environment: &Environment) -> BTreeMap<ResourceKind, f64> {
+    environment
+        .resources
+        .iter()
+        .map(|(kind, pool)| {
+            let factor = match (kind, environment.weather) {
+                (ResourceKind::Water, Some(weather)) => weather.wetness().min(3.0),
+                (ResourceKind::FoodBiomass, Some(weather)) => weather.growth(),
+                _ => 1.0,
+            };
+            (kind, pool.regeneration * factor)
+        })
+        .collect()
+}
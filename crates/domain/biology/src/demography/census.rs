@@ -0,0 +1,122 @@
+use core_shared::Identifier;
+use crate::Sex;
+use crate::demography::life_table::{LifeTable, Lifespan};
+use crate::demography::pyramid::AgePyramid;
+use crate::ecosystem::population::Population;
+use crate::simulation::engine::Simulation;
+
+/// One member as counted in a census.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CensusEntry {
+    pub id: Identifier,
+    pub species: String,
+    pub sex: Sex,
+    // Age in ticks; for the dead, their age at death. `None` when unknown.
+    pub age: Option<u32>,
+    pub alive: bool,
+}
+
+/// A snapshot of who is in a population, the dead included.
+///
+/// Organisms do not record their own age, so a census either takes ages
+/// from a `Simulation` (which tracks them per member) or from the caller.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Census {
+    entries: Vec<CensusEntry>,
+}
+
+impl Census {
+    pub fn of_population(population: &Population, age_of: impl Fn(Identifier) -> Option<u32>) -> Self {
+        let entries = population
+            .members()
+            .map(|member| CensusEntry {
+                id: member.id(),
+                species: member.species(),
+                sex: member.sex(),
+                age: age_of(member.id()),
+                alive: member.is_alive(),
+            })
+            .collect();
+        Self { entries }
+    }
+
+    // Ages come from the simulation; members stop ageing when they die.
+    pub fn of_simulation(simulation: &Simulation) -> Self {
+        Self::of_population(simulation.population(), |id| simulation.age(id))
+    }
+
+    pub fn of_species(&self, species: &str) -> Census {
+        Census {
+            entries: self
+                .entries
+                .iter()
+                .filter(|entry| entry.species == species)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub fn entries(&self) -> &[CensusEntry] {
+        &self.entries
+    }
+
+    pub fn size(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn living(&self) -> usize {
+        self.entries.iter().filter(|entry| entry.alive).count()
+    }
+
+    pub fn count_living(&self, sex: Sex) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.alive && entry.sex == sex)
+            .count()
+    }
+
+    // Living males per living female; `None` without females.
+    pub fn sex_ratio(&self) -> Option<f64> {
+        let females = self.count_living(Sex::Female);
+        (females > 0).then(|| self.count_living(Sex::Male) as f64 / females as f64)
+    }
+
+    // Mean age of the living members whose age is known.
+    pub fn mean_age(&self) -> Option<f64> {
+        let ages: Vec<u32> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.alive)
+            .filter_map(|entry| entry.age)
+            .collect();
+        (!ages.is_empty()).then(|| ages.iter().map(|&age| age as f64).sum::<f64>() / ages.len() as f64)
+    }
+
+    // The living, by age band and sex.
+    pub fn pyramid(&self, band_width: u32) -> AgePyramid {
+        let mut pyramid = AgePyramid::new(band_width);
+        for entry in self.entries.iter().filter(|entry| entry.alive) {
+            if let Some(age) = entry.age {
+                pyramid.add(age, entry.sex);
+            }
+        }
+        pyramid
+    }
+
+    // Everyone whose age is known: the dead at their age of death, the living as still at risk.
+    pub fn life_table(&self, interval: u32) -> LifeTable {
+        let lifespans: Vec<Lifespan> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                Some(Lifespan {
+                    age: entry.age?,
+                    died: !entry.alive,
+                })
+            })
+            .collect();
+        LifeTable::from_lifespans(&lifespans, interval)
+    }
+}
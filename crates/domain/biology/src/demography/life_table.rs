@@ -0,0 +1,108 @@
+/// How long one member lived, or has lived so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lifespan {
+    pub age: u32,
+    // False for members still alive, whose full lifespan is not yet known.
+    pub died: bool,
+}
+
+/// One age interval of a life table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LifeTableRow {
+    // Start of the interval.
+    pub age: u32,
+    // Members who reached this age.
+    pub at_risk: usize,
+    // Members who died during the interval.
+    pub deaths: usize,
+    // Members still alive whose age falls in the interval.
+    pub censored: usize,
+    // q(x): the chance of dying during the interval, having reached it.
+    pub mortality: f64,
+    // l(x): the share of newborns expected to reach this age.
+    pub survivorship: f64,
+    // e(x): the ticks a member of this age can expect to live on.
+    pub life_expectancy: f64,
+}
+
+/// A cohort life table, built from lifespans.
+///
+/// Members still alive count as at risk up to their current age and then
+/// drop out (actuarial censoring: they count for half the interval they are
+/// in), so a table can be built mid-run without treating the living as dead.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LifeTable {
+    interval: u32,
+    rows: Vec<LifeTableRow>,
+}
+
+impl LifeTable {
+    pub fn from_lifespans(lifespans: &[Lifespan], interval: u32) -> Self {
+        let interval = interval.max(1);
+        let oldest = lifespans.iter().map(|lifespan| lifespan.age).max();
+        let Some(oldest) = oldest else {
+            return Self { interval, rows: Vec::new() };
+        };
+
+        let mut rows = Vec::new();
+        let mut survivorship = 1.0;
+        for age in (0..=oldest).step_by(interval as usize) {
+            let within = |lifespan: &&Lifespan| lifespan.age >= age && lifespan.age < age.saturating_add(interval);
+            let at_risk = lifespans.iter().filter(|lifespan| lifespan.age >= age).count();
+            let deaths = lifespans.iter().filter(within).filter(|lifespan| lifespan.died).count();
+            let censored = lifespans.iter().filter(within).filter(|lifespan| !lifespan.died).count();
+
+            let exposed = at_risk as f64 - censored as f64 / 2.0;
+            let mortality = if exposed > 0.0 { (deaths as f64 / exposed).min(1.0) } else { 0.0 };
+            rows.push(LifeTableRow {
+                age,
+                at_risk,
+                deaths,
+                censored,
+                mortality,
+                survivorship,
+                life_expectancy: 0.0,
+            });
+            survivorship *= 1.0 - mortality;
+        }
+
+        // e(x) = T(x) / l(x), where T sums the ticks lived from x on, assuming
+        // deaths are spread evenly through each interval.
+        let mut remaining = 0.0;
+        for row in rows.iter_mut().rev() {
+            let next = row.survivorship * (1.0 - row.mortality);
+            remaining += interval as f64 * (row.survivorship + next) / 2.0;
+            row.life_expectancy = if row.survivorship > 0.0 { remaining / row.survivorship } else { 0.0 };
+        }
+
+        Self { interval, rows }
+    }
+
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    pub fn rows(&self) -> &[LifeTableRow] {
+        &self.rows
+    }
+
+    // l(x) at the start of every interval: the survival curve.
+    pub fn survival_curve(&self) -> Vec<(u32, f64)> {
+        self.rows.iter().map(|row| (row.age, row.survivorship)).collect()
+    }
+
+    // Expected lifespan of a newborn, in ticks.
+    pub fn life_expectancy(&self) -> f64 {
+        self.rows.first().map_or(0.0, |row| row.life_expectancy)
+    }
+
+    // The age, rounded up to an interval, by which half of newborns have died.
+    pub fn median_lifespan(&self) -> Option<u32> {
+        self.rows
+            .iter()
+            .find(|row| row.survivorship * (1.0 - row.mortality) <= 0.5)
+            .map(|row| row.age + self.interval)
+    }
+}
@@ -0,0 +1,78 @@
+/// Logistic growth towards a carrying capacity:
+///
+/// ```text
+/// dN/dt = growth_rate * N * (1 - N / capacity)
+/// ```
+///
+/// Small populations grow almost exponentially; growth slows as they near
+/// `capacity` and stops there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Logistic {
+    pub growth_rate: f64,
+    pub capacity: f64,
+}
+
+impl Logistic {
+    pub fn new(growth_rate: f64, capacity: f64) -> Self {
+        Self { growth_rate, capacity }
+    }
+
+    pub fn rate(&self, size: f64) -> f64 {
+        self.growth_rate * size * (1.0 - size / self.capacity)
+    }
+
+    // The exact size after `time`, starting from `initial`.
+    pub fn size_at(&self, initial: f64, time: f64) -> f64 {
+        if initial <= 0.0 {
+            return 0.0;
+        }
+        let k = self.capacity;
+        k / (1.0 + (k - initial) / initial * (-self.growth_rate * time).exp())
+    }
+
+    // `steps + 1` sizes, `dt` apart, starting with `initial`.
+    pub fn trajectory(&self, initial: f64, dt: f64, steps: usize) -> Vec<f64> {
+        (0..=steps).map(|step| self.size_at(initial, step as f64 * dt)).collect()
+    }
+
+    // How long it takes to grow from `initial` to `target`; `None` if it never gets there.
+    pub fn time_to(&self, initial: f64, target: f64) -> Option<f64> {
+        let k = self.capacity;
+        if initial <= 0.0 || target <= 0.0 || target >= k || self.growth_rate <= 0.0 {
+            return None;
+        }
+        let time = ((target * (k - initial)) / (initial * (k - target))).ln() / self.growth_rate;
+        (time >= 0.0).then_some(time)
+    }
+
+    /// Fits the model to sizes counted every `dt`, e.g. after each tick.
+    ///
+    /// Per-capita growth falls in a straight line as a logistic population
+    /// grows, from `growth_rate` at zero to nothing at `capacity`, so a
+    /// least-squares line through `(N, ΔN / N / dt)` gives both parameters.
+    /// `None` if the counts do not show growth slowing with size.
+    pub fn fit(sizes: &[f64], dt: f64) -> Option<Self> {
+        let points: Vec<(f64, f64)> = sizes
+            .windows(2)
+            .filter(|pair| pair[0] > 0.0)
+            .map(|pair| (pair[0], (pair[1] - pair[0]) / pair[0] / dt))
+            .collect();
+        if points.len() < 2 {
+            return None;
+        }
+
+        let n = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let covariance: f64 = points.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+        let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+        if variance <= 0.0 {
+            return None;
+        }
+
+        let slope = covariance / variance;
+        let growth_rate = mean_y - slope * mean_x;
+        (growth_rate > 0.0 && slope < 0.0).then(|| Self::new(growth_rate, -growth_rate / slope))
+    }
+}
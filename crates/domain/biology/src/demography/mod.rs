@@ -0,0 +1,25 @@
+//! Population statistics: who is alive, how fast they are born and die,
+//! and how many the environment can hold.
+//!
+//! - `census` → `Census` snapshots of a population or a simulation run
+//! - `pyramid` → `AgePyramid` counts by age band and `Sex`
+//! - `life_table` → `LifeTable`, survival curves and life expectancy
+//! - `rates` → `PeriodRates`: births and deaths per period of a run
+//! - `capacity` → `CarryingCapacity` estimated from environment resources
+//! - `logistic` → the `Logistic` growth model, and fitting it to counts
+//!
+//! See the module README.md for detailed guidance.
+
+pub mod capacity;
+pub mod census;
+pub mod life_table;
+pub mod logistic;
+pub mod pyramid;
+pub mod rates;
+
+pub use capacity::{CarryingCapacity, DEFAULT_NEEDS};
+pub use census::{Census, CensusEntry};
+pub use life_table::{LifeTable, LifeTableRow, Lifespan};
+pub use logistic::Logistic;
+pub use pyramid::{AgeBand, AgePyramid};
+pub use rates::PeriodRates;
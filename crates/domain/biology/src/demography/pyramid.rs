@@ -0,0 +1,111 @@
+use std::fmt::Write;
+use crate::Sex;
+
+/// Members of one age band, by sex.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AgeBand {
+    // Ages `from` up to, but not including, `to`.
+    pub from: u32,
+    pub to: u32,
+    pub male: usize,
+    pub female: usize,
+    pub unknown: usize,
+}
+
+impl AgeBand {
+    pub fn total(&self) -> usize {
+        self.male + self.female + self.unknown
+    }
+}
+
+/// Counts by age band and sex, youngest band first.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AgePyramid {
+    band_width: u32,
+    bands: Vec<AgeBand>,
+}
+
+impl AgePyramid {
+    pub fn new(band_width: u32) -> Self {
+        Self {
+            band_width: band_width.max(1),
+            bands: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, age: u32, sex: Sex) {
+        let index = (age / self.band_width) as usize;
+        while self.bands.len() <= index {
+            let from = self.bands.len() as u32 * self.band_width;
+            self.bands.push(AgeBand {
+                from,
+                to: from + self.band_width,
+                ..AgeBand::default()
+            });
+        }
+
+        let band = &mut self.bands[index];
+        match sex {
+            Sex::Male => band.male += 1,
+            Sex::Female => band.female += 1,
+            Sex::Unknown => band.unknown += 1,
+        }
+    }
+
+    pub fn band_width(&self) -> u32 {
+        self.band_width
+    }
+
+    pub fn bands(&self) -> &[AgeBand] {
+        &self.bands
+    }
+
+    pub fn total(&self) -> usize {
+        self.bands.iter().map(AgeBand::total).sum()
+    }
+
+    /// A text chart, oldest band at the top, males left and females right.
+    ///
+    /// The fullest side of any band is `width` characters; members of unknown
+    /// sex are counted after the bars.
+    ///
+    /// ```text
+    ///  20-29      ##|#
+    ///  10-19    ####|###
+    ///   0-9   ######|#####  (+2)
+    /// ```
+    pub fn render(&self, width: usize) -> String {
+        let widest = self
+            .bands
+            .iter()
+            .map(|band| band.male.max(band.female))
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let bar = |count: usize| "#".repeat((count * width).div_ceil(widest));
+        let label_width = self
+            .bands
+            .last()
+            .map_or(0, |band| format!("{}-{}", band.from, band.to - 1).len());
+
+        let mut chart = String::new();
+        for band in self.bands.iter().rev() {
+            let label = format!("{}-{}", band.from, band.to - 1);
+            let _ = write!(
+                chart,
+                "{:>label_width$} {:>width$}|{:<width$}",
+                label,
+                bar(band.male),
+                bar(band.female),
+            );
+            if band.unknown > 0 {
+                let _ = write!(chart, "  (+{})", band.unknown);
+            }
+            chart = chart.trim_end().to_string();
+            chart.push('\n');
+        }
+        chart
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+use crate::simulation::events::{SimulationEvent, TickReport};
+
+/// Births, deaths and growth over a stretch of ticks.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeriodRates {
+    // First and last tick of the period, inclusive.
+    pub from_tick: u64,
+    pub to_tick: u64,
+    pub births: usize,
+    pub deaths: usize,
+    // Living members before the first tick and after the last.
+    pub start: usize,
+    pub end: usize,
+    // Deaths by `CauseOfDeath`, as displayed, e.g. "Starvation".
+    pub causes: BTreeMap<String, usize>,
+}
+
+impl PeriodRates {
+    /// Splits a run's reports into periods of `period` ticks (the last may be shorter).
+    ///
+    /// `initial` is the number of living members before the first report,
+    /// e.g. `Census::of_simulation(&simulation).living()` taken before running.
+    pub fn from_reports(reports: &[TickReport], initial: usize, period: u64) -> Vec<PeriodRates> {
+        let mut periods = Vec::new();
+        let mut start = initial;
+
+        for chunk in reports.chunks(period.max(1) as usize) {
+            let (Some(first), Some(last)) = (chunk.first(), chunk.last()) else {
+                continue;
+            };
+            let mut causes = BTreeMap::new();
+            for event in chunk.iter().flat_map(|report| &report.events) {
+                if let SimulationEvent::Died { cause, .. } = event {
+                    *causes.entry(cause.to_string()).or_insert(0) += 1;
+                }
+            }
+
+            periods.push(PeriodRates {
+                from_tick: first.tick,
+                to_tick: last.tick,
+                births: chunk.iter().map(TickReport::births).sum(),
+                deaths: chunk.iter().map(TickReport::deaths).sum(),
+                start,
+                end: last.alive,
+                causes,
+            });
+            start = last.alive;
+        }
+        periods
+    }
+
+    pub fn ticks(&self) -> u64 {
+        self.to_tick - self.from_tick + 1
+    }
+
+    // The average of the living at the start and end, which rates are relative to.
+    pub fn mean_size(&self) -> f64 {
+        (self.start + self.end) as f64 / 2.0
+    }
+
+    // Births per member per tick.
+    pub fn birth_rate(&self) -> f64 {
+        self.per_member_tick(self.births)
+    }
+
+    // Deaths per member per tick.
+    pub fn death_rate(&self) -> f64 {
+        self.per_member_tick(self.deaths)
+    }
+
+    // Change in size over the period, relative to the start; `None` from nothing.
+    pub fn growth(&self) -> Option<f64> {
+        (self.start > 0).then(|| (self.end as f64 - self.start as f64) / self.start as f64)
+    }
+
+    fn per_member_tick(&self, count: usize) -> f64 {
+        let exposure = self.mean_size() * self.ticks() as f64;
+        if exposure > 0.0 { count as f64 / exposure } else { 0.0 }
+    }
+}
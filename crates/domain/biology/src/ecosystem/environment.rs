@@ -1,15 +1,51 @@
 use core_shared::Centimeters;
+use crate::ecosystem::resources::Resources;
+use crate::ecosystem::weather::{ClimateNormals, Conditions};
 use crate::vitals::vital_types::Celsius;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Environment {
     pub climate: Climate,
     pub terrain: Terrain,
     pub altitude: Centimeters,
     pub humidity: f32,
+    pub resources: Resources,
+    // Current weather, kept up to date by the simulation's `Weather` rule.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub weather: Option<Conditions>,
+}
+
+impl Environment {
+    // Starts with the typical resources for the climate and terrain.
+    pub fn new(climate: Climate, terrain: Terrain, altitude: Centimeters, humidity: f32) -> Self {
+        let resources = Resources::for_habitat(&climate, &terrain);
+        Self {
+            climate,
+            terrain,
+            altitude,
+            humidity,
+            resources,
+            weather: None,
+        }
+    }
+
+    pub fn with_resources(mut self, resources: Resources) -> Self {
+        self.resources = resources;
+        self
+    }
+
+    // The air temperature now, in °C: the weather's while the `Weather` rule runs, otherwise the climate's yearly mean.
+    pub fn ambient_temperature(&self) -> f32 {
+        match &self.weather {
+            Some(conditions) => conditions.temperature,
+            None => ClimateNormals::for_climate(&self.climate).mean_temperature,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
 pub enum Climate {
     Tropical,
     Temperate,
@@ -21,7 +57,8 @@ pub enum Climate {
     },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
 pub enum Terrain {
     Forest,
     Plains,
@@ -33,11 +70,6 @@ pub enum Terrain {
 
 impl Default for Environment {
     fn default() -> Self {
-        Self {
-            climate: Climate::Temperate,
-            terrain: Terrain::Plains,
-            altitude: Centimeters(0),
-            humidity: 0.5,
-        }
+        Self::new(Climate::Temperate, Terrain::Plains, Centimeters(0), 0.5)
     }
 }
\ No newline at end of file
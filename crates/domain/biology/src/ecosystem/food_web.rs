@@ -0,0 +1,574 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write;
+use crate::ecosystem::interactions::Interaction;
+use crate::ecosystem::population::Population;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum LinkKind {
+    Predation,
+    Parasitism,
+    Competition,
+    Mutualism,
+}
+
+impl LinkKind {
+    // Feeding links carry energy from the resource to the consumer.
+    pub fn is_feeding(&self) -> bool {
+        matches!(self, LinkKind::Predation | LinkKind::Parasitism)
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            LinkKind::Predation => "predation",
+            LinkKind::Parasitism => "parasitism",
+            LinkKind::Competition => "competition",
+            LinkKind::Mutualism => "mutualism",
+        }
+    }
+}
+
+/// A link between two species, summed over every member-level interaction.
+///
+/// Feeding links point from the resource (prey, host) to the consumer
+/// (predator, parasite), the direction energy flows. Competition and
+/// mutualism are symmetric; `from` is simply the species seen first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Link {
+    pub from: String,
+    pub to: String,
+    pub kind: LinkKind,
+    // How many member-level interactions this link stands for.
+    pub weight: usize,
+}
+
+/// How much of the web collapses if a species disappears.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeystoneScore {
+    pub species: String,
+    // Consumers left with nothing to eat, counted through the whole cascade.
+    pub secondary_extinctions: usize,
+    // Feeding links in and out of the species.
+    pub degree: usize,
+}
+
+/// The species-level graph of a population's interactions.
+///
+/// Nodes are species, in the order their first member joined the
+/// population, so analyses and exports are stable from run to run.
+/// Species whose members make their own food (`Organism::is_producer`)
+/// are recorded as the web's producers.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FoodWeb {
+    species: Vec<String>,
+    links: Vec<Link>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    producers: Vec<String>,
+}
+
+impl FoodWeb {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_population(population: &Population) -> Self {
+        let mut web = Self::new();
+        for member in population.members() {
+            web.add_species(&member.species());
+            if member.is_producer() {
+                web.add_producer(&member.species());
+            }
+        }
+
+        for interaction in population.interactions() {
+            let (first, second) = interaction.participants();
+            let (Some(first), Some(second)) = (population.get(first), population.get(second)) else {
+                continue;
+            };
+            let (first, second) = (first.species(), second.species());
+            match interaction {
+                // Energy flows from the second participant (prey, host) to the first.
+                Interaction::Predation { .. } => web.add_link(&second, &first, LinkKind::Predation),
+                Interaction::Parasitism { .. } => web.add_link(&second, &first, LinkKind::Parasitism),
+                Interaction::Competition { .. } => web.add_link(&first, &second, LinkKind::Competition),
+                Interaction::Mutualism { .. } => web.add_link(&first, &second, LinkKind::Mutualism),
+            }
+        }
+        web
+    }
+
+    pub fn add_species(&mut self, species: &str) {
+        if !self.species.iter().any(|known| known == species) {
+            self.species.push(species.to_string());
+        }
+    }
+
+    pub fn add_producer(&mut self, species: &str) {
+        self.add_species(species);
+        if !self.producers.iter().any(|known| known == species) {
+            self.producers.push(species.to_string());
+        }
+    }
+
+    // Adds a link, or strengthens the existing one between the same species.
+    pub fn add_link(&mut self, from: &str, to: &str, kind: LinkKind) {
+        self.add_species(from);
+        self.add_species(to);
+
+        let symmetric = !kind.is_feeding();
+        let existing = self.links.iter_mut().find(|link| {
+            link.kind == kind
+                && ((link.from == from && link.to == to) || (symmetric && link.from == to && link.to == from))
+        });
+        match existing {
+            Some(link) => link.weight += 1,
+            None => self.links.push(Link {
+                from: from.to_string(),
+                to: to.to_string(),
+                kind,
+                weight: 1,
+            }),
+        }
+    }
+
+    pub fn species(&self) -> &[String] {
+        &self.species
+    }
+
+    pub fn links(&self) -> &[Link] {
+        &self.links
+    }
+
+    pub fn producers(&self) -> &[String] {
+        &self.producers
+    }
+
+    pub fn is_producer(&self, species: &str) -> bool {
+        self.producers.iter().any(|producer| producer == species)
+    }
+
+    // Species that eat (or live off) at least one producer: the herbivores.
+    pub fn primary_consumers(&self) -> Vec<&str> {
+        self.species
+            .iter()
+            .map(String::as_str)
+            .filter(|species| self.resources_of(species).iter().any(|resource| self.is_producer(resource)))
+            .collect()
+    }
+
+    // What a species eats (or lives off), excluding cannibalism.
+    pub fn resources_of(&self, species: &str) -> Vec<&str> {
+        self.feeding_links()
+            .filter(|link| link.to == species && link.from != species)
+            .map(|link| link.from.as_str())
+            .collect()
+    }
+
+    // What eats (or lives off) a species, excluding cannibalism.
+    pub fn consumers_of(&self, species: &str) -> Vec<&str> {
+        self.feeding_links()
+            .filter(|link| link.from == species && link.to != species)
+            .map(|link| link.to.as_str())
+            .collect()
+    }
+
+    /// Prey-averaged trophic level of every species.
+    ///
+    /// Species that eat nothing here (producers, or consumers whose food is
+    /// not modelled) are level 1; every other species sits one level above
+    /// the weighted average of what it eats. Cannibalism is ignored.
+    ///
+    /// Species that eat each other, directly or around a longer loop, are
+    /// solved together as a linear system. A closed loop, whose members eat
+    /// nothing from outside it, has no solution; its links are ignored like
+    /// cannibalism, so its members are level 1.
+    pub fn trophic_levels(&self) -> BTreeMap<String, f64> {
+        // diet[i]: (resource, weight) pairs for species i, cannibalism excluded.
+        let mut diet: Vec<Vec<(usize, f64)>> = vec![Vec::new(); self.species.len()];
+        for link in self.feeding_links().filter(|link| link.from != link.to) {
+            diet[self.index_of(&link.to)].push((self.index_of(&link.from), link.weight as f64));
+        }
+
+        let mut levels = vec![1.0; self.species.len()];
+        // Components come resources first, so what they eat from outside is already settled.
+        for component in strongly_connected(&diet) {
+            let position: HashMap<usize, usize> = component.iter().enumerate().map(|(row, &i)| (i, row)).collect();
+            let feeds_outside = component
+                .iter()
+                .any(|&i| diet[i].iter().any(|(resource, _)| !position.contains_key(resource)));
+            if !feeds_outside {
+                continue;
+            }
+
+            // level_i - Σ share_ij level_j (j inside) = 1 + Σ share_ij level_j (j outside)
+            let size = component.len();
+            let mut matrix = vec![vec![0.0; size + 1]; size];
+            for (row, &i) in component.iter().enumerate() {
+                let total: f64 = diet[i].iter().map(|(_, weight)| weight).sum();
+                matrix[row][row] = 1.0;
+                matrix[row][size] = 1.0;
+                for &(resource, weight) in &diet[i] {
+                    let share = weight / total;
+                    match position.get(&resource) {
+                        Some(&column) => matrix[row][column] -= share,
+                        None => matrix[row][size] += share * levels[resource],
+                    }
+                }
+            }
+            for (row, level) in solve(matrix).into_iter().enumerate() {
+                levels[component[row]] = level;
+            }
+        }
+
+        self.species.iter().cloned().zip(levels).collect()
+    }
+
+    /// Directed connectance: distinct feeding links over the species count squared.
+    pub fn connectance(&self) -> f64 {
+        if self.species.is_empty() {
+            return 0.0;
+        }
+        let links: BTreeSet<(&str, &str)> = self
+            .feeding_links()
+            .map(|link| (link.from.as_str(), link.to.as_str()))
+            .collect();
+        links.len() as f64 / (self.species.len() * self.species.len()) as f64
+    }
+
+    /// Every species ranked by the damage its loss would do.
+    ///
+    /// Removing a species starves any consumer that fed only on it, which
+    /// may in turn starve its own consumers; the score counts the whole
+    /// cascade. Ties are broken by the number of feeding links, then by name.
+    pub fn keystone_ranking(&self) -> Vec<KeystoneScore> {
+        let mut scores: Vec<KeystoneScore> = self
+            .species
+            .iter()
+            .map(|species| KeystoneScore {
+                species: species.clone(),
+                secondary_extinctions: self.secondary_extinctions(species),
+                degree: self
+                    .feeding_links()
+                    .filter(|link| (link.from == *species) != (link.to == *species))
+                    .count(),
+            })
+            .collect();
+
+        scores.sort_by(|a, b| {
+            b.secondary_extinctions
+                .cmp(&a.secondary_extinctions)
+                .then(b.degree.cmp(&a.degree))
+                .then(a.species.cmp(&b.species))
+        });
+        scores
+    }
+
+    // The species whose loss would trigger the largest cascade, if any would.
+    pub fn keystone_species(&self) -> Option<String> {
+        self.keystone_ranking()
+            .into_iter()
+            .next()
+            .filter(|score| score.secondary_extinctions > 0)
+            .map(|score| score.species)
+    }
+
+    /// Loops of mutualism between three or more species (A helps B helps C helps A).
+    ///
+    /// Returns one cycle per independent loop (a cycle basis of the mutualism
+    /// graph), each listed from its first species in web order.
+    pub fn mutualism_cycles(&self) -> Vec<Vec<String>> {
+        let mut neighbours: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+        for link in self.links.iter().filter(|link| link.kind == LinkKind::Mutualism) {
+            let (a, b) = (self.index_of(&link.from), self.index_of(&link.to));
+            if a != b {
+                neighbours.entry(a).or_default().insert(b);
+                neighbours.entry(b).or_default().insert(a);
+            }
+        }
+
+        let mut parent: HashMap<usize, usize> = HashMap::new();
+        let mut depth: HashMap<usize, usize> = HashMap::new();
+        let mut cycles = Vec::new();
+
+        for &root in neighbours.keys() {
+            if depth.contains_key(&root) {
+                continue;
+            }
+            depth.insert(root, 0);
+            let mut stack = vec![root];
+            while let Some(node) = stack.pop() {
+                for &next in &neighbours[&node] {
+                    if parent.get(&node) == Some(&next) {
+                        continue;
+                    }
+                    match depth.get(&next) {
+                        None => {
+                            parent.insert(next, node);
+                            depth.insert(next, depth[&node] + 1);
+                            stack.push(next);
+                        }
+                        // A non-tree edge closes a loop; record each once, from the deeper end.
+                        Some(&next_depth) if next_depth <= depth[&node] && parent.get(&next) != Some(&node) => {
+                            cycles.push(self.close_cycle(node, next, &parent, &depth));
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+
+        cycles.sort();
+        cycles.dedup();
+        cycles
+    }
+
+    /// Graphviz DOT source, e.g. for `dot -Tsvg food_web.dot -o food_web.svg`.
+    ///
+    /// Feeding links are solid arrows from resource to consumer, competition
+    /// is a dashed red line and mutualism a dotted green one. Nodes are
+    /// labelled with their trophic level and ranked by it.
+    pub fn to_dot(&self) -> String {
+        let levels = self.trophic_levels();
+        let mut dot = String::from("digraph food_web {\n    rankdir=BT;\n    node [shape=ellipse];\n");
+
+        for species in &self.species {
+            let level = levels[species];
+            let shape = if self.is_producer(species) { ", shape=box" } else { "" };
+            let _ = writeln!(
+                dot,
+                "    \"{}\" [label=\"{}\\nTL {:.2}\"{}];",
+                escape_dot(species),
+                escape_dot(species),
+                level,
+                shape
+            );
+        }
+        for link in &self.links {
+            let style = match link.kind {
+                LinkKind::Predation => "color=black",
+                LinkKind::Parasitism => "color=purple",
+                LinkKind::Competition => "dir=none, style=dashed, color=red",
+                LinkKind::Mutualism => "dir=none, style=dotted, color=darkgreen",
+            };
+            let _ = writeln!(
+                dot,
+                "    \"{}\" -> \"{}\" [label=\"{}\", penwidth={}, {}];",
+                escape_dot(&link.from),
+                escape_dot(&link.to),
+                link.kind.label(),
+                link.weight.min(8),
+                style
+            );
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// GraphML, for tools such as Gephi, yEd or Cytoscape.
+    pub fn to_graphml(&self) -> String {
+        let levels = self.trophic_levels();
+        let mut xml = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+            "  <key id=\"trophic_level\" for=\"node\" attr.name=\"trophic_level\" attr.type=\"double\"/>\n",
+            "  <key id=\"kind\" for=\"edge\" attr.name=\"kind\" attr.type=\"string\"/>\n",
+            "  <key id=\"weight\" for=\"edge\" attr.name=\"weight\" attr.type=\"int\"/>\n",
+            "  <graph id=\"food_web\" edgedefault=\"directed\">\n",
+        ));
+
+        for species in &self.species {
+            let _ = writeln!(
+                xml,
+                "    <node id=\"{}\"><data key=\"trophic_level\">{}</data></node>",
+                escape_xml(species),
+                levels[species]
+            );
+        }
+        for (index, link) in self.links.iter().enumerate() {
+            let _ = writeln!(
+                xml,
+                "    <edge id=\"e{}\" source=\"{}\" target=\"{}\" directed=\"{}\"><data key=\"kind\">{}</data><data key=\"weight\">{}</data></edge>",
+                index,
+                escape_xml(&link.from),
+                escape_xml(&link.to),
+                link.kind.is_feeding(),
+                link.kind.label(),
+                link.weight
+            );
+        }
+
+        xml.push_str("  </graph>\n</graphml>\n");
+        xml
+    }
+
+    fn feeding_links(&self) -> impl Iterator<Item = &Link> {
+        self.links.iter().filter(|link| link.kind.is_feeding())
+    }
+
+    fn index_of(&self, species: &str) -> usize {
+        self.species
+            .iter()
+            .position(|known| known == species)
+            .expect("links only name known species")
+    }
+
+    fn secondary_extinctions(&self, removed: &str) -> usize {
+        let mut extinct: BTreeSet<&str> = BTreeSet::from([removed]);
+        loop {
+            let starving: Vec<&str> = self
+                .species
+                .iter()
+                .map(String::as_str)
+                .filter(|species| !extinct.contains(species))
+                .filter(|species| {
+                    let diet = self.resources_of(species);
+                    !diet.is_empty() && diet.iter().all(|resource| extinct.contains(resource))
+                })
+                .collect();
+            if starving.is_empty() {
+                return extinct.len() - 1;
+            }
+            extinct.extend(starving);
+        }
+    }
+
+    // Walks both ends of a non-tree edge up the spanning tree to where they meet.
+    fn close_cycle(
+        &self,
+        from: usize,
+        to: usize,
+        parent: &HashMap<usize, usize>,
+        depth: &HashMap<usize, usize>,
+    ) -> Vec<String> {
+        let (mut a, mut b) = (from, to);
+        let (mut a_path, mut b_path) = (vec![a], vec![b]);
+        while depth[&a] > depth[&b] {
+            a = parent[&a];
+            a_path.push(a);
+        }
+        while depth[&b] > depth[&a] {
+            b = parent[&b];
+            b_path.push(b);
+        }
+        while a != b {
+            a = parent[&a];
+            b = parent[&b];
+            a_path.push(a);
+            b_path.push(b);
+        }
+        b_path.pop();
+        a_path.extend(b_path.into_iter().rev());
+
+        // Start from the earliest species and go round in a fixed direction.
+        let start = a_path.iter().enumerate().min_by_key(|(_, node)| **node).map_or(0, |(i, _)| i);
+        a_path.rotate_left(start);
+        if a_path.len() > 2 && a_path[a_path.len() - 1] < a_path[1] {
+            a_path[1..].reverse();
+        }
+        a_path.into_iter().map(|node| self.species[node].clone()).collect()
+    }
+}
+
+// Tarjan's algorithm over `edges`; each component comes after every component it has edges into.
+fn strongly_connected(edges: &[Vec<(usize, f64)>]) -> Vec<Vec<usize>> {
+    struct Search<'a> {
+        edges: &'a [Vec<(usize, f64)>],
+        index: Vec<Option<usize>>,
+        low: Vec<usize>,
+        on_stack: Vec<bool>,
+        stack: Vec<usize>,
+        next: usize,
+        components: Vec<Vec<usize>>,
+    }
+
+    impl Search<'_> {
+        fn visit(&mut self, node: usize) {
+            self.index[node] = Some(self.next);
+            self.low[node] = self.next;
+            self.next += 1;
+            self.stack.push(node);
+            self.on_stack[node] = true;
+
+            for &(target, _) in &self.edges[node] {
+                match self.index[target] {
+                    None => {
+                        self.visit(target);
+                        self.low[node] = self.low[node].min(self.low[target]);
+                    }
+                    Some(index) if self.on_stack[target] => self.low[node] = self.low[node].min(index),
+                    Some(_) => {}
+                }
+            }
+
+            if Some(self.low[node]) == self.index[node] {
+                let mut component = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                self.components.push(component);
+            }
+        }
+    }
+
+    let mut search = Search {
+        edges,
+        index: vec![None; edges.len()],
+        low: vec![0; edges.len()],
+        on_stack: vec![false; edges.len()],
+        stack: Vec::new(),
+        next: 0,
+        components: Vec::new(),
+    };
+    for node in 0..edges.len() {
+        if search.index[node].is_none() {
+            search.visit(node);
+        }
+    }
+    search.components
+}
+
+// Gaussian elimination with partial pivoting on an augmented `n × (n + 1)` matrix.
+fn solve(mut matrix: Vec<Vec<f64>>) -> Vec<f64> {
+    let size = matrix.len();
+    for column in 0..size {
+        let pivot = (column..size)
+            .max_by(|&a, &b| matrix[a][column].abs().total_cmp(&matrix[b][column].abs()))
+            .unwrap_or(column);
+        matrix.swap(column, pivot);
+        let (upper, lower) = matrix.split_at_mut(column + 1);
+        let pivot_row = &upper[column];
+        for row in lower {
+            let factor = row[column] / pivot_row[column];
+            for (value, pivot_value) in row[column..].iter_mut().zip(&pivot_row[column..]) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+
+    let mut solution = vec![0.0; size];
+    for row in (0..size).rev() {
+        let known: f64 = ((row + 1)..size).map(|k| matrix[row][k] * solution[k]).sum();
+        solution[row] = (matrix[row][size] - known) / matrix[row][row];
+    }
+    solution
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
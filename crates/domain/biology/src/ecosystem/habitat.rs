@@ -0,0 +1,45 @@
+use crate::ecosystem::environment::{Environment, Terrain};
+
+/// The surroundings an organism can live in.
+///
+/// An empty preference allows anywhere. Organisms that keep one answer
+/// `Organism::tolerates` with it, so `Migration` keeps them out of cells
+/// they cannot live in and `Population::unsuited` lists them when the
+/// environment changes around them.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HabitatPreference {
+    // Terrains it can live on; empty for any.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub terrains: Vec<Terrain>,
+    // The coldest and warmest air it can stand, in °C.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub temperature: Option<(f32, f32)>,
+}
+
+impl HabitatPreference {
+    pub fn anywhere() -> Self {
+        Self::default()
+    }
+
+    pub fn on(terrains: &[Terrain]) -> Self {
+        Self {
+            terrains: terrains.to_vec(),
+            temperature: None,
+        }
+    }
+
+    pub fn between(mut self, coldest: f32, warmest: f32) -> Self {
+        self.temperature = Some((coldest, warmest));
+        self
+    }
+
+    // Checks the terrain, and the air temperature of the day's weather or the climate's mean.
+    pub fn allows(&self, environment: &Environment) -> bool {
+        let terrain = self.terrains.is_empty() || self.terrains.contains(&environment.terrain);
+        let temperature = self.temperature.is_none_or(|(coldest, warmest)| {
+            (coldest..=warmest).contains(&environment.ambient_temperature())
+        });
+        terrain && temperature
+    }
+}
@@ -1,21 +1,47 @@
-#[derive(Debug, Clone)]
+use core_shared::Identifier;
+use crate::ecosystem::resources::ResourceKind;
+
+// Participants are referenced by their `Identifier`; `Population` only accepts
+// interactions whose participants are members.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type", rename_all = "snake_case"))]
 pub enum Interaction {
     Predation {
-        predator_id: usize,
-        prey_id: usize,
+        predator_id: Identifier,
+        prey_id: Identifier,
     },
     Competition {
-        competitor1_id: usize,
-        competitor2_id: usize,
-        resource: String,
+        competitor1_id: Identifier,
+        competitor2_id: Identifier,
+        resource: ResourceKind,
     },
     Mutualism {
-        participant1_id: usize,
-        participant2_id: usize,
+        participant1_id: Identifier,
+        participant2_id: Identifier,
         benefit: String,
     },
     Parasitism {
-        parasite_id: usize,
-        host_id: usize,
+        parasite_id: Identifier,
+        host_id: Identifier,
     },
-}
\ No newline at end of file
+}
+
+impl Interaction {
+    pub fn participants(&self) -> (Identifier, Identifier) {
+        match self {
+            Interaction::Predation { predator_id, prey_id } => (*predator_id, *prey_id),
+            Interaction::Competition { competitor1_id, competitor2_id, .. } => {
+                (*competitor1_id, *competitor2_id)
+            }
+            Interaction::Mutualism { participant1_id, participant2_id, .. } => {
+                (*participant1_id, *participant2_id)
+            }
+            Interaction::Parasitism { parasite_id, host_id } => (*parasite_id, *host_id),
+        }
+    }
+
+    pub fn involves(&self, id: Identifier) -> bool {
+        let (first, second) = self.participants();
+        first == id || second == id
+    }
+}
@@ -0,0 +1,299 @@
+use std::fmt::{Display, Formatter};
+use crate::characteristics::locomotion::Locomotion;
+use crate::ecosystem::environment::{Environment, Terrain};
+
+/// A cell's column (`x`) and row (`y`), counted from the top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    pub x: u32,
+    pub y: u32,
+}
+
+impl Position {
+    pub fn new(x: u32, y: u32) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// How cells touch each other.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Topology {
+    // Square cells, each with four neighbours (no diagonals).
+    #[default]
+    Square,
+    // Pointy-topped hexagons with six neighbours; odd rows are shifted half a cell right.
+    Hex,
+}
+
+/// One leg of a journey: the cells walked through and what it cost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    // Every cell from start to destination, both included.
+    pub steps: Vec<Position>,
+    pub cost: f64,
+}
+
+/// A grid of cells, each with its own `Environment`.
+///
+/// The landscape is pure geography; which members stand where is kept by
+/// the `Population` that owns it.
+///
+/// A saved landscape is checked as it loads: its cells must fill the grid
+/// exactly, or every lookup by position could land outside them.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "SavedLandscape")
+)]
+pub struct Landscape {
+    width: u32,
+    height: u32,
+    topology: Topology,
+    // Row by row, `width` cells to a row.
+    cells: Vec<Environment>,
+}
+
+// A landscape as it was saved, before its grid is checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SavedLandscape {
+    width: u32,
+    height: u32,
+    topology: Topology,
+    cells: Vec<Environment>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SavedLandscape> for Landscape {
+    type Error = String;
+
+    fn try_from(saved: SavedLandscape) -> Result<Self, Self::Error> {
+        if saved.width == 0 || saved.height == 0 {
+            return Err(format!("a {} × {} landscape has no cells", saved.width, saved.height));
+        }
+        let expected = saved.width as u64 * saved.height as u64;
+        if saved.cells.len() as u64 != expected {
+            return Err(format!(
+                "{} cells do not fill a {} × {} landscape of {}",
+                saved.cells.len(),
+                saved.width,
+                saved.height,
+                expected
+            ));
+        }
+        Ok(Self {
+            width: saved.width,
+            height: saved.height,
+            topology: saved.topology,
+            cells: saved.cells,
+        })
+    }
+}
+
+impl Landscape {
+    // Every cell starts as a copy of `environment`.
+    pub fn new(width: u32, height: u32, topology: Topology, environment: Environment) -> Self {
+        Self::from_fn(width, height, topology, |_| environment.clone())
+    }
+
+    pub fn from_fn(width: u32, height: u32, topology: Topology, mut cell: impl FnMut(Position) -> Environment) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Position::new(x, y)))
+            .map(&mut cell)
+            .collect();
+        Self {
+            width,
+            height,
+            topology,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    pub fn cell(&self, position: Position) -> Option<&Environment> {
+        self.offset(position).map(|offset| &self.cells[offset])
+    }
+
+    pub fn cell_mut(&mut self, position: Position) -> Option<&mut Environment> {
+        self.offset(position).map(|offset| &mut self.cells[offset])
+    }
+
+    // Every cell, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (Position, &Environment)> {
+        self.cells.iter().enumerate().map(|(offset, cell)| (self.position_at(offset), cell))
+    }
+
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = &mut Environment> {
+        self.cells.iter_mut()
+    }
+
+    // Neighbouring cells inside the landscape, clockwise from the top.
+    pub fn neighbours(&self, position: Position) -> Vec<Position> {
+        let (x, y) = (position.x as i64, position.y as i64);
+        let offsets: &[(i64, i64)] = match (self.topology, y % 2 == 1) {
+            (Topology::Square, _) => &[(0, -1), (1, 0), (0, 1), (-1, 0)],
+            (Topology::Hex, false) => &[(0, -1), (1, 0), (0, 1), (-1, 1), (-1, 0), (-1, -1)],
+            (Topology::Hex, true) => &[(1, -1), (1, 0), (1, 1), (0, 1), (-1, 0), (0, -1)],
+        };
+
+        offsets
+            .iter()
+            .map(|(dx, dy)| (x + dx, y + dy))
+            .filter(|&(x, y)| x >= 0 && y >= 0)
+            .map(|(x, y)| Position::new(x as u32, y as u32))
+            .filter(|&neighbour| self.contains(neighbour))
+            .collect()
+    }
+
+    // The fewest steps between two cells, ignoring terrain.
+    pub fn distance(&self, from: Position, to: Position) -> u32 {
+        match self.topology {
+            Topology::Square => from.x.abs_diff(to.x) + from.y.abs_diff(to.y),
+            Topology::Hex => {
+                let (aq, ar) = axial(from);
+                let (bq, br) = axial(to);
+                let (dq, dr) = (aq - bq, ar - br);
+                ((dq.abs() + dr.abs() + (dq + dr).abs()) / 2) as u32
+            }
+        }
+    }
+
+    /// What stepping from one cell into a neighbouring one costs.
+    ///
+    /// The terrain of the cell entered sets the base cost (see
+    /// `movement_cost`); climbing adds one unit per 100 metres gained.
+    /// `None` when the cells are not neighbours or the terrain cannot be
+    /// crossed that way.
+    pub fn step_cost(&self, from: Position, to: Position, locomotion: Locomotion) -> Option<f64> {
+        if !self.neighbours(from).contains(&to) {
+            return None;
+        }
+        let (origin, destination) = (self.cell(from)?, self.cell(to)?);
+        let base = movement_cost(&destination.terrain, locomotion)?;
+
+        let climb = destination.altitude.0.saturating_sub(origin.altitude.0) as f64 / 10_000.0;
+        let climb = if locomotion == Locomotion::Aerial { climb / 2.0 } else { climb };
+        Some(base + climb)
+    }
+
+    // The cheapest way from one cell to another, or `None` if there is no way through.
+    pub fn route(&self, from: Position, to: Position, locomotion: Locomotion) -> Option<Route> {
+        let (start, goal) = (self.offset(from)?, self.offset(to)?);
+        let mut cost = vec![f64::INFINITY; self.cells.len()];
+        let mut previous = vec![None; self.cells.len()];
+        let mut settled = vec![false; self.cells.len()];
+        cost[start] = 0.0;
+
+        // Dijkstra's algorithm; landscapes are small enough to scan for the next cell.
+        while let Some(current) = (0..self.cells.len())
+            .filter(|&offset| !settled[offset] && cost[offset].is_finite())
+            .min_by(|&a, &b| cost[a].total_cmp(&cost[b]))
+        {
+            if current == goal {
+                break;
+            }
+            settled[current] = true;
+
+            let position = self.position_at(current);
+            for neighbour in self.neighbours(position) {
+                let Some(step) = self.step_cost(position, neighbour, locomotion) else {
+                    continue;
+                };
+                let next = self.offset(neighbour)?;
+                if cost[current] + step < cost[next] {
+                    cost[next] = cost[current] + step;
+                    previous[next] = Some(current);
+                }
+            }
+        }
+
+        if !cost[goal].is_finite() {
+            return None;
+        }
+        let mut steps = vec![to];
+        let mut current = goal;
+        while let Some(before) = previous[current] {
+            steps.push(self.position_at(before));
+            current = before;
+        }
+        steps.reverse();
+        Some(Route { steps, cost: cost[goal] })
+    }
+
+    fn offset(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| (position.y * self.width + position.x) as usize)
+    }
+
+    fn position_at(&self, offset: usize) -> Position {
+        Position::new(offset as u32 % self.width, offset as u32 / self.width)
+    }
+}
+
+/// The cost of entering a cell of this terrain, relative to walking on plains.
+///
+/// `None` when the terrain cannot be entered at all: land animals cannot
+/// cross open ocean, swimmers cannot leave the water, and sessile organisms
+/// do not move.
+pub fn movement_cost(terrain: &Terrain, locomotion: Locomotion) -> Option<f64> {
+    let cost = match (locomotion, terrain) {
+        (Locomotion::Sessile, _) => return None,
+
+        (Locomotion::Terrestrial, Terrain::Plains) => 1.0,
+        (Locomotion::Terrestrial, Terrain::Urban) => 1.5,
+        (Locomotion::Terrestrial, Terrain::Forest) => 2.0,
+        (Locomotion::Terrestrial, Terrain::Wetlands) => 3.0,
+        (Locomotion::Terrestrial, Terrain::Mountains) => 4.0,
+        (Locomotion::Terrestrial, Terrain::Ocean) => return None,
+
+        (Locomotion::Aquatic, Terrain::Ocean) => 1.0,
+        (Locomotion::Aquatic, Terrain::Wetlands) => 2.0,
+        (Locomotion::Aquatic, _) => return None,
+
+        (Locomotion::Aerial, Terrain::Forest | Terrain::Mountains) => 1.5,
+        (Locomotion::Aerial, _) => 1.0,
+
+        (Locomotion::Amphibious, Terrain::Wetlands) => 1.0,
+        (Locomotion::Amphibious, Terrain::Plains | Terrain::Ocean) => 1.5,
+        (Locomotion::Amphibious, Terrain::Forest | Terrain::Urban) => 2.0,
+        (Locomotion::Amphibious, Terrain::Mountains) => 5.0,
+
+        (Locomotion::Fossorial, Terrain::Plains) => 1.5,
+        (Locomotion::Fossorial, Terrain::Forest) => 2.5,
+        (Locomotion::Fossorial, Terrain::Urban) => 3.0,
+        (Locomotion::Fossorial, Terrain::Wetlands) => 4.0,
+        (Locomotion::Fossorial, Terrain::Mountains) => 6.0,
+        (Locomotion::Fossorial, Terrain::Ocean) => return None,
+    };
+    Some(cost)
+}
+
+// Axial hex coordinates for the odd-row offset layout.
+fn axial(position: Position) -> (i64, i64) {
+    let (x, y) = (position.x as i64, position.y as i64);
+    (x - (y - (y & 1)) / 2, y)
+}
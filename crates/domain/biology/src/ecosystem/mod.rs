@@ -1,3 +1,8 @@
 pub mod environment;
+pub mod food_web;
+pub mod habitat;
 pub mod interactions;
-pub mod population;
\ No newline at end of file
+pub mod landscape;
+pub mod population;
+pub mod resources;
+pub mod weather;
\ No newline at end of file
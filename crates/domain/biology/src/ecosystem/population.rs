@@ -1,31 +1,300 @@
-use crate::anatomy::body::Body;
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use core_shared::Identifier;
+use crate::Organism;
 use crate::ecosystem::environment::Environment;
 use crate::ecosystem::interactions::Interaction;
+use crate::ecosystem::landscape::{Landscape, Position};
 
-pub struct Population<'a> {
-    pub members: Vec<Body<'a>>,
+/// An owning store of organisms, indexed by their `Identifier`.
+///
+/// Members keep their insertion order, so iterating a population is
+/// deterministic. Interactions may only reference current members, and
+/// removing a member removes every interaction it takes part in.
+///
+/// A population may also spread over a `Landscape`. Members placed on it
+/// live in their cell's environment; the rest share `environment`.
+///
+/// Parent–child links are kept like interactions: both ends must be
+/// members, and removing a member removes its links.
+pub struct Population {
+    members: Vec<Box<dyn Organism>>,
+    index: HashMap<Identifier, usize>,
+    interactions: Vec<Interaction>,
+    // Each member's parents, mother (or only parent) first.
+    parents: HashMap<Identifier, Vec<Identifier>>,
+    landscape: Option<Landscape>,
+    positions: HashMap<Identifier, Position>,
     pub environment: Environment,
-    pub interactions: Vec<Interaction>,
 }
 
-impl<'a> Population<'a> {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PopulationError {
+    DuplicateMember(Identifier),
+    UnknownMember(Identifier),
+    OutOfBounds(Position),
+    OwnParent(Identifier),
+}
+
+impl Display for PopulationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PopulationError::DuplicateMember(id) => write!(f, "{} is already a member", id),
+            PopulationError::UnknownMember(id) => write!(f, "{} is not a member", id),
+            PopulationError::OutOfBounds(position) => write!(f, "{} is outside the landscape", position),
+            PopulationError::OwnParent(id) => write!(f, "{} cannot be its own parent", id),
+        }
+    }
+}
+
+impl std::error::Error for PopulationError {}
+
+impl Population {
     pub fn new(environment: Environment) -> Self {
         Self {
             members: Vec::new(),
-            environment,
+            index: HashMap::new(),
             interactions: Vec::new(),
+            parents: HashMap::new(),
+            landscape: None,
+            positions: HashMap::new(),
+            environment,
+        }
+    }
+
+    pub fn with_landscape(mut self, landscape: Landscape) -> Self {
+        self.landscape = Some(landscape);
+        self
+    }
+
+    pub fn insert(&mut self, organism: impl Organism) -> Result<Identifier, PopulationError> {
+        self.insert_boxed(Box::new(organism))
+    }
+
+    pub fn insert_boxed(&mut self, organism: Box<dyn Organism>) -> Result<Identifier, PopulationError> {
+        let id = organism.id();
+        if self.index.contains_key(&id) {
+            return Err(PopulationError::DuplicateMember(id));
+        }
+
+        self.index.insert(id, self.members.len());
+        self.members.push(organism);
+        Ok(id)
+    }
+
+    // Removes the member and every interaction it takes part in.
+    pub fn remove(&mut self, id: Identifier) -> Option<Box<dyn Organism>> {
+        let position = self.index.remove(&id)?;
+        let organism = self.members.remove(position);
+        for later in &self.members[position..] {
+            if let Some(slot) = self.index.get_mut(&later.id()) {
+                *slot -= 1;
+            }
+        }
+
+        self.interactions.retain(|interaction| !interaction.involves(id));
+        self.parents.remove(&id);
+        for parents in self.parents.values_mut() {
+            parents.retain(|parent| *parent != id);
         }
+        self.positions.remove(&id);
+        Some(organism)
     }
 
-    pub fn add_member(&mut self, member: Body<'a>) {
-        self.members.push(member);
+    pub fn get(&self, id: Identifier) -> Option<&dyn Organism> {
+        self.index.get(&id).map(|&position| self.members[position].as_ref())
     }
 
-    pub fn add_interaction(&mut self, interaction: Interaction) {
+    pub fn get_mut(&mut self, id: Identifier) -> Option<&mut dyn Organism> {
+        let position = *self.index.get(&id)?;
+        Some(self.members[position].as_mut())
+    }
+
+    // Looks up a member as its concrete type, e.g. `population.get_as::<Human>(id)`.
+    pub fn get_as<T: Organism>(&self, id: Identifier) -> Option<&T> {
+        let organism: &dyn Any = self.get(id)?;
+        organism.downcast_ref::<T>()
+    }
+
+    pub fn get_as_mut<T: Organism>(&mut self, id: Identifier) -> Option<&mut T> {
+        let organism: &mut dyn Any = self.get_mut(id)?;
+        organism.downcast_mut::<T>()
+    }
+
+    pub fn contains(&self, id: Identifier) -> bool {
+        self.index.contains_key(&id)
+    }
+
+    pub fn members(&self) -> impl Iterator<Item = &dyn Organism> {
+        self.members.iter().map(|member| member.as_ref())
+    }
+
+    pub fn members_mut(&mut self) -> impl Iterator<Item = &mut dyn Organism> {
+        self.members.iter_mut().map(|member| member.as_mut())
+    }
+
+    pub fn ids(&self) -> Vec<Identifier> {
+        self.members.iter().map(|member| member.id()).collect()
+    }
+
+    pub fn add_interaction(&mut self, interaction: Interaction) -> Result<(), PopulationError> {
+        let (first, second) = interaction.participants();
+        for id in [first, second] {
+            if !self.contains(id) {
+                return Err(PopulationError::UnknownMember(id));
+            }
+        }
+
         self.interactions.push(interaction);
+        Ok(())
+    }
+
+    pub fn interactions(&self) -> &[Interaction] {
+        &self.interactions
+    }
+
+    pub fn interactions_involving(&self, id: Identifier) -> impl Iterator<Item = &Interaction> {
+        self.interactions
+            .iter()
+            .filter(move |interaction| interaction.involves(id))
+    }
+
+    // Records a member's parents, mother (or only parent) first, replacing any recorded before.
+    pub fn set_parents(&mut self, child: Identifier, parents: &[Identifier]) -> Result<(), PopulationError> {
+        for &id in std::iter::once(&child).chain(parents) {
+            if !self.contains(id) {
+                return Err(PopulationError::UnknownMember(id));
+            }
+        }
+        if parents.contains(&child) {
+            return Err(PopulationError::OwnParent(child));
+        }
+
+        self.parents.insert(child, parents.to_vec());
+        Ok(())
+    }
+
+    // Empty for founders and members whose parents are not recorded.
+    pub fn parents_of(&self, id: Identifier) -> &[Identifier] {
+        self.parents.get(&id).map_or(&[], Vec::as_slice)
+    }
+
+    // Children in insertion order.
+    pub fn children_of(&self, id: Identifier) -> Vec<Identifier> {
+        self.members
+            .iter()
+            .map(|member| member.id())
+            .filter(|child| self.parents_of(*child).contains(&id))
+            .collect()
+    }
+
+    // Every member with recorded parents, in insertion order, e.g. for saving.
+    pub fn parentage(&self) -> Vec<(Identifier, Vec<Identifier>)> {
+        self.members
+            .iter()
+            .filter_map(|member| Some((member.id(), self.parents.get(&member.id())?.clone())))
+            .collect()
     }
 
     pub fn size(&self) -> usize {
         self.members.len()
     }
-}
\ No newline at end of file
+
+    // Living members of one species; the dead stay members but are not counted.
+    pub fn count_living(&self, species: &str) -> usize {
+        self.members()
+            .filter(|organism| organism.is_alive() && organism.species() == species)
+            .count()
+    }
+
+    pub fn landscape(&self) -> Option<&Landscape> {
+        self.landscape.as_ref()
+    }
+
+    pub fn landscape_mut(&mut self) -> Option<&mut Landscape> {
+        self.landscape.as_mut()
+    }
+
+    // Puts a member in a cell of the landscape, or moves it there.
+    pub fn place(&mut self, id: Identifier, position: Position) -> Result<(), PopulationError> {
+        if !self.contains(id) {
+            return Err(PopulationError::UnknownMember(id));
+        }
+        if !self.landscape.as_ref().is_some_and(|landscape| landscape.contains(position)) {
+            return Err(PopulationError::OutOfBounds(position));
+        }
+
+        self.positions.insert(id, position);
+        Ok(())
+    }
+
+    pub fn position(&self, id: Identifier) -> Option<Position> {
+        self.positions.get(&id).copied()
+    }
+
+    // Placed members and their cells, in insertion order.
+    pub fn positions(&self) -> Vec<(Identifier, Position)> {
+        self.members
+            .iter()
+            .filter_map(|member| Some((member.id(), self.position(member.id())?)))
+            .collect()
+    }
+
+    // Members standing in a cell, in insertion order.
+    pub fn members_at(&self, position: Position) -> Vec<Identifier> {
+        self.members
+            .iter()
+            .map(|member| member.id())
+            .filter(|id| self.position(*id) == Some(position))
+            .collect()
+    }
+
+    // How many living members stand in each occupied cell.
+    pub fn occupancy(&self) -> HashMap<Position, usize> {
+        let mut counts = HashMap::new();
+        for member in self.members.iter().filter(|member| member.is_alive()) {
+            if let Some(position) = self.position(member.id()) {
+                *counts.entry(position).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    // The member's cell, or the shared environment if it has none.
+    pub fn environment_of(&self, id: Identifier) -> &Environment {
+        self.position(id)
+            .and_then(|position| self.landscape.as_ref()?.cell(position))
+            .unwrap_or(&self.environment)
+    }
+
+    pub fn environment_of_mut(&mut self, id: Identifier) -> &mut Environment {
+        let position = self.position(id);
+        match (position, self.landscape.as_mut()) {
+            (Some(position), Some(landscape)) if landscape.contains(position) => {
+                landscape.cell_mut(position).expect("position is inside the landscape")
+            }
+            _ => &mut self.environment,
+        }
+    }
+
+    // Living members whose environment they cannot tolerate, e.g. a freshwater fish at sea.
+    pub fn unsuited(&self) -> Vec<Identifier> {
+        self.members()
+            .filter(|member| member.is_alive() && !member.tolerates(self.environment_of(member.id())))
+            .map(|member| member.id())
+            .collect()
+    }
+
+    // Finds every member known by the given name, designation, or former name.
+    pub fn find_by_alias(&self, alias: &str) -> Vec<&dyn Organism> {
+        self.members()
+            .filter(|organism| organism.answers_to(alias))
+            .collect()
+    }
+
+    // Hands back the members and interactions, e.g. for saving.
+    pub fn into_parts(self) -> (Environment, Vec<Box<dyn Organism>>, Vec<Interaction>) {
+        (self.environment, self.members, self.interactions)
+    }
+}
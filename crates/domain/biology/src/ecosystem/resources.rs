@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use core_shared::Identifier;
+use crate::ecosystem::environment::{Climate, Terrain};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum ResourceKind {
+    Water,
+    FoodBiomass,
+    Territory,
+    Light,
+    Nutrients,
+}
+
+impl ResourceKind {
+    pub const ALL: [ResourceKind; 5] = [
+        ResourceKind::Water,
+        ResourceKind::FoodBiomass,
+        ResourceKind::Territory,
+        ResourceKind::Light,
+        ResourceKind::Nutrients,
+    ];
+
+    /// Recognises a free-text resource description such as "Water sources" or
+    /// "Food resources". Returns `None` when no kind matches.
+    pub fn from_label(label: &str) -> Option<ResourceKind> {
+        let label = label.to_lowercase();
+        let mentions = |words: &[&str]| words.iter().any(|word| label.contains(word));
+
+        if mentions(&["water", "drink"]) {
+            Some(ResourceKind::Water)
+        } else if mentions(&["food", "prey", "forage", "biomass", "graz"]) {
+            Some(ResourceKind::FoodBiomass)
+        } else if mentions(&["territor", "space", "nest", "burrow", "shelter"]) {
+            Some(ResourceKind::Territory)
+        } else if mentions(&["light", "sun"]) {
+            Some(ResourceKind::Light)
+        } else if mentions(&["nutrient", "soil", "mineral"]) {
+            Some(ResourceKind::Nutrients)
+        } else {
+            None
+        }
+    }
+
+    // Per-tick regeneration as a share of capacity. Territory and light are
+    // not used up, only divided, so they return in full every tick.
+    fn renewal(&self) -> f64 {
+        match self {
+            ResourceKind::Water => 0.1,
+            ResourceKind::FoodBiomass => 0.05,
+            ResourceKind::Territory | ResourceKind::Light => 1.0,
+            ResourceKind::Nutrients => 0.02,
+        }
+    }
+}
+
+impl Display for ResourceKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            ResourceKind::Water => "Water",
+            ResourceKind::FoodBiomass => "Food biomass",
+            ResourceKind::Territory => "Territory",
+            ResourceKind::Light => "Light",
+            ResourceKind::Nutrients => "Nutrients",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+/// A quantity of one resource, in abstract units for the whole habitat.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResourcePool {
+    pub amount: f64,
+    pub capacity: f64,
+    pub regeneration: f64,
+}
+
+/// One competitor's claim on a pool: how much it wants and how strongly it competes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Claim {
+    pub claimant: Identifier,
+    pub demand: f64,
+    pub strength: f64,
+}
+
+impl ResourcePool {
+    // Starts full.
+    pub fn new(capacity: f64, regeneration: f64) -> Self {
+        Self {
+            amount: capacity,
+            capacity,
+            regeneration,
+        }
+    }
+
+    pub fn replenish(&mut self) {
+        self.amount = (self.amount + self.regeneration).min(self.capacity);
+    }
+
+    // Adds to (or, if negative, removes from) the pool, within zero and capacity.
+    pub fn adjust(&mut self, change: f64) {
+        self.amount = (self.amount + change).clamp(0.0, self.capacity);
+    }
+
+    pub fn take(&mut self, wanted: f64) -> f64 {
+        let taken = wanted.clamp(0.0, self.amount);
+        self.amount -= taken;
+        taken
+    }
+
+    /// Divides what is available among the claims and removes it from the pool.
+    ///
+    /// Each claimant receives a share in proportion to its `strength`, never
+    /// more than its `demand`; whatever a satisfied claimant leaves is shared
+    /// out again among the rest. Returns the amounts in the order of `claims`.
+    pub fn allocate(&mut self, claims: &[Claim]) -> Vec<f64> {
+        let mut shares = vec![0.0; claims.len()];
+        let mut open: Vec<usize> = (0..claims.len()).filter(|&i| claims[i].demand > 0.0).collect();
+        let mut available = self.amount;
+
+        while !open.is_empty() && available > f64::EPSILON {
+            let total_strength: f64 = open.iter().map(|&i| claims[i].strength.max(f64::EPSILON)).sum();
+            let mut handed_out = 0.0;
+            let mut still_open = Vec::new();
+
+            for &i in &open {
+                let offer = available * claims[i].strength.max(f64::EPSILON) / total_strength;
+                let wanted = claims[i].demand - shares[i];
+                if offer >= wanted {
+                    shares[i] += wanted;
+                    handed_out += wanted;
+                } else {
+                    shares[i] += offer;
+                    handed_out += offer;
+                    still_open.push(i);
+                }
+            }
+
+            available -= handed_out;
+            // Nobody was satisfied this round, so everything available was handed out.
+            if still_open.len() == open.len() {
+                break;
+            }
+            open = still_open;
+        }
+
+        self.amount = available.max(0.0);
+        shares
+    }
+}
+
+/// The resources available in a habitat, one pool per kind.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct Resources(BTreeMap<ResourceKind, ResourcePool>);
+
+impl Resources {
+    /// Typical pools for a climate and terrain, relative to temperate plains.
+    ///
+    /// Capacity scales with both; regeneration is a fixed share of capacity
+    /// per tick (faster for water than food, slowest for soil nutrients).
+    pub fn for_habitat(climate: &Climate, terrain: &Terrain) -> Self {
+        let pools = ResourceKind::ALL
+            .into_iter()
+            .map(|kind| {
+                let capacity = 100.0 * climate_factor(climate, kind) * terrain_factor(terrain, kind);
+                (kind, ResourcePool::new(capacity, capacity * kind.renewal()))
+            })
+            .collect();
+        Self(pools)
+    }
+
+    pub fn get(&self, kind: ResourceKind) -> Option<&ResourcePool> {
+        self.0.get(&kind)
+    }
+
+    pub fn get_mut(&mut self, kind: ResourceKind) -> Option<&mut ResourcePool> {
+        self.0.get_mut(&kind)
+    }
+
+    pub fn insert(&mut self, kind: ResourceKind, pool: ResourcePool) {
+        self.0.insert(kind, pool);
+    }
+
+    pub fn amount(&self, kind: ResourceKind) -> f64 {
+        self.get(kind).map_or(0.0, |pool| pool.amount)
+    }
+
+    pub fn replenish(&mut self) {
+        for pool in self.0.values_mut() {
+            pool.replenish();
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (ResourceKind, &ResourcePool)> {
+        self.0.iter().map(|(kind, pool)| (*kind, pool))
+    }
+}
+
+fn climate_factor(climate: &Climate, kind: ResourceKind) -> f64 {
+    let (water, growth, light) = match climate {
+        Climate::Tropical => (1.5, 1.4, 1.1),
+        Climate::Temperate => (1.0, 1.0, 1.0),
+        Climate::Arctic => (0.6, 0.3, 0.6),
+        Climate::Desert => (0.2, 0.3, 1.3),
+        Climate::Custom { avg_temp, rainfall_mm } => {
+            let water = (*rainfall_mm as f64 / 800.0).clamp(0.1, 2.0);
+            let warmth = if (5..=30).contains(&avg_temp.0) { 1.0 } else { 0.5 };
+            (water, water.min(1.5) * warmth, 1.0)
+        }
+    };
+
+    match kind {
+        ResourceKind::Water => water,
+        ResourceKind::FoodBiomass | ResourceKind::Nutrients => growth,
+        ResourceKind::Light => light,
+        ResourceKind::Territory => 1.0,
+    }
+}
+
+fn terrain_factor(terrain: &Terrain, kind: ResourceKind) -> f64 {
+    use ResourceKind::*;
+    match (terrain, kind) {
+        (Terrain::Forest, FoodBiomass) => 1.3,
+        (Terrain::Forest, Light) => 0.5,
+        (Terrain::Forest, Territory) => 0.8,
+        (Terrain::Forest, Nutrients) => 1.2,
+        (Terrain::Mountains, Water) => 0.8,
+        (Terrain::Mountains, FoodBiomass) => 0.5,
+        (Terrain::Mountains, Territory) => 0.7,
+        (Terrain::Mountains, Nutrients) => 0.6,
+        (Terrain::Ocean, Water) => 5.0,
+        (Terrain::Ocean, FoodBiomass) => 0.8,
+        (Terrain::Ocean, Light) => 0.6,
+        (Terrain::Ocean, Territory) => 2.0,
+        (Terrain::Urban, Water) => 0.8,
+        (Terrain::Urban, FoodBiomass) => 0.4,
+        (Terrain::Urban, Territory) => 0.3,
+        (Terrain::Urban, Nutrients) => 0.3,
+        (Terrain::Wetlands, Water) => 2.0,
+        (Terrain::Wetlands, FoodBiomass) => 1.2,
+        (Terrain::Wetlands, Nutrients) => 1.5,
+        _ => 1.0,
+    }
+}
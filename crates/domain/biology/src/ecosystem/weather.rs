@@ -0,0 +1,237 @@
+use std::f32::consts::TAU;
+use std::fmt::{Display, Formatter};
+use crate::ecosystem::environment::Climate;
+
+pub const DAYS_PER_YEAR: u32 = 365;
+
+// Mid-July; the calendar is the northern hemisphere's.
+const WARMEST_DAY: u32 = 196;
+// Mid-afternoon.
+const WARMEST_HOUR: u32 = 15;
+
+/// Meteorological seasons: whole months, winter starting in December.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    // `day_of_year` counts from 0 (1 January) and wraps every 365 days.
+    pub fn of_day(day_of_year: u32) -> Season {
+        match day_of_year % DAYS_PER_YEAR {
+            59..151 => Season::Spring,
+            151..243 => Season::Summer,
+            243..334 => Season::Autumn,
+            _ => Season::Winter,
+        }
+    }
+}
+
+impl Display for Season {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Season::Spring => write!(f, "Spring"),
+            Season::Summer => write!(f, "Summer"),
+            Season::Autumn => write!(f, "Autumn"),
+            Season::Winter => write!(f, "Winter"),
+        }
+    }
+}
+
+/// The long-run averages that shape a climate's year.
+///
+/// Temperatures follow a cosine over the year, warmest in mid-July, and
+/// another over the day, warmest mid-afternoon. Rainfall follows a cosine
+/// around `wettest_day`; `rain_seasonality` is how much wetter that day is
+/// than average (0 = even all year).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClimateNormals {
+    // °C. Ambient temperatures drop below zero, which `Celsius` cannot hold.
+    pub mean_temperature: f32,
+    pub annual_swing: f32,
+    pub daily_swing: f32,
+    // mm a year.
+    pub annual_rainfall: f32,
+    pub rain_seasonality: f32,
+    pub wettest_day: u32,
+}
+
+impl ClimateNormals {
+    pub fn for_climate(climate: &Climate) -> Self {
+        let (mean_temperature, annual_swing, daily_swing, annual_rainfall, rain_seasonality, wettest_day) = match climate {
+            // Monsoon rains peak in July.
+            Climate::Tropical => (26.0, 2.0, 5.0, 2000.0, 0.6, 196),
+            Climate::Temperate => (11.0, 9.0, 5.0, 800.0, 0.2, 320),
+            Climate::Arctic => (-12.0, 18.0, 4.0, 250.0, 0.3, 227),
+            // What little rain there is falls in winter.
+            Climate::Desert => (22.0, 10.0, 12.0, 100.0, 0.5, 30),
+            Climate::Custom { avg_temp, rainfall_mm } => (avg_temp.0 as f32, 8.0, 6.0, *rainfall_mm as f32, 0.2, 320),
+        };
+        Self {
+            mean_temperature,
+            annual_swing,
+            daily_swing,
+            annual_rainfall,
+            rain_seasonality,
+            wettest_day,
+        }
+    }
+
+    pub fn daily_mean_temperature(&self, day_of_year: u32) -> f32 {
+        self.mean_temperature + self.annual_swing * cycle(day_of_year, WARMEST_DAY, DAYS_PER_YEAR)
+    }
+
+    // `hour` counts from 0 (midnight) to 23.
+    pub fn temperature_at(&self, day_of_year: u32, hour: u32) -> f32 {
+        self.daily_mean_temperature(day_of_year) + self.daily_swing * cycle(hour, WARMEST_HOUR, 24)
+    }
+
+    // Expected rainfall on the day, in mm.
+    pub fn rainfall(&self, day_of_year: u32) -> f32 {
+        let seasonal = 1.0 + self.rain_seasonality * cycle(day_of_year, self.wettest_day, DAYS_PER_YEAR);
+        self.mean_daily_rainfall() * seasonal
+    }
+
+    pub fn mean_daily_rainfall(&self) -> f32 {
+        self.annual_rainfall / DAYS_PER_YEAR as f32
+    }
+}
+
+/// Severe weather that lasts for days and overrides the usual conditions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum WeatherEvent {
+    // No rain; water evaporates.
+    Drought,
+    // Snow and a sharp frost.
+    Blizzard,
+    // Five times the usual rain; standing water ruins forage.
+    Flood,
+}
+
+impl WeatherEvent {
+    pub const ALL: [WeatherEvent; 3] = [WeatherEvent::Drought, WeatherEvent::Blizzard, WeatherEvent::Flood];
+
+    pub fn duration_days(&self) -> u32 {
+        match self {
+            WeatherEvent::Drought => 30,
+            WeatherEvent::Blizzard => 3,
+            WeatherEvent::Flood => 5,
+        }
+    }
+
+    // The chance the event begins on any one day of the season.
+    pub fn daily_chance(&self, climate: &Climate, season: Season) -> f64 {
+        use Season::*;
+        match (self, climate) {
+            (WeatherEvent::Drought, Climate::Desert) => 0.004,
+            (WeatherEvent::Drought, Climate::Tropical) if season == Winter => 0.003,
+            (WeatherEvent::Drought, Climate::Temperate | Climate::Custom { .. }) if season == Summer => 0.002,
+
+            (WeatherEvent::Blizzard, Climate::Arctic) if season != Summer => 0.02,
+            (WeatherEvent::Blizzard, Climate::Temperate | Climate::Custom { .. }) if season == Winter => 0.005,
+
+            (WeatherEvent::Flood, Climate::Tropical) if season == Summer => 0.01,
+            (WeatherEvent::Flood, Climate::Temperate | Climate::Custom { .. }) if matches!(season, Spring | Autumn) => 0.002,
+            (WeatherEvent::Flood, Climate::Arctic) if season == Spring => 0.002,
+            (WeatherEvent::Flood, Climate::Desert) => 0.0005,
+
+            _ => 0.0,
+        }
+    }
+}
+
+impl Display for WeatherEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WeatherEvent::Drought => write!(f, "Drought"),
+            WeatherEvent::Blizzard => write!(f, "Blizzard"),
+            WeatherEvent::Flood => write!(f, "Flood"),
+        }
+    }
+}
+
+/// The weather at one moment: what organisms feel and plants grow in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Conditions {
+    pub day_of_year: u32,
+    pub season: Season,
+    // Ambient °C.
+    pub temperature: f32,
+    // mm that day, and what the climate usually brings on an average day.
+    pub rainfall: f32,
+    pub normal_rainfall: f32,
+    pub event: Option<WeatherEvent>,
+}
+
+impl Conditions {
+    /// The conditions for a climate on a day, optionally at an hour.
+    ///
+    /// Without an hour the temperature is the day's mean. An event replaces
+    /// the usual rainfall (none in a drought, five times as much in a flood)
+    /// or drops the temperature by 15 °C (blizzard).
+    pub fn for_climate(climate: &Climate, day_of_year: u32, hour: Option<u32>, event: Option<WeatherEvent>) -> Self {
+        let normals = ClimateNormals::for_climate(climate);
+        let day_of_year = day_of_year % DAYS_PER_YEAR;
+        let mut temperature = match hour {
+            Some(hour) => normals.temperature_at(day_of_year, hour),
+            None => normals.daily_mean_temperature(day_of_year),
+        };
+        let mut rainfall = normals.rainfall(day_of_year);
+
+        match event {
+            Some(WeatherEvent::Drought) => rainfall = 0.0,
+            Some(WeatherEvent::Flood) => rainfall *= 5.0,
+            Some(WeatherEvent::Blizzard) => temperature -= 15.0,
+            None => {}
+        }
+
+        Self {
+            day_of_year,
+            season: Season::of_day(day_of_year),
+            temperature,
+            rainfall,
+            normal_rainfall: normals.mean_daily_rainfall(),
+            event,
+        }
+    }
+
+    // Rainfall relative to an average day: 0 is bone dry, 1 is usual.
+    pub fn wetness(&self) -> f64 {
+        if self.normal_rainfall <= 0.0 {
+            return 1.0;
+        }
+        (self.rainfall / self.normal_rainfall) as f64
+    }
+
+    /// How fast plants grow, relative to a mild day with usual rain.
+    ///
+    /// Nothing grows at or below freezing or above 45 °C; growth is full
+    /// between 10 and 30 °C. Drought stops it, and rain beyond half again
+    /// the usual adds nothing.
+    pub fn growth(&self) -> f64 {
+        growing_warmth(self.temperature) as f64 * self.wetness().min(1.5)
+    }
+}
+
+// How well plants grow at a temperature, from 0 (not at all) to 1 (fully, 10 to 30 °C).
+pub fn growing_warmth(temperature: f32) -> f32 {
+    match temperature {
+        t if t <= 0.0 => 0.0,
+        t if t < 10.0 => t / 10.0,
+        t if t <= 30.0 => 1.0,
+        t if t < 45.0 => (45.0 - t) / 15.0,
+        _ => 0.0,
+    }
+}
+
+// Cosine over a period: 1 at `peak`, -1 half a period away.
+fn cycle(at: u32, peak: u32, period: u32) -> f32 {
+    let phase = (at as f32 - peak as f32) / period as f32;
+    (TAU * phase).cos()
+}
@@ -0,0 +1,61 @@
+/// Where a host stands in the course of an infection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Compartment {
+    Susceptible,
+    Exposed,
+    Infectious,
+    Recovered,
+    Dead,
+}
+
+/// The number of hosts in each compartment at one moment.
+///
+/// Counts are `f64` so the same type carries the integer tallies of a
+/// simulated outbreak and the continuous values of the `Seir` model.
+/// With no exposed stage these are plain SIR statistics.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeirCounts {
+    pub susceptible: f64,
+    pub exposed: f64,
+    pub infectious: f64,
+    pub recovered: f64,
+    pub dead: f64,
+}
+
+impl SeirCounts {
+    pub fn new(susceptible: f64, exposed: f64, infectious: f64, recovered: f64, dead: f64) -> Self {
+        Self {
+            susceptible,
+            exposed,
+            infectious,
+            recovered,
+            dead,
+        }
+    }
+
+    pub fn count(&mut self, compartment: Compartment) {
+        match compartment {
+            Compartment::Susceptible => self.susceptible += 1.0,
+            Compartment::Exposed => self.exposed += 1.0,
+            Compartment::Infectious => self.infectious += 1.0,
+            Compartment::Recovered => self.recovered += 1.0,
+            Compartment::Dead => self.dead += 1.0,
+        }
+    }
+
+    // Living hosts, i.e. everyone but the dead.
+    pub fn living(&self) -> f64 {
+        self.susceptible + self.exposed + self.infectious + self.recovered
+    }
+
+    pub fn total(&self) -> f64 {
+        self.living() + self.dead
+    }
+
+    // Hosts who have caught the infection at some point.
+    pub fn ever_infected(&self) -> f64 {
+        self.exposed + self.infectious + self.recovered + self.dead
+    }
+}
@@ -0,0 +1,18 @@
+//! Infectious disease: pathogens, compartments and the SEIR model.
+//!
+//! - `pathogen` → the `Pathogen` definition and who can catch it
+//! - `compartments` → `Compartment` and the `SeirCounts` statistics
+//! - `seir` → the aggregate `Seir` model
+//! - `pathogens` → bacterial, viral, fungal and protist pathogens
+//!
+//! The `Epidemic` simulation rule spreads a pathogen through a population.
+//! See the module README.md for detailed guidance.
+
+pub mod compartments;
+pub mod pathogen;
+pub mod pathogens;
+pub mod seir;
+
+pub use compartments::{Compartment, SeirCounts};
+pub use pathogen::{Pathogen, PathogenKind};
+pub use seir::Seir;
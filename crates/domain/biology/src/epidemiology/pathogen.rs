@@ -0,0 +1,86 @@
+use std::fmt::{Display, Formatter};
+use crate::Organism;
+use crate::classification::species::covers;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum PathogenKind {
+    Bacterial,
+    Viral,
+    Fungal,
+    Protist,
+}
+
+impl Display for PathogenKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            PathogenKind::Bacterial => "Bacterial",
+            PathogenKind::Viral => "Viral",
+            PathogenKind::Fungal => "Fungal",
+            PathogenKind::Protist => "Protist",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+/// An infectious agent and the course its infections take.
+///
+/// Like the anatomy templates, pathogens are plain data, published as
+/// constants (`biology::epidemiology::pathogens::YERSINIA_PESTIS`).
+/// Periods are in simulation ticks.
+///
+/// `hosts` and `vectors` list species or genera ("Rattus" covers
+/// "Rattus rattus"). An empty `hosts` list means any species can fall ill.
+/// Vectors carry the pathogen without symptoms; when a pathogen has
+/// vectors, it only spreads along `Interaction::Parasitism` links (bites).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pathogen {
+    pub name: &'static str,
+    pub kind: PathogenKind,
+    pub hosts: &'static [&'static str],
+    pub vectors: &'static [&'static str],
+    // Chance of passing the infection on at each contact.
+    pub transmission: f64,
+    // Ticks between exposure and becoming infectious; 0 skips the exposed stage.
+    pub incubation: u32,
+    pub infectious_period: u32,
+    // Chance an infection ends in death rather than recovery.
+    pub lethality: f64,
+    // Degrees added to the host's temperature while infectious.
+    pub fever: u32,
+    // Beats per minute added to the host's pulse while infectious.
+    pub tachycardia: u32,
+    // Energy drained from the host each infectious tick.
+    pub virulence: f32,
+    // Whether recovered hosts stay immune, or become susceptible again.
+    pub lasting_immunity: bool,
+}
+
+impl Pathogen {
+    pub fn infects(&self, species: &str) -> bool {
+        self.hosts.is_empty() || matches_any(self.hosts, species)
+    }
+
+    pub fn carried_by(&self, species: &str) -> bool {
+        matches_any(self.vectors, species)
+    }
+
+    pub fn is_vector_borne(&self) -> bool {
+        !self.vectors.is_empty()
+    }
+
+    /// Whether this organism can catch the pathogen: a living host or vector.
+    pub fn is_susceptible(&self, host: &dyn Organism) -> bool {
+        let species = host.species();
+        host.is_alive() && (self.infects(&species) || self.carried_by(&species))
+    }
+
+    // Average number of ticks from exposure to the end of the infection.
+    pub fn duration(&self) -> u32 {
+        self.incubation + self.infectious_period
+    }
+}
+
+fn matches_any(names: &[&str], species: &str) -> bool {
+    names.iter().any(|name| covers(name, species))
+}
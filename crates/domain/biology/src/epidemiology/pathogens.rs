@@ -0,0 +1,156 @@
+// Pathogens of every kind, for the `Epidemic` rule.
+// Figures are rough population averages for modelling, with ticks as days.
+
+use crate::epidemiology::pathogen::{Pathogen, PathogenKind};
+
+/// Yersinia pestis - plague; kept in rodent populations and spread by their fleas.
+pub const YERSINIA_PESTIS: Pathogen = Pathogen {
+    name: "Yersinia pestis",
+    kind: PathogenKind::Bacterial,
+    hosts: &["Rattus", "Cynomys", "Spermophilus", "Homo sapiens"],
+    vectors: &["Xenopsylla cheopis"],
+    transmission: 0.3,
+    incubation: 4,
+    infectious_period: 6,
+    lethality: 0.6,
+    fever: 3,
+    tachycardia: 35,
+    virulence: 0.1,
+    lasting_immunity: true,
+};
+
+/// Mycobacterium tuberculosis - slow airborne infection with a long infectious period.
+pub const MYCOBACTERIUM_TUBERCULOSIS: Pathogen = Pathogen {
+    name: "Mycobacterium tuberculosis",
+    kind: PathogenKind::Bacterial,
+    hosts: &["Homo sapiens"],
+    vectors: &[],
+    transmission: 0.01,
+    incubation: 60,
+    infectious_period: 180,
+    lethality: 0.45,
+    fever: 1,
+    tachycardia: 15,
+    virulence: 0.005,
+    lasting_immunity: false,
+};
+
+/// Rabies lyssavirus - spread by bites between mammals; almost always fatal once symptomatic.
+pub const RABIES: Pathogen = Pathogen {
+    name: "Rabies lyssavirus",
+    kind: PathogenKind::Viral,
+    hosts: &["Canis", "Vulpes", "Procyon", "Mephitis", "Homo sapiens"],
+    vectors: &[],
+    transmission: 0.05,
+    incubation: 45,
+    infectious_period: 7,
+    lethality: 0.99,
+    fever: 2,
+    tachycardia: 30,
+    virulence: 0.05,
+    lasting_immunity: true,
+};
+
+/// Influenza A - fast-spreading respiratory virus; immunity fades as strains drift.
+pub const INFLUENZA_A: Pathogen = Pathogen {
+    name: "Influenza A virus",
+    kind: PathogenKind::Viral,
+    hosts: &["Homo sapiens", "Sus", "Anas", "Gallus"],
+    vectors: &[],
+    transmission: 0.15,
+    incubation: 2,
+    infectious_period: 5,
+    lethality: 0.001,
+    fever: 2,
+    tachycardia: 20,
+    virulence: 0.03,
+    lasting_immunity: false,
+};
+
+/// Batrachochytrium dendrobatidis - chytrid fungus; spreads through water between amphibians.
+pub const BATRACHOCHYTRIUM_DENDROBATIDIS: Pathogen = Pathogen {
+    name: "Batrachochytrium dendrobatidis",
+    kind: PathogenKind::Fungal,
+    hosts: &["Lithobates", "Atelopus", "Bufo", "Rana"],
+    vectors: &[],
+    transmission: 0.2,
+    incubation: 10,
+    infectious_period: 20,
+    lethality: 0.8,
+    fever: 0,
+    tachycardia: 10,
+    virulence: 0.04,
+    lasting_immunity: false,
+};
+
+/// Pseudogymnoascus destructans - white-nose syndrome; spreads between hibernating bats.
+pub const PSEUDOGYMNOASCUS_DESTRUCTANS: Pathogen = Pathogen {
+    name: "Pseudogymnoascus destructans",
+    kind: PathogenKind::Fungal,
+    hosts: &["Myotis", "Perimyotis", "Eptesicus"],
+    vectors: &[],
+    transmission: 0.25,
+    incubation: 30,
+    infectious_period: 60,
+    lethality: 0.7,
+    fever: 0,
+    tachycardia: 20,
+    virulence: 0.02,
+    lasting_immunity: false,
+};
+
+/// Plasmodium falciparum - malaria; passed between people only by Anopheles mosquito bites.
+pub const PLASMODIUM_FALCIPARUM: Pathogen = Pathogen {
+    name: "Plasmodium falciparum",
+    kind: PathogenKind::Protist,
+    hosts: &["Homo sapiens"],
+    vectors: &["Anopheles"],
+    transmission: 0.3,
+    incubation: 10,
+    infectious_period: 14,
+    lethality: 0.01,
+    fever: 3,
+    tachycardia: 25,
+    virulence: 0.06,
+    lasting_immunity: false,
+};
+
+/// Toxoplasma gondii - mostly mild in warm-blooded hosts; cats shed it for everyone else.
+pub const TOXOPLASMA_GONDII: Pathogen = Pathogen {
+    name: "Toxoplasma gondii",
+    kind: PathogenKind::Protist,
+    hosts: &["Felis", "Mus", "Rattus", "Homo sapiens"],
+    vectors: &[],
+    transmission: 0.05,
+    incubation: 7,
+    infectious_period: 21,
+    lethality: 0.001,
+    fever: 1,
+    tachycardia: 5,
+    virulence: 0.01,
+    lasting_immunity: true,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plague_and_malaria_are_vector_borne() {
+        assert!(YERSINIA_PESTIS.infects("Rattus rattus"));
+        assert!(YERSINIA_PESTIS.carried_by("Xenopsylla cheopis"));
+        assert!(YERSINIA_PESTIS.is_vector_borne());
+        assert!(!MYCOBACTERIUM_TUBERCULOSIS.is_vector_borne());
+        assert!(PLASMODIUM_FALCIPARUM.carried_by("Anopheles gambiae"));
+        assert!(!PLASMODIUM_FALCIPARUM.infects("Anopheles gambiae"));
+        assert!(TOXOPLASMA_GONDII.infects("Felis catus"));
+    }
+
+    #[test]
+    fn test_fungal_pathogens_match_genera() {
+        assert!(BATRACHOCHYTRIUM_DENDROBATIDIS.infects("Atelopus zeteki"));
+        assert!(!BATRACHOCHYTRIUM_DENDROBATIDIS.infects("Myotis lucifugus"));
+        assert!(PSEUDOGYMNOASCUS_DESTRUCTANS.infects("Myotis lucifugus"));
+        assert_eq!(PSEUDOGYMNOASCUS_DESTRUCTANS.kind, PathogenKind::Fungal);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::epidemiology::compartments::SeirCounts;
+use crate::epidemiology::pathogen::Pathogen;
+
+/// The aggregate SEIR model, with deaths split off the removed hosts:
+///
+/// ```text
+/// dS/dt = -beta * S * I / N
+/// dE/dt =  beta * S * I / N - sigma * E
+/// dI/dt =  sigma * E - gamma * I
+/// dR/dt =  (1 - fatality) * gamma * I
+/// dD/dt =  fatality * gamma * I
+/// ```
+///
+/// `N` is the living population. Like `LotkaVolterra`, it is a well-mixed
+/// baseline to compare simulated outbreaks against.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Seir {
+    // Infectious contacts per infectious host per tick.
+    pub beta: f64,
+    // Rate at which exposed hosts become infectious (1 / incubation).
+    pub sigma: f64,
+    // Rate at which infectious hosts are removed (1 / infectious period).
+    pub gamma: f64,
+    pub fatality: f64,
+}
+
+impl Seir {
+    pub fn new(beta: f64, sigma: f64, gamma: f64, fatality: f64) -> Self {
+        Self {
+            beta,
+            sigma,
+            gamma,
+            fatality,
+        }
+    }
+
+    /// Parameters implied by a pathogen when each host meets `contacts` others per tick.
+    ///
+    /// Pathogens without an incubation period get a one-tick exposed stage,
+    /// which keeps the model well-behaved and close to SIR.
+    pub fn for_pathogen(pathogen: &Pathogen, contacts: f64) -> Self {
+        Self {
+            beta: pathogen.transmission * contacts,
+            sigma: 1.0 / pathogen.incubation.max(1) as f64,
+            gamma: 1.0 / pathogen.infectious_period.max(1) as f64,
+            fatality: pathogen.lethality,
+        }
+    }
+
+    // Basic reproduction number: infections caused by one case in a fully susceptible population.
+    pub fn r0(&self) -> f64 {
+        self.beta / self.gamma
+    }
+
+    // Share of the population that must be immune for an outbreak to shrink.
+    pub fn herd_immunity_threshold(&self) -> f64 {
+        (1.0 - 1.0 / self.r0()).max(0.0)
+    }
+
+    pub fn rates(&self, counts: SeirCounts) -> SeirCounts {
+        let living = counts.living();
+        let new_infections = if living > 0.0 {
+            self.beta * counts.susceptible * counts.infectious / living
+        } else {
+            0.0
+        };
+        let onsets = self.sigma * counts.exposed;
+        let removals = self.gamma * counts.infectious;
+
+        SeirCounts {
+            susceptible: -new_infections,
+            exposed: new_infections - onsets,
+            infectious: onsets - removals,
+            recovered: (1.0 - self.fatality) * removals,
+            dead: self.fatality * removals,
+        }
+    }
+
+    // Advances the model by `dt` with a fourth-order Runge–Kutta step.
+    pub fn step(&self, counts: SeirCounts, dt: f64) -> SeirCounts {
+        let k1 = self.rates(counts);
+        let k2 = self.rates(offset(counts, k1, dt / 2.0));
+        let k3 = self.rates(offset(counts, k2, dt / 2.0));
+        let k4 = self.rates(offset(counts, k3, dt));
+
+        let slope = SeirCounts {
+            susceptible: k1.susceptible + 2.0 * k2.susceptible + 2.0 * k3.susceptible + k4.susceptible,
+            exposed: k1.exposed + 2.0 * k2.exposed + 2.0 * k3.exposed + k4.exposed,
+            infectious: k1.infectious + 2.0 * k2.infectious + 2.0 * k3.infectious + k4.infectious,
+            recovered: k1.recovered + 2.0 * k2.recovered + 2.0 * k3.recovered + k4.recovered,
+            dead: k1.dead + 2.0 * k2.dead + 2.0 * k3.dead + k4.dead,
+        };
+        let next = offset(counts, slope, dt / 6.0);
+
+        SeirCounts {
+            susceptible: next.susceptible.max(0.0),
+            exposed: next.exposed.max(0.0),
+            infectious: next.infectious.max(0.0),
+            recovered: next.recovered.max(0.0),
+            dead: next.dead.max(0.0),
+        }
+    }
+
+    // Counts after each of `ticks` steps of length `dt`, starting with `initial`.
+    pub fn trajectory(&self, initial: SeirCounts, dt: f64, ticks: usize) -> Vec<SeirCounts> {
+        let mut counts = initial;
+        let mut trajectory = Vec::with_capacity(ticks + 1);
+        trajectory.push(counts);
+        for _ in 0..ticks {
+            counts = self.step(counts, dt);
+            trajectory.push(counts);
+        }
+        trajectory
+    }
+}
+
+fn offset(base: SeirCounts, rate: SeirCounts, factor: f64) -> SeirCounts {
+    SeirCounts {
+        susceptible: base.susceptible + rate.susceptible * factor,
+        exposed: base.exposed + rate.exposed * factor,
+        infectious: base.infectious + rate.infectious * factor,
+        recovered: base.recovered + rate.recovered * factor,
+        dead: base.dead + rate.dead * factor,
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use core_shared::Identifier;
+use crate::genealogy::pedigree::Pedigree;
+
+impl Pedigree {
+    /// The coefficient of kinship (coancestry) of two individuals.
+    ///
+    /// The chance that an allele drawn at random from each of them is
+    /// identical by descent: 0.5 for an individual with itself (more if it
+    /// is inbred), 0.25 between a parent and child or two full siblings,
+    /// 0.125 between half siblings, and 0 between unrelated individuals.
+    /// Founders and parents missing from the pedigree count as unrelated.
+    ///
+    /// Computed recursively from the pedigree (Karigl's method): the
+    /// kinship of A and B is the mean of the kinships of A's parents with B,
+    /// taking A as whichever of the two is further from the founders. The
+    /// young of an asexual birth are clones, as related to everyone as their
+    /// one parent.
+    pub fn kinship(&self, first: Identifier, second: Identifier) -> f64 {
+        Kinship::new(self).between(first, second)
+    }
+
+    // Wright's inbreeding coefficient: the kinship of the parents, 0 for founders.
+    pub fn inbreeding(&self, id: Identifier) -> f64 {
+        Kinship::new(self).inbreeding(id)
+    }
+
+    // Twice the kinship: the share of genes the two have in common by descent.
+    pub fn relatedness(&self, first: Identifier, second: Identifier) -> f64 {
+        2.0 * self.kinship(first, second)
+    }
+}
+
+// Memoises depths and kinships for one query, since pedigrees share ancestors heavily.
+struct Kinship<'a> {
+    pedigree: &'a Pedigree,
+    depths: HashMap<Identifier, u32>,
+    kinships: HashMap<(Identifier, Identifier), f64>,
+}
+
+impl<'a> Kinship<'a> {
+    fn new(pedigree: &'a Pedigree) -> Self {
+        Self {
+            pedigree,
+            depths: HashMap::new(),
+            kinships: HashMap::new(),
+        }
+    }
+
+    fn inbreeding(&mut self, id: Identifier) -> f64 {
+        match self.parents(id).as_slice() {
+            [mother, father] => self.between(*mother, *father),
+            [mother] => self.inbreeding(*mother),
+            _ => 0.0,
+        }
+    }
+
+    fn between(&mut self, first: Identifier, second: Identifier) -> f64 {
+        let key = if first <= second { (first, second) } else { (second, first) };
+        if let Some(&kinship) = self.kinships.get(&key) {
+            return kinship;
+        }
+
+        let kinship = if first == second {
+            0.5 * (1.0 + self.inbreeding(first))
+        } else {
+            // Recurse through the one further from the founders; it cannot be an ancestor of the other.
+            let (younger, other) = if self.depth(first) >= self.depth(second) {
+                (first, second)
+            } else {
+                (second, first)
+            };
+            match self.parents(younger).as_slice() {
+                [mother, father] => 0.5 * (self.between(*mother, other) + self.between(*father, other)),
+                [mother] => self.between(*mother, other),
+                _ => 0.0,
+            }
+        };
+        self.kinships.insert(key, kinship);
+        kinship
+    }
+
+    // Parents nearer the founders than the individual. Any others can only
+    // come from parent links that form a loop, and are left out.
+    fn parents(&mut self, id: Identifier) -> Vec<Identifier> {
+        let depth = self.depth(id);
+        let parents = self.pedigree.parents_of(id).to_vec();
+        parents
+            .into_iter()
+            .filter(|&parent| self.depth(parent) < depth)
+            .collect()
+    }
+
+    // Generations from the furthest founder: 0 for founders.
+    fn depth(&mut self, id: Identifier) -> u32 {
+        if let Some(&depth) = self.depths.get(&id) {
+            return depth;
+        }
+        // Provisional, so a loop of parent links ends here.
+        self.depths.insert(id, 0);
+        let depth = self
+            .pedigree
+            .parents_of(id)
+            .to_vec()
+            .into_iter()
+            .map(|parent| self.depth(parent) + 1)
+            .max()
+            .unwrap_or(0);
+        self.depths.insert(id, depth);
+        depth
+    }
+}
@@ -0,0 +1,17 @@
+//! Family trees: who descends from whom, and how closely related they are.
+//!
+//! - `pedigree` → `Pedigree` of `Individual`s, ancestors and descendants
+//!   to N generations, common ancestors
+//! - `kinship` → kinship, relatedness and inbreeding coefficients
+//! - `studbook` → studbook export, numbered with sire and dam
+//!
+//! Pedigrees are keyed by `Identifier`, so they work for any organism with
+//! parent links in a `Population`. GEDCOM export for humans lives in
+//! `biology_animalia::genealogy`, which knows how human names are built.
+//! See the module README.md for detailed guidance.
+
+pub mod kinship;
+pub mod pedigree;
+pub mod studbook;
+
+pub use pedigree::{Individual, Pedigree, Relative};
@@ -0,0 +1,200 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use core_shared::Identifier;
+use crate::Sex;
+use crate::ecosystem::population::Population;
+
+/// One member of a pedigree, as recorded when the pedigree was taken.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Individual {
+    pub id: Identifier,
+    pub species: String,
+    pub sex: Sex,
+    pub name: String,
+    pub alive: bool,
+}
+
+/// An ancestor or descendant, and how many generations away it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Relative {
+    pub id: Identifier,
+    // 1 for parents and children, 2 for grandparents and grandchildren, ...
+    pub generation: u32,
+}
+
+/// A family tree: who is descended from whom.
+///
+/// Individuals are kept in the order they were added, which for a
+/// population is the order members joined it, so queries and exports are
+/// stable from run to run. Parents are listed mother (or only parent)
+/// first, as `Population::set_parents` records them. Parents need not be
+/// individuals of the pedigree themselves: a parent that was never added
+/// is simply an ancestor nothing more is known about.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pedigree {
+    individuals: Vec<Individual>,
+    parents: BTreeMap<Identifier, Vec<Identifier>>,
+}
+
+impl Pedigree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Every member of the population, with the parent links it records.
+    pub fn from_population(population: &Population) -> Self {
+        let mut pedigree = Self::new();
+        for member in population.members() {
+            let individual = Individual {
+                id: member.id(),
+                species: member.species(),
+                sex: member.sex(),
+                name: member.display_name(),
+                alive: member.is_alive(),
+            };
+            pedigree.add(individual, population.parents_of(member.id()));
+        }
+        pedigree
+    }
+
+    // Adds an individual, or replaces the one with the same id.
+    pub fn add(&mut self, individual: Individual, parents: &[Identifier]) {
+        let id = individual.id;
+        match self.individuals.iter_mut().find(|known| known.id == id) {
+            Some(known) => *known = individual,
+            None => self.individuals.push(individual),
+        }
+        if parents.is_empty() {
+            self.parents.remove(&id);
+        } else {
+            self.parents.insert(id, parents.iter().copied().filter(|&parent| parent != id).collect());
+        }
+    }
+
+    pub fn individuals(&self) -> &[Individual] {
+        &self.individuals
+    }
+
+    pub fn individual(&self, id: Identifier) -> Option<&Individual> {
+        self.individuals.iter().find(|individual| individual.id == id)
+    }
+
+    pub fn size(&self) -> usize {
+        self.individuals.len()
+    }
+
+    // Empty for founders.
+    pub fn parents_of(&self, id: Identifier) -> &[Identifier] {
+        self.parents.get(&id).map_or(&[], Vec::as_slice)
+    }
+
+    // The first parent: the mother, or the only parent of an asexual birth.
+    pub fn mother_of(&self, id: Identifier) -> Option<Identifier> {
+        self.parents_of(id).first().copied()
+    }
+
+    pub fn father_of(&self, id: Identifier) -> Option<Identifier> {
+        self.parents_of(id).get(1).copied()
+    }
+
+    // Children in the order they were added.
+    pub fn children_of(&self, id: Identifier) -> Vec<Identifier> {
+        self.individuals
+            .iter()
+            .map(|individual| individual.id)
+            .filter(|&child| self.parents_of(child).contains(&id))
+            .collect()
+    }
+
+    // Individuals without recorded parents.
+    pub fn founders(&self) -> Vec<Identifier> {
+        self.individuals
+            .iter()
+            .map(|individual| individual.id)
+            .filter(|&id| self.parents_of(id).is_empty())
+            .collect()
+    }
+
+    // Ancestors up to `generations` back, nearest first; each is listed once,
+    // at the nearest generation it appears.
+    pub fn ancestors(&self, id: Identifier, generations: u32) -> Vec<Relative> {
+        self.walk(id, generations, |id| self.parents_of(id).to_vec())
+    }
+
+    // Descendants up to `generations` down, nearest first.
+    pub fn descendants(&self, id: Identifier, generations: u32) -> Vec<Relative> {
+        self.walk(id, generations, |id| self.children_of(id))
+    }
+
+    /// Ancestors the two individuals share, nearest first.
+    ///
+    /// Distance is the number of generations from both individuals combined;
+    /// an individual that is itself an ancestor of the other counts, at
+    /// generation 0 on its own side. Ancestors only reachable through a
+    /// nearer common ancestor are included too, e.g. both parents of a shared
+    /// grandparent.
+    pub fn common_ancestors(&self, first: Identifier, second: Identifier) -> Vec<Identifier> {
+        // The individual itself at generation 0, then its ancestors.
+        let lineage = |id: Identifier| {
+            std::iter::once(Relative { id, generation: 0 })
+                .chain(self.ancestors(id, u32::MAX))
+                .collect::<Vec<Relative>>()
+        };
+        let second_lineage: HashMap<Identifier, u32> = lineage(second)
+            .into_iter()
+            .map(|relative| (relative.id, relative.generation))
+            .collect();
+
+        let mut common: Vec<(Identifier, u32)> = lineage(first)
+            .into_iter()
+            .filter_map(|relative| Some((relative.id, relative.generation + second_lineage.get(&relative.id)?)))
+            .collect();
+        common.sort_by_key(|&(_, distance)| distance);
+        common.into_iter().map(|(id, _)| id).collect()
+    }
+
+    // The shared ancestors not reached through a nearer one.
+    pub fn most_recent_common_ancestors(&self, first: Identifier, second: Identifier) -> Vec<Identifier> {
+        let common = self.common_ancestors(first, second);
+        common
+            .iter()
+            .copied()
+            .filter(|&ancestor| {
+                !common
+                    .iter()
+                    .any(|&other| other != ancestor && self.is_ancestor(ancestor, other))
+            })
+            .collect()
+    }
+
+    pub fn is_ancestor(&self, ancestor: Identifier, of: Identifier) -> bool {
+        self.ancestors(of, u32::MAX)
+            .iter()
+            .any(|relative| relative.id == ancestor)
+    }
+
+    // Breadth-first, so every relative is found at its nearest generation.
+    // The visited set also guards against parent links that form a loop.
+    fn walk(&self, id: Identifier, generations: u32, next: impl Fn(Identifier) -> Vec<Identifier>) -> Vec<Relative> {
+        let mut seen: HashSet<Identifier> = HashSet::from([id]);
+        let mut relatives = Vec::new();
+        let mut frontier = vec![id];
+        let mut generation = 0;
+
+        while !frontier.is_empty() && generation < generations {
+            generation += 1;
+            let mut reached = Vec::new();
+            for id in frontier {
+                for relative in next(id) {
+                    if seen.insert(relative) {
+                        relatives.push(Relative { id: relative, generation });
+                        reached.push(relative);
+                    }
+                }
+            }
+            frontier = reached;
+        }
+        relatives
+    }
+}
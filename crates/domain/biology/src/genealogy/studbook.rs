@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use core_shared::Identifier;
+use crate::Sex;
+use crate::genealogy::pedigree::Pedigree;
+
+impl Pedigree {
+    /// A studbook listing, tab-separated, one row per individual.
+    ///
+    /// Individuals are numbered from 1 in pedigree order, and sire and dam
+    /// refer to those numbers, as in zoo and breed studbooks. Parents
+    /// outside the pedigree, or not recorded, are `UNK`. The `Identifier`
+    /// column ties each row back to the population.
+    ///
+    /// ```text
+    /// Stud    Sex  Sire  Dam  Status  F       Species        Name           Identifier
+    /// 1       F    UNK   UNK  Alive   0.0000  Canis latrans  Canis latrans  6f1c…
+    /// ```
+    pub fn to_studbook(&self) -> String {
+        let numbers: HashMap<Identifier, usize> = self
+            .individuals()
+            .iter()
+            .enumerate()
+            .map(|(index, individual)| (individual.id, index + 1))
+            .collect();
+        let number = |id: Option<Identifier>| {
+            id.and_then(|id| numbers.get(&id))
+                .map_or_else(|| "UNK".to_string(), usize::to_string)
+        };
+
+        let mut studbook = String::from("Stud\tSex\tSire\tDam\tStatus\tF\tSpecies\tName\tIdentifier\n");
+        for (index, individual) in self.individuals().iter().enumerate() {
+            let sex = match individual.sex {
+                Sex::Male => "M",
+                Sex::Female => "F",
+                Sex::Unknown => "U",
+            };
+            let _ = writeln!(
+                studbook,
+                "{}\t{}\t{}\t{}\t{}\t{:.4}\t{}\t{}\t{}",
+                index + 1,
+                sex,
+                number(self.father_of(individual.id)),
+                number(self.mother_of(individual.id)),
+                if individual.alive { "Alive" } else { "Dead" },
+                self.inbreeding(individual.id),
+                escape_tsv(&individual.species),
+                escape_tsv(&individual.name),
+                String::from(individual.id)
+            );
+        }
+        studbook
+    }
+}
+
+fn escape_tsv(text: &str) -> String {
+    text.replace(['\t', '\n', '\r'], " ")
+}
@@ -0,0 +1,127 @@
+use std::collections::BTreeMap;
+use core_shared::SeededRng;
+use crate::genetics::locus::Locus;
+
+/// The two alleles an organism carries at one locus, one from each parent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Genotype {
+    pub first: String,
+    pub second: String,
+}
+
+impl Genotype {
+    pub fn new(first: &str, second: &str) -> Self {
+        Self {
+            first: first.to_string(),
+            second: second.to_string(),
+        }
+    }
+
+    pub fn is_homozygous(&self) -> bool {
+        self.first == self.second
+    }
+
+    pub fn contains(&self, symbol: &str) -> bool {
+        self.first == symbol || self.second == symbol
+    }
+}
+
+/// An organism's genotypes, keyed by locus name.
+///
+/// Loci are inherited independently (no linkage), and a genome may hold
+/// loci its species' published definitions do not know about; they are
+/// passed on but never expressed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct Genome(BTreeMap<String, Genotype>);
+
+impl Genome {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, locus: &str, first: &str, second: &str) -> Self {
+        self.set(locus, Genotype::new(first, second));
+        self
+    }
+
+    // Two uniformly random alleles at every locus, e.g. for founders.
+    pub fn random(loci: &[Locus], rng: &mut SeededRng) -> Self {
+        let mut genome = Self::new();
+        for locus in loci {
+            if let (Some(first), Some(second)) = (locus.random_allele(rng), locus.random_allele(rng)) {
+                genome.set(locus.name, Genotype::new(first, second));
+            }
+        }
+        genome
+    }
+
+    pub fn set(&mut self, locus: &str, genotype: Genotype) {
+        self.0.insert(locus.to_string(), genotype);
+    }
+
+    pub fn genotype(&self, locus: &str) -> Option<&Genotype> {
+        self.0.get(locus)
+    }
+
+    pub fn loci(&self) -> impl Iterator<Item = (&str, &Genotype)> {
+        self.0.iter().map(|(name, genotype)| (name.as_str(), genotype))
+    }
+
+    // The alleles shown at the locus; `None` if the genome lacks it.
+    pub fn expressed(&self, locus: &Locus) -> Option<Vec<&'static str>> {
+        self.genotype(locus.name).map(|genotype| locus.expressed(genotype))
+    }
+
+    pub fn value(&self, locus: &Locus) -> Option<f64> {
+        self.genotype(locus.name).map(|genotype| locus.value(genotype))
+    }
+
+    /// A child's genome by Mendelian inheritance.
+    ///
+    /// At every locus of the mother's genome the child gets one of her two
+    /// alleles at random and one of the father's; without a father (or
+    /// where he lacks the locus) it gets a copy of hers, as in asexual
+    /// reproduction. Each inherited allele then mutates into another allele
+    /// of its locus with `mutation_rate`; loci not in `loci` never mutate.
+    pub fn inherit(
+        mother: &Genome,
+        father: Option<&Genome>,
+        loci: &[Locus],
+        mutation_rate: f64,
+        rng: &mut SeededRng,
+    ) -> Genome {
+        let mut child = Genome::new();
+        for (name, maternal) in mother.loci() {
+            let paternal = father.and_then(|father| father.genotype(name));
+            let (first, second) = match paternal {
+                Some(paternal) => (gamete(maternal, rng), gamete(paternal, rng)),
+                None => (maternal.first.clone(), maternal.second.clone()),
+            };
+
+            let locus = loci.iter().find(|locus| locus.name == name);
+            let mut mutated = |symbol: String| match locus {
+                Some(locus) if rng.chance(mutation_rate) => {
+                    locus.mutate(&symbol, rng).map_or(symbol, str::to_string)
+                }
+                _ => symbol,
+            };
+            let genotype = Genotype {
+                first: mutated(first),
+                second: mutated(second),
+            };
+            child.set(name, genotype);
+        }
+        child
+    }
+}
+
+// One of the two alleles, with even odds.
+fn gamete(genotype: &Genotype, rng: &mut SeededRng) -> String {
+    if rng.chance(0.5) {
+        genotype.first.clone()
+    } else {
+        genotype.second.clone()
+    }
+}
@@ -0,0 +1,29 @@
+use core_shared::SeededRng;
+use crate::genetics::genome::Genome;
+use crate::genetics::locus::Locus;
+
+/// The loci a `Reproduction` rule passes on, and how often they mutate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heredity {
+    pub loci: Vec<Locus>,
+    // Chance per allele per birth of turning into another allele of its locus.
+    pub mutation_rate: f64,
+}
+
+impl Heredity {
+    pub fn new(loci: &[Locus]) -> Self {
+        Self {
+            loci: loci.to_vec(),
+            mutation_rate: 0.001,
+        }
+    }
+
+    pub fn with_mutation_rate(mut self, mutation_rate: f64) -> Self {
+        self.mutation_rate = mutation_rate;
+        self
+    }
+
+    pub fn inherit(&self, mother: &Genome, father: Option<&Genome>, rng: &mut SeededRng) -> Genome {
+        Genome::inherit(mother, father, &self.loci, self.mutation_rate, rng)
+    }
+}
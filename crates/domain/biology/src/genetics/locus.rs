@@ -0,0 +1,110 @@
+use core_shared::SeededRng;
+use crate::genetics::genome::{Genome, Genotype};
+
+/// One variant of a gene.
+///
+/// `dominance` decides what a heterozygote shows: an allele with a higher
+/// level masks one with a lower level (complete dominance), while alleles
+/// of equal level both show (codominance, like the A and B blood groups)
+/// and their effects average out (incomplete dominance).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Allele {
+    pub symbol: &'static str,
+    pub dominance: u8,
+    // Contribution to a quantitative trait; 0.0 for purely qualitative genes.
+    pub effect: f64,
+}
+
+/// A gene and its known alleles.
+///
+/// Like pathogens, loci are plain data, so kingdom crates publish them as
+/// constants (`biology_animalia::genetics::ABO`). Genomes store allele
+/// symbols; the locus gives them meaning.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Locus {
+    pub name: &'static str,
+    pub alleles: &'static [Allele],
+}
+
+impl Locus {
+    pub fn allele(&self, symbol: &str) -> Option<&'static Allele> {
+        self.alleles.iter().find(|allele| allele.symbol == symbol)
+    }
+
+    // The alleles a genotype shows, in the locus's order; unknown symbols are ignored.
+    pub fn expressed(&self, genotype: &Genotype) -> Vec<&'static str> {
+        let present: Vec<&'static Allele> = [&genotype.first, &genotype.second]
+            .into_iter()
+            .filter_map(|symbol| self.allele(symbol))
+            .collect();
+        let Some(top) = present.iter().map(|allele| allele.dominance).max() else {
+            return Vec::new();
+        };
+
+        self.alleles
+            .iter()
+            .filter(|allele| allele.dominance == top && present.contains(allele))
+            .map(|allele| allele.symbol)
+            .collect()
+    }
+
+    // The mean effect of the expressed alleles, counting a homozygote's allele twice.
+    pub fn value(&self, genotype: &Genotype) -> f64 {
+        let expressed = self.expressed(genotype);
+        let effects: Vec<f64> = [&genotype.first, &genotype.second]
+            .into_iter()
+            .filter(|symbol| expressed.contains(&symbol.as_str()))
+            .filter_map(|symbol| self.allele(symbol))
+            .map(|allele| allele.effect)
+            .collect();
+        if effects.is_empty() {
+            return 0.0;
+        }
+        effects.iter().sum::<f64>() / effects.len() as f64
+    }
+
+    // A uniformly random allele, e.g. for founders or mutations.
+    pub fn random_allele(&self, rng: &mut SeededRng) -> Option<&'static str> {
+        if self.alleles.is_empty() {
+            return None;
+        }
+        Some(self.alleles[rng.below(self.alleles.len() as u64) as usize].symbol)
+    }
+
+    // Any allele but `symbol`; `None` if the locus has no other.
+    pub fn mutate(&self, symbol: &str, rng: &mut SeededRng) -> Option<&'static str> {
+        let others: Vec<&'static str> = self
+            .alleles
+            .iter()
+            .map(|allele| allele.symbol)
+            .filter(|other| *other != symbol)
+            .collect();
+        if others.is_empty() {
+            return None;
+        }
+        Some(others[rng.below(others.len() as u64) as usize])
+    }
+}
+
+/// A quantitative trait shaped by several loci, like stature.
+///
+/// Each locus adds its `value` to `base`, so most genomes land near the
+/// middle and the extremes need many alleles pulling the same way.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolygenicTrait {
+    pub name: &'static str,
+    pub base: f64,
+    pub loci: &'static [Locus],
+}
+
+impl PolygenicTrait {
+    // Loci missing from the genome add nothing.
+    pub fn value(&self, genome: &Genome) -> f64 {
+        self.base
+            + self
+                .loci
+                .iter()
+                .filter_map(|locus| Some(locus.value(genome.genotype(locus.name)?)))
+                .sum::<f64>()
+    }
+}
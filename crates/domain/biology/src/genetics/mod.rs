@@ -0,0 +1,18 @@
+//! Genomes, alleles and how they are inherited.
+//!
+//! - `locus` → `Locus` and `Allele` definitions, dominance and expression,
+//!   and `PolygenicTrait`s summed over several loci
+//! - `genome` → `Genome` and `Genotype`, Mendelian inheritance and mutation
+//! - `heredity` → `Heredity`, the loci and mutation rate a run passes on
+//!
+//! Kingdom crates publish their loci and map them to phenotype fields; the
+//! `Reproduction` rule passes genomes on when given a `Heredity`.
+//! See the module README.md for detailed guidance.
+
+pub mod genome;
+pub mod heredity;
+pub mod locus;
+
+pub use genome::{Genome, Genotype};
+pub use heredity::Heredity;
+pub use locus::{Allele, Locus, PolygenicTrait};
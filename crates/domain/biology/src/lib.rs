@@ -6,7 +6,18 @@ pub mod characteristics;
 pub mod patterns;
 pub mod templates;
 pub mod ecosystem;
+pub mod demography;
+pub mod epidemiology;
+pub mod genealogy;
+pub mod genetics;
+pub mod reproduction;
+pub mod simulation;
+pub mod builder;
+pub mod medical;
+#[cfg(feature = "serde")]
+pub mod persistence;
 
 pub use classification::sex::Sex;
 pub use nomenclature::nomenclature::Nomenclature;
 pub use characteristics::presentation::Summarizable;
+pub use characteristics::organism::Organism;
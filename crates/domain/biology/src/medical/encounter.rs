@@ -0,0 +1,104 @@
+use std::fmt::{Display, Formatter};
+use crate::vitals::live_vitals::LiveVitals;
+
+/// Why the patient was seen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum EncounterKind {
+    #[default]
+    Checkup,
+    FollowUp,
+    Emergency,
+    Admission,
+    Discharge,
+    // Added by `MedicalRecord::close` with the post-mortem report.
+    PostMortem,
+}
+
+impl Display for EncounterKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EncounterKind::Checkup => write!(f, "Checkup"),
+            EncounterKind::FollowUp => write!(f, "Follow-up"),
+            EncounterKind::Emergency => write!(f, "Emergency"),
+            EncounterKind::Admission => write!(f, "Admission"),
+            EncounterKind::Discharge => write!(f, "Discharge"),
+            EncounterKind::PostMortem => write!(f, "Post-mortem"),
+        }
+    }
+}
+
+/// One visit, with the vitals taken during it.
+///
+/// Timestamps are ISO 8601 (`"2024-03-01T09:30"`, or just a date), so they
+/// sort in the order they happened.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Encounter {
+    pub timestamp: String,
+    pub kind: EncounterKind,
+    pub reason: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub clinician: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub vitals: Option<LiveVitals>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub notes: Vec<String>,
+}
+
+impl Encounter {
+    pub fn new(timestamp: &str, kind: EncounterKind, reason: &str) -> Self {
+        Self {
+            timestamp: timestamp.to_string(),
+            kind,
+            reason: reason.to_string(),
+            clinician: None,
+            vitals: None,
+            notes: Vec::new(),
+        }
+    }
+
+    pub fn with_clinician(mut self, clinician: &str) -> Self {
+        self.clinician = Some(clinician.to_string());
+        self
+    }
+
+    pub fn with_vitals(mut self, vitals: LiveVitals) -> Self {
+        self.vitals = Some(vitals);
+        self
+    }
+
+    pub fn with_note(mut self, note: &str) -> Self {
+        self.notes.push(note.to_string());
+        self
+    }
+
+    // The date part of the timestamp.
+    pub fn date(&self) -> &str {
+        self.timestamp.split('T').next().unwrap_or(&self.timestamp)
+    }
+}
+
+impl Display for Encounter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}: {}", self.timestamp, self.kind, self.reason)?;
+        if let Some(clinician) = &self.clinician {
+            write!(f, " ({})", clinician)?;
+        }
+        if let Some(vitals) = &self.vitals {
+            write!(
+                f,
+                "; BP {}/{}, {} °C, {} BPM, {}",
+                vitals.blood_pressure.0,
+                vitals.blood_pressure.1,
+                vitals.temperature.0,
+                vitals.pulse.0,
+                vitals.describe_respiration()
+            )?;
+        }
+        for note in &self.notes {
+            write!(f, "; {}", note)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+use std::fmt::{Display, Formatter};
+
+/// How bad an allergic reaction is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Severity {
+    Mild,
+    #[default]
+    Moderate,
+    Severe,
+    LifeThreatening,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Mild => write!(f, "mild"),
+            Severity::Moderate => write!(f, "moderate"),
+            Severity::Severe => write!(f, "severe"),
+            Severity::LifeThreatening => write!(f, "life-threatening"),
+        }
+    }
+}
+
+/// A substance the patient reacts to, e.g. penicillin causing hives.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Allergy {
+    pub substance: String,
+    pub reaction: String,
+    pub severity: Severity,
+}
+
+impl Allergy {
+    pub fn new(substance: &str, reaction: &str, severity: Severity) -> Self {
+        Self {
+            substance: substance.to_string(),
+            reaction: reaction.to_string(),
+            severity,
+        }
+    }
+}
+
+impl Display for Allergy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}: {})", self.substance, self.severity, self.reaction)
+    }
+}
+
+/// A diagnosis; ongoing until it has a `resolved` date.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Condition {
+    pub name: String,
+    pub diagnosed: Option<String>,
+    pub resolved: Option<String>,
+}
+
+impl Condition {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            diagnosed: None,
+            resolved: None,
+        }
+    }
+
+    pub fn diagnosed_on(mut self, date: &str) -> Self {
+        self.diagnosed = Some(date.to_string());
+        self
+    }
+
+    pub fn resolved_on(mut self, date: &str) -> Self {
+        self.resolved = Some(date.to_string());
+        self
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.resolved.is_none()
+    }
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        match (&self.diagnosed, &self.resolved) {
+            (Some(diagnosed), Some(resolved)) => write!(f, " ({} to {})", diagnosed, resolved),
+            (None, Some(resolved)) => write!(f, " (resolved {})", resolved),
+            (Some(diagnosed), None) => write!(f, " (since {})", diagnosed),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// A prescription: what, how much and how often; current until it is stopped.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Medication {
+    pub name: String,
+    // Free text, e.g. "500 mg".
+    pub dose: String,
+    // Free text, e.g. "twice daily" or "as needed".
+    pub frequency: String,
+    pub started: Option<String>,
+    pub stopped: Option<String>,
+}
+
+impl Medication {
+    pub fn new(name: &str, dose: &str, frequency: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            dose: dose.to_string(),
+            frequency: frequency.to_string(),
+            started: None,
+            stopped: None,
+        }
+    }
+
+    pub fn started_on(mut self, date: &str) -> Self {
+        self.started = Some(date.to_string());
+        self
+    }
+
+    pub fn stopped_on(mut self, date: &str) -> Self {
+        self.stopped = Some(date.to_string());
+        self
+    }
+
+    pub fn is_current(&self) -> bool {
+        self.stopped.is_none()
+    }
+}
+
+impl Display for Medication {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}, {}", self.name, self.dose, self.frequency)
+    }
+}
+
+/// An operation or other intervention, on a given date.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Procedure {
+    pub name: String,
+    pub date: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub notes: Option<String>,
+}
+
+impl Procedure {
+    pub fn new(name: &str, date: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            date: date.to_string(),
+            notes: None,
+        }
+    }
+
+    pub fn with_notes(mut self, notes: &str) -> Self {
+        self.notes = Some(notes.to_string());
+        self
+    }
+}
+
+impl Display for Procedure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.date, self.name)?;
+        match &self.notes {
+            Some(notes) => write!(f, " - {}", notes),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Medical records: a patient's history, for clinical-training scenarios.
+//!
+//! - `record` → `MedicalRecord`: date of birth and age, allergies,
+//!   conditions, medications, procedures, encounters, and the post-mortem
+//!   report that closes it
+//! - `entries` → `Allergy`, `Condition`, `Medication`, `Procedure`
+//! - `encounter` → `Encounter`, a timestamped visit with its `LiveVitals`
+//!
+//! Records hold no organism; `biology_animalia::Human` carries one and keeps
+//! its vitals in step with the encounters it records.
+//! See the module README.md for detailed guidance.
+
+pub mod encounter;
+pub mod entries;
+pub mod record;
+
+pub use encounter::{Encounter, EncounterKind};
+pub use entries::{Allergy, Condition, Medication, Procedure, Severity};
+pub use record::MedicalRecord;
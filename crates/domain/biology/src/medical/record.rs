@@ -0,0 +1,191 @@
+use std::fmt::Display;
+use crate::characteristics::presentation::Summarizable;
+use crate::medical::encounter::{Encounter, EncounterKind};
+use crate::medical::entries::{Allergy, Condition, Medication, Procedure};
+use crate::vitals::live_vitals::LiveVitals;
+use crate::vitals::post_mortem_report::PostMortemReport;
+
+/// A patient's medical history.
+///
+/// Dates are ISO 8601 strings, matching `PostMortemReport`, so they
+/// compare and sort as text. Encounters are kept in the order they
+/// happened, whatever order they were recorded in, and each may hold the
+/// `LiveVitals` taken at the time. `close` files the post-mortem report
+/// as the last encounter.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let mut record = MedicalRecord::new().with_date_of_birth("1990-04-12");
+/// record.add_allergy(Allergy::new("Penicillin", "Hives", Severity::Moderate));
+/// record.record_encounter(
+///     Encounter::new("2024-03-01T09:30", EncounterKind::Checkup, "Annual physical").with_vitals(vitals),
+/// );
+/// assert_eq!(record.age_on("2024-03-01"), Some(33));
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct MedicalRecord {
+    pub date_of_birth: Option<String>,
+    pub allergies: Vec<Allergy>,
+    pub conditions: Vec<Condition>,
+    pub medications: Vec<Medication>,
+    pub procedures: Vec<Procedure>,
+    // Private so it stays in timestamp order; see `record_encounter`.
+    encounters: Vec<Encounter>,
+    pub post_mortem: Option<PostMortemReport>,
+}
+
+impl MedicalRecord {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_date_of_birth(mut self, date: &str) -> Self {
+        self.date_of_birth = Some(date.to_string());
+        self
+    }
+
+    // Whole years on the given date; None without a date of birth, before it, or for a malformed date.
+    pub fn age_on(&self, date: &str) -> Option<u32> {
+        let (born_year, born_month, born_day) = parse_date(self.date_of_birth.as_deref()?)?;
+        let (year, month, day) = parse_date(date)?;
+        let years = year.checked_sub(born_year)?;
+        if (month, day) < (born_month, born_day) {
+            years.checked_sub(1)
+        } else {
+            Some(years)
+        }
+    }
+
+    pub fn add_allergy(&mut self, allergy: Allergy) {
+        self.allergies.push(allergy);
+    }
+
+    pub fn diagnose(&mut self, condition: Condition) {
+        self.conditions.push(condition);
+    }
+
+    pub fn prescribe(&mut self, medication: Medication) {
+        self.medications.push(medication);
+    }
+
+    pub fn add_procedure(&mut self, procedure: Procedure) {
+        self.procedures.push(procedure);
+    }
+
+    // Files the encounter among the others by timestamp.
+    pub fn record_encounter(&mut self, encounter: Encounter) {
+        let at = self.encounters.partition_point(|earlier| earlier.timestamp <= encounter.timestamp);
+        self.encounters.insert(at, encounter);
+    }
+
+    // Closes the record: keeps the report and files the examination as the last encounter.
+    pub fn close(&mut self, report: PostMortemReport) {
+        let encounter = Encounter::new(&report.date_of_death, EncounterKind::PostMortem, &report.cause_of_death);
+        let encounter = report.findings.iter().fold(encounter, |encounter, finding| encounter.with_note(finding));
+        self.record_encounter(encounter);
+        self.post_mortem = Some(report);
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.post_mortem.is_some()
+    }
+
+    // Oldest first.
+    pub fn encounters(&self) -> &[Encounter] {
+        &self.encounters
+    }
+
+    // Encounters on or after `from` and before `until`, compared as ISO 8601 text.
+    pub fn encounters_between(&self, from: &str, until: &str) -> Vec<&Encounter> {
+        self.encounters
+            .iter()
+            .filter(|encounter| encounter.timestamp.as_str() >= from && encounter.timestamp.as_str() < until)
+            .collect()
+    }
+
+    // Every reading taken, oldest first, with when it was taken.
+    pub fn vitals_history(&self) -> Vec<(&str, &LiveVitals)> {
+        self.encounters
+            .iter()
+            .filter_map(|encounter| Some((encounter.timestamp.as_str(), encounter.vitals.as_ref()?)))
+            .collect()
+    }
+
+    pub fn latest_vitals(&self) -> Option<&LiveVitals> {
+        self.vitals_history().last().map(|(_, vitals)| *vitals)
+    }
+
+    pub fn active_conditions(&self) -> Vec<&Condition> {
+        self.conditions.iter().filter(|condition| condition.is_active()).collect()
+    }
+
+    pub fn current_medications(&self) -> Vec<&Medication> {
+        self.medications.iter().filter(|medication| medication.is_current()).collect()
+    }
+
+    // Ignores case, so "penicillin" matches an allergy to "Penicillin".
+    pub fn is_allergic_to(&self, substance: &str) -> bool {
+        self.allergies
+            .iter()
+            .any(|allergy| allergy.substance.eq_ignore_ascii_case(substance))
+    }
+
+    // Current medications named after a substance the patient is allergic to, e.g. "Penicillin V".
+    pub fn allergy_warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for medication in self.current_medications() {
+            let name = medication.name.to_lowercase();
+            for allergy in &self.allergies {
+                if name.contains(&allergy.substance.to_lowercase()) {
+                    warnings.push(format!(
+                        "{} is prescribed despite a {} allergy to {}",
+                        medication.name, allergy.severity, allergy.substance
+                    ));
+                }
+            }
+        }
+        warnings
+    }
+}
+
+// "YYYY-MM-DD", optionally followed by a time, as (year, month, day); None for months past 12 or days past 31.
+fn parse_date(date: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = date.get(..10)?.split('-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts.next()?.parse().ok().filter(|month| (1..=12).contains(month))?;
+    let day = parts.next()?.parse().ok().filter(|day| (1..=31).contains(day))?;
+    Some((year, month, day))
+}
+
+// "None recorded" for an empty section, otherwise one entry per line.
+fn section<T: Display>(title: &str, entries: &[T]) -> String {
+    if entries.is_empty() {
+        return format!("{}: None recorded\n", title);
+    }
+    let lines: Vec<String> = entries.iter().map(|entry| format!("  - {}\n", entry)).collect();
+    format!("{}:\n{}", title, lines.concat())
+}
+
+impl Summarizable for MedicalRecord {
+    fn summary(&self) -> String {
+        let mut summary = String::from("--- Medical Record ---\n");
+        summary.push_str(&format!(
+            "Date of birth: {}\n",
+            self.date_of_birth.as_deref().unwrap_or("Unknown")
+        ));
+        summary.push_str(&section("Allergies", &self.allergies));
+        summary.push_str(&section("Conditions", &self.conditions));
+        summary.push_str(&section("Current medications", &self.current_medications()));
+        summary.push_str(&section("Procedures", &self.procedures));
+        summary.push_str(&section("Encounters", &self.encounters));
+        for warning in self.allergy_warnings() {
+            summary.push_str(&format!("WARNING: {}\n", warning));
+        }
+        if let Some(report) = &self.post_mortem {
+            summary.push_str(&format!("Deceased {}: {}\n", report.date_of_death, report.cause_of_death));
+        }
+        summary
+    }
+}
@@ -0,0 +1,138 @@
+//! Culture-aware naming conventions and formatting styles.
+//!
+//! Structured names are stored once (as `NomenclatureComponents`) and rendered
+//! according to a `NamingConvention`, which captures the ordering rules of a
+//! naming culture, and a `NameStyle`, which captures the purpose of the output.
+
+use crate::nomenclature::nomenclature::NomenclatureComponents;
+
+/// The ordering rules a structured name follows.
+///
+/// Conventions are named after the rule rather than a single culture, since
+/// several cultures share each rule (e.g. Hungarian names are also family-name-first).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum NamingConvention {
+    /// Given name first, family name last: "Jane Marie Smith".
+    #[default]
+    GivenNameFirst,
+    /// Family name first, given name last: "Mao Zedong", "Yamada Taro".
+    FamilyNameFirst,
+    /// Given name followed by a patronymic (or matronymic) rather than a family name.
+    /// Sorted and addressed by the given name: "Björk Guðmundsdóttir".
+    Patronymic,
+    /// Given name followed by paternal and maternal surnames: "Gabriel García Márquez".
+    DoubleSurname,
+}
+
+impl NamingConvention {
+    /// Picks the convention for a BCP 47 language tag such as `"ja-JP"` or `"is"`.
+    ///
+    /// Only the primary language subtag is considered. Unknown languages fall
+    /// back to `GivenNameFirst`.
+    pub fn for_locale(tag: &str) -> Self {
+        let language = tag
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match language.as_str() {
+            "zh" | "ja" | "ko" | "vi" | "hu" => NamingConvention::FamilyNameFirst,
+            "is" => NamingConvention::Patronymic,
+            "es" => NamingConvention::DoubleSurname,
+            _ => NamingConvention::GivenNameFirst,
+        }
+    }
+}
+
+/// The purpose a formatted name is rendered for.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum NameStyle {
+    /// Every component, including prefix and suffix: "Dr. Jane Marie Smith Jr."
+    #[default]
+    Formal,
+    /// The given name alone: "Jane".
+    Informal,
+    /// A key suitable for alphabetical listings: "Smith, Jane Marie".
+    SortKey,
+    /// Initials of the name components in display order: "J. M. S."
+    Initials,
+}
+
+/// Formats structured names according to their naming convention.
+///
+/// Types only need to report which convention their name follows; the
+/// formatting itself is provided by `format_name()`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let name = NameParts::parse("Mao Zedong", NamingConvention::FamilyNameFirst);
+///
+/// assert_eq!(name.format_name(NameStyle::Formal), "Mao Zedong");
+/// assert_eq!(name.format_name(NameStyle::SortKey), "Mao, Zedong");
+/// assert_eq!(name.format_name(NameStyle::Informal), "Zedong");
+/// ```
+pub trait NameFormatting: NomenclatureComponents {
+    fn naming_convention(&self) -> NamingConvention;
+
+    fn format_name(&self, style: NameStyle) -> String {
+        let convention = self.naming_convention();
+        let given = join([self.first_name(), self.middle_name()]);
+        let family = match convention {
+            NamingConvention::DoubleSurname => join([self.last_name(), self.second_last_name()]),
+            _ => join([self.last_name(), None]),
+        };
+
+        let ordered = match convention {
+            NamingConvention::FamilyNameFirst => join([family.clone(), given.clone()]),
+            _ => join([given.clone(), family.clone()]),
+        };
+
+        match style {
+            NameStyle::Formal => join([self.prefix_name(), ordered, self.suffix_name()])
+                .unwrap_or_default(),
+            NameStyle::Informal => self
+                .first_name()
+                .or_else(|| join([self.prefix_name(), ordered]))
+                .unwrap_or_default(),
+            NameStyle::SortKey => {
+                let key = match convention {
+                    NamingConvention::Patronymic => join([given, family]),
+                    _ => match (family, given) {
+                        (Some(family), Some(given)) => Some(format!("{}, {}", family, given)),
+                        (family, given) => family.or(given),
+                    },
+                };
+                match (key, self.suffix_name()) {
+                    (Some(key), Some(suffix)) => format!("{}, {}", key, suffix),
+                    (key, _) => key.unwrap_or_default(),
+                }
+            }
+            NameStyle::Initials => ordered
+                .unwrap_or_default()
+                .split_whitespace()
+                .filter_map(|part| part.chars().next())
+                .map(|initial| format!("{}.", initial.to_uppercase()))
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+// Joins the present, non-empty parts with single spaces.
+fn join<const N: usize>(parts: [Option<String>; N]) -> Option<String> {
+    let present: Vec<String> = parts
+        .into_iter()
+        .flatten()
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    if present.is_empty() {
+        None
+    } else {
+        Some(present.join(" "))
+    }
+}
@@ -1 +1,6 @@
+#[allow(clippy::module_inception)]
 pub mod nomenclature;
+pub mod conventions;
+
+pub use conventions::{NameFormatting, NameStyle, NamingConvention};
+pub use nomenclature::{common_aliases, common_display_name};
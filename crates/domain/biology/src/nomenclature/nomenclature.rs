@@ -1,5 +1,36 @@
 pub trait Nomenclature {
     fn display_name(&self) -> String;
+
+    // Every name the entity is known by. Entities with a single name only have their display name.
+    fn aliases(&self) -> Vec<String> {
+        vec![self.display_name()]
+    }
+
+    // Case-insensitive match against any alias.
+    fn answers_to(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        self.aliases()
+            .iter()
+            .any(|alias| alias.to_lowercase() == query)
+    }
+}
+
+// For organisms named by an optional individual name, an optional common name
+// for the species (e.g. "Emperor penguin") and the species itself: the first
+// name they have.
+pub fn common_display_name(name: Option<&str>, common_name: Option<&str>, species: &str) -> String {
+    name.or(common_name).unwrap_or(species).to_string()
+}
+
+// Every one of those names, the display name first and without repeats.
+pub fn common_aliases(name: Option<&str>, common_name: Option<&str>, species: &str) -> Vec<String> {
+    let mut aliases: Vec<String> = Vec::new();
+    for alias in name.into_iter().chain(common_name).chain([species]) {
+        if !aliases.iter().any(|known| known == alias) {
+            aliases.push(alias.to_string());
+        }
+    }
+    aliases
 }
 
 pub trait NomenclatureComponents {
@@ -8,4 +39,9 @@ pub trait NomenclatureComponents {
     fn middle_name(&self) -> Option<String>;
     fn last_name(&self) -> Option<String>;
     fn suffix_name(&self) -> Option<String>;
-}
\ No newline at end of file
+
+    // Only naming conventions with double surnames (e.g. Spanish) use a second last name.
+    fn second_last_name(&self) -> Option<String> {
+        None
+    }
+}
@@ -2,14 +2,15 @@
 //!
 //! This module provides helper traits for analyzing anatomical structures.
 
-use crate::anatomy::appendage::Appendage;
-use crate::anatomy::limb_status::LimbStatus;
+use crate::anatomy::appendage::{Appendage, AppendageKind};
 
 /// Provides analysis tools for examining anatomical structures.
 ///
 /// This trait offers default implementations for common anatomical analysis tasks,
 /// such as counting limbs and describing limb status. Types only need to implement
-/// `get_appendages()` to gain access to all the helper methods.
+/// `get_appendages()` to gain access to all the helper methods. Descriptions
+/// cover every kind of appendage present, so the same methods serve humans,
+/// birds, and any other body plan built from `Appendage`s.
 ///
 /// # Examples
 ///
@@ -29,45 +30,92 @@ use crate::anatomy::limb_status::LimbStatus;
 pub trait AnatomyAnalyzer {
     fn get_appendages(&self) -> &Vec<Appendage>;
 
-    fn count_limbs(&self) -> (u32, u32, u32, u32) {
-        let mut attached_arms = 0;
-        let mut missing_arms = 0;
-        let mut attached_legs = 0;
-        let mut missing_legs = 0;
+    // Attached and missing appendages of one kind; injured and prosthetic ones count as attached.
+    fn count_appendages(&self, kind: AppendageKind) -> (u32, u32) {
+        let mut attached = 0;
+        let mut missing = 0;
+
+        for appendage in self.get_appendages().iter().filter(|appendage| appendage.kind() == kind) {
+            if appendage.status().is_missing() {
+                missing += 1;
+            } else {
+                attached += 1;
+            }
+        }
 
+        (attached, missing)
+    }
+
+    // The kinds of appendage present, in the order they first appear.
+    fn appendage_kinds(&self) -> Vec<AppendageKind> {
+        let mut kinds = Vec::new();
         for appendage in self.get_appendages() {
-            match appendage {
-                Appendage::Arm { status, .. } => match status {
-                    LimbStatus::Intact => attached_arms += 1,
-                    LimbStatus::Amputated => missing_arms += 1,
-                    _ => attached_arms += 1,
-                },
-                Appendage::Leg { status, .. } => match status {
-                    LimbStatus::Intact => attached_legs += 1,
-                    LimbStatus::Amputated => missing_legs += 1,
-                    _ => attached_legs += 1,
-                },
+            if !kinds.contains(&appendage.kind()) {
+                kinds.push(appendage.kind());
             }
         }
+        kinds
+    }
+
+    fn count_limbs(&self) -> (u32, u32, u32, u32) {
+        let (attached_arms, missing_arms) = self.count_appendages(AppendageKind::Arm);
+        let (attached_legs, missing_legs) = self.count_appendages(AppendageKind::Leg);
 
         (attached_arms, missing_arms, attached_legs, missing_legs)
     }
 
-    fn describe_limb_status(&self) -> String {
-        let (attached_arms, missing_arms, attached_legs, missing_legs) = self.count_limbs();
+    // Named by the legs the body plan has, lost ones included: a one-legged human is still bipedal.
+    fn body_form(&self) -> String {
+        let (attached, missing) = self.count_appendages(AppendageKind::Leg);
+        match attached + missing {
+            0 if self.appendage_kinds().contains(&AppendageKind::Fin) => "A finned form".to_string(),
+            0 if self.appendage_kinds().contains(&AppendageKind::Tentacle) => "A tentacled form".to_string(),
+            0 => "A legless form".to_string(),
+            2 => "A bipedal form".to_string(),
+            4 => "A quadrupedal form".to_string(),
+            6 => "A six-legged form".to_string(),
+            8 => "An eight-legged form".to_string(),
+            legs => format!("A {}-legged form", legs),
+        }
+    }
 
-        let arm_desc = format!("{} attached arms", attached_arms);
-        let leg_desc = format!("{} attached legs", attached_legs);
+    fn describe_limb_status(&self) -> String {
+        let kinds = self.appendage_kinds();
+        let counts: Vec<(AppendageKind, u32, u32)> = kinds
+            .iter()
+            .map(|&kind| {
+                let (attached, missing) = self.count_appendages(kind);
+                (kind, attached, missing)
+            })
+            .collect();
 
-        let mut description = format!("A bipedal form with {} and {}.", arm_desc, leg_desc);
+        let attached: Vec<String> = counts
+            .iter()
+            .map(|(kind, attached, _)| format!("{} attached {}", attached, kind.plural()))
+            .collect();
+        let mut description = if attached.is_empty() {
+            format!("{} without appendages.", self.body_form())
+        } else {
+            format!("{} with {}.", self.body_form(), join(&attached))
+        };
 
-        if missing_arms > 0 || missing_legs > 0 {
-            description.push_str(&format!(
-                " It is noted that {} arms and {} legs are missing.",
-                missing_arms, missing_legs
-            ));
+        if counts.iter().any(|(_, _, missing)| *missing > 0) {
+            let missing: Vec<String> = counts
+                .iter()
+                .map(|(kind, _, missing)| format!("{} {}", missing, kind.plural()))
+                .collect();
+            description.push_str(&format!(" It is noted that {} are missing.", join(&missing)));
         }
 
         description
     }
 }
+
+// "a", "a and b", "a, b and c".
+fn join(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
@@ -0,0 +1,101 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::persistence::error::PersistenceError;
+use crate::persistence::migration::{self, MigrationStep};
+
+/// Marks a document written by this project, as opposed to arbitrary JSON.
+pub const FORMAT: &str = "blocks_of_life";
+
+/// The envelope every saved document is wrapped in.
+///
+/// ```json
+/// { "format": "blocks_of_life", "kind": "organism", "schema_version": 1, "data": { ... } }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Document {
+    pub format: String,
+    pub kind: String,
+    pub schema_version: u32,
+    pub data: Value,
+}
+
+impl Document {
+    /// Reads an envelope, treating anything without one as unversioned (version 0) data.
+    ///
+    /// Version 0 is how the domain types serialized before documents were
+    /// versioned, so a bare `Human` saved with `serde_json::to_string` still loads.
+    pub fn read(value: Value, kind: &str) -> Result<Self, PersistenceError> {
+        let is_envelope = value.get("format").and_then(Value::as_str) == Some(FORMAT);
+        if is_envelope {
+            Ok(serde_json::from_value(value)?)
+        } else {
+            Ok(Self {
+                format: FORMAT.to_string(),
+                kind: kind.to_string(),
+                schema_version: 0,
+                data: value,
+            })
+        }
+    }
+}
+
+/// A type that can be saved as a versioned document and loaded from any older version.
+///
+/// Implementors declare their document kind, the current schema version, and
+/// the migration steps from every earlier version. Bump `SCHEMA_VERSION` and
+/// append a step whenever a change to the type would stop old documents from
+/// deserializing; additive changes with `#[serde(default)]` need neither.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// impl Versioned for OrganismRecord {
+///     const KIND: &'static str = "organism";
+///     const SCHEMA_VERSION: u32 = 1;
+///     const MIGRATIONS: &'static [MigrationStep] = ORGANISM_MIGRATIONS;
+/// }
+///
+/// let json = record.to_json()?;
+/// let restored = OrganismRecord::from_json(&json)?;
+/// ```
+pub trait Versioned: Serialize + DeserializeOwned {
+    const KIND: &'static str;
+    const SCHEMA_VERSION: u32;
+    const MIGRATIONS: &'static [MigrationStep];
+
+    fn to_document(&self) -> Result<Document, PersistenceError> {
+        Ok(Document {
+            format: FORMAT.to_string(),
+            kind: Self::KIND.to_string(),
+            schema_version: Self::SCHEMA_VERSION,
+            data: serde_json::to_value(self)?,
+        })
+    }
+
+    fn from_document(document: Document) -> Result<Self, PersistenceError> {
+        if document.kind != Self::KIND {
+            return Err(PersistenceError::WrongKind {
+                expected: Self::KIND,
+                found: document.kind,
+            });
+        }
+
+        let data = migration::upgrade(
+            Self::MIGRATIONS,
+            document.data,
+            document.schema_version,
+            Self::SCHEMA_VERSION,
+        )?;
+        Ok(serde_json::from_value(data)?)
+    }
+
+    fn to_json(&self) -> Result<String, PersistenceError> {
+        Ok(serde_json::to_string_pretty(&self.to_document()?)?)
+    }
+
+    fn from_json(json: &str) -> Result<Self, PersistenceError> {
+        let value: Value = serde_json::from_str(json)?;
+        Self::from_document(Document::read(value, Self::KIND)?)
+    }
+}
@@ -0,0 +1,69 @@
+use std::fmt::{Display, Formatter};
+
+/// Everything that can go wrong while saving or loading a versioned document.
+#[derive(Debug)]
+pub enum PersistenceError {
+    /// The text was not valid JSON, or the migrated data did not match the current types.
+    Json(serde_json::Error),
+    /// The document holds a different kind of data than was requested.
+    WrongKind {
+        expected: &'static str,
+        found: String,
+    },
+    /// The document was written by a newer release than this one.
+    UnsupportedVersion {
+        found: u32,
+        current: u32,
+    },
+    /// No migration step upgrades documents from this version.
+    MissingMigration {
+        from: u32,
+    },
+    /// A migration step could not make sense of the data it was given.
+    Migration {
+        from: u32,
+        reason: String,
+    },
+    /// The data is well-formed but cannot be saved or restored, e.g. a dangling member reference.
+    Invalid {
+        reason: String,
+    },
+}
+
+impl Display for PersistenceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PersistenceError::Json(error) => write!(f, "invalid document: {}", error),
+            PersistenceError::WrongKind { expected, found } => {
+                write!(f, "expected a {} document, found {}", expected, found)
+            }
+            PersistenceError::UnsupportedVersion { found, current } => write!(
+                f,
+                "document schema version {} is newer than the supported version {}",
+                found, current
+            ),
+            PersistenceError::MissingMigration { from } => {
+                write!(f, "no migration from schema version {}", from)
+            }
+            PersistenceError::Migration { from, reason } => {
+                write!(f, "migration from schema version {} failed: {}", from, reason)
+            }
+            PersistenceError::Invalid { reason } => write!(f, "invalid document: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for PersistenceError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PersistenceError::Json(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for PersistenceError {
+    fn from(error: serde_json::Error) -> Self {
+        PersistenceError::Json(error)
+    }
+}
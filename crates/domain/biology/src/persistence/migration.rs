@@ -0,0 +1,49 @@
+use serde_json::Value;
+use crate::persistence::error::PersistenceError;
+
+/// One step in a migration chain, upgrading data from `from` to `from + 1`.
+///
+/// Steps work on the untyped JSON tree, because the Rust types that could
+/// read the old layout no longer exist. Chains are plain data, so each
+/// document kind declares its history as a constant slice:
+///
+/// ```rust,ignore
+/// pub const ORGANISM_MIGRATIONS: &[MigrationStep] = &[
+///     MigrationStep {
+///         from: 0,
+///         description: "Wrap bare organisms in a tagged record",
+///         apply: tag_bare_organism,
+///     },
+/// ];
+/// ```
+#[derive(Clone, Copy)]
+pub struct MigrationStep {
+    pub from: u32,
+    pub description: &'static str,
+    pub apply: fn(Value) -> Result<Value, PersistenceError>,
+}
+
+/// Applies each step in turn until `data` reaches `target` version.
+pub fn upgrade(
+    steps: &[MigrationStep],
+    mut data: Value,
+    from: u32,
+    target: u32,
+) -> Result<Value, PersistenceError> {
+    if from > target {
+        return Err(PersistenceError::UnsupportedVersion {
+            found: from,
+            current: target,
+        });
+    }
+
+    for version in from..target {
+        let step = steps
+            .iter()
+            .find(|step| step.from == version)
+            .ok_or(PersistenceError::MissingMigration { from: version })?;
+        data = (step.apply)(data)?;
+    }
+
+    Ok(data)
+}
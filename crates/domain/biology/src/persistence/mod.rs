@@ -0,0 +1,19 @@
+//! Versioned documents for saving and loading domain types.
+//!
+//! Available with the `serde` feature. Every saved document carries a kind
+//! and schema version, and loading runs the migration chain from the
+//! document's version up to the current one:
+//!
+//! - `document` → `Document` envelope and the `Versioned` trait
+//! - `migration` → `MigrationStep` and the `upgrade` chain runner
+//! - `error` → `PersistenceError`
+//!
+//! See the module README.md for detailed guidance.
+
+pub mod document;
+pub mod error;
+pub mod migration;
+
+pub use document::{Document, Versioned};
+pub use error::PersistenceError;
+pub use migration::MigrationStep;
@@ -0,0 +1,16 @@
+//! Mating, gestation and incubation, and who is whose parent.
+//!
+//! - `strategy` → `ReproductiveStrategy` definitions: `MatingSystem`,
+//!   `Development`, periods and litter sizes
+//! - `pregnancy` → `Pregnancy`, young on the way, and the `Birth` handed to
+//!   offspring functions
+//!
+//! The `Reproduction` simulation rule pairs up mates and delivers their
+//! young; parent–child links are recorded in the `Population`.
+//! See the module README.md for detailed guidance.
+
+pub mod pregnancy;
+pub mod strategy;
+
+pub use pregnancy::{Birth, Pregnancy};
+pub use strategy::{Development, MatingSystem, ReproductiveStrategy};
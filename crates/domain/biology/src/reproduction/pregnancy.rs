@@ -0,0 +1,40 @@
+use core_shared::Identifier;
+use crate::genetics::genome::Genome;
+use crate::reproduction::strategy::Development;
+use crate::{Organism, Sex};
+
+/// Young on the way: carried by their mother, or laid as a clutch of eggs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pregnancy {
+    pub mother_id: Identifier,
+    // `None` for asexual reproduction.
+    pub father_id: Option<Identifier>,
+    pub development: Development,
+    pub litter: u32,
+    // Ticks of conception and of the birth or hatching.
+    pub conceived: u64,
+    pub due: u64,
+}
+
+impl Pregnancy {
+    pub fn is_due(&self, tick: u64) -> bool {
+        tick >= self.due
+    }
+}
+
+/// Everything an offspring function needs to build one newborn.
+///
+/// The biology crate cannot construct concrete organisms, so the
+/// `Reproduction` rule hands each birth to a function like
+/// `biology_animalia::reproduction::offspring`, which builds a `Human` or a
+/// `Mammal` that inherits from its parents.
+pub struct Birth<'a> {
+    // The id the newborn is to have, drawn from the simulation's seeded generator.
+    pub id: Identifier,
+    pub mother: &'a dyn Organism,
+    pub father: Option<&'a dyn Organism>,
+    pub sex: Sex,
+    // Inherited from the parents when the rule has a `Heredity` and the mother a genome.
+    pub genome: Option<Genome>,
+}
@@ -0,0 +1,98 @@
+use core_shared::SeededRng;
+use crate::classification::species::covers;
+use crate::{Organism, Sex};
+
+/// Who can mate with whom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum MatingSystem {
+    // A female and a male; the female bears the young.
+    Sexual,
+    // Any two members of the species, each able to bear young (snails, earthworms).
+    Hermaphroditic,
+    // No mate needed: every mature member reproduces alone.
+    Asexual,
+}
+
+/// How the young develop before they join the population.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Development {
+    // Carried by the mother and born alive; lost if she dies first.
+    Gestation,
+    // Laid as eggs at mating; they hatch whatever becomes of the mother.
+    Incubation,
+}
+
+/// How a species reproduces.
+///
+/// Like pathogens, strategies are plain data, so kingdom crates publish
+/// them as constants (`biology_animalia::reproduction::COYOTE`). `species`
+/// names a species or a whole genus. Periods are in simulation ticks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReproductiveStrategy {
+    pub species: &'static str,
+    pub mating: MatingSystem,
+    pub development: Development,
+    // Ticks from conception to birth, or from laying to hatching.
+    pub period: u32,
+    // The fewest and most young in one litter or clutch.
+    pub litter: (u32, u32),
+    // Age at which members can first mate.
+    pub maturity: u32,
+    // Ticks after a birth or laying before the mother can conceive again.
+    pub interval: u32,
+}
+
+impl ReproductiveStrategy {
+    pub fn applies_to(&self, species: &str) -> bool {
+        covers(self.species, species)
+    }
+
+    // Whether a member of this sex carries the young.
+    pub fn can_bear(&self, sex: Sex) -> bool {
+        match self.mating {
+            MatingSystem::Sexual => sex == Sex::Female,
+            MatingSystem::Hermaphroditic | MatingSystem::Asexual => true,
+        }
+    }
+
+    /// Whether `mate` can father `bearer`'s young.
+    ///
+    /// Both must be alive, distinct and of the same species, which this
+    /// strategy covers. Sexual species need a female and a male; members of
+    /// unknown sex cannot mate. Asexual species never take a mate.
+    pub fn compatible(&self, bearer: &dyn Organism, mate: &dyn Organism) -> bool {
+        if bearer.id() == mate.id() || !bearer.is_alive() || !mate.is_alive() {
+            return false;
+        }
+        let species = bearer.species();
+        if species != mate.species() || !self.applies_to(&species) {
+            return false;
+        }
+
+        match self.mating {
+            MatingSystem::Sexual => bearer.sex() == Sex::Female && mate.sex() == Sex::Male,
+            MatingSystem::Hermaphroditic => true,
+            MatingSystem::Asexual => false,
+        }
+    }
+
+    // Draws the size of one litter or clutch, uniformly between the bounds.
+    pub fn litter_size(&self, rng: &mut SeededRng) -> u32 {
+        let (fewest, most) = self.litter;
+        if most <= fewest {
+            return fewest;
+        }
+        fewest + rng.below((most - fewest + 1) as u64) as u32
+    }
+
+    // The newborn's sex: even odds for sexual species, otherwise the mother's.
+    pub fn offspring_sex(&self, mother: Sex, rng: &mut SeededRng) -> Sex {
+        match self.mating {
+            MatingSystem::Sexual if rng.chance(0.5) => Sex::Female,
+            MatingSystem::Sexual => Sex::Male,
+            MatingSystem::Hermaphroditic | MatingSystem::Asexual => mother,
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+use core_shared::Identifier;
+use crate::ecosystem::interactions::Interaction;
+use crate::ecosystem::landscape::Position;
+use crate::ecosystem::resources::{Claim, ResourceKind};
+use crate::simulation::engine::TickContext;
+use crate::simulation::events::SimulationEvent;
+use crate::simulation::rules::Rule;
+
+/// Refills the environment's resource pools by their regeneration rate,
+/// and those of every cell of the landscape, if there is one.
+///
+/// Register it before the rules that consume resources.
+pub struct Replenishment;
+
+impl Rule for Replenishment {
+    fn name(&self) -> &'static str {
+        "replenishment"
+    }
+
+    fn apply(&mut self, context: &mut TickContext) {
+        context.population.environment.resources.replenish();
+        if let Some(landscape) = context.population.landscape_mut() {
+            for cell in landscape.cells_mut() {
+                cell.resources.replenish();
+            }
+        }
+    }
+}
+
+/// Divides contested resources along `Interaction::Competition` links.
+///
+/// Every living member that competes for a resource claims `demand` units
+/// of it each tick, with a strength equal to its body mass in kilograms
+/// (1 when unknown). Competitors only meet when they share a cell of the
+/// landscape, and draw on that cell's pool; members off the landscape draw
+/// on the population's environment. The pool is shared out with
+/// `ResourcePool::allocate`, and each competitor gains `energy_value`
+/// scaled by how much of its demand it got. Energy is what keeps members
+/// alive (`Mortality`) and lets them breed (`Births`), so losing out on a
+/// resource shows up in both.
+pub struct Competition {
+    pub demand: f64,
+    pub energy_value: f32,
+}
+
+impl Default for Competition {
+    fn default() -> Self {
+        Self {
+            demand: 1.0,
+            energy_value: 0.1,
+        }
+    }
+}
+
+impl Rule for Competition {
+    fn name(&self) -> &'static str {
+        "competition"
+    }
+
+    fn apply(&mut self, context: &mut TickContext) {
+        let mut contests: BTreeMap<(Option<Position>, ResourceKind), Vec<Identifier>> = BTreeMap::new();
+        let links: Vec<Interaction> = context
+            .population
+            .interactions()
+            .iter()
+            .filter(|interaction| matches!(interaction, Interaction::Competition { .. }))
+            .cloned()
+            .collect();
+
+        for interaction in links {
+            let Interaction::Competition { competitor1_id, competitor2_id, resource } = interaction else {
+                continue;
+            };
+            if !context.is_alive(competitor1_id) || !context.is_alive(competitor2_id) {
+                continue;
+            }
+            let position = context.population.position(competitor1_id);
+            if position != context.population.position(competitor2_id) {
+                continue;
+            }
+
+            let competitors = contests.entry((position, resource)).or_default();
+            for id in [competitor1_id, competitor2_id] {
+                if !competitors.contains(&id) {
+                    competitors.push(id);
+                }
+            }
+            context.emit(SimulationEvent::Interacted { interaction });
+        }
+
+        for ((_, kind), competitors) in contests {
+            let claims: Vec<Claim> = competitors
+                .iter()
+                .map(|&claimant| Claim {
+                    claimant,
+                    demand: self.demand,
+                    strength: context
+                        .population
+                        .get(claimant)
+                        .and_then(|member| member.body_mass())
+                        .map_or(1.0, |mass| mass.0.max(1) as f64),
+                })
+                .collect();
+
+            let Some(pool) = context.population.environment_of_mut(competitors[0]).resources.get_mut(kind) else {
+                continue;
+            };
+            let shares = pool.allocate(&claims);
+
+            for (claim, share) in claims.iter().zip(shares) {
+                let satisfied = (share / self.demand) as f32;
+                context.state_mut(claim.claimant).adjust_energy(self.energy_value * satisfied);
+            }
+        }
+    }
+}
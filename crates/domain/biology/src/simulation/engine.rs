@@ -0,0 +1,239 @@
+use std::any::Any;
+use std::collections::HashMap;
+use core_shared::{Identifier, SeededRng};
+use crate::Organism;
+use crate::ecosystem::population::{Population, PopulationError};
+use crate::simulation::events::{CauseOfDeath, SimulationEvent, TickReport};
+use crate::simulation::rules::Rule;
+use crate::simulation::state::MemberState;
+use crate::vitals::post_mortem_report::PostMortemReport;
+
+/// Advances a population through discrete ticks.
+///
+/// Each tick applies the registered rules in the order they were added.
+/// Rules visit members in the population's insertion order and draw every
+/// random number from one seeded generator, so two simulations built the
+/// same way with the same seed produce the same births, deaths and
+/// interactions. Newborn ids come from the same generator, so they match too.
+///
+/// Age and energy are tracked while a member lives; once it dies only its
+/// age at death is kept, and nothing at all once it leaves the population.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let mut simulation = Simulation::new(desert, 42)
+///     .with_rule(Aging)
+///     .with_rule(Metabolism::default())
+///     .with_rule(Mortality::default());
+///
+/// for report in simulation.run(100) {
+///     println!("day {}: {} alive", report.tick, report.alive);
+/// }
+/// ```
+pub struct Simulation {
+    population: Population,
+    states: HashMap<Identifier, MemberState>,
+    ages_at_death: HashMap<Identifier, u32>,
+    rules: Vec<Box<dyn Rule>>,
+    rng: SeededRng,
+    tick: u64,
+}
+
+impl Simulation {
+    pub fn new(population: Population, seed: u64) -> Self {
+        Self {
+            population,
+            states: HashMap::new(),
+            ages_at_death: HashMap::new(),
+            rules: Vec::new(),
+            rng: SeededRng::new(seed),
+            tick: 0,
+        }
+    }
+
+    pub fn with_rule(mut self, rule: impl Rule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    // Sets a member's starting age or energy; members without one start at the default.
+    pub fn with_state(mut self, id: Identifier, state: MemberState) -> Self {
+        self.states.insert(id, state);
+        self
+    }
+
+    pub fn step(&mut self) -> TickReport {
+        self.tick += 1;
+        let mut events = Vec::new();
+
+        let mut context = TickContext {
+            tick: self.tick,
+            population: &mut self.population,
+            rng: &mut self.rng,
+            states: &mut self.states,
+            events: &mut events,
+        };
+        for rule in &mut self.rules {
+            rule.apply(&mut context);
+        }
+        self.prune(&events);
+
+        TickReport {
+            tick: self.tick,
+            events,
+            alive: self.population.members().filter(|member| member.is_alive()).count(),
+        }
+    }
+
+    // Keeps only the age at death of members who died this tick, and forgets
+    // members who have left the population.
+    fn prune(&mut self, events: &[SimulationEvent]) {
+        for event in events {
+            if let SimulationEvent::Died { id, .. } = event {
+                let age = self.states.remove(id).unwrap_or_default().age;
+                self.ages_at_death.insert(*id, age);
+            }
+        }
+        let population = &self.population;
+        self.states.retain(|&id, _| population.get(id).is_some_and(|member| member.is_alive()));
+        self.ages_at_death.retain(|&id, _| population.contains(id));
+    }
+
+    pub fn run(&mut self, ticks: u64) -> Vec<TickReport> {
+        (0..ticks).map(|_| self.step()).collect()
+    }
+
+    // The number of ticks run so far.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    pub fn population(&self) -> &Population {
+        &self.population
+    }
+
+    pub fn population_mut(&mut self) -> &mut Population {
+        &mut self.population
+    }
+
+    // The first registered rule of type `T`, e.g. `simulation.rule::<Epidemic>()`.
+    pub fn rule<T: Rule>(&self) -> Option<&T> {
+        self.rules.iter().find_map(|rule| {
+            let rule: &dyn Any = rule.as_ref();
+            rule.downcast_ref::<T>()
+        })
+    }
+
+    // A living member's state; `None` for the dead and for unknown ids.
+    pub fn state(&self, id: Identifier) -> Option<MemberState> {
+        if !self.population.get(id).is_some_and(|member| member.is_alive()) {
+            return None;
+        }
+        Some(self.states.get(&id).copied().unwrap_or_default())
+    }
+
+    // A living member's age, or a dead member's age at death.
+    pub fn age(&self, id: Identifier) -> Option<u32> {
+        self.state(id)
+            .map(|state| state.age)
+            .or_else(|| self.ages_at_death.get(&id).copied())
+    }
+
+    pub fn into_population(self) -> Population {
+        self.population
+    }
+}
+
+/// What a rule can see and change during a tick.
+pub struct TickContext<'a> {
+    pub tick: u64,
+    pub population: &'a mut Population,
+    pub rng: &'a mut SeededRng,
+    states: &'a mut HashMap<Identifier, MemberState>,
+    events: &'a mut Vec<SimulationEvent>,
+}
+
+impl TickContext<'_> {
+    // Ids of living members, in insertion order.
+    pub fn living(&self) -> Vec<Identifier> {
+        self.population
+            .members()
+            .filter(|member| member.is_alive())
+            .map(|member| member.id())
+            .collect()
+    }
+
+    pub fn is_alive(&self, id: Identifier) -> bool {
+        self.population.get(id).is_some_and(|member| member.is_alive())
+    }
+
+    pub fn state(&self, id: Identifier) -> MemberState {
+        self.states.get(&id).copied().unwrap_or_default()
+    }
+
+    pub fn state_mut(&mut self, id: Identifier) -> &mut MemberState {
+        self.states.entry(id).or_default()
+    }
+
+    // Marks a living member as deceased. Returns false if it was not alive.
+    pub fn kill(&mut self, id: Identifier, cause: CauseOfDeath) -> bool {
+        let Some(member) = self.population.get_mut(id) else {
+            return false;
+        };
+        if !member.is_alive() {
+            return false;
+        }
+
+        member.record_death(PostMortemReport::new(cause.to_string(), format!("Tick {}", self.tick), vec![]));
+        self.events.push(SimulationEvent::Died { id, cause });
+        true
+    }
+
+    // An id for a newborn, drawn from the seeded generator; pass it to the offspring function.
+    pub fn newborn_id(&mut self) -> Identifier {
+        Identifier::seeded(self.rng)
+    }
+
+    // Adds a newborn to the population with a fresh state, in its parent's cell.
+    pub fn spawn(
+        &mut self,
+        offspring: Box<dyn Organism>,
+        parent_id: Identifier,
+    ) -> Result<Identifier, PopulationError> {
+        self.spawn_from(offspring, parent_id, None)
+    }
+
+    /// Adds a newborn born to `mother_id`, and fathered by `father_id` if given.
+    ///
+    /// The newborn starts with a fresh state in its mother's cell, and its
+    /// parents are recorded in the population.
+    pub fn spawn_from(
+        &mut self,
+        offspring: Box<dyn Organism>,
+        mother_id: Identifier,
+        father_id: Option<Identifier>,
+    ) -> Result<Identifier, PopulationError> {
+        let parents: Vec<Identifier> = std::iter::once(mother_id).chain(father_id).collect();
+        let id = self.population.insert_boxed(offspring)?;
+        if let Err(error) = self.population.set_parents(id, &parents) {
+            self.population.remove(id);
+            return Err(error);
+        }
+        if let Some(position) = self.population.position(mother_id) {
+            self.population.place(id, position)?;
+        }
+        self.states.insert(id, MemberState::default());
+        self.events.push(SimulationEvent::Born { id, parent_id: mother_id });
+        Ok(id)
+    }
+
+    pub fn emit(&mut self, event: SimulationEvent) {
+        self.events.push(event);
+    }
+
+    // Events emitted so far this tick.
+    pub fn events(&self) -> &[SimulationEvent] {
+        self.events
+    }
+}
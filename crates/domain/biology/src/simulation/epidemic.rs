@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+use core_shared::Identifier;
+use crate::ecosystem::interactions::Interaction;
+use crate::epidemiology::compartments::{Compartment, SeirCounts};
+use crate::epidemiology::pathogen::Pathogen;
+use crate::simulation::engine::TickContext;
+use crate::simulation::events::{CauseOfDeath, SimulationEvent};
+use crate::simulation::rules::Rule;
+use crate::vitals::live_vitals::LiveVitals;
+use crate::vitals::vital_status::VitalStatus;
+use crate::vitals::vital_types::{Celsius, BPM};
+
+#[derive(Debug, Clone)]
+struct Infection {
+    compartment: Compartment,
+    since: u64,
+    // The host's vitals before symptoms began, restored on recovery.
+    baseline: Option<LiveVitals>,
+}
+
+/// Spreads one pathogen through the population.
+///
+/// Each tick, infections progress first: exposed hosts become infectious
+/// after the incubation period, gaining fever and tachycardia in their
+/// `LiveVitals` and losing `virulence` energy a tick; at the end of the
+/// infectious period they die (`CauseOfDeath::Disease`) with the
+/// pathogen's lethality, or recover with their old vitals back.
+///
+/// Then every infectious member makes contact with everyone it shares an
+/// interaction with, plus `contact_rate` random members. Each contact
+/// that is susceptible catches the infection with the pathogen's
+/// transmission chance, up to doubled for hosts out of energy.
+/// Vector-borne pathogens spread only along `Interaction::Parasitism`
+/// links, between vectors and hosts.
+///
+/// Read the outbreak back with `Simulation::rule::<Epidemic>()`.
+pub struct Epidemic {
+    pub pathogen: Pathogen,
+    pub contact_rate: u32,
+    infections: HashMap<Identifier, Infection>,
+    history: Vec<SeirCounts>,
+}
+
+impl Epidemic {
+    pub fn new(pathogen: Pathogen) -> Self {
+        Self {
+            pathogen,
+            contact_rate: 2,
+            infections: HashMap::new(),
+            history: Vec::new(),
+        }
+    }
+
+    pub fn with_contact_rate(mut self, contact_rate: u32) -> Self {
+        self.contact_rate = contact_rate;
+        self
+    }
+
+    // Introduces the infection: the member is infectious from the first tick.
+    pub fn with_case(mut self, id: Identifier) -> Self {
+        self.infections.insert(
+            id,
+            Infection {
+                compartment: Compartment::Infectious,
+                since: 0,
+                baseline: None,
+            },
+        );
+        self
+    }
+
+    pub fn compartment(&self, id: Identifier) -> Compartment {
+        self.infections
+            .get(&id)
+            .map_or(Compartment::Susceptible, |infection| infection.compartment)
+    }
+
+    // SEIR counts at the end of each tick so far.
+    pub fn history(&self) -> &[SeirCounts] {
+        &self.history
+    }
+
+    pub fn counts(&self) -> SeirCounts {
+        self.history.last().copied().unwrap_or_default()
+    }
+
+    fn progress(&mut self, context: &mut TickContext) {
+        for id in context.living() {
+            let Some(infection) = self.infections.get(&id) else {
+                continue;
+            };
+            let elapsed = context.tick.saturating_sub(infection.since);
+
+            match infection.compartment {
+                Compartment::Exposed if elapsed >= self.pathogen.incubation as u64 => {
+                    self.set(id, Compartment::Infectious, context.tick);
+                    self.fall_ill(id, context);
+                }
+                Compartment::Infectious => {
+                    if infection.baseline.is_none() {
+                        self.fall_ill(id, context);
+                    }
+                    context.state_mut(id).adjust_energy(-self.pathogen.virulence);
+                    if elapsed >= self.pathogen.infectious_period as u64 {
+                        self.resolve(id, context);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn fall_ill(&mut self, id: Identifier, context: &mut TickContext) {
+        let is_vector = self.is_vector(id, context);
+        let Some(VitalStatus::Alive(vitals)) = context.population.get_mut(id).map(|member| member.vitals_mut()) else {
+            return;
+        };
+
+        let baseline = vitals.clone();
+        if !is_vector {
+            vitals.temperature = Celsius(vitals.temperature.0 + self.pathogen.fever);
+            vitals.pulse = BPM(vitals.pulse.0 + self.pathogen.tachycardia);
+        }
+        if let Some(infection) = self.infections.get_mut(&id) {
+            infection.baseline = Some(baseline);
+        }
+    }
+
+    fn resolve(&mut self, id: Identifier, context: &mut TickContext) {
+        let pathogen = self.pathogen.name.to_string();
+        let is_vector = self.is_vector(id, context);
+
+        if !is_vector && context.rng.chance(self.pathogen.lethality) {
+            context.kill(id, CauseOfDeath::Disease { pathogen });
+            self.set(id, Compartment::Dead, context.tick);
+            return;
+        }
+
+        let baseline = self.infections.get_mut(&id).and_then(|infection| infection.baseline.take());
+        if let (Some(baseline), Some(member)) = (baseline, context.population.get_mut(id)) {
+            *member.vitals_mut() = VitalStatus::Alive(baseline);
+        }
+        if self.pathogen.lasting_immunity {
+            self.set(id, Compartment::Recovered, context.tick);
+        } else {
+            self.infections.remove(&id);
+        }
+        context.emit(SimulationEvent::Recovered { id, pathogen });
+    }
+
+    fn transmit(&mut self, context: &mut TickContext) {
+        let living = context.living();
+        let spreaders: Vec<Identifier> = living
+            .iter()
+            .copied()
+            .filter(|id| self.compartment(*id) == Compartment::Infectious)
+            .collect();
+
+        for spreader in spreaders {
+            for contact in self.contacts(spreader, &living, context) {
+                if self.compartment(contact) != Compartment::Susceptible {
+                    continue;
+                }
+                if !context.population.get(contact).is_some_and(|member| self.pathogen.is_susceptible(member)) {
+                    continue;
+                }
+                let weakness = 2.0 - context.state(contact).energy as f64;
+                let chance = self.pathogen.transmission * weakness;
+                if !context.rng.chance(chance) {
+                    continue;
+                }
+
+                let compartment = if self.pathogen.incubation == 0 {
+                    Compartment::Infectious
+                } else {
+                    Compartment::Exposed
+                };
+                self.set(contact, compartment, context.tick);
+                context.emit(SimulationEvent::Infected {
+                    id: contact,
+                    pathogen: self.pathogen.name.to_string(),
+                });
+            }
+        }
+    }
+
+    // Who an infectious member meets this tick, in a reproducible order.
+    fn contacts(&self, spreader: Identifier, living: &[Identifier], context: &mut TickContext) -> Vec<Identifier> {
+        let mut contacts = Vec::new();
+        for interaction in context.population.interactions_involving(spreader) {
+            if self.pathogen.is_vector_borne() && !matches!(interaction, Interaction::Parasitism { .. }) {
+                continue;
+            }
+            let (first, second) = interaction.participants();
+            contacts.push(if first == spreader { second } else { first });
+        }
+
+        if self.pathogen.is_vector_borne() {
+            // Vectors only infect hosts and hosts only infect vectors.
+            let spreader_is_vector = self.is_vector(spreader, context);
+            contacts.retain(|&contact| self.is_vector(contact, context) != spreader_is_vector);
+        } else if living.len() > 1 {
+            for _ in 0..self.contact_rate {
+                let contact = living[context.rng.below(living.len() as u64) as usize];
+                if contact != spreader {
+                    contacts.push(contact);
+                }
+            }
+        }
+        contacts
+    }
+
+    fn is_vector(&self, id: Identifier, context: &TickContext) -> bool {
+        context
+            .population
+            .get(id)
+            .is_some_and(|member| self.pathogen.carried_by(&member.species()))
+    }
+
+    fn set(&mut self, id: Identifier, compartment: Compartment, tick: u64) {
+        let baseline = self.infections.remove(&id).and_then(|infection| infection.baseline);
+        self.infections.insert(
+            id,
+            Infection {
+                compartment,
+                since: tick,
+                baseline,
+            },
+        );
+    }
+
+    // Hosts only; vectors carry the pathogen but are not counted as cases.
+    fn tally(&self, context: &TickContext) -> SeirCounts {
+        let mut counts = SeirCounts::default();
+        for member in context.population.members() {
+            if !self.pathogen.infects(&member.species()) || self.pathogen.carried_by(&member.species()) {
+                continue;
+            }
+            match self.compartment(member.id()) {
+                Compartment::Dead => counts.count(Compartment::Dead),
+                // Deaths from other causes leave the outbreak altogether.
+                _ if !member.is_alive() => {}
+                compartment => counts.count(compartment),
+            }
+        }
+        counts
+    }
+}
+
+impl Rule for Epidemic {
+    fn name(&self) -> &'static str {
+        "epidemic"
+    }
+
+    fn apply(&mut self, context: &mut TickContext) {
+        self.progress(context);
+        self.transmit(context);
+        let counts = self.tally(context);
+        self.history.push(counts);
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt::{Display, Formatter};
+use core_shared::Identifier;
+use crate::ecosystem::interactions::Interaction;
+use crate::ecosystem::landscape::Position;
+use crate::ecosystem::weather::WeatherEvent;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type", rename_all = "snake_case"))]
+pub enum CauseOfDeath {
+    OldAge,
+    Starvation,
+    Predation { predator_id: Identifier },
+    Disease { pathogen: String },
+    NaturalCauses,
+}
+
+impl Display for CauseOfDeath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CauseOfDeath::OldAge => write!(f, "Old age"),
+            CauseOfDeath::Starvation => write!(f, "Starvation"),
+            CauseOfDeath::Predation { predator_id } => write!(f, "Predation by {}", predator_id),
+            CauseOfDeath::Disease { pathogen } => write!(f, "Disease ({})", pathogen),
+            CauseOfDeath::NaturalCauses => write!(f, "Natural causes"),
+        }
+    }
+}
+
+/// Something that happened to the population during a tick.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type", rename_all = "snake_case"))]
+pub enum SimulationEvent {
+    Conceived {
+        mother_id: Identifier,
+        father_id: Option<Identifier>,
+        litter: u32,
+    },
+    Born {
+        id: Identifier,
+        parent_id: Identifier,
+    },
+    // A newborn the population would not take, and the rest of its litter with it.
+    BirthFailed {
+        mother_id: Identifier,
+        reason: String,
+    },
+    Died {
+        id: Identifier,
+        cause: CauseOfDeath,
+    },
+    Interacted {
+        interaction: Interaction,
+    },
+    Infected {
+        id: Identifier,
+        pathogen: String,
+    },
+    Recovered {
+        id: Identifier,
+        pathogen: String,
+    },
+    Moved {
+        id: Identifier,
+        from: Position,
+        to: Position,
+    },
+    WeatherBegan {
+        event: WeatherEvent,
+    },
+    WeatherEnded {
+        event: WeatherEvent,
+    },
+}
+
+/// Everything that happened during one tick, in the order it happened.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickReport {
+    pub tick: u64,
+    pub events: Vec<SimulationEvent>,
+    // Living members at the end of the tick.
+    pub alive: usize,
+}
+
+impl TickReport {
+    pub fn births(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|event| matches!(event, SimulationEvent::Born { .. }))
+            .count()
+    }
+
+    pub fn deaths(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|event| matches!(event, SimulationEvent::Died { .. }))
+            .count()
+    }
+}
@@ -0,0 +1,122 @@
+use crate::ecosystem::population::Population;
+
+/// Sizes of a predator and a prey population at one moment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PredatorPrey {
+    pub prey: f64,
+    pub predators: f64,
+}
+
+impl PredatorPrey {
+    pub fn new(prey: f64, predators: f64) -> Self {
+        Self { prey, predators }
+    }
+
+    // Counts the living members of each species, e.g. after every simulation tick.
+    pub fn census(population: &Population, prey_species: &str, predator_species: &str) -> Self {
+        Self {
+            prey: population.count_living(prey_species) as f64,
+            predators: population.count_living(predator_species) as f64,
+        }
+    }
+}
+
+/// The classic aggregate predator–prey model:
+///
+/// ```text
+/// dPrey/dt      = prey_growth * prey - predation_rate * prey * predators
+/// dPredators/dt = conversion * prey * predators - predator_death * predators
+/// ```
+///
+/// It treats both populations as continuous quantities in a well-mixed
+/// habitat, which makes it a baseline to compare individual-based
+/// simulations against rather than a prediction for small populations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LotkaVolterra {
+    pub prey_growth: f64,
+    pub predation_rate: f64,
+    pub conversion: f64,
+    pub predator_death: f64,
+}
+
+impl LotkaVolterra {
+    pub fn new(prey_growth: f64, predation_rate: f64, conversion: f64, predator_death: f64) -> Self {
+        Self {
+            prey_growth,
+            predation_rate,
+            conversion,
+            predator_death,
+        }
+    }
+
+    pub fn rates(&self, sizes: PredatorPrey) -> PredatorPrey {
+        let PredatorPrey { prey, predators } = sizes;
+        PredatorPrey {
+            prey: self.prey_growth * prey - self.predation_rate * prey * predators,
+            predators: self.conversion * prey * predators - self.predator_death * predators,
+        }
+    }
+
+    // The non-trivial fixed point, where neither population changes.
+    pub fn equilibrium(&self) -> PredatorPrey {
+        PredatorPrey {
+            prey: self.predator_death / self.conversion,
+            predators: self.prey_growth / self.predation_rate,
+        }
+    }
+
+    // Advances the model by `dt` with a fourth-order Runge–Kutta step.
+    pub fn step(&self, sizes: PredatorPrey, dt: f64) -> PredatorPrey {
+        let offset = |base: PredatorPrey, rate: PredatorPrey, factor: f64| PredatorPrey {
+            prey: base.prey + rate.prey * factor,
+            predators: base.predators + rate.predators * factor,
+        };
+
+        let k1 = self.rates(sizes);
+        let k2 = self.rates(offset(sizes, k1, dt / 2.0));
+        let k3 = self.rates(offset(sizes, k2, dt / 2.0));
+        let k4 = self.rates(offset(sizes, k3, dt));
+
+        PredatorPrey {
+            prey: (sizes.prey + dt / 6.0 * (k1.prey + 2.0 * k2.prey + 2.0 * k3.prey + k4.prey)).max(0.0),
+            predators: (sizes.predators
+                + dt / 6.0 * (k1.predators + 2.0 * k2.predators + 2.0 * k3.predators + k4.predators))
+                .max(0.0),
+        }
+    }
+
+    // Sizes after each of `ticks` steps of length `dt`, starting with `initial`.
+    pub fn trajectory(&self, initial: PredatorPrey, dt: f64, ticks: usize) -> Vec<PredatorPrey> {
+        let mut sizes = initial;
+        let mut trajectory = Vec::with_capacity(ticks + 1);
+        trajectory.push(sizes);
+        for _ in 0..ticks {
+            sizes = self.step(sizes, dt);
+            trajectory.push(sizes);
+        }
+        trajectory
+    }
+
+    /// Root-mean-square gap between this model and observed sizes, tick for tick.
+    ///
+    /// `observed` is typically a `PredatorPrey::census` taken before the first
+    /// and after every simulation tick; the model starts from `observed[0]`.
+    pub fn deviation(&self, observed: &[PredatorPrey], dt: f64) -> PredatorPrey {
+        let Some(&initial) = observed.first() else {
+            return PredatorPrey::new(0.0, 0.0);
+        };
+
+        let expected = self.trajectory(initial, dt, observed.len() - 1);
+        let (prey, predators) = expected.iter().zip(observed).fold((0.0, 0.0), |(prey, predators), (model, seen)| {
+            (
+                prey + (model.prey - seen.prey).powi(2),
+                predators + (model.predators - seen.predators).powi(2),
+            )
+        });
+
+        let count = observed.len() as f64;
+        PredatorPrey::new((prey / count).sqrt(), (predators / count).sqrt())
+    }
+}
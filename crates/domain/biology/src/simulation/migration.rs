@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use core_shared::Identifier;
+use crate::characteristics::locomotion::Locomotion;
+use crate::ecosystem::environment::Environment;
+use crate::ecosystem::landscape::Position;
+use crate::ecosystem::resources::ResourceKind;
+use crate::simulation::engine::TickContext;
+use crate::simulation::events::SimulationEvent;
+use crate::simulation::rules::Rule;
+
+/// Members on a landscape move towards cells with more to eat and drink.
+///
+/// A cell's quality for a member is its water plus food biomass, shared
+/// with every other living member already there. Each tick, every living
+/// member that can move looks at its neighbouring cells and steps into the
+/// one with the best quality per unit of movement cost, if that cell is at
+/// least `min_gain` (as a fraction) better than where it stands. The step
+/// costs `energy_per_cost` energy per unit of `Landscape::step_cost`, and a
+/// member without the energy to spare stays put.
+///
+/// Members never step into a cell they cannot tolerate (see
+/// `Organism::tolerates`), so a freshwater fish stays out of the sea.
+/// Members off the landscape, and sessile ones, never move.
+pub struct Migration {
+    pub min_gain: f64,
+    pub energy_per_cost: f32,
+}
+
+impl Default for Migration {
+    fn default() -> Self {
+        Self {
+            min_gain: 0.25,
+            energy_per_cost: 0.02,
+        }
+    }
+}
+
+impl Migration {
+    // How well a cell would feed one more member.
+    pub fn quality(environment: &Environment, others: usize) -> f64 {
+        let supply = environment.resources.amount(ResourceKind::Water)
+            + environment.resources.amount(ResourceKind::FoodBiomass);
+        supply / (1 + others) as f64
+    }
+
+    // Where the member would step this tick, and what the step costs.
+    // `occupancy` counts the living members in each cell, the member itself included.
+    fn destination(
+        &self,
+        id: Identifier,
+        context: &TickContext,
+        occupancy: &HashMap<Position, usize>,
+    ) -> Option<(Position, f64)> {
+        let population = &*context.population;
+        let landscape = population.landscape()?;
+        let from = population.position(id)?;
+        let member = population.get(id)?;
+        let locomotion = member.locomotion();
+        if locomotion == Locomotion::Sessile {
+            return None;
+        }
+
+        let others = |position: Position| {
+            let count = occupancy.get(&position).copied().unwrap_or(0);
+            if position == from { count.saturating_sub(1) } else { count }
+        };
+        let here = Self::quality(landscape.cell(from)?, others(from));
+
+        let mut best: Option<(Position, f64, f64)> = None;
+        for neighbour in landscape.neighbours(from) {
+            let Some(cost) = landscape.step_cost(from, neighbour, locomotion) else {
+                continue;
+            };
+            if !member.tolerates(landscape.cell(neighbour)?) {
+                continue;
+            }
+            let quality = Self::quality(landscape.cell(neighbour)?, others(neighbour));
+            if quality < here * (1.0 + self.min_gain) || quality <= 0.0 {
+                continue;
+            }
+
+            let appeal = quality / cost.max(f64::EPSILON);
+            if best.is_none_or(|(_, _, best_appeal)| appeal > best_appeal) {
+                best = Some((neighbour, cost, appeal));
+            }
+        }
+        best.map(|(position, cost, _)| (position, cost))
+    }
+}
+
+impl Rule for Migration {
+    fn name(&self) -> &'static str {
+        "migration"
+    }
+
+    fn apply(&mut self, context: &mut TickContext) {
+        if context.population.landscape().is_none() {
+            return;
+        }
+
+        // Counted once a tick and kept up to date as members move.
+        let mut occupancy = context.population.occupancy();
+        for id in context.living() {
+            let (Some(from), Some((to, cost))) =
+                (context.population.position(id), self.destination(id, context, &occupancy))
+            else {
+                continue;
+            };
+            let energy = self.energy_per_cost * cost as f32;
+            if context.state(id).energy < energy {
+                continue;
+            }
+
+            if context.population.place(id, to).is_ok() {
+                if let Some(count) = occupancy.get_mut(&from) {
+                    *count -= 1;
+                }
+                *occupancy.entry(to).or_insert(0) += 1;
+                context.state_mut(id).adjust_energy(-energy);
+                context.emit(SimulationEvent::Moved { id, from, to });
+            }
+        }
+    }
+}
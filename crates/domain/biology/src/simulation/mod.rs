@@ -0,0 +1,48 @@
+//! Tick-based simulation of a population over time.
+//!
+//! A `Simulation` owns a population, a seeded random number generator and
+//! a list of rules, and advances them one tick at a time:
+//!
+//! - `engine` → `Simulation` and the `TickContext` rules work through
+//! - `rules` → the `Rule` trait and the built-in aging, metabolism,
+//!   interaction, birth and mortality rules
+//! - `predation` → the `Predation` rule: density-driven encounters and
+//!   size/speed-driven captures along `Interaction::Predation` links
+//! - `competition` → `Replenishment` of the environment's resource pools
+//!   and `Competition` for them along `Interaction::Competition` links
+//! - `migration` → the `Migration` rule: members on a landscape moving
+//!   towards better-supplied cells
+//! - `weather` → the `Weather` rule: seasons, daily cycles and severe
+//!   weather acting on resources and members
+//! - `epidemic` → the `Epidemic` rule: a pathogen spreading between members
+//! - `reproduction` → the `Reproduction` rule: mating, gestation or
+//!   incubation, and births with parent links
+//! - `lotka_volterra` → the aggregate `LotkaVolterra` model, for comparison
+//! - `events` → `SimulationEvent`, `CauseOfDeath` and per-tick `TickReport`s
+//! - `state` → `MemberState`, the age and energy tracked per member
+//!
+//! See the module README.md for detailed guidance.
+
+pub mod competition;
+pub mod engine;
+pub mod epidemic;
+pub mod events;
+pub mod lotka_volterra;
+pub mod migration;
+pub mod predation;
+pub mod reproduction;
+pub mod rules;
+pub mod state;
+pub mod weather;
+
+pub use competition::{Competition, Replenishment};
+pub use engine::{Simulation, TickContext};
+pub use epidemic::Epidemic;
+pub use events::{CauseOfDeath, SimulationEvent, TickReport};
+pub use lotka_volterra::{LotkaVolterra, PredatorPrey};
+pub use migration::Migration;
+pub use predation::Predation;
+pub use reproduction::{Offspring, Reproduction};
+pub use rules::{Aging, Births, InteractionEffects, Metabolism, Mortality, Rule};
+pub use state::MemberState;
+pub use weather::{thermal_stress, Exposure, Weather};
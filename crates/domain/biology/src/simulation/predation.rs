@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use core_shared::Identifier;
+use crate::Organism;
+use crate::ecosystem::environment::{Climate, Environment, Terrain};
+use crate::ecosystem::interactions::Interaction;
+use crate::simulation::engine::TickContext;
+use crate::simulation::events::{CauseOfDeath, SimulationEvent};
+use crate::simulation::rules::Rule;
+
+/// Hunting along the population's `Interaction::Predation` links.
+///
+/// Each tick, every living predator hunts once. Its prey are the living
+/// members it is linked to; on a landscape, only those sharing its cell.
+/// It meets one of them with probability
+/// `1 - exp(-search_rate * density * modifier)`, where density is the
+/// number of those prey per hectare of `habitat_area` and the modifier comes
+/// from the terrain (cover) and climate (animals gathering at scarce water)
+/// of the predator's cell. For sparse prey that is close to linear in their
+/// number, the functional response `LotkaVolterra` assumes. The prey it
+/// meets is picked at random, and the meeting becomes a kill with
+/// `base_capture`, scaled by how much heavier and faster the predator is.
+///
+/// Links to producers (plants) are skipped: grazing them is left to a
+/// rule from the producer's own crate.
+///
+/// A kill marks the prey deceased and feeds the predator up to `energy_gain`
+/// (less for prey much smaller than itself). An escape costs both animals
+/// `chase_cost` energy.
+pub struct Predation {
+    pub search_rate: f64,
+    pub habitat_area: f64,
+    pub base_capture: f64,
+    pub energy_gain: f32,
+    pub chase_cost: f32,
+}
+
+impl Default for Predation {
+    fn default() -> Self {
+        Self {
+            search_rate: 1.0,
+            habitat_area: 10.0,
+            base_capture: 0.3,
+            energy_gain: 0.6,
+            chase_cost: 0.05,
+        }
+    }
+}
+
+impl Predation {
+    pub fn encounter_chance(&self, prey_count: usize, environment: &Environment) -> f64 {
+        let density = prey_count as f64 / self.habitat_area.max(f64::EPSILON);
+        1.0 - (-self.search_rate * density * encounter_modifier(environment)).exp()
+    }
+
+    pub fn capture_chance(&self, predator: &dyn Organism, prey: &dyn Organism) -> f64 {
+        let size = ratio(predator.body_mass().map(|mass| mass.0), prey.body_mass().map(|mass| mass.0));
+        let speed = ratio(predator.top_speed().map(|speed| speed.0), prey.top_speed().map(|speed| speed.0));
+        (self.base_capture * size.sqrt() * speed).clamp(0.0, 1.0)
+    }
+
+    // Share of `energy_gain` a kill provides; a meal half the predator's size or more fills it up.
+    fn meal_size(predator: &dyn Organism, prey: &dyn Organism) -> f32 {
+        match (predator.body_mass(), prey.body_mass()) {
+            (Some(predator), Some(prey)) if predator.0 > 0 => {
+                (2.0 * prey.0 as f32 / predator.0 as f32).clamp(0.1, 1.0)
+            }
+            _ => 1.0,
+        }
+    }
+}
+
+impl Rule for Predation {
+    fn name(&self) -> &'static str {
+        "predation"
+    }
+
+    fn apply(&mut self, context: &mut TickContext) {
+        // Each predator's prey, with predators in the order they are first linked.
+        let mut hunts: Vec<(Identifier, Vec<Identifier>)> = Vec::new();
+        let mut index: HashMap<Identifier, usize> = HashMap::new();
+        for interaction in context.population.interactions() {
+            let Interaction::Predation { predator_id, prey_id } = *interaction else {
+                continue;
+            };
+            let slot = *index.entry(predator_id).or_insert_with(|| {
+                hunts.push((predator_id, Vec::new()));
+                hunts.len() - 1
+            });
+            hunts[slot].1.push(prey_id);
+        }
+
+        for (predator_id, prey_ids) in hunts {
+            if !context.is_alive(predator_id) {
+                continue;
+            }
+            let position = context.population.position(predator_id);
+            // Producers are grazed, not hunted; kingdom crates model that with rules of their own.
+            let prey_ids: Vec<Identifier> = prey_ids
+                .into_iter()
+                .filter(|&prey_id| {
+                    context.is_alive(prey_id)
+                        && context.population.position(prey_id) == position
+                        && context.population.get(prey_id).is_some_and(|prey| !prey.is_producer())
+                })
+                .collect();
+            if prey_ids.is_empty() {
+                continue;
+            }
+
+            let encounter = self.encounter_chance(prey_ids.len(), context.population.environment_of(predator_id));
+            if !context.rng.chance(encounter) {
+                continue;
+            }
+            let prey_id = prey_ids[context.rng.below(prey_ids.len() as u64) as usize];
+            let (Some(predator), Some(prey)) = (context.population.get(predator_id), context.population.get(prey_id))
+            else {
+                continue;
+            };
+            let capture = self.capture_chance(predator, prey);
+            let meal = Self::meal_size(predator, prey);
+            context.emit(SimulationEvent::Interacted {
+                interaction: Interaction::Predation { predator_id, prey_id },
+            });
+
+            if context.rng.chance(capture) {
+                context.kill(prey_id, CauseOfDeath::Predation { predator_id });
+                context.state_mut(predator_id).adjust_energy(self.energy_gain * meal);
+            } else {
+                context.state_mut(predator_id).adjust_energy(-self.chase_cost);
+                context.state_mut(prey_id).adjust_energy(-self.chase_cost);
+            }
+        }
+    }
+}
+
+// How easily animals find each other here, relative to temperate plains.
+fn encounter_modifier(environment: &Environment) -> f64 {
+    let cover = match environment.terrain {
+        Terrain::Plains => 1.0,
+        Terrain::Ocean => 0.9,
+        Terrain::Urban => 0.8,
+        Terrain::Mountains | Terrain::Wetlands => 0.7,
+        Terrain::Forest => 0.6,
+    };
+    let climate = match environment.climate {
+        Climate::Desert => 1.2,
+        Climate::Tropical => 1.1,
+        Climate::Temperate | Climate::Custom { .. } => 1.0,
+        Climate::Arctic => 0.8,
+    };
+    cover * climate
+}
+
+// Predator-to-prey ratio, limited to 0.25..=4; unknown measures count as evenly matched.
+fn ratio(predator: Option<u32>, prey: Option<u32>) -> f64 {
+    match (predator, prey) {
+        (Some(predator), Some(prey)) if prey > 0 => (predator as f64 / prey as f64).clamp(0.25, 4.0),
+        _ => 1.0,
+    }
+}
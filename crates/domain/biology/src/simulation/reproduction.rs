@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use core_shared::Identifier;
+use crate::genetics::heredity::Heredity;
+use crate::reproduction::pregnancy::{Birth, Pregnancy};
+use crate::reproduction::strategy::{Development, MatingSystem, ReproductiveStrategy};
+use crate::simulation::engine::TickContext;
+use crate::simulation::events::SimulationEvent;
+use crate::simulation::rules::Rule;
+use crate::Organism;
+
+/// Builds one newborn from its parents, e.g. `biology_animalia::reproduction::offspring`.
+pub type Offspring = fn(&Birth) -> Option<Box<dyn Organism>>;
+
+/// Mating between compatible members, and the births that follow.
+///
+/// Each tick, young that are due are born first: `offspring` builds each
+/// one from its parents, and the newborns join the population with their
+/// parent links recorded. Young still carried by a mother who has died are
+/// lost, but eggs hatch after her death as long as she is still a member:
+/// the offspring function builds each hatchling from her, so a clutch whose
+/// mother has been removed from the population is lost with her. A father
+/// who has been removed is left out of the parent links. A newborn the
+/// population will not take ends its litter with a `BirthFailed` event.
+///
+/// Then every mature member able to bear young, and not already expecting
+/// or resting after a birth, looks for a mate with `mating_chance` per
+/// tick. Mates are picked at random among the living, mature, compatible
+/// members in the same cell (see `ReproductiveStrategy::compatible`).
+/// Conceiving costs the mother `energy_cost` and fixes the litter size.
+///
+/// With a `Heredity`, each newborn of a mother with a genome inherits one
+/// by Mendelian inheritance, and the offspring function expresses it.
+///
+/// Members of species without a strategy are left alone.
+pub struct Reproduction {
+    pub strategies: Vec<ReproductiveStrategy>,
+    pub mating_chance: f64,
+    pub energy_cost: f32,
+    pub offspring: Offspring,
+    pub heredity: Option<Heredity>,
+    // In order of conception, so births happen in the same order every run.
+    pregnancies: Vec<Pregnancy>,
+    // When each mother can conceive again.
+    resting_until: HashMap<Identifier, u64>,
+}
+
+impl Reproduction {
+    pub fn new(strategies: &[ReproductiveStrategy], offspring: Offspring) -> Self {
+        Self {
+            strategies: strategies.to_vec(),
+            mating_chance: 0.1,
+            energy_cost: 0.2,
+            offspring,
+            heredity: None,
+            pregnancies: Vec::new(),
+            resting_until: HashMap::new(),
+        }
+    }
+
+    pub fn with_mating_chance(mut self, mating_chance: f64) -> Self {
+        self.mating_chance = mating_chance;
+        self
+    }
+
+    pub fn with_energy_cost(mut self, energy_cost: f32) -> Self {
+        self.energy_cost = energy_cost;
+        self
+    }
+
+    pub fn with_heredity(mut self, heredity: Heredity) -> Self {
+        self.heredity = Some(heredity);
+        self
+    }
+
+    // The first strategy covering the species.
+    pub fn strategy_for(&self, species: &str) -> Option<&ReproductiveStrategy> {
+        self.strategies.iter().find(|strategy| strategy.applies_to(species))
+    }
+
+    // Young on the way, in order of conception.
+    pub fn pregnancies(&self) -> &[Pregnancy] {
+        &self.pregnancies
+    }
+
+    pub fn pregnancy(&self, mother_id: Identifier) -> Option<&Pregnancy> {
+        self.pregnancies.iter().find(|pregnancy| pregnancy.mother_id == mother_id)
+    }
+
+    fn deliver(&mut self, context: &mut TickContext) {
+        let (due, waiting): (Vec<Pregnancy>, Vec<Pregnancy>) = self
+            .pregnancies
+            .drain(..)
+            .partition(|pregnancy| pregnancy.is_due(context.tick));
+        self.pregnancies = waiting;
+
+        for pregnancy in due {
+            if pregnancy.development == Development::Gestation && !context.is_alive(pregnancy.mother_id) {
+                continue;
+            }
+            let Some(mother) = context.population.get(pregnancy.mother_id) else {
+                continue;
+            };
+            let Some(strategy) = self.strategy_for(&mother.species()).copied() else {
+                continue;
+            };
+            let mother_sex = mother.sex();
+            let father_id = pregnancy.father_id.filter(|&id| context.population.contains(id));
+
+            for _ in 0..pregnancy.litter {
+                let sex = strategy.offspring_sex(mother_sex, context.rng);
+                let id = context.newborn_id();
+                let newborn = {
+                    let Some(mother) = context.population.get(pregnancy.mother_id) else {
+                        break;
+                    };
+                    let father = father_id.and_then(|id| context.population.get(id));
+                    let genome = match (&self.heredity, mother.genome()) {
+                        (Some(heredity), Some(maternal)) => {
+                            Some(heredity.inherit(maternal, father.and_then(|father| father.genome()), context.rng))
+                        }
+                        _ => None,
+                    };
+                    (self.offspring)(&Birth { id, mother, father, sex, genome })
+                };
+                let Some(newborn) = newborn else {
+                    break;
+                };
+                if let Err(error) = context.spawn_from(newborn, pregnancy.mother_id, father_id) {
+                    context.emit(SimulationEvent::BirthFailed {
+                        mother_id: pregnancy.mother_id,
+                        reason: error.to_string(),
+                    });
+                    break;
+                }
+            }
+            self.resting_until
+                .insert(pregnancy.mother_id, context.tick + strategy.interval as u64);
+        }
+    }
+
+    fn mate(&mut self, context: &mut TickContext) {
+        let living = context.living();
+        for &id in &living {
+            if self.pregnancy(id).is_some() || self.resting_until.get(&id).is_some_and(|&until| context.tick < until) {
+                continue;
+            }
+            let Some(bearer) = context.population.get(id) else {
+                continue;
+            };
+            let Some(strategy) = self.strategy_for(&bearer.species()).copied() else {
+                continue;
+            };
+            let state = context.state(id);
+            if !strategy.can_bear(bearer.sex()) || state.age < strategy.maturity || state.energy < self.energy_cost {
+                continue;
+            }
+            if !context.rng.chance(self.mating_chance) {
+                continue;
+            }
+
+            let father_id = if strategy.mating == MatingSystem::Asexual {
+                None
+            } else {
+                let position = context.population.position(id);
+                let mates: Vec<Identifier> = living
+                    .iter()
+                    .copied()
+                    .filter(|&mate_id| {
+                        context.state(mate_id).age >= strategy.maturity
+                            && context.population.position(mate_id) == position
+                            && context
+                                .population
+                                .get(mate_id)
+                                .is_some_and(|mate| strategy.compatible(bearer, mate))
+                    })
+                    .collect();
+                if mates.is_empty() {
+                    continue;
+                }
+                Some(mates[context.rng.below(mates.len() as u64) as usize])
+            };
+
+            let litter = strategy.litter_size(context.rng);
+            self.pregnancies.push(Pregnancy {
+                mother_id: id,
+                father_id,
+                development: strategy.development,
+                litter,
+                conceived: context.tick,
+                due: context.tick + strategy.period as u64,
+            });
+            context.state_mut(id).adjust_energy(-self.energy_cost);
+            context.emit(SimulationEvent::Conceived { mother_id: id, father_id, litter });
+        }
+    }
+}
+
+impl Rule for Reproduction {
+    fn name(&self) -> &'static str {
+        "reproduction"
+    }
+
+    fn apply(&mut self, context: &mut TickContext) {
+        self.deliver(context);
+        self.mate(context);
+    }
+}
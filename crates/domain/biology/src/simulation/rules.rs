@@ -0,0 +1,242 @@
+use std::any::Any;
+use core_shared::Identifier;
+use crate::Organism;
+use crate::ecosystem::interactions::Interaction;
+use crate::simulation::engine::TickContext;
+use crate::simulation::events::{CauseOfDeath, SimulationEvent};
+
+/// A process applied to the population once per tick.
+///
+/// Rules run in the order they were registered with the `Simulation`.
+/// To keep runs reproducible, visit members in the order `living()`
+/// returns them and take all randomness from `context.rng`. Because `Any`
+/// is a supertrait, a rule that keeps state can be read back with
+/// `Simulation::rule::<T>()`.
+pub trait Rule: Any {
+    fn name(&self) -> &'static str;
+    fn apply(&mut self, context: &mut TickContext);
+}
+
+/// Every living member gets one tick older.
+pub struct Aging;
+
+impl Rule for Aging {
+    fn name(&self) -> &'static str {
+        "aging"
+    }
+
+    fn apply(&mut self, context: &mut TickContext) {
+        for id in context.living() {
+            context.state_mut(id).age += 1;
+        }
+    }
+}
+
+/// Members spend `upkeep` energy each tick and forage between nothing and `foraging`.
+///
+/// Producers (`Organism::is_producer`) make their own food and are skipped;
+/// kingdom crates grow them with their own rules, e.g. `biology_plantae::Growth`.
+pub struct Metabolism {
+    pub upkeep: f32,
+    pub foraging: f32,
+}
+
+impl Default for Metabolism {
+    fn default() -> Self {
+        Self {
+            upkeep: 0.05,
+            foraging: 0.1,
+        }
+    }
+}
+
+impl Rule for Metabolism {
+    fn name(&self) -> &'static str {
+        "metabolism"
+    }
+
+    fn apply(&mut self, context: &mut TickContext) {
+        for id in context.living() {
+            if context.population.get(id).is_some_and(|member| member.is_producer()) {
+                continue;
+            }
+            let found = self.foraging * context.rng.next_f64() as f32;
+            context.state_mut(id).adjust_energy(found - self.upkeep);
+        }
+    }
+}
+
+/// Plays out the population's recorded mutualism and parasitism.
+///
+/// Each interaction between living members happens with `encounter_chance`
+/// per tick. Parasites take `energy_transfer` from their host; mutualists
+/// both gain half of it. Predation and competition links are left to the
+/// `Predation` and `Competition` rules.
+pub struct InteractionEffects {
+    pub encounter_chance: f64,
+    pub energy_transfer: f32,
+}
+
+impl Default for InteractionEffects {
+    fn default() -> Self {
+        Self {
+            encounter_chance: 0.2,
+            energy_transfer: 0.3,
+        }
+    }
+}
+
+impl Rule for InteractionEffects {
+    fn name(&self) -> &'static str {
+        "interactions"
+    }
+
+    fn apply(&mut self, context: &mut TickContext) {
+        let interactions: Vec<Interaction> = context
+            .population
+            .interactions()
+            .iter()
+            .filter(|interaction| matches!(interaction, Interaction::Mutualism { .. } | Interaction::Parasitism { .. }))
+            .cloned()
+            .collect();
+
+        for interaction in interactions {
+            let (first, second) = interaction.participants();
+            if !context.is_alive(first) || !context.is_alive(second) {
+                continue;
+            }
+            if !context.rng.chance(self.encounter_chance) {
+                continue;
+            }
+
+            let share = self.energy_transfer / 2.0;
+            match &interaction {
+                Interaction::Mutualism { .. } => {
+                    context.state_mut(first).adjust_energy(share);
+                    context.state_mut(second).adjust_energy(share);
+                }
+                Interaction::Parasitism { parasite_id, host_id } => {
+                    context.state_mut(*host_id).adjust_energy(-self.energy_transfer);
+                    context.state_mut(*parasite_id).adjust_energy(self.energy_transfer);
+                }
+                Interaction::Predation { .. } | Interaction::Competition { .. } => continue,
+            }
+            context.emit(SimulationEvent::Interacted { interaction });
+        }
+    }
+}
+
+/// Mature, well-fed members give birth with `chance` per tick.
+///
+/// The biology crate cannot construct concrete organisms, so `offspring`
+/// builds the newborn from its parent and the id it is to have, e.g. a new
+/// `Mammal` of the same species. Returning `None` means the parent cannot
+/// reproduce this way.
+pub struct Births {
+    pub chance: f64,
+    pub maturity: u32,
+    pub energy_cost: f32,
+    pub offspring: fn(&dyn Organism, Identifier) -> Option<Box<dyn Organism>>,
+}
+
+impl Births {
+    pub fn new(offspring: fn(&dyn Organism, Identifier) -> Option<Box<dyn Organism>>) -> Self {
+        Self {
+            chance: 0.01,
+            maturity: 365,
+            energy_cost: 0.4,
+            offspring,
+        }
+    }
+
+    pub fn with_chance(mut self, chance: f64) -> Self {
+        self.chance = chance;
+        self
+    }
+
+    pub fn with_maturity(mut self, maturity: u32) -> Self {
+        self.maturity = maturity;
+        self
+    }
+
+    pub fn with_energy_cost(mut self, energy_cost: f32) -> Self {
+        self.energy_cost = energy_cost;
+        self
+    }
+}
+
+impl Rule for Births {
+    fn name(&self) -> &'static str {
+        "births"
+    }
+
+    fn apply(&mut self, context: &mut TickContext) {
+        for parent_id in context.living() {
+            let state = context.state(parent_id);
+            if state.age < self.maturity || state.energy < self.energy_cost {
+                continue;
+            }
+            if !context.rng.chance(self.chance) {
+                continue;
+            }
+
+            let id = context.newborn_id();
+            let Some(parent) = context.population.get(parent_id) else {
+                continue;
+            };
+            let Some(newborn) = (self.offspring)(parent, id) else {
+                continue;
+            };
+            if context.spawn(newborn, parent_id).is_ok() {
+                context.state_mut(parent_id).adjust_energy(-self.energy_cost);
+            }
+        }
+    }
+}
+
+/// Members die of old age at their species' lifespan (`Organism::lifespan`),
+/// or at `lifespan` ticks when it is not known, of starvation at zero
+/// energy, and otherwise with `background_chance` per tick.
+pub struct Mortality {
+    pub lifespan: u32,
+    pub background_chance: f64,
+}
+
+impl Default for Mortality {
+    fn default() -> Self {
+        Self {
+            lifespan: 3650,
+            background_chance: 0.0005,
+        }
+    }
+}
+
+impl Rule for Mortality {
+    fn name(&self) -> &'static str {
+        "mortality"
+    }
+
+    fn apply(&mut self, context: &mut TickContext) {
+        for id in context.living() {
+            let state = context.state(id);
+            let lifespan = context
+                .population
+                .get(id)
+                .and_then(|member| member.lifespan())
+                .unwrap_or(self.lifespan);
+            let cause = if state.age >= lifespan {
+                Some(CauseOfDeath::OldAge)
+            } else if state.energy <= 0.0 {
+                Some(CauseOfDeath::Starvation)
+            } else if context.rng.chance(self.background_chance) {
+                Some(CauseOfDeath::NaturalCauses)
+            } else {
+                None
+            };
+
+            if let Some(cause) = cause {
+                context.kill(id, cause);
+            }
+        }
+    }
+}
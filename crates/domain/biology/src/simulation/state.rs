@@ -0,0 +1,30 @@
+/// What the simulation tracks about a member between ticks.
+///
+/// `age` counts ticks lived in the simulation. `energy` is the fraction of
+/// the member's reserves left, from `0.0` (starving) to `1.0` (full).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemberState {
+    pub age: u32,
+    pub energy: f32,
+}
+
+impl MemberState {
+    pub fn new(age: u32, energy: f32) -> Self {
+        Self {
+            age,
+            energy: energy.clamp(0.0, 1.0),
+        }
+    }
+
+    // Adds (or with a negative amount, spends) energy, staying within 0.0..=1.0.
+    pub fn adjust_energy(&mut self, amount: f32) {
+        self.energy = (self.energy + amount).clamp(0.0, 1.0);
+    }
+}
+
+impl Default for MemberState {
+    fn default() -> Self {
+        Self { age: 0, energy: 1.0 }
+    }
+}
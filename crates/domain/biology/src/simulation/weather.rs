@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+use core_shared::Identifier;
+use crate::Organism;
+use crate::ecosystem::environment::Environment;
+use crate::ecosystem::resources::ResourceKind;
+use crate::ecosystem::weather::{Conditions, Season, WeatherEvent, DAYS_PER_YEAR};
+use crate::simulation::engine::TickContext;
+use crate::simulation::events::SimulationEvent;
+use crate::simulation::rules::Rule;
+use crate::vitals::vital_status::VitalStatus;
+
+/// How a member's body answers the weather.
+///
+/// The vital shifts hold for as long as the conditions that caused them:
+/// each tick the `Weather` rule takes back the last shift and applies the
+/// new one. `energy` is spent (if negative) per day.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Exposure {
+    pub temperature: i32,
+    pub pulse: i32,
+    pub respiration_rate: i32,
+    pub energy: f32,
+}
+
+/// The default response to the weather: cold and heat stress.
+///
+/// Below freezing, every 10 °C of frost lowers body temperature by a degree
+/// and every 2 °C raises the pulse by a beat. Above 30 °C, every 5 °C raises
+/// body temperature by a degree, the pulse by 5 and breathing by 2.5. Each
+/// degree outside 0–30 °C costs 0.001 energy a day, a blizzard a further
+/// 0.05 and a drought 0.02.
+pub fn thermal_stress(_organism: &dyn Organism, conditions: &Conditions) -> Exposure {
+    let cold = (-conditions.temperature).max(0.0);
+    let heat = (conditions.temperature - 30.0).max(0.0);
+    let hardship = match conditions.event {
+        Some(WeatherEvent::Blizzard) => 0.05,
+        Some(WeatherEvent::Drought) => 0.02,
+        Some(WeatherEvent::Flood) | None => 0.0,
+    };
+
+    Exposure {
+        temperature: (heat / 5.0 - cold / 10.0).round() as i32,
+        pulse: (cold / 2.0 + heat).round() as i32,
+        respiration_rate: (heat / 2.0).round() as i32,
+        energy: -(0.001 * (cold + heat) + hardship),
+    }
+}
+
+/// Seasons, daily cycles and severe weather.
+///
+/// Each tick the rule works out the conditions from the climate, the day of
+/// the year and, with more than one tick a day, the hour, and records them
+/// as `Environment::weather` on the population's environment and every
+/// landscape cell. Then:
+///
+/// - Water regenerates in proportion to rainfall, and food biomass in
+///   proportion to `Conditions::growth`, so winters and dry seasons are lean.
+///   A drought also evaporates 2% of the water a day; a flood fills the
+///   water pool but spoils 2% of the food a day.
+/// - Every living member's vitals and energy respond through `response`
+///   (by default `thermal_stress`). Ectotherms, whose
+///   `Organism::body_temperature_in` is set, take their body temperature
+///   from their environment instead of the response's shift. Producers
+///   are left alone: they answer the weather by how they grow.
+///
+/// Once a day, if no severe weather is under way, each `WeatherEvent` may
+/// begin with its `daily_chance` for the population's climate and season.
+/// Register it after `Replenishment`, whose regeneration it scales.
+pub struct Weather {
+    // Day of the year (0 = 1 January) of the first tick.
+    pub start_day: u32,
+    pub ticks_per_day: u32,
+    pub response: fn(&dyn Organism, &Conditions) -> Exposure,
+    forecast: Option<WeatherEvent>,
+    // The event under way and the last tick it lasts.
+    event: Option<(WeatherEvent, u64)>,
+    exposures: HashMap<Identifier, Exposure>,
+    history: Vec<Conditions>,
+}
+
+impl Default for Weather {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Weather {
+    pub fn new() -> Self {
+        Self {
+            start_day: 0,
+            ticks_per_day: 1,
+            response: thermal_stress,
+            forecast: None,
+            event: None,
+            exposures: HashMap::new(),
+            history: Vec::new(),
+        }
+    }
+
+    pub fn with_start_day(mut self, start_day: u32) -> Self {
+        self.start_day = start_day % DAYS_PER_YEAR;
+        self
+    }
+
+    // E.g. 24 for hourly ticks, which brings in the daily temperature cycle.
+    pub fn with_ticks_per_day(mut self, ticks_per_day: u32) -> Self {
+        self.ticks_per_day = ticks_per_day.max(1);
+        self
+    }
+
+    pub fn with_response(mut self, response: fn(&dyn Organism, &Conditions) -> Exposure) -> Self {
+        self.response = response;
+        self
+    }
+
+    // Starts the event on the first tick, for its usual duration.
+    pub fn with_event(mut self, event: WeatherEvent) -> Self {
+        self.forecast = Some(event);
+        self
+    }
+
+    // The severe weather under way, if any.
+    pub fn event(&self) -> Option<WeatherEvent> {
+        self.event.map(|(event, _)| event)
+    }
+
+    // The population environment's conditions at the end of each tick so far.
+    pub fn history(&self) -> &[Conditions] {
+        &self.history
+    }
+
+    pub fn conditions(&self) -> Option<Conditions> {
+        self.history.last().copied()
+    }
+
+    // Day of the year and, when ticks are shorter than a day, the hour.
+    fn calendar(&self, tick: u64) -> (u32, Option<u32>) {
+        let elapsed = tick.saturating_sub(1);
+        let per_day = self.ticks_per_day as u64;
+        let day = ((self.start_day as u64 + elapsed / per_day) % DAYS_PER_YEAR as u64) as u32;
+        let hour = (per_day > 1).then(|| ((elapsed % per_day) * 24 / per_day) as u32);
+        (day, hour)
+    }
+
+    fn forecast(&mut self, day: u32, context: &mut TickContext) {
+        if let Some((event, last_tick)) = self.event
+            && context.tick > last_tick
+        {
+            self.event = None;
+            context.emit(SimulationEvent::WeatherEnded { event });
+        }
+        if self.event.is_some() {
+            return;
+        }
+
+        let starting = match self.forecast.take() {
+            Some(event) => Some(event),
+            None if (context.tick - 1).is_multiple_of(self.ticks_per_day as u64) => {
+                let climate = context.population.environment.climate.clone();
+                let season = Season::of_day(day);
+                WeatherEvent::ALL
+                    .into_iter()
+                    .find(|event| context.rng.chance(event.daily_chance(&climate, season)))
+            }
+            None => None,
+        };
+        if let Some(event) = starting {
+            let last_tick = context.tick - 1 + event.duration_days() as u64 * self.ticks_per_day as u64;
+            self.event = Some((event, last_tick));
+            context.emit(SimulationEvent::WeatherBegan { event });
+        }
+    }
+
+    fn expose(&mut self, id: Identifier, context: &mut TickContext) {
+        let environment = context.population.environment_of(id);
+        let Some(conditions) = environment.weather else {
+            return;
+        };
+        let Some(member) = context.population.get(id) else {
+            return;
+        };
+        if member.is_producer() {
+            return;
+        }
+        let ectothermic = member.body_temperature_in(environment);
+        let Some(member) = context.population.get_mut(id) else {
+            return;
+        };
+
+        let mut exposure = (self.response)(member, &conditions);
+        if ectothermic.is_some() {
+            exposure.temperature = 0;
+        }
+        let previous = self.exposures.insert(id, exposure).unwrap_or_default();
+        if let VitalStatus::Alive(vitals) = member.vitals_mut() {
+            match ectothermic {
+                Some(temperature) => vitals.temperature = temperature,
+                None => {
+                    vitals.temperature.0 =
+                        vitals.temperature.0.saturating_add_signed(exposure.temperature - previous.temperature)
+                }
+            }
+            vitals.pulse.0 = vitals.pulse.0.saturating_add_signed(exposure.pulse - previous.pulse);
+            vitals.respiration_rate = vitals
+                .respiration_rate
+                .saturating_add_signed(exposure.respiration_rate - previous.respiration_rate);
+        }
+        context.state_mut(id).adjust_energy(exposure.energy / self.ticks_per_day as f32);
+    }
+}
+
+impl Rule for Weather {
+    fn name(&self) -> &'static str {
+        "weather"
+    }
+
+    fn apply(&mut self, context: &mut TickContext) {
+        let (day, hour) = self.calendar(context.tick);
+        self.forecast(day, context);
+        let event = self.event();
+
+        let per_day = self.ticks_per_day as f64;
+        settle(&mut context.population.environment, day, hour, event, per_day);
+        if let Some(landscape) = context.population.landscape_mut() {
+            for cell in landscape.cells_mut() {
+                settle(cell, day, hour, event, per_day);
+            }
+        }
+
+        for id in context.living() {
+            self.expose(id, context);
+        }
+        if let Some(conditions) = context.population.environment.weather {
+            self.history.push(conditions);
+        }
+    }
+}
+
+// Records the conditions and lets the rain and warmth act on the resources.
+fn settle(environment: &mut Environment, day: u32, hour: Option<u32>, event: Option<WeatherEvent>, per_day: f64) {
+    let conditions = Conditions::for_climate(&environment.climate, day, hour, event);
+    environment.weather = Some(conditions);
+
+    // `Replenishment` has already added one tick's regeneration; scale it.
+    if let Some(water) = environment.resources.get_mut(ResourceKind::Water) {
+        match event {
+            Some(WeatherEvent::Flood) => water.amount = water.capacity,
+            Some(WeatherEvent::Drought) => water.adjust(-water.regeneration - 0.02 * water.amount / per_day),
+            _ => water.adjust(water.regeneration * (conditions.wetness().min(3.0) - 1.0)),
+        }
+    }
+    if let Some(food) = environment.resources.get_mut(ResourceKind::FoodBiomass) {
+        food.adjust(food.regeneration * (conditions.growth() - 1.0));
+        if event == Some(WeatherEvent::Flood) {
+            food.adjust(-0.02 * food.amount / per_day);
+        }
+    }
+}
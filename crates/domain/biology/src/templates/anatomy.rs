@@ -1,8 +1,40 @@
+use core_shared::Direction;
+use crate::anatomy::appendage::Appendage;
 use crate::anatomy::arm_measurements::ArmMeasurements;
 use crate::anatomy::leg_measurements::LegMeasurements;
+use crate::anatomy::limb_status::LimbStatus;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnatomyTemplate {
     pub arm_measurements: ArmMeasurements,
     pub leg_measurements: LegMeasurements,
     // We could add others here later, like torso_measurements, etc.
+}
+
+impl AnatomyTemplate {
+    // Every measurement multiplied by `factor`, e.g. for a taller or shorter build.
+    pub fn scaled(&self, factor: f64) -> AnatomyTemplate {
+        AnatomyTemplate {
+            arm_measurements: self.arm_measurements.scaled(factor),
+            leg_measurements: self.leg_measurements.scaled(factor),
+        }
+    }
+
+    // Two arms then two legs, left before right, all intact.
+    pub fn appendages(&self) -> Vec<Appendage> {
+        let sides = [Direction::Left, Direction::Right];
+        sides
+            .into_iter()
+            .map(|side| Appendage::Arm {
+                side,
+                measurements: self.arm_measurements,
+                status: LimbStatus::Intact,
+            })
+            .chain(sides.into_iter().map(|side| Appendage::Leg {
+                side,
+                measurements: self.leg_measurements,
+                status: LimbStatus::Intact,
+            }))
+            .collect()
+    }
 }
\ No newline at end of file
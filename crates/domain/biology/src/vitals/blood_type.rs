@@ -0,0 +1,36 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum BloodGroup {
+    A,
+    B,
+    AB,
+    O,
+}
+
+/// ABO group and Rh factor, e.g. "AB+".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BloodType {
+    pub group: BloodGroup,
+    pub rh_positive: bool,
+}
+
+impl BloodType {
+    pub fn new(group: BloodGroup, rh_positive: bool) -> Self {
+        Self { group, rh_positive }
+    }
+}
+
+impl Display for BloodType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let group = match self.group {
+            BloodGroup::A => "A",
+            BloodGroup::B => "B",
+            BloodGroup::AB => "AB",
+            BloodGroup::O => "O",
+        };
+        write!(f, "{}{}", group, if self.rh_positive { "+" } else { "-" })
+    }
+}
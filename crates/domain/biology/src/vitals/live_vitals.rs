@@ -1,12 +1,16 @@
 use crate::vitals::blood_pressure::BloodPressure;
-use crate::vitals::vital_types::{Celsius, BPM};
+use crate::vitals::vital_types::{Celsius, RespiratoryOrgan, BPM};
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiveVitals {
     pub blood_pressure: BloodPressure,
     pub temperature: Celsius,
     pub pulse: BPM,
     pub respiration_rate: u32,
+    // What `respiration_rate` counts: breaths for lungs, opercular beats for gills.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub respiratory_organ: RespiratoryOrgan,
 }
 
 impl LiveVitals {
@@ -22,6 +26,17 @@ impl LiveVitals {
             temperature,
             pulse,
             respiration_rate,
+            respiratory_organ: RespiratoryOrgan::Lungs,
         }
     }
+
+    pub fn with_respiratory_organ(mut self, respiratory_organ: RespiratoryOrgan) -> Self {
+        self.respiratory_organ = respiratory_organ;
+        self
+    }
+
+    // "16 breaths/min", or "70 gill beats/min" for a fish.
+    pub fn describe_respiration(&self) -> String {
+        format!("{} {}", self.respiration_rate, self.respiratory_organ.unit())
+    }
 }
@@ -1,5 +1,7 @@
 pub mod blood_pressure;
+pub mod blood_type;
 pub mod live_vitals;
 pub mod post_mortem_report;
+pub mod reference_ranges;
 pub mod vital_status;
 pub mod vital_types;
\ No newline at end of file
@@ -1,4 +1,5 @@
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PostMortemReport {
     pub cause_of_death: String,
     pub date_of_death: String,
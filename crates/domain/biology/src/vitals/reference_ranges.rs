@@ -0,0 +1,54 @@
+use crate::vitals::live_vitals::LiveVitals;
+use crate::vitals::vital_types::{Celsius, BPM};
+
+/// Normal resting values for a species, each as an inclusive (low, high) range.
+///
+/// Readings outside a range are reported by `deviations`, e.g. for a pet
+/// with a fever or a horse with a racing pulse. Respiration is counted in
+/// whatever unit the vitals' `respiratory_organ` uses.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReferenceRanges {
+    pub pulse: (BPM, BPM),
+    pub respiration: (u32, u32),
+    pub temperature: (Celsius, Celsius),
+}
+
+impl ReferenceRanges {
+    // Middle of every range, for building a healthy resting animal.
+    pub fn midpoint(&self) -> (BPM, u32, Celsius) {
+        (
+            BPM((self.pulse.0.0 + self.pulse.1.0) / 2),
+            (self.respiration.0 + self.respiration.1) / 2,
+            Celsius((self.temperature.0.0 + self.temperature.1.0) / 2),
+        )
+    }
+
+    pub fn is_normal(&self, vitals: &LiveVitals) -> bool {
+        self.deviations(vitals).is_empty()
+    }
+
+    // One line per reading outside its range: "Pulse of 180 BPM is above 60-140 BPM".
+    pub fn deviations(&self, vitals: &LiveVitals) -> Vec<String> {
+        let unit = vitals.respiratory_organ.unit();
+        [
+            deviation("Pulse", vitals.pulse.0, (self.pulse.0.0, self.pulse.1.0), "BPM"),
+            deviation("Respiration", vitals.respiration_rate, self.respiration, unit),
+            deviation("Temperature", vitals.temperature.0, (self.temperature.0.0, self.temperature.1.0), "°C"),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+fn deviation(reading: &str, value: u32, (low, high): (u32, u32), unit: &str) -> Option<String> {
+    let direction = if value < low {
+        "below"
+    } else if value > high {
+        "above"
+    } else {
+        return None;
+    };
+    Some(format!("{} of {} {} is {} {}-{} {}", reading, value, unit, direction, low, high, unit))
+}
@@ -1,7 +1,8 @@
 use crate::vitals::live_vitals::LiveVitals;
 use crate::vitals::post_mortem_report::PostMortemReport;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
 pub enum VitalStatus {
     Alive(LiveVitals),
     Deceased(PostMortemReport),
@@ -1,5 +1,40 @@
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
 pub struct Celsius(pub u32);
 
+impl Celsius {
+    // Rounds an ambient reading to whole degrees; below freezing reads as 0, which `Celsius` cannot go under.
+    pub fn from_degrees(degrees: f32) -> Self {
+        Celsius(degrees.max(0.0).round() as u32)
+    }
+}
+
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
 pub struct BPM(pub u32);
+
+/// The organ an animal breathes with, which decides what `respiration_rate` counts.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum RespiratoryOrgan {
+    // Breaths per minute.
+    #[default]
+    Lungs,
+    // Opercular (gill cover) beats per minute, pumping water over the gills.
+    Gills,
+    // Air tubes opening along the body; counts abdominal pumps per minute.
+    Tracheae,
+    // Pores in a plant's leaves; counts the share open, in percent.
+    Stomata,
+}
+
+impl RespiratoryOrgan {
+    pub fn unit(&self) -> &'static str {
+        match self {
+            RespiratoryOrgan::Lungs => "breaths/min",
+            RespiratoryOrgan::Gills => "gill beats/min",
+            RespiratoryOrgan::Tracheae => "abdominal pumps/min",
+            RespiratoryOrgan::Stomata => "% stomata open",
+        }
+    }
+}
@@ -0,0 +1,344 @@
+use biology::{Nomenclature, Organism, Sex};
+use biology::nomenclature::{common_aliases, common_display_name};
+use biology::anatomy::appendage::{Appendage, FinPosition};
+use biology::anatomy::fin_measurements::FinMeasurements;
+use biology::anatomy::leg_measurements::LegMeasurements;
+use biology::anatomy::limb_status::LimbStatus;
+use biology::characteristics::structural::Anatomy;
+use biology::characteristics::locomotion::{Locomotion, Mobility};
+use biology::characteristics::presentation::Summarizable;
+use biology::ecosystem::environment::{Environment, Terrain};
+use biology::ecosystem::habitat::HabitatPreference;
+use biology::patterns::presentation_patterns::{BasicSummary, DetailedSummary};
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::vitals::blood_pressure::BloodPressure;
+use biology::vitals::live_vitals::LiveVitals;
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::{Celsius, RespiratoryOrgan, BPM};
+use core_shared::{Centimeters, Direction, Identifier, Kilograms, MetersPerSecond};
+use crate::amphibians::features::{AmphibianOrder, LifeStage};
+use crate::amphibians::builder::AmphibianBuilder;
+
+/// An amphibian - a cold-blooded vertebrate that starts life in the water
+/// and, after metamorphosis, lives partly on land.
+///
+/// A larva (a tadpole, for frogs) breathes through gills, swims with a
+/// finned tail and can only live in `Terrain::Wetlands`. `grow` counts down
+/// its larval period and then metamorphoses it: the tail fin goes, the legs
+/// of its order grow (none for caecilians), and it switches to lungs. Adults
+/// live anywhere but salt water. Like reptiles, amphibians are ectotherms:
+/// their body temperature is the air's.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let mut tadpole = Amphibian::builder("Rana temporaria")
+///     .common_name("Common frog")
+///     .larva()
+///     .build()?;
+/// tadpole.grow(tadpole.larval_period); // now a froglet
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Amphibian {
+    pub id: Identifier,
+    pub species: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub common_name: Option<String>,
+    // An individual's own name or tag code.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
+    pub vitals: VitalStatus,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sex: Sex,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub body_mass: Option<Kilograms>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub top_speed: Option<MetersPerSecond>,
+    pub appendages: Vec<Appendage>,
+    pub order: AmphibianOrder,
+    pub stage: LifeStage,
+    // Days from hatching to metamorphosis.
+    pub larval_period: u32,
+    // Days a larva has left before it metamorphoses; 0 for adults.
+    pub days_to_metamorphosis: u32,
+    // Where it can live, within what its body allows; `None` for wherever that is.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub habitat: Option<HabitatPreference>,
+}
+
+impl Amphibian {
+    // An adult frog; about 12 weeks as a tadpole.
+    pub fn new(species: String) -> Self {
+        AmphibianBuilder::new(&species).assemble()
+    }
+
+    pub fn builder(species: &str) -> AmphibianBuilder {
+        AmphibianBuilder::new(species)
+    }
+
+    // A resting frog, breathing with its lungs.
+    pub fn adult_vitals() -> LiveVitals {
+        LiveVitals::new(BloodPressure(30, 20), Celsius(18), BPM(40), 20)
+    }
+
+    // A tadpole pumps water over its gills much faster.
+    pub fn larval_vitals() -> LiveVitals {
+        LiveVitals::new(BloodPressure(15, 10), Celsius(18), BPM(60), 80)
+            .with_respiratory_organ(RespiratoryOrgan::Gills)
+    }
+
+    pub(crate) fn larval_appendages() -> Vec<Appendage> {
+        vec![Appendage::Fin {
+            position: FinPosition::Caudal,
+            measurements: FinMeasurements {
+                length: Centimeters(3),
+                base: Centimeters(1),
+            },
+            status: LimbStatus::Intact,
+        }]
+    }
+
+    // Frogs grow long hind legs for leaping, salamanders four short ones, caecilians none.
+    pub(crate) fn adult_appendages(order: AmphibianOrder) -> Vec<Appendage> {
+        let (fore, hind) = match order {
+            AmphibianOrder::Frog => ((3, 1), (9, 2)),
+            AmphibianOrder::Salamander => ((2, 1), (2, 1)),
+            AmphibianOrder::Caecilian => return Vec::new(),
+        };
+        let leg = |(inseam, quad): (u32, u32), side| Appendage::Leg {
+            side,
+            measurements: LegMeasurements {
+                inseam: Centimeters(inseam),
+                quad: Centimeters(quad),
+            },
+            status: LimbStatus::Intact,
+        };
+        vec![
+            leg(fore, Direction::Left),
+            leg(fore, Direction::Right),
+            leg(hind, Direction::Left),
+            leg(hind, Direction::Right),
+        ]
+    }
+
+    // Sets the order and gives the body plan of its current stage.
+    pub fn with_order(mut self, order: AmphibianOrder) -> Self {
+        self.order = order;
+        if self.stage == LifeStage::Adult {
+            self.appendages = Self::adult_appendages(order);
+        }
+        self
+    }
+
+    pub fn with_larval_period(mut self, days: u32) -> Self {
+        self.larval_period = days;
+        if self.stage == LifeStage::Larva {
+            self.days_to_metamorphosis = days;
+        }
+        self
+    }
+
+    // Turns it into a newly hatched larva, with its whole larval period ahead.
+    pub fn as_larva(mut self) -> Self {
+        self.stage = LifeStage::Larva;
+        self.days_to_metamorphosis = self.larval_period;
+        self.appendages = Self::larval_appendages();
+        self.vitals = VitalStatus::Alive(Self::larval_vitals());
+        self
+    }
+
+    pub fn is_larva(&self) -> bool {
+        self.stage == LifeStage::Larva
+    }
+
+    // Counts down the larval period; returns true if it metamorphosed.
+    pub fn grow(&mut self, days: u32) -> bool {
+        if !self.is_larva() {
+            return false;
+        }
+        self.days_to_metamorphosis = self.days_to_metamorphosis.saturating_sub(days);
+        if self.days_to_metamorphosis == 0 {
+            self.metamorphose();
+            return true;
+        }
+        false
+    }
+
+    // Larva to adult: the tail fin is absorbed, legs grow, and lungs take over from gills.
+    pub fn metamorphose(&mut self) {
+        self.stage = LifeStage::Adult;
+        self.days_to_metamorphosis = 0;
+        self.appendages = Self::adult_appendages(self.order);
+        if let VitalStatus::Alive(vitals) = &mut self.vitals {
+            let adult = Self::adult_vitals();
+            vitals.respiration_rate = adult.respiration_rate;
+            vitals.respiratory_organ = adult.respiratory_organ;
+        }
+    }
+}
+
+impl Nomenclature for Amphibian {
+    fn display_name(&self) -> String {
+        common_display_name(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+
+    fn aliases(&self) -> Vec<String> {
+        common_aliases(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+}
+
+impl AnatomyAnalyzer for Amphibian {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
+    }
+}
+
+impl Anatomy for Amphibian {
+    fn describe_anatomy(&self) -> String {
+        let stage = match self.stage {
+            LifeStage::Larva => format!(
+                "A {} {} breathing through gills, {} days from metamorphosis",
+                self.order,
+                self.order.larva_name(),
+                self.days_to_metamorphosis
+            ),
+            LifeStage::Adult => format!("An adult {} breathing with lungs and through its skin", self.order),
+        };
+        format!(
+            "{} {}; moist-skinned and cold-blooded, laying its eggs in water.",
+            self.describe_limb_status(),
+            stage
+        )
+    }
+}
+
+impl Mobility for Amphibian {
+    fn describe_locomotion(&self) -> String {
+        match (self.stage, self.order) {
+            (LifeStage::Larva, _) => "Swims by beating its finned tail.".to_string(),
+            (LifeStage::Adult, AmphibianOrder::Frog) => {
+                "Hops and leaps on long hind legs, and swims with powerful kicks.".to_string()
+            }
+            (LifeStage::Adult, AmphibianOrder::Salamander) => {
+                "Walks with a side-to-side sway on four short legs, and swims by undulating.".to_string()
+            }
+            (LifeStage::Adult, AmphibianOrder::Caecilian) => "Burrows through damp soil head first.".to_string(),
+        }
+    }
+
+    fn locomotion(&self) -> Locomotion {
+        match (self.stage, self.order) {
+            (LifeStage::Larva, _) => Locomotion::Aquatic,
+            (LifeStage::Adult, AmphibianOrder::Caecilian) => Locomotion::Fossorial,
+            (LifeStage::Adult, _) => Locomotion::Amphibious,
+        }
+    }
+}
+
+impl Organism for Amphibian {
+    fn id(&self) -> Identifier {
+        self.id
+    }
+
+    fn species(&self) -> String {
+        self.species.clone()
+    }
+
+    fn vitals(&self) -> &VitalStatus {
+        &self.vitals
+    }
+
+    fn vitals_mut(&mut self) -> &mut VitalStatus {
+        &mut self.vitals
+    }
+
+    fn sex(&self) -> Sex {
+        self.sex
+    }
+
+    fn body_mass(&self) -> Option<Kilograms> {
+        self.body_mass
+    }
+
+    fn top_speed(&self) -> Option<MetersPerSecond> {
+        self.top_speed
+    }
+
+    // Larvae need fresh water; salt water dries out an adult's permeable skin.
+    fn tolerates(&self, environment: &Environment) -> bool {
+        let suits_body = match self.stage {
+            LifeStage::Larva => matches!(environment.terrain, Terrain::Wetlands),
+            LifeStage::Adult => !matches!(environment.terrain, Terrain::Ocean),
+        };
+        suits_body && self.habitat.as_ref().is_none_or(|habitat| habitat.allows(environment))
+    }
+
+    fn body_temperature_in(&self, environment: &Environment) -> Option<Celsius> {
+        Some(Celsius::from_degrees(environment.ambient_temperature()))
+    }
+}
+
+impl BasicSummary for Amphibian {
+    fn get_name(&self) -> String {
+        self.display_name()
+    }
+
+    fn get_gender(&self) -> &Sex {
+        &self.sex
+    }
+}
+
+impl DetailedSummary for Amphibian {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
+    }
+}
+
+impl Summarizable for Amphibian {
+    fn summary(&self) -> String {
+        format!(
+            "--- Amphibian: {} ({}) ---\nID: {}\nAnatomy: {}\nMobility: {}\n\n {}",
+            self.display_name(),
+            self.species,
+            self.id,
+            self.describe_anatomy(),
+            self.describe_locomotion(),
+            self.generate_summary()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biology::anatomy::appendage::AppendageKind;
+
+    #[test]
+    fn test_tadpole_metamorphoses_into_a_frog() {
+        let mut frog = Amphibian::new("Rana temporaria".to_string())
+            .with_larval_period(60)
+            .as_larva();
+        assert_eq!(frog.count_appendages(AppendageKind::Fin), (1, 0));
+        assert_eq!(frog.locomotion(), Locomotion::Aquatic);
+
+        assert!(!frog.grow(59));
+        assert!(frog.is_larva());
+        assert!(frog.grow(1));
+
+        assert_eq!(frog.stage, LifeStage::Adult);
+        assert_eq!(frog.count_limbs(), (0, 0, 4, 0));
+        assert_eq!(frog.count_appendages(AppendageKind::Fin), (0, 0));
+        assert_eq!(frog.locomotion(), Locomotion::Amphibious);
+        assert!(matches!(&frog.vitals, VitalStatus::Alive(vitals) if vitals.respiratory_organ == RespiratoryOrgan::Lungs));
+    }
+
+    #[test]
+    fn test_caecilians_are_limbless_burrowers() {
+        let caecilian = Amphibian::new("Dermophis mexicanus".to_string()).with_order(AmphibianOrder::Caecilian);
+
+        assert!(caecilian.appendages.is_empty());
+        assert_eq!(caecilian.locomotion(), Locomotion::Fossorial);
+        assert!(caecilian.describe_anatomy().starts_with("A legless form without appendages."));
+    }
+}
@@ -0,0 +1,120 @@
+use biology::anatomy::appendage::AppendageKind;
+use biology::builder::{BuildError, BuildProblem, OrganismBuilder, OrganismBuilderExt};
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::vitals::vital_status::VitalStatus;
+use crate::amphibians::amphibian::Amphibian;
+use crate::amphibians::features::{AmphibianOrder, LifeStage};
+
+/// Builds an `Amphibian` with the shared `OrganismBuilder` settings, plus
+/// its order, life stage and larval period.
+///
+/// Unless `appendages` replaces them, it gets the body plan of its stage:
+/// a larva's tail fin, or the legs of its order. `build` reports
+/// appendages other than legs and fins, legs on a caecilian, and a larva
+/// with no larval period left.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let tadpole = Amphibian::builder("Rana temporaria")
+///     .common_name("Common frog")
+///     .larva()
+///     .build()?;
+/// ```
+pub struct AmphibianBuilder {
+    organism: OrganismBuilder,
+    order: AmphibianOrder,
+    stage: LifeStage,
+    larval_period: u32,
+}
+
+impl AmphibianBuilder {
+    pub fn new(species: &str) -> Self {
+        Self {
+            organism: OrganismBuilder::new(species).vitals(VitalStatus::Alive(Amphibian::adult_vitals())),
+            order: AmphibianOrder::default(),
+            stage: LifeStage::Adult,
+            larval_period: 84,
+        }
+    }
+
+    pub fn order(mut self, order: AmphibianOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    // Days from hatching to metamorphosis.
+    pub fn larval_period(mut self, days: u32) -> Self {
+        self.larval_period = days;
+        self
+    }
+
+    // A newly hatched larva, breathing through gills; later setters override its vitals.
+    pub fn larva(mut self) -> Self {
+        self.stage = LifeStage::Larva;
+        self.vitals(VitalStatus::Alive(Amphibian::larval_vitals()))
+    }
+
+    // Everything wrong with the settings: the shared checks, then the amphibian's own.
+    pub fn problems(&self) -> Vec<BuildProblem> {
+        let mut problems = self.organism.problems();
+        problems.extend(
+            self.organism
+                .unexpected_appendages(&[AppendageKind::Leg, AppendageKind::Fin], "an amphibian"),
+        );
+        let (legs, _) = self.organism.count_appendages(AppendageKind::Leg);
+        if self.order == AmphibianOrder::Caecilian && legs > 0 {
+            problems.push(BuildProblem::Invalid {
+                field: "appendages",
+                reason: format!("a caecilian has no legs, not {}", legs),
+            });
+        }
+        if self.stage == LifeStage::Larva && self.larval_period == 0 {
+            problems.push(BuildProblem::Invalid {
+                field: "larval period",
+                reason: "a larva needs at least a day before it metamorphoses".to_string(),
+            });
+        }
+        problems
+    }
+
+    pub fn build(self) -> Result<Amphibian, BuildError> {
+        BuildError::check(self.problems())?;
+        Ok(self.assemble())
+    }
+
+    // The amphibian without any checks, for `Amphibian::new`.
+    pub(crate) fn assemble(self) -> Amphibian {
+        let parts = self.organism.into_parts();
+        let appendages = match (parts.appendages.is_empty(), self.stage) {
+            (false, _) => parts.appendages,
+            (true, LifeStage::Larva) => Amphibian::larval_appendages(),
+            (true, LifeStage::Adult) => Amphibian::adult_appendages(self.order),
+        };
+        Amphibian {
+            id: parts.id,
+            species: parts.species,
+            common_name: parts.common_name,
+            name: parts.name,
+            vitals: parts.vitals,
+            sex: parts.sex,
+            body_mass: parts.body_mass,
+            top_speed: parts.top_speed,
+            appendages,
+            order: self.order,
+            stage: self.stage,
+            larval_period: self.larval_period,
+            days_to_metamorphosis: match self.stage {
+                LifeStage::Larva => self.larval_period,
+                LifeStage::Adult => 0,
+            },
+            habitat: parts.habitat,
+        }
+    }
+}
+
+impl OrganismBuilderExt for AmphibianBuilder {
+    fn organism_builder(&mut self) -> &mut OrganismBuilder {
+        &mut self.organism
+    }
+}
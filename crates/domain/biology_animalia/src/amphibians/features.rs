@@ -0,0 +1,45 @@
+use std::fmt::{Display, Formatter};
+
+/// The three living orders of amphibians, which differ in body plan.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum AmphibianOrder {
+    // Anura: tailless adults with long hind legs for leaping.
+    #[default]
+    Frog,
+    // Caudata: long-bodied, tailed, four short legs: salamanders and newts.
+    Salamander,
+    // Gymnophiona: limbless burrowers that look like earthworms.
+    Caecilian,
+}
+
+impl AmphibianOrder {
+    // What the larva is called.
+    pub fn larva_name(&self) -> &'static str {
+        match self {
+            AmphibianOrder::Frog => "tadpole",
+            AmphibianOrder::Salamander | AmphibianOrder::Caecilian => "larva",
+        }
+    }
+}
+
+impl Display for AmphibianOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AmphibianOrder::Frog => write!(f, "frog"),
+            AmphibianOrder::Salamander => write!(f, "salamander"),
+            AmphibianOrder::Caecilian => write!(f, "caecilian"),
+        }
+    }
+}
+
+/// Where an amphibian is in its life cycle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum LifeStage {
+    // Aquatic, breathing through gills and swimming with a finned tail.
+    Larva,
+    // After metamorphosis: legs, lungs and a life partly on land.
+    #[default]
+    Adult,
+}
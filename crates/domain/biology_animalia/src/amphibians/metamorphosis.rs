@@ -0,0 +1,24 @@
+use biology::simulation::engine::TickContext;
+use biology::simulation::rules::Rule;
+use crate::amphibians::Amphibian;
+
+/// Every living larva grows for a day towards metamorphosis.
+///
+/// A tick is a day, as for the `Weather` rule and plants' `Growth`. A larva
+/// whose larval period runs out metamorphoses into an adult; adults are left
+/// as they are.
+pub struct Metamorphosis;
+
+impl Rule for Metamorphosis {
+    fn name(&self) -> &'static str {
+        "metamorphosis"
+    }
+
+    fn apply(&mut self, context: &mut TickContext) {
+        for id in context.living() {
+            if let Some(amphibian) = context.population.get_as_mut::<Amphibian>(id) {
+                amphibian.grow(1);
+            }
+        }
+    }
+}
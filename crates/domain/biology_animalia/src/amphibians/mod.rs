@@ -0,0 +1,9 @@
+pub mod amphibian;
+pub mod builder;
+pub mod features;
+pub mod metamorphosis;
+
+pub use amphibian::Amphibian;
+pub use builder::AmphibianBuilder;
+pub use features::{AmphibianOrder, LifeStage};
+pub use metamorphosis::Metamorphosis;
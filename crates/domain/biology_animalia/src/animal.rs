@@ -1,21 +1,46 @@
-use biology::Nomenclature;
+use biology::{Nomenclature, Organism, Sex};
+use biology::nomenclature::{common_aliases, common_display_name};
+use biology::anatomy::appendage::Appendage;
+use biology::builder::{BuildError, OrganismBuilder, OrganismBuilderExt};
 use biology::characteristics::structural::Anatomy;
-use biology::characteristics::locomotion::Mobility;
+use biology::characteristics::locomotion::{Locomotion, Mobility};
 use biology::characteristics::presentation::Summarizable;
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::habitat::HabitatPreference;
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
 use biology::vitals::vital_status::VitalStatus;
 use biology::vitals::live_vitals::LiveVitals;
-use core_shared::Identifier;
+use core_shared::{Identifier, Kilograms, MetersPerSecond};
 
 /// A generic animal - the simplest representation of an organism.
 ///
 /// This struct demonstrates the baseline structure that all animals share,
 /// with minimal complexity. More specific animal types (like Human) add
-/// additional fields and behavior.
-#[derive(Debug)]
+/// additional fields and behavior. `Animal::builder` checks its settings
+/// and can also give it names, appendages and a habitat preference.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Animal {
     pub id: Identifier,
     pub species: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub common_name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
     pub vitals: VitalStatus,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sex: Sex,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub body_mass: Option<Kilograms>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub top_speed: Option<MetersPerSecond>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub locomotion: Locomotion,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub appendages: Vec<Appendage>,
+    // Where it can live; `None` for anywhere.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub habitat: Option<HabitatPreference>,
 }
 
 impl Animal {
@@ -23,25 +48,72 @@ impl Animal {
         Self {
             id: Identifier::new(),
             species,
+            common_name: None,
+            name: None,
             vitals: VitalStatus::Alive(LiveVitals::default()),
+            sex: Sex::Unknown,
+            body_mass: None,
+            top_speed: None,
+            locomotion: Locomotion::Terrestrial,
+            appendages: Vec::new(),
+            habitat: None,
         }
     }
 
+    pub fn builder(species: &str) -> AnimalBuilder {
+        AnimalBuilder::new(species)
+    }
+
     pub fn with_vitals(mut self, vitals: VitalStatus) -> Self {
         self.vitals = vitals;
         self
     }
+
+    pub fn with_sex(mut self, sex: Sex) -> Self {
+        self.sex = sex;
+        self
+    }
+
+    pub fn with_body_mass(mut self, body_mass: Kilograms) -> Self {
+        self.body_mass = Some(body_mass);
+        self
+    }
+
+    pub fn with_top_speed(mut self, top_speed: MetersPerSecond) -> Self {
+        self.top_speed = Some(top_speed);
+        self
+    }
+
+    pub fn with_locomotion(mut self, locomotion: Locomotion) -> Self {
+        self.locomotion = locomotion;
+        self
+    }
 }
 
 impl Nomenclature for Animal {
     fn display_name(&self) -> String {
-        self.species.clone()
+        common_display_name(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+
+    fn aliases(&self) -> Vec<String> {
+        common_aliases(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+}
+
+impl AnatomyAnalyzer for Animal {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
     }
 }
 
 impl Anatomy for Animal {
     fn describe_anatomy(&self) -> String {
-        format!("A {} with standard anatomical structure.", self.species)
+        let description = format!("A {} with standard anatomical structure.", self.species);
+        if self.appendages.is_empty() {
+            description
+        } else {
+            format!("{} {}", self.describe_limb_status(), description)
+        }
     }
 }
 
@@ -49,6 +121,44 @@ impl Mobility for Animal {
     fn describe_locomotion(&self) -> String {
         "Moves in species-appropriate manner.".to_string()
     }
+
+    fn locomotion(&self) -> Locomotion {
+        self.locomotion
+    }
+}
+
+impl Organism for Animal {
+    fn id(&self) -> Identifier {
+        self.id
+    }
+
+    fn species(&self) -> String {
+        self.species.clone()
+    }
+
+    fn vitals(&self) -> &VitalStatus {
+        &self.vitals
+    }
+
+    fn vitals_mut(&mut self) -> &mut VitalStatus {
+        &mut self.vitals
+    }
+
+    fn sex(&self) -> Sex {
+        self.sex
+    }
+
+    fn body_mass(&self) -> Option<Kilograms> {
+        self.body_mass
+    }
+
+    fn top_speed(&self) -> Option<MetersPerSecond> {
+        self.top_speed
+    }
+
+    fn tolerates(&self, environment: &Environment) -> bool {
+        self.habitat.as_ref().is_none_or(|habitat| habitat.allows(environment))
+    }
 }
 
 impl Summarizable for Animal {
@@ -63,9 +173,59 @@ impl Summarizable for Animal {
     }
 }
 
+/// Builds an `Animal` with the shared `OrganismBuilder` settings and a locomotion.
+pub struct AnimalBuilder {
+    organism: OrganismBuilder,
+    locomotion: Locomotion,
+}
+
+impl AnimalBuilder {
+    pub fn new(species: &str) -> Self {
+        Self {
+            organism: OrganismBuilder::new(species),
+            locomotion: Locomotion::Terrestrial,
+        }
+    }
+
+    pub fn locomotion(mut self, locomotion: Locomotion) -> Self {
+        self.locomotion = locomotion;
+        self
+    }
+
+    pub fn build(self) -> Result<Animal, BuildError> {
+        BuildError::check(self.organism.problems())?;
+        Ok(self.assemble())
+    }
+
+    // The animal without any checks, e.g. for a newborn.
+    pub(crate) fn assemble(self) -> Animal {
+        let parts = self.organism.into_parts();
+        Animal {
+            id: parts.id,
+            species: parts.species,
+            common_name: parts.common_name,
+            name: parts.name,
+            vitals: parts.vitals,
+            sex: parts.sex,
+            body_mass: parts.body_mass,
+            top_speed: parts.top_speed,
+            locomotion: self.locomotion,
+            appendages: parts.appendages,
+            habitat: parts.habitat,
+        }
+    }
+}
+
+impl OrganismBuilderExt for AnimalBuilder {
+    fn organism_builder(&mut self) -> &mut OrganismBuilder {
+        &mut self.organism
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use biology::ecosystem::environment::Terrain;
     use biology::vitals::post_mortem_report::PostMortemReport;
 
     #[test]
@@ -111,6 +271,19 @@ mod tests {
         assert!(mobility.contains("species-appropriate"));
     }
 
+    #[test]
+    fn test_animal_builder() {
+        let animal = Animal::builder("Vulpes vulpes")
+            .common_name("Red fox")
+            .habitat(HabitatPreference::on(&[Terrain::Forest]))
+            .build()
+            .unwrap();
+
+        assert_eq!(animal.display_name(), "Red fox");
+        assert!(!animal.tolerates(&Environment::default()));
+        assert!(Animal::builder(" ").build().is_err());
+    }
+
     #[test]
     fn test_animal_summary() {
         let animal = Animal::new("Test Species".to_string());
@@ -1,6 +1,256 @@
-// TODO: Implement generic Bird struct
-// Similar to Mammal, but with bird-specific characteristics:
-// - Feathers
-// - Beak
-// - Egg-laying
-// - Flight capability (optional)
+use biology::{Nomenclature, Organism, Sex};
+use biology::nomenclature::{common_aliases, common_display_name};
+use biology::anatomy::appendage::{Appendage, AppendageKind};
+use biology::anatomy::limb_status::LimbStatus;
+use biology::characteristics::structural::Anatomy;
+use biology::characteristics::locomotion::{Locomotion, Mobility};
+use biology::characteristics::presentation::Summarizable;
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::habitat::HabitatPreference;
+use biology::patterns::presentation_patterns::{BasicSummary, DetailedSummary};
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::vitals::vital_status::VitalStatus;
+use core_shared::{Direction, Identifier, Kilograms, MetersPerSecond};
+use crate::birds::builder::BirdBuilder;
+use crate::birds::features::{Beak, Flight, Plumage};
+
+/// A bird - a feathered, beaked, egg-laying vertebrate with two wings and two legs.
+///
+/// Whether a bird flies decides how it moves through a landscape: birds
+/// that can fly are `Locomotion::Aerial`, while flightless ones (ostriches,
+/// penguins) and birds grounded by an injured wing fall back to their
+/// `locomotion` on the ground or in the water.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let penguin = Bird::builder("Aptenodytes forsteri")
+///     .common_name("Emperor penguin")
+///     .flight(Flight::Flightless)
+///     .locomotion(Locomotion::Amphibious)
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bird {
+    pub id: Identifier,
+    pub species: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub common_name: Option<String>,
+    // An individual's own name or ring code.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
+    pub vitals: VitalStatus,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sex: Sex,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub body_mass: Option<Kilograms>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub top_speed: Option<MetersPerSecond>,
+    pub appendages: Vec<Appendage>,
+    pub plumage: Plumage,
+    pub beak: Beak,
+    pub flight: Flight,
+    // How it moves when not flying.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub locomotion: Locomotion,
+    // Where it can live; `None` for anywhere.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub habitat: Option<HabitatPreference>,
+}
+
+impl Bird {
+    // A flying bird with default plumage, beak and measurements.
+    pub fn new(species: String) -> Self {
+        BirdBuilder::new(&species).assemble()
+    }
+
+    pub fn builder(species: &str) -> BirdBuilder {
+        BirdBuilder::new(species)
+    }
+
+    // Flight needs a flying species and both wings intact.
+    pub fn can_fly(&self) -> bool {
+        let (attached, _) = self.count_appendages(AppendageKind::Wing);
+        let wings_intact = self
+            .appendages
+            .iter()
+            .filter(|appendage| appendage.kind() == AppendageKind::Wing)
+            .all(|wing| *wing.status() == LimbStatus::Intact);
+        self.flight.can_fly() && attached >= 2 && wings_intact
+    }
+
+    // Every bird lays eggs; the `Reproduction` rule incubates them.
+    pub fn lays_eggs(&self) -> bool {
+        true
+    }
+
+    pub fn set_wing_status(&mut self, side: Direction, status: LimbStatus) {
+        if let Some(wing) = self
+            .appendages
+            .iter_mut()
+            .find(|appendage| appendage.kind() == AppendageKind::Wing && appendage.side() == Some(side))
+        {
+            *wing.status_mut() = status;
+        }
+    }
+}
+
+impl Nomenclature for Bird {
+    fn display_name(&self) -> String {
+        common_display_name(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+
+    fn aliases(&self) -> Vec<String> {
+        common_aliases(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+}
+
+impl AnatomyAnalyzer for Bird {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
+    }
+}
+
+impl Anatomy for Bird {
+    fn describe_anatomy(&self) -> String {
+        format!(
+            "{} Feathered in {} plumage, with a {} beak for {}; warm-blooded and egg-laying.",
+            self.describe_limb_status(),
+            self.plumage,
+            self.beak,
+            self.beak.diet()
+        )
+    }
+}
+
+impl Mobility for Bird {
+    fn describe_locomotion(&self) -> String {
+        if self.flight.can_fly() && !self.can_fly() {
+            return "Grounded by an injured wing; walks and hops on two legs.".to_string();
+        }
+        match self.flight {
+            Flight::Flapping => "Flies with steady wingbeats; walks or hops on two legs.".to_string(),
+            Flight::Soaring => "Soars on long wings, riding thermals and wind; walks on two legs.".to_string(),
+            Flight::Hovering => "Hovers and darts on rapid wingbeats; perches on two small legs.".to_string(),
+            Flight::Burst => "Flies in short bursts to escape, then glides; walks and runs on two legs.".to_string(),
+            Flight::Flightless => match self.locomotion {
+                Locomotion::Aquatic | Locomotion::Amphibious => {
+                    "Flightless; swims with its wings as flippers and waddles on two legs.".to_string()
+                }
+                _ => "Flightless; walks and runs on two legs.".to_string(),
+            },
+        }
+    }
+
+    fn locomotion(&self) -> Locomotion {
+        if self.can_fly() {
+            Locomotion::Aerial
+        } else {
+            self.locomotion
+        }
+    }
+}
+
+impl Organism for Bird {
+    fn id(&self) -> Identifier {
+        self.id
+    }
+
+    fn species(&self) -> String {
+        self.species.clone()
+    }
+
+    fn vitals(&self) -> &VitalStatus {
+        &self.vitals
+    }
+
+    fn vitals_mut(&mut self) -> &mut VitalStatus {
+        &mut self.vitals
+    }
+
+    fn sex(&self) -> Sex {
+        self.sex
+    }
+
+    fn body_mass(&self) -> Option<Kilograms> {
+        self.body_mass
+    }
+
+    fn top_speed(&self) -> Option<MetersPerSecond> {
+        self.top_speed
+    }
+
+    fn tolerates(&self, environment: &Environment) -> bool {
+        self.habitat.as_ref().is_none_or(|habitat| habitat.allows(environment))
+    }
+}
+
+impl BasicSummary for Bird {
+    fn get_name(&self) -> String {
+        self.display_name()
+    }
+
+    fn get_gender(&self) -> &Sex {
+        &self.sex
+    }
+}
+
+impl DetailedSummary for Bird {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
+    }
+}
+
+impl Summarizable for Bird {
+    fn summary(&self) -> String {
+        format!(
+            "--- Bird: {} ({}) ---\nID: {}\nAnatomy: {}\nMobility: {}\n\n {}",
+            self.display_name(),
+            self.species,
+            self.id,
+            self.describe_anatomy(),
+            self.describe_locomotion(),
+            self.generate_summary()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biology::builder::OrganismBuilderExt;
+
+    #[test]
+    fn test_bird_creation() {
+        let bird = Bird::builder("Passer domesticus").build().unwrap();
+
+        assert_eq!(bird.species, "Passer domesticus");
+        assert_eq!(bird.count_appendages(AppendageKind::Wing), (2, 0));
+        assert_eq!(bird.count_appendages(AppendageKind::Leg), (2, 0));
+        assert!(bird.can_fly());
+        assert!(bird.lays_eggs());
+        assert!(matches!(bird.vitals, VitalStatus::Alive(_)));
+    }
+
+    #[test]
+    fn test_injured_wing_grounds_a_bird() {
+        let mut bird = Bird::builder("Columba livia").build().unwrap();
+        assert_eq!(bird.locomotion(), Locomotion::Aerial);
+
+        bird.set_wing_status(Direction::Left, LimbStatus::Injured("Broken primary feathers".to_string()));
+
+        assert!(!bird.can_fly());
+        assert_eq!(bird.locomotion(), Locomotion::Terrestrial);
+        assert!(bird.describe_locomotion().contains("Grounded"));
+    }
+
+    #[test]
+    fn test_bird_nomenclature() {
+        let bird = Bird::builder("Struthio camelus").common_name("Common ostrich").name("Olive").build().unwrap();
+
+        assert_eq!(bird.display_name(), "Olive");
+        assert_eq!(bird.aliases(), vec!["Olive", "Common ostrich", "Struthio camelus"]);
+        assert!(bird.answers_to("common ostrich"));
+    }
+}
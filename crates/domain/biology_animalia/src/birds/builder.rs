@@ -0,0 +1,152 @@
+use biology::anatomy::appendage::{Appendage, AppendageKind};
+use biology::anatomy::leg_measurements::LegMeasurements;
+use biology::anatomy::limb_status::LimbStatus;
+use biology::anatomy::wing_measurements::WingMeasurements;
+use biology::builder::{BuildError, BuildProblem, OrganismBuilder, OrganismBuilderExt};
+use biology::characteristics::locomotion::Locomotion;
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use core_shared::{Centimeters, Direction};
+use crate::birds::bird::Bird;
+use crate::birds::features::{Beak, Flight, Plumage};
+
+/// Builds a `Bird` with the shared `OrganismBuilder` settings, plus its
+/// wings and legs, plumage, beak and flight.
+///
+/// Unless `appendages` replaces them, the bird gets two wings and two legs
+/// of the configured measurements. `build` reports a flying bird without
+/// two wings, and appendages no bird has.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let penguin = Bird::builder("Aptenodytes forsteri")
+///     .common_name("Emperor penguin")
+///     .flight(Flight::Flightless)
+///     .locomotion(Locomotion::Amphibious)
+///     .build()?;
+/// ```
+pub struct BirdBuilder {
+    organism: OrganismBuilder,
+    wing_measurements: WingMeasurements,
+    leg_measurements: LegMeasurements,
+    plumage: Plumage,
+    beak: Beak,
+    flight: Flight,
+    locomotion: Locomotion,
+}
+
+impl BirdBuilder {
+    pub fn new(species: &str) -> Self {
+        Self {
+            organism: OrganismBuilder::new(species),
+            wing_measurements: WingMeasurements::default(),
+            // A pigeon's legs: leg length and thigh girth.
+            leg_measurements: LegMeasurements {
+                inseam: Centimeters(9),
+                quad: Centimeters(4),
+            },
+            plumage: Plumage::default(),
+            beak: Beak::default(),
+            flight: Flight::default(),
+            locomotion: Locomotion::Terrestrial,
+        }
+    }
+
+    pub fn wing_measurements(mut self, measurements: WingMeasurements) -> Self {
+        self.wing_measurements = measurements;
+        self
+    }
+
+    pub fn leg_measurements(mut self, measurements: LegMeasurements) -> Self {
+        self.leg_measurements = measurements;
+        self
+    }
+
+    pub fn plumage(mut self, plumage: Plumage) -> Self {
+        self.plumage = plumage;
+        self
+    }
+
+    pub fn beak(mut self, beak: Beak) -> Self {
+        self.beak = beak;
+        self
+    }
+
+    pub fn flight(mut self, flight: Flight) -> Self {
+        self.flight = flight;
+        self
+    }
+
+    // How the bird moves when it is not flying; `Amphibious` for penguins.
+    pub fn locomotion(mut self, locomotion: Locomotion) -> Self {
+        self.locomotion = locomotion;
+        self
+    }
+
+    // Everything wrong with the settings: the shared checks, then the bird's own.
+    pub fn problems(&self) -> Vec<BuildProblem> {
+        let mut problems = self.organism.problems();
+        if self.organism.get_appendages().is_empty() {
+            return problems;
+        }
+        problems.extend(self.organism.unexpected_appendages(&[AppendageKind::Wing, AppendageKind::Leg], "a bird"));
+        let (wings, _) = self.organism.count_appendages(AppendageKind::Wing);
+        if self.flight.can_fly() && wings < 2 {
+            problems.push(BuildProblem::Invalid {
+                field: "flight",
+                reason: format!("a flying bird needs two wings, not {}", wings),
+            });
+        }
+        problems
+    }
+
+    pub fn build(self) -> Result<Bird, BuildError> {
+        BuildError::check(self.problems())?;
+        Ok(self.assemble())
+    }
+
+    // The bird without any checks, for `Bird::new`.
+    pub(crate) fn assemble(self) -> Bird {
+        let parts = self.organism.into_parts();
+        let appendages = if parts.appendages.is_empty() {
+            [Direction::Left, Direction::Right]
+                .into_iter()
+                .map(|side| Appendage::Wing {
+                    side,
+                    measurements: self.wing_measurements,
+                    status: LimbStatus::Intact,
+                })
+                .chain([Direction::Left, Direction::Right].into_iter().map(|side| Appendage::Leg {
+                    side,
+                    measurements: self.leg_measurements,
+                    status: LimbStatus::Intact,
+                }))
+                .collect()
+        } else {
+            parts.appendages
+        };
+
+        Bird {
+            id: parts.id,
+            species: parts.species,
+            common_name: parts.common_name,
+            name: parts.name,
+            vitals: parts.vitals,
+            sex: parts.sex,
+            body_mass: parts.body_mass,
+            top_speed: parts.top_speed,
+            appendages,
+            plumage: self.plumage,
+            beak: self.beak,
+            flight: self.flight,
+            locomotion: self.locomotion,
+            habitat: parts.habitat,
+        }
+    }
+}
+
+impl OrganismBuilderExt for BirdBuilder {
+    fn organism_builder(&mut self) -> &mut OrganismBuilder {
+        &mut self.organism
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt::{Display, Formatter};
+
+/// Beak shape, which follows what a bird eats.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Beak {
+    // Short and thick, for cracking seeds: finches, sparrows.
+    Conical,
+    // Curved and sharp, for tearing meat: eagles, owls.
+    Hooked,
+    // Long and thin, for nectar or probing mud: hummingbirds, sandpipers.
+    Probing,
+    // Broad and flat, for filtering water: ducks, flamingos.
+    Filtering,
+    // Straight and hard, for drilling wood: woodpeckers.
+    Chisel,
+    // Long, with a throat pouch, for scooping fish: pelicans.
+    Pouched,
+    // Thin and pointed, for picking insects: warblers, wrens.
+    Insectivore,
+    // Medium, for a bit of everything: crows, chickens, ostriches.
+    #[default]
+    Generalist,
+}
+
+impl Beak {
+    pub fn diet(&self) -> &'static str {
+        match self {
+            Beak::Conical => "seeds",
+            Beak::Hooked => "meat",
+            Beak::Probing => "nectar and small invertebrates",
+            Beak::Filtering => "plankton and water plants",
+            Beak::Chisel => "insects in wood",
+            Beak::Pouched => "fish",
+            Beak::Insectivore => "insects",
+            Beak::Generalist => "whatever is available",
+        }
+    }
+}
+
+impl Display for Beak {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Beak::Conical => write!(f, "conical"),
+            Beak::Hooked => write!(f, "hooked"),
+            Beak::Probing => write!(f, "probing"),
+            Beak::Filtering => write!(f, "filtering"),
+            Beak::Chisel => write!(f, "chisel"),
+            Beak::Pouched => write!(f, "pouched"),
+            Beak::Insectivore => write!(f, "insect-picking"),
+            Beak::Generalist => write!(f, "generalist"),
+        }
+    }
+}
+
+/// How a bird flies, if it flies at all.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Flight {
+    // Steady wingbeats: pigeons, ducks, most songbirds.
+    #[default]
+    Flapping,
+    // Rides thermals and wind on long wings: albatrosses, vultures.
+    Soaring,
+    // Holds still in the air: hummingbirds.
+    Hovering,
+    // Short bursts to escape, then glides down: pheasants, chickens.
+    Burst,
+    // Cannot fly: ostriches, kiwis, penguins.
+    Flightless,
+}
+
+impl Flight {
+    pub fn can_fly(&self) -> bool {
+        !matches!(self, Flight::Flightless)
+    }
+}
+
+/// Colour and pattern of the feathers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plumage {
+    // The main colours, most prominent first.
+    pub colors: Vec<String>,
+    pub pattern: PlumagePattern,
+}
+
+impl Default for Plumage {
+    fn default() -> Self {
+        Self {
+            colors: vec!["brown".to_string()],
+            pattern: PlumagePattern::Solid,
+        }
+    }
+}
+
+impl Plumage {
+    pub fn new(colors: &[&str], pattern: PlumagePattern) -> Self {
+        Self {
+            colors: colors.iter().map(|color| color.to_string()).collect(),
+            pattern,
+        }
+    }
+}
+
+impl Display for Plumage {
+    // "black and white countershaded", "brown, buff and white barred"
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let colors = match self.colors.as_slice() {
+            [] => "colourless".to_string(),
+            [only] => only.clone(),
+            [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+        };
+        write!(f, "{} {}", colors, self.pattern)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum PlumagePattern {
+    #[default]
+    Solid,
+    Barred,
+    Spotted,
+    Streaked,
+    // Dark above and pale below, as camouflage from both sides: penguins.
+    Countershaded,
+    Iridescent,
+}
+
+impl Display for PlumagePattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlumagePattern::Solid => write!(f, "solid"),
+            PlumagePattern::Barred => write!(f, "barred"),
+            PlumagePattern::Spotted => write!(f, "spotted"),
+            PlumagePattern::Streaked => write!(f, "streaked"),
+            PlumagePattern::Countershaded => write!(f, "countershaded"),
+            PlumagePattern::Iridescent => write!(f, "iridescent"),
+        }
+    }
+}
@@ -1,4 +1,7 @@
 pub mod bird;
+pub mod builder;
+pub mod features;
 
-// Re-export when implemented
-// pub use bird::Bird;
+pub use bird::Bird;
+pub use builder::BirdBuilder;
+pub use features::{Beak, Flight, Plumage, PlumagePattern};
@@ -0,0 +1,98 @@
+use biology::anatomy::appendage::AppendageKind;
+use biology::anatomy::fin_measurements::FinMeasurements;
+use biology::builder::{BuildError, BuildProblem, OrganismBuilder, OrganismBuilderExt};
+use biology::vitals::vital_status::VitalStatus;
+use crate::fish::features::{Scales, WaterType};
+use crate::fish::fish::Fish;
+
+/// Builds a `Fish` with the shared `OrganismBuilder` settings, plus its
+/// fins, scales and water.
+///
+/// Unless `appendages` replaces them, the fish gets a full set of fins
+/// sized from `fin_measurements`. `build` reports appendages other than
+/// fins. A habitat narrows where the fish can live, but never beyond its
+/// `water`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let cod = Fish::builder("Gadus morhua")
+///     .common_name("Atlantic cod")
+///     .water(WaterType::Saltwater)
+///     .build()?;
+/// ```
+pub struct FishBuilder {
+    organism: OrganismBuilder,
+    fin_measurements: FinMeasurements,
+    scales: Scales,
+    water: WaterType,
+}
+
+impl FishBuilder {
+    pub fn new(species: &str) -> Self {
+        Self {
+            organism: OrganismBuilder::new(species).vitals(VitalStatus::Alive(Fish::default_vitals())),
+            fin_measurements: FinMeasurements::default(),
+            scales: Scales::default(),
+            water: WaterType::default(),
+        }
+    }
+
+    // The size of a pectoral fin; the others are sized from it.
+    pub fn fin_measurements(mut self, measurements: FinMeasurements) -> Self {
+        self.fin_measurements = measurements;
+        self
+    }
+
+    pub fn scales(mut self, scales: Scales) -> Self {
+        self.scales = scales;
+        self
+    }
+
+    pub fn water(mut self, water: WaterType) -> Self {
+        self.water = water;
+        self
+    }
+
+    // Everything wrong with the settings: the shared checks, then the fish's own.
+    pub fn problems(&self) -> Vec<BuildProblem> {
+        let mut problems = self.organism.problems();
+        problems.extend(self.organism.unexpected_appendages(&[AppendageKind::Fin], "a fish"));
+        problems
+    }
+
+    pub fn build(self) -> Result<Fish, BuildError> {
+        BuildError::check(self.problems())?;
+        Ok(self.assemble())
+    }
+
+    // The fish without any checks, for `Fish::new`.
+    pub(crate) fn assemble(self) -> Fish {
+        let parts = self.organism.into_parts();
+        let appendages = if parts.appendages.is_empty() {
+            Fish::fins(self.fin_measurements)
+        } else {
+            parts.appendages
+        };
+        Fish {
+            id: parts.id,
+            species: parts.species,
+            common_name: parts.common_name,
+            name: parts.name,
+            vitals: parts.vitals,
+            sex: parts.sex,
+            body_mass: parts.body_mass,
+            top_speed: parts.top_speed,
+            appendages,
+            scales: self.scales,
+            water: self.water,
+            habitat: parts.habitat,
+        }
+    }
+}
+
+impl OrganismBuilderExt for FishBuilder {
+    fn organism_builder(&mut self) -> &mut OrganismBuilder {
+        &mut self.organism
+    }
+}
@@ -0,0 +1,68 @@
+use std::fmt::{Display, Formatter};
+use biology::ecosystem::environment::Terrain;
+
+/// Scale type, which follows a fish's lineage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Scales {
+    // Thin, round and smooth-edged: salmon, trout, carp.
+    #[default]
+    Cycloid,
+    // Like cycloid, with a toothed rear edge: perch, bass.
+    Ctenoid,
+    // Thick, bony and diamond-shaped: gars, sturgeons.
+    Ganoid,
+    // Tooth-like denticles, rough as sandpaper: sharks, rays.
+    Placoid,
+    // Bare, slimy skin: most catfish.
+    Scaleless,
+}
+
+impl Display for Scales {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Scales::Cycloid => write!(f, "covered in smooth cycloid scales"),
+            Scales::Ctenoid => write!(f, "covered in toothed ctenoid scales"),
+            Scales::Ganoid => write!(f, "armoured in bony ganoid scales"),
+            Scales::Placoid => write!(f, "covered in tooth-like placoid denticles"),
+            Scales::Scaleless => write!(f, "scaleless, with bare skin"),
+        }
+    }
+}
+
+/// The salinity a fish can live in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum WaterType {
+    // Rivers, lakes and marshes: trout, carp, pike.
+    #[default]
+    Freshwater,
+    // The open sea: cod, tuna, sharks.
+    Saltwater,
+    // Estuaries and salt marshes, where the two mix: mudskippers.
+    Brackish,
+    // Moves between fresh and salt water: salmon, eels.
+    Euryhaline,
+}
+
+impl WaterType {
+    // `Ocean` is salt water and `Wetlands` fresh or brackish; no fish lives on dry land.
+    pub fn tolerates(&self, terrain: &Terrain) -> bool {
+        match terrain {
+            Terrain::Ocean => matches!(self, WaterType::Saltwater | WaterType::Euryhaline),
+            Terrain::Wetlands => !matches!(self, WaterType::Saltwater),
+            Terrain::Forest | Terrain::Plains | Terrain::Mountains | Terrain::Urban => false,
+        }
+    }
+}
+
+impl Display for WaterType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WaterType::Freshwater => write!(f, "fresh water"),
+            WaterType::Saltwater => write!(f, "salt water"),
+            WaterType::Brackish => write!(f, "brackish water"),
+            WaterType::Euryhaline => write!(f, "both fresh and salt water"),
+        }
+    }
+}
@@ -1,7 +1,291 @@
-// TODO: Implement generic Fish struct
-// Fish characteristics:
-// - Aquatic
-// - Gills
-// - Fins
-// - Swimming locomotion
-// - Scales (most species)
+use biology::{Nomenclature, Organism, Sex};
+use biology::nomenclature::{common_aliases, common_display_name};
+use biology::anatomy::appendage::{Appendage, FinPosition};
+use biology::anatomy::fin_measurements::FinMeasurements;
+use biology::anatomy::limb_status::LimbStatus;
+use biology::characteristics::structural::Anatomy;
+use biology::characteristics::locomotion::{Locomotion, Mobility};
+use biology::characteristics::presentation::Summarizable;
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::habitat::HabitatPreference;
+use biology::patterns::presentation_patterns::{BasicSummary, DetailedSummary};
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::vitals::blood_pressure::BloodPressure;
+use biology::vitals::live_vitals::LiveVitals;
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::{Celsius, RespiratoryOrgan, BPM};
+use core_shared::{Direction, Identifier, Kilograms, MetersPerSecond};
+use crate::fish::features::{Scales, WaterType};
+use crate::fish::builder::FishBuilder;
+
+/// A fish - a cold-blooded, finned vertebrate that breathes water through gills.
+///
+/// Fish are always `Locomotion::Aquatic`, and their `water` decides which
+/// aquatic habitats they can live in: saltwater fish need the `Ocean`,
+/// freshwater and brackish fish need `Wetlands`, and euryhaline fish such
+/// as salmon manage both. `Organism::tolerates` reports this, so the
+/// simulation's `Migration` rule keeps a trout out of the sea.
+///
+/// Their vitals count gill beats rather than breaths (`RespiratoryOrgan::Gills`).
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let cod = Fish::builder("Gadus morhua")
+///     .common_name("Atlantic cod")
+///     .water(WaterType::Saltwater)
+///     .scales(Scales::Cycloid)
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fish {
+    pub id: Identifier,
+    pub species: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub common_name: Option<String>,
+    // An individual's own name or tag code.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
+    pub vitals: VitalStatus,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sex: Sex,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub body_mass: Option<Kilograms>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub top_speed: Option<MetersPerSecond>,
+    pub appendages: Vec<Appendage>,
+    pub scales: Scales,
+    // The salinity it can live in.
+    pub water: WaterType,
+    // Where it can live, within what its body allows; `None` for wherever that is.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub habitat: Option<HabitatPreference>,
+}
+
+impl Fish {
+    // A freshwater fish with cycloid scales and a full set of trout-sized fins.
+    pub fn new(species: String) -> Self {
+        FishBuilder::new(&species).assemble()
+    }
+
+    pub fn builder(species: &str) -> FishBuilder {
+        FishBuilder::new(species)
+    }
+
+    // A resting trout: cool, slow-hearted, with the gills beating about 70 times a minute.
+    pub fn default_vitals() -> LiveVitals {
+        LiveVitals::new(BloodPressure(40, 20), Celsius(12), BPM(60), 70)
+            .with_respiratory_organ(RespiratoryOrgan::Gills)
+    }
+
+    // Paired pectoral and pelvic fins, then the dorsal, anal and caudal fins; the
+    // dorsal and tail fins are larger than the paired ones.
+    pub(crate) fn fins(measurements: FinMeasurements) -> Vec<Appendage> {
+        let fin = |position: FinPosition, factor: f64| Appendage::Fin {
+            position,
+            measurements: measurements.scaled(factor),
+            status: LimbStatus::Intact,
+        };
+        vec![
+            fin(FinPosition::Pectoral(Direction::Left), 1.0),
+            fin(FinPosition::Pectoral(Direction::Right), 1.0),
+            fin(FinPosition::Pelvic(Direction::Left), 1.0),
+            fin(FinPosition::Pelvic(Direction::Right), 1.0),
+            fin(FinPosition::Dorsal, 1.5),
+            fin(FinPosition::Anal, 1.0),
+            fin(FinPosition::Caudal, 2.0),
+        ]
+    }
+
+    pub fn with_scales(mut self, scales: Scales) -> Self {
+        self.scales = scales;
+        self
+    }
+
+    pub fn with_water(mut self, water: WaterType) -> Self {
+        self.water = water;
+        self
+    }
+
+    // Resizes every fin from the size of a pectoral fin; all fins start intact.
+    pub fn with_fin_measurements(mut self, measurements: FinMeasurements) -> Self {
+        self.appendages = Self::fins(measurements);
+        self
+    }
+
+    pub fn set_fin_status(&mut self, position: FinPosition, status: LimbStatus) {
+        if let Some(fin) = self
+            .appendages
+            .iter_mut()
+            .find(|appendage| matches!(appendage, Appendage::Fin { position: at, .. } if *at == position))
+        {
+            *fin.status_mut() = status;
+        }
+    }
+
+    // The tail fin drives a fish forward; without it, it can only wriggle.
+    pub fn has_tail_fin(&self) -> bool {
+        self.appendages.iter().any(|appendage| {
+            matches!(appendage, Appendage::Fin { position: FinPosition::Caudal, status, .. } if !status.is_missing())
+        })
+    }
+
+    // Whether the water here suits the fish; see `WaterType::tolerates`.
+    pub fn can_live_in(&self, environment: &Environment) -> bool {
+        self.water.tolerates(&environment.terrain)
+    }
+}
+
+impl Nomenclature for Fish {
+    fn display_name(&self) -> String {
+        common_display_name(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+
+    fn aliases(&self) -> Vec<String> {
+        common_aliases(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+}
+
+impl AnatomyAnalyzer for Fish {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
+    }
+}
+
+impl Anatomy for Fish {
+    fn describe_anatomy(&self) -> String {
+        format!(
+            "{} Its body is {}; it breathes through gills and is cold-blooded, living in {}.",
+            self.describe_limb_status(),
+            self.scales,
+            self.water
+        )
+    }
+}
+
+impl Mobility for Fish {
+    fn describe_locomotion(&self) -> String {
+        if !self.has_tail_fin() {
+            return "Swims weakly by flexing its body, having lost its tail fin.".to_string();
+        }
+        "Swims by sweeping its tail fin, steering and braking with its pectoral fins.".to_string()
+    }
+
+    fn locomotion(&self) -> Locomotion {
+        Locomotion::Aquatic
+    }
+}
+
+impl Organism for Fish {
+    fn id(&self) -> Identifier {
+        self.id
+    }
+
+    fn species(&self) -> String {
+        self.species.clone()
+    }
+
+    fn vitals(&self) -> &VitalStatus {
+        &self.vitals
+    }
+
+    fn vitals_mut(&mut self) -> &mut VitalStatus {
+        &mut self.vitals
+    }
+
+    fn sex(&self) -> Sex {
+        self.sex
+    }
+
+    fn body_mass(&self) -> Option<Kilograms> {
+        self.body_mass
+    }
+
+    fn top_speed(&self) -> Option<MetersPerSecond> {
+        self.top_speed
+    }
+
+    fn tolerates(&self, environment: &Environment) -> bool {
+        self.can_live_in(environment) && self.habitat.as_ref().is_none_or(|habitat| habitat.allows(environment))
+    }
+}
+
+impl BasicSummary for Fish {
+    fn get_name(&self) -> String {
+        self.display_name()
+    }
+
+    fn get_gender(&self) -> &Sex {
+        &self.sex
+    }
+}
+
+impl DetailedSummary for Fish {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
+    }
+}
+
+impl Summarizable for Fish {
+    fn summary(&self) -> String {
+        let respiration = match &self.vitals {
+            VitalStatus::Alive(vitals) => format!("\nRespiration: {}", vitals.describe_respiration()),
+            VitalStatus::Deceased(_) => String::new(),
+        };
+        format!(
+            "--- Fish: {} ({}) ---\nID: {}\nAnatomy: {}\nMobility: {}{}\n\n {}",
+            self.display_name(),
+            self.species,
+            self.id,
+            self.describe_anatomy(),
+            self.describe_locomotion(),
+            respiration,
+            self.generate_summary()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biology::anatomy::appendage::AppendageKind;
+    use biology::ecosystem::environment::{Climate, Terrain};
+    use core_shared::Centimeters;
+
+    #[test]
+    fn test_fish_creation() {
+        let fish = Fish::new("Salmo trutta".to_string());
+
+        assert_eq!(fish.count_appendages(AppendageKind::Fin), (7, 0));
+        assert_eq!(fish.count_limbs(), (0, 0, 0, 0));
+        assert_eq!(fish.locomotion(), Locomotion::Aquatic);
+        match &fish.vitals {
+            VitalStatus::Alive(vitals) => assert_eq!(vitals.respiratory_organ, RespiratoryOrgan::Gills),
+            VitalStatus::Deceased(_) => panic!("a new fish is alive"),
+        }
+    }
+
+    #[test]
+    fn test_water_type_must_match_the_terrain() {
+        let habitat = |terrain| Environment::new(Climate::Temperate, terrain, Centimeters(0), 0.9);
+        let trout = Fish::new("Salmo trutta".to_string());
+        let cod = Fish::new("Gadus morhua".to_string()).with_water(WaterType::Saltwater);
+
+        assert!(trout.tolerates(&habitat(Terrain::Wetlands)));
+        assert!(!trout.tolerates(&habitat(Terrain::Ocean)));
+        assert!(cod.tolerates(&habitat(Terrain::Ocean)));
+        assert!(!cod.tolerates(&habitat(Terrain::Wetlands)));
+        assert!(!cod.tolerates(&habitat(Terrain::Plains)));
+    }
+
+    #[test]
+    fn test_losing_the_tail_fin() {
+        let mut fish = Fish::new("Cyprinus carpio".to_string());
+        fish.set_fin_status(FinPosition::Caudal, LimbStatus::Amputated);
+
+        assert!(!fish.has_tail_fin());
+        assert_eq!(fish.count_appendages(AppendageKind::Fin), (6, 1));
+        assert!(fish.describe_locomotion().contains("Swims weakly"));
+    }
+}
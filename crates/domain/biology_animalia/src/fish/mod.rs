@@ -1,4 +1,8 @@
+pub mod builder;
+#[allow(clippy::module_inception)]
 pub mod fish;
+pub mod features;
 
-// Re-export when implemented
-// pub use fish::Fish;
+pub use builder::FishBuilder;
+pub use fish::Fish;
+pub use features::{Scales, WaterType};
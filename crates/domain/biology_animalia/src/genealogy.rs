@@ -0,0 +1,121 @@
+// Family trees of humans, for genealogy software.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use core_shared::Identifier;
+use biology::{Organism, Sex};
+use biology::ecosystem::population::Population;
+use crate::{Human, Moniker};
+
+/// A GEDCOM 5.5.1 file of the humans in a population and their families.
+///
+/// Other organisms are left out; use `Pedigree::to_studbook` for them.
+/// Each human is an `INDI` record with its legal name (surname between
+/// slashes), sex and `Identifier` (as `REFN`). Each pair of parents, or lone
+/// parent, becomes a `FAM` record: the mother as `WIFE`, the father as
+/// `HUSB`, and their children in the order they joined the population.
+pub fn to_gedcom(population: &Population) -> String {
+    let humans: Vec<&Human> = population
+        .ids()
+        .into_iter()
+        .filter_map(|id| population.get_as::<Human>(id))
+        .collect();
+    let individuals: HashMap<Identifier, usize> = humans
+        .iter()
+        .enumerate()
+        .map(|(index, human)| (human.id, index + 1))
+        .collect();
+
+    // Families by parents, in the order their first child appears.
+    let mut families: Vec<(Option<usize>, Option<usize>, Vec<usize>)> = Vec::new();
+    for human in &humans {
+        // Mother (or only parent) first, as the population records them.
+        let parents = population.parents_of(human.id);
+        let parent = |index: usize| parents.get(index).and_then(|id| individuals.get(id).copied());
+        let (wife, husband) = (parent(0), parent(1));
+        if wife.is_none() && husband.is_none() {
+            continue;
+        }
+        let child = individuals[&human.id];
+        match families.iter_mut().find(|family| family.0 == wife && family.1 == husband) {
+            Some(family) => family.2.push(child),
+            None => families.push((wife, husband, vec![child])),
+        }
+    }
+
+    let mut gedcom = String::from(concat!(
+        "0 HEAD\n",
+        "1 SOUR BLOCKS_OF_LIFE\n",
+        "1 GEDC\n",
+        "2 VERS 5.5.1\n",
+        "2 FORM LINEAGE-LINKED\n",
+        "1 CHAR UTF-8\n",
+    ));
+    for human in &humans {
+        let number = individuals[&human.id];
+        let _ = writeln!(gedcom, "0 @I{}@ INDI", number);
+        write_name(&mut gedcom, human);
+        let sex = match human.sex {
+            Sex::Male => "M",
+            Sex::Female => "F",
+            Sex::Unknown => "U",
+        };
+        let _ = writeln!(gedcom, "1 SEX {}", sex);
+        if !human.is_alive() {
+            gedcom.push_str("1 DEAT Y\n");
+        }
+        let _ = writeln!(gedcom, "1 REFN {}", String::from(human.id));
+        for (index, (wife, husband, children)) in families.iter().enumerate() {
+            if children.contains(&number) {
+                let _ = writeln!(gedcom, "1 FAMC @F{}@", index + 1);
+            }
+            if *wife == Some(number) || *husband == Some(number) {
+                let _ = writeln!(gedcom, "1 FAMS @F{}@", index + 1);
+            }
+        }
+    }
+    for (index, (wife, husband, children)) in families.iter().enumerate() {
+        let _ = writeln!(gedcom, "0 @F{}@ FAM", index + 1);
+        if let Some(husband) = husband {
+            let _ = writeln!(gedcom, "1 HUSB @I{}@", husband);
+        }
+        if let Some(wife) = wife {
+            let _ = writeln!(gedcom, "1 WIFE @I{}@", wife);
+        }
+        for child in children {
+            let _ = writeln!(gedcom, "1 CHIL @I{}@", child);
+        }
+    }
+    gedcom.push_str("0 TRLR\n");
+    gedcom
+}
+
+// Given names, then the surname(s) between slashes; designations are written as given.
+fn write_name(gedcom: &mut String, human: &Human) {
+    match human.legal_name() {
+        Some(Moniker::Name(parts)) => {
+            let given: Vec<&str> = [&parts.first, &parts.middle]
+                .into_iter()
+                .flatten()
+                .map(String::as_str)
+                .collect();
+            let surname: Vec<&str> = [&parts.last, &parts.second_last]
+                .into_iter()
+                .flatten()
+                .map(String::as_str)
+                .collect();
+            let (given, surname) = (given.join(" "), surname.join(" "));
+            let _ = writeln!(gedcom, "1 NAME {} /{}/", given, surname);
+            if !given.is_empty() {
+                let _ = writeln!(gedcom, "2 GIVN {}", given);
+            }
+            if !surname.is_empty() {
+                let _ = writeln!(gedcom, "2 SURN {}", surname);
+            }
+        }
+        Some(Moniker::Designation(designation)) => {
+            let _ = writeln!(gedcom, "1 NAME {}", designation);
+        }
+        None => {}
+    }
+}
@@ -0,0 +1,87 @@
+// Animal loci and how they map onto phenotype fields.
+// Real traits involve far more genes; these keep one or a few per trait for modelling.
+
+use biology::genetics::{Allele, Genome, Locus, PolygenicTrait};
+use biology::vitals::blood_type::{BloodGroup, BloodType};
+
+/// ABO blood group - A and B are codominant, O is recessive.
+pub const ABO: Locus = Locus {
+    name: "ABO",
+    alleles: &[
+        Allele { symbol: "A", dominance: 1, effect: 0.0 },
+        Allele { symbol: "B", dominance: 1, effect: 0.0 },
+        Allele { symbol: "O", dominance: 0, effect: 0.0 },
+    ],
+};
+
+/// RhD antigen - present (D) is dominant over absent (d).
+pub const RH: Locus = Locus {
+    name: "RHD",
+    alleles: &[
+        Allele { symbol: "D", dominance: 1, effect: 1.0 },
+        Allele { symbol: "d", dominance: 0, effect: 0.0 },
+    ],
+};
+
+/// Hairless - two recessive copies (hr/hr) give a hairless mammal.
+pub const FUR: Locus = Locus {
+    name: "HR",
+    alleles: &[
+        Allele { symbol: "Hr", dominance: 1, effect: 1.0 },
+        Allele { symbol: "hr", dominance: 0, effect: 0.0 },
+    ],
+};
+
+// Stature loci: additive alleles with no dominance, each worth 4% of height.
+const TALL: Allele = Allele { symbol: "+", dominance: 0, effect: 0.04 };
+const SHORT: Allele = Allele { symbol: "-", dominance: 0, effect: -0.04 };
+
+pub const HEIGHT_1: Locus = Locus { name: "HGT1", alleles: &[TALL, SHORT] };
+pub const HEIGHT_2: Locus = Locus { name: "HGT2", alleles: &[TALL, SHORT] };
+pub const HEIGHT_3: Locus = Locus { name: "HGT3", alleles: &[TALL, SHORT] };
+
+/// Build relative to the anatomy template: from 0.88 (all short) to 1.12 (all tall).
+pub const STATURE: PolygenicTrait = PolygenicTrait {
+    name: "Stature",
+    base: 1.0,
+    loci: &[HEIGHT_1, HEIGHT_2, HEIGHT_3],
+};
+
+pub const HUMAN_LOCI: &[Locus] = &[ABO, RH, HEIGHT_1, HEIGHT_2, HEIGHT_3];
+pub const MAMMAL_LOCI: &[Locus] = &[FUR];
+
+// `None` unless the genome has both the ABO and RhD loci.
+pub fn blood_type(genome: &Genome) -> Option<BloodType> {
+    let group = match genome.expressed(&ABO)?.as_slice() {
+        ["A", "B"] => BloodGroup::AB,
+        ["A"] => BloodGroup::A,
+        ["B"] => BloodGroup::B,
+        _ => BloodGroup::O,
+    };
+    let rh_positive = genome.value(&RH)? > 0.5;
+    Some(BloodType::new(group, rh_positive))
+}
+
+pub fn has_fur(genome: &Genome) -> Option<bool> {
+    genome.value(&FUR).map(|value| value > 0.5)
+}
+
+// The factor to scale anatomy templates by; 1.0 without the stature loci.
+pub fn stature(genome: &Genome) -> f64 {
+    STATURE.value(genome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blood_types() {
+        let genome = |first: &str, second: &str| Genome::new().with("ABO", first, second).with("RHD", "D", "d");
+
+        assert_eq!(blood_type(&genome("A", "B")).unwrap().to_string(), "AB+");
+        assert_eq!(blood_type(&genome("B", "O")).unwrap().to_string(), "B+");
+        assert_eq!(blood_type(&genome("O", "O")).unwrap().group, BloodGroup::O);
+        assert_eq!(blood_type(&Genome::new().with("ABO", "A", "A")), None);
+    }
+}
@@ -0,0 +1,223 @@
+use biology::{Nomenclature, Organism, Sex};
+use biology::nomenclature::{common_aliases, common_display_name};
+use biology::anatomy::appendage::Appendage;
+use biology::anatomy::leg_measurements::LegMeasurements;
+use biology::anatomy::limb_status::LimbStatus;
+use biology::characteristics::structural::Anatomy;
+use biology::characteristics::locomotion::{Locomotion, Mobility};
+use biology::characteristics::presentation::Summarizable;
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::habitat::HabitatPreference;
+use biology::patterns::presentation_patterns::{BasicSummary, DetailedSummary};
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::vitals::blood_pressure::BloodPressure;
+use biology::vitals::live_vitals::LiveVitals;
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::{Celsius, RespiratoryOrgan, BPM};
+use core_shared::{Direction, Identifier, Kilograms, MetersPerSecond};
+use crate::invertebrates::features::ArachnidOrder;
+use crate::invertebrates::builder::ArachnidBuilder;
+
+/// An arachnid - eight legs, no antennae and no wings.
+///
+/// The `order` decides what it can do: spiders spin silk, and spiders and
+/// scorpions carry venom unless `with_venom(false)` says otherwise.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let emperor = Arachnid::builder("Pandinus imperator")
+///     .common_name("Emperor scorpion")
+///     .order(ArachnidOrder::Scorpion)
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Arachnid {
+    pub id: Identifier,
+    pub species: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub common_name: Option<String>,
+    // An individual's own name or tag code.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
+    pub vitals: VitalStatus,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sex: Sex,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub body_mass: Option<Kilograms>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub top_speed: Option<MetersPerSecond>,
+    pub appendages: Vec<Appendage>,
+    pub order: ArachnidOrder,
+    pub venomous: bool,
+    // Where it can live; `None` for anywhere.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub habitat: Option<HabitatPreference>,
+}
+
+impl Arachnid {
+    // A venomous, silk-spinning spider with garden-spider legs.
+    pub fn new(species: String) -> Self {
+        ArachnidBuilder::new(&species).assemble()
+    }
+
+    pub fn builder(species: &str) -> ArachnidBuilder {
+        ArachnidBuilder::new(species)
+    }
+
+    // Book lungs and tracheae; like insects, an open circulation.
+    pub fn default_vitals() -> LiveVitals {
+        LiveVitals::new(BloodPressure(0, 0), Celsius(22), BPM(40), 20)
+            .with_respiratory_organ(RespiratoryOrgan::Tracheae)
+    }
+
+    pub(crate) fn legs(measurements: LegMeasurements) -> Vec<Appendage> {
+        (0..4)
+            .flat_map(|_| [Direction::Left, Direction::Right])
+            .map(|side| Appendage::Leg {
+                side,
+                measurements,
+                status: LimbStatus::Intact,
+            })
+            .collect()
+    }
+
+    // Also resets `venomous` to what is usual for the order.
+    pub fn with_order(mut self, order: ArachnidOrder) -> Self {
+        self.order = order;
+        self.venomous = order.is_venomous();
+        self
+    }
+
+    pub fn with_venom(mut self, venomous: bool) -> Self {
+        self.venomous = venomous;
+        self
+    }
+
+    pub fn with_leg_measurements(mut self, measurements: LegMeasurements) -> Self {
+        self.appendages = Self::legs(measurements);
+        self
+    }
+
+    pub fn spins_silk(&self) -> bool {
+        self.order.spins_silk()
+    }
+}
+
+impl Nomenclature for Arachnid {
+    fn display_name(&self) -> String {
+        common_display_name(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+
+    fn aliases(&self) -> Vec<String> {
+        common_aliases(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+}
+
+impl AnatomyAnalyzer for Arachnid {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
+    }
+}
+
+impl Anatomy for Arachnid {
+    fn describe_anatomy(&self) -> String {
+        let body = match self.order {
+            ArachnidOrder::Spider => "Cephalothorax and abdomen, with fangs and silk-spinning spinnerets",
+            ArachnidOrder::Scorpion => "Cephalothorax and segmented tail, with grasping pincers and a sting",
+            ArachnidOrder::Mite => "A tiny, fused body with piercing mouthparts",
+            ArachnidOrder::Harvestman => "A single, fused oval body",
+        };
+        let venom = if self.venomous { "venomous" } else { "harmless" };
+        format!(
+            "{} {}; no antennae or wings; {} and cold-blooded.",
+            self.describe_limb_status(),
+            body,
+            venom
+        )
+    }
+}
+
+impl Mobility for Arachnid {
+    fn describe_locomotion(&self) -> String {
+        match self.order {
+            ArachnidOrder::Spider => "Scuttles on eight legs, and climbs on silk threads.".to_string(),
+            ArachnidOrder::Scorpion => "Walks on eight legs with its tail raised.".to_string(),
+            ArachnidOrder::Mite => "Crawls slowly on eight short legs, or hitches a ride on a host.".to_string(),
+            ArachnidOrder::Harvestman => "Stalks on eight long, thin legs.".to_string(),
+        }
+    }
+
+    fn locomotion(&self) -> Locomotion {
+        Locomotion::Terrestrial
+    }
+}
+
+impl Organism for Arachnid {
+    fn id(&self) -> Identifier {
+        self.id
+    }
+
+    fn species(&self) -> String {
+        self.species.clone()
+    }
+
+    fn vitals(&self) -> &VitalStatus {
+        &self.vitals
+    }
+
+    fn vitals_mut(&mut self) -> &mut VitalStatus {
+        &mut self.vitals
+    }
+
+    fn sex(&self) -> Sex {
+        self.sex
+    }
+
+    fn body_mass(&self) -> Option<Kilograms> {
+        self.body_mass
+    }
+
+    fn top_speed(&self) -> Option<MetersPerSecond> {
+        self.top_speed
+    }
+
+    fn tolerates(&self, environment: &Environment) -> bool {
+        self.habitat.as_ref().is_none_or(|habitat| habitat.allows(environment))
+    }
+
+    fn body_temperature_in(&self, environment: &Environment) -> Option<Celsius> {
+        Some(Celsius::from_degrees(environment.ambient_temperature()))
+    }
+}
+
+impl BasicSummary for Arachnid {
+    fn get_name(&self) -> String {
+        self.display_name()
+    }
+
+    fn get_gender(&self) -> &Sex {
+        &self.sex
+    }
+}
+
+impl DetailedSummary for Arachnid {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
+    }
+}
+
+impl Summarizable for Arachnid {
+    fn summary(&self) -> String {
+        format!(
+            "--- Arachnid: {} ({}) ---\nID: {}\nAnatomy: {}\nMobility: {}\n\n {}",
+            self.display_name(),
+            self.species,
+            self.id,
+            self.describe_anatomy(),
+            self.describe_locomotion(),
+            self.generate_summary()
+        )
+    }
+}
@@ -0,0 +1,375 @@
+use biology::anatomy::appendage::AppendageKind;
+use biology::anatomy::leg_measurements::LegMeasurements;
+use biology::anatomy::wing_measurements::WingMeasurements;
+use biology::builder::{BuildError, BuildProblem, OrganismBuilder, OrganismBuilderExt};
+use biology::characteristics::locomotion::Locomotion;
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::vitals::vital_status::VitalStatus;
+use core_shared::{Centimeters, Millimeters};
+use crate::invertebrates::arachnid::Arachnid;
+use crate::invertebrates::features::{ArachnidOrder, MolluscClass};
+use crate::invertebrates::insect::Insect;
+use crate::invertebrates::mollusc::Mollusc;
+
+/// Builds an `Insect` with the shared `OrganismBuilder` settings, plus its
+/// legs, wings, antennae and ground locomotion.
+///
+/// Unless `appendages` replaces them, the insect gets six legs,
+/// `wing_pairs` pairs of wings and two antennae. `build` reports
+/// appendages an insect has none of, and a body plan without six legs or
+/// with more than two pairs of wings.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let mosquito = Insect::builder("Aedes aegypti")
+///     .common_name("Yellow fever mosquito")
+///     .wing_pairs(1)
+///     .build()?;
+/// ```
+pub struct InsectBuilder {
+    organism: OrganismBuilder,
+    locomotion: Locomotion,
+    leg_measurements: LegMeasurements,
+    wing_pairs: u32,
+    wing_measurements: WingMeasurements,
+    antenna_length: Millimeters,
+}
+
+impl InsectBuilder {
+    pub fn new(species: &str) -> Self {
+        Self {
+            organism: OrganismBuilder::new(species).vitals(VitalStatus::Alive(Insect::default_vitals())),
+            locomotion: Locomotion::Terrestrial,
+            leg_measurements: LegMeasurements {
+                inseam: Centimeters(3),
+                quad: Centimeters(1),
+            },
+            wing_pairs: 2,
+            wing_measurements: WingMeasurements {
+                length: Centimeters(5),
+                chord: Centimeters(2),
+            },
+            antenna_length: Millimeters(20),
+        }
+    }
+
+    // How it moves when not flying; `Aquatic` for water beetles.
+    pub fn locomotion(mut self, locomotion: Locomotion) -> Self {
+        self.locomotion = locomotion;
+        self
+    }
+
+    pub fn leg_measurements(mut self, measurements: LegMeasurements) -> Self {
+        self.leg_measurements = measurements;
+        self
+    }
+
+    // 2 for most insects, 1 for flies and mosquitoes, 0 for the wingless.
+    pub fn wing_pairs(mut self, pairs: u32) -> Self {
+        self.wing_pairs = pairs;
+        self
+    }
+
+    pub fn wingless(self) -> Self {
+        self.wing_pairs(0)
+    }
+
+    pub fn wing_measurements(mut self, measurements: WingMeasurements) -> Self {
+        self.wing_measurements = measurements;
+        self
+    }
+
+    pub fn antenna_length(mut self, length: Millimeters) -> Self {
+        self.antenna_length = length;
+        self
+    }
+
+    // Everything wrong with the settings: the shared checks, then the insect's own.
+    pub fn problems(&self) -> Vec<BuildProblem> {
+        let mut problems = self.organism.problems();
+        if self.wing_pairs > 2 {
+            problems.push(BuildProblem::Invalid {
+                field: "wing pairs",
+                reason: format!("an insect has at most two pairs of wings, not {}", self.wing_pairs),
+            });
+        }
+        if self.organism.get_appendages().is_empty() {
+            return problems;
+        }
+        problems.extend(self.organism.unexpected_appendages(
+            &[AppendageKind::Leg, AppendageKind::Wing, AppendageKind::Antenna],
+            "an insect",
+        ));
+        let legs = limbs_of(&self.organism, AppendageKind::Leg);
+        if legs != 6 {
+            problems.push(BuildProblem::Invalid {
+                field: "appendages",
+                reason: format!("an insect has six legs, not {}", legs),
+            });
+        }
+        let wings = limbs_of(&self.organism, AppendageKind::Wing);
+        if wings > 4 {
+            problems.push(BuildProblem::Invalid {
+                field: "appendages",
+                reason: format!("an insect has at most four wings, not {}", wings),
+            });
+        }
+        problems
+    }
+
+    pub fn build(self) -> Result<Insect, BuildError> {
+        BuildError::check(self.problems())?;
+        Ok(self.assemble())
+    }
+
+    // The insect without any checks, for `Insect::new`.
+    pub(crate) fn assemble(self) -> Insect {
+        let parts = self.organism.into_parts();
+        let appendages = if parts.appendages.is_empty() {
+            Insect::body_plan(self.leg_measurements, self.wing_pairs, self.wing_measurements, self.antenna_length)
+        } else {
+            parts.appendages
+        };
+        Insect {
+            id: parts.id,
+            species: parts.species,
+            common_name: parts.common_name,
+            name: parts.name,
+            vitals: parts.vitals,
+            sex: parts.sex,
+            body_mass: parts.body_mass,
+            top_speed: parts.top_speed,
+            locomotion: self.locomotion,
+            appendages,
+            habitat: parts.habitat,
+        }
+    }
+}
+
+impl OrganismBuilderExt for InsectBuilder {
+    fn organism_builder(&mut self) -> &mut OrganismBuilder {
+        &mut self.organism
+    }
+}
+
+/// Builds an `Arachnid` with the shared `OrganismBuilder` settings, plus
+/// its order, venom and legs.
+///
+/// Unless `appendages` replaces them, the arachnid gets eight legs.
+/// `build` reports appendages other than legs, and any other number of
+/// legs.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let emperor = Arachnid::builder("Pandinus imperator")
+///     .common_name("Emperor scorpion")
+///     .order(ArachnidOrder::Scorpion)
+///     .build()?;
+/// ```
+pub struct ArachnidBuilder {
+    organism: OrganismBuilder,
+    order: ArachnidOrder,
+    // What is usual for the order unless set.
+    venomous: Option<bool>,
+    leg_measurements: LegMeasurements,
+}
+
+impl ArachnidBuilder {
+    pub fn new(species: &str) -> Self {
+        Self {
+            organism: OrganismBuilder::new(species).vitals(VitalStatus::Alive(Arachnid::default_vitals())),
+            order: ArachnidOrder::default(),
+            venomous: None,
+            leg_measurements: LegMeasurements {
+                inseam: Centimeters(2),
+                quad: Centimeters(0),
+            },
+        }
+    }
+
+    pub fn order(mut self, order: ArachnidOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub fn venom(mut self, venomous: bool) -> Self {
+        self.venomous = Some(venomous);
+        self
+    }
+
+    pub fn leg_measurements(mut self, measurements: LegMeasurements) -> Self {
+        self.leg_measurements = measurements;
+        self
+    }
+
+    // Everything wrong with the settings: the shared checks, then the arachnid's own.
+    pub fn problems(&self) -> Vec<BuildProblem> {
+        let mut problems = self.organism.problems();
+        if self.organism.get_appendages().is_empty() {
+            return problems;
+        }
+        problems.extend(self.organism.unexpected_appendages(&[AppendageKind::Leg], "an arachnid"));
+        let legs = limbs_of(&self.organism, AppendageKind::Leg);
+        if legs != 8 {
+            problems.push(BuildProblem::Invalid {
+                field: "appendages",
+                reason: format!("an arachnid has eight legs, not {}", legs),
+            });
+        }
+        problems
+    }
+
+    pub fn build(self) -> Result<Arachnid, BuildError> {
+        BuildError::check(self.problems())?;
+        Ok(self.assemble())
+    }
+
+    // The arachnid without any checks, for `Arachnid::new`.
+    pub(crate) fn assemble(self) -> Arachnid {
+        let parts = self.organism.into_parts();
+        let appendages = if parts.appendages.is_empty() {
+            Arachnid::legs(self.leg_measurements)
+        } else {
+            parts.appendages
+        };
+        Arachnid {
+            id: parts.id,
+            species: parts.species,
+            common_name: parts.common_name,
+            name: parts.name,
+            vitals: parts.vitals,
+            sex: parts.sex,
+            body_mass: parts.body_mass,
+            top_speed: parts.top_speed,
+            appendages,
+            order: self.order,
+            venomous: self.venomous.unwrap_or(self.order.is_venomous()),
+            habitat: parts.habitat,
+        }
+    }
+}
+
+impl OrganismBuilderExt for ArachnidBuilder {
+    fn organism_builder(&mut self) -> &mut OrganismBuilder {
+        &mut self.organism
+    }
+}
+
+/// Builds a `Mollusc` with the shared `OrganismBuilder` settings, plus its
+/// class, shell and locomotion.
+///
+/// The class decides the vitals, tentacles, shell and locomotion, unless
+/// they are set separately. `build` reports appendages other than
+/// tentacles, tentacles on a bivalve, and a bivalve without a shell.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let octopus = Mollusc::builder("Octopus vulgaris")
+///     .common_name("Common octopus")
+///     .class(MolluscClass::Cephalopod)
+///     .build()?;
+/// ```
+pub struct MolluscBuilder {
+    organism: OrganismBuilder,
+    class: MolluscClass,
+    // What is usual for the class unless set.
+    locomotion: Option<Locomotion>,
+    has_shell: Option<bool>,
+}
+
+impl MolluscBuilder {
+    pub fn new(species: &str) -> Self {
+        let class = MolluscClass::default();
+        Self {
+            organism: OrganismBuilder::new(species).vitals(VitalStatus::Alive(Mollusc::vitals_for(class))),
+            class,
+            locomotion: None,
+            has_shell: None,
+        }
+    }
+
+    // Also gives the vitals usual for the class; later setters override them.
+    pub fn class(mut self, class: MolluscClass) -> Self {
+        self.class = class;
+        self.vitals(VitalStatus::Alive(Mollusc::vitals_for(class)))
+    }
+
+    // `Aquatic` for sea snails and pond snails.
+    pub fn locomotion(mut self, locomotion: Locomotion) -> Self {
+        self.locomotion = Some(locomotion);
+        self
+    }
+
+    // Slugs are shell-less gastropods; nautiluses are shelled cephalopods.
+    pub fn shell(mut self, has_shell: bool) -> Self {
+        self.has_shell = Some(has_shell);
+        self
+    }
+
+    // Everything wrong with the settings: the shared checks, then the mollusc's own.
+    pub fn problems(&self) -> Vec<BuildProblem> {
+        let mut problems = self.organism.problems();
+        problems.extend(self.organism.unexpected_appendages(&[AppendageKind::Tentacle], "a mollusc"));
+        if self.class == MolluscClass::Bivalve {
+            let tentacles = limbs_of(&self.organism, AppendageKind::Tentacle);
+            if tentacles > 0 {
+                problems.push(BuildProblem::Invalid {
+                    field: "appendages",
+                    reason: format!("a bivalve has no tentacles, not {}", tentacles),
+                });
+            }
+            if self.has_shell == Some(false) {
+                problems.push(BuildProblem::Invalid {
+                    field: "shell",
+                    reason: "a bivalve always has a shell".to_string(),
+                });
+            }
+        }
+        problems
+    }
+
+    pub fn build(self) -> Result<Mollusc, BuildError> {
+        BuildError::check(self.problems())?;
+        Ok(self.assemble())
+    }
+
+    // The mollusc without any checks, for `Mollusc::new`.
+    pub(crate) fn assemble(self) -> Mollusc {
+        let parts = self.organism.into_parts();
+        let appendages = if parts.appendages.is_empty() {
+            Mollusc::tentacles_for(self.class)
+        } else {
+            parts.appendages
+        };
+        Mollusc {
+            id: parts.id,
+            species: parts.species,
+            common_name: parts.common_name,
+            name: parts.name,
+            vitals: parts.vitals,
+            sex: parts.sex,
+            body_mass: parts.body_mass,
+            top_speed: parts.top_speed,
+            locomotion: self.locomotion.unwrap_or(Mollusc::locomotion_for(self.class)),
+            appendages,
+            class: self.class,
+            has_shell: self.has_shell.unwrap_or(self.class != MolluscClass::Cephalopod),
+            habitat: parts.habitat,
+        }
+    }
+}
+
+impl OrganismBuilderExt for MolluscBuilder {
+    fn organism_builder(&mut self) -> &mut OrganismBuilder {
+        &mut self.organism
+    }
+}
+
+// Appendages of one kind set so far, attached or missing.
+fn limbs_of(organism: &OrganismBuilder, kind: AppendageKind) -> u32 {
+    let (attached, missing) = organism.count_appendages(kind);
+    attached + missing
+}
@@ -0,0 +1,61 @@
+use std::fmt::{Display, Formatter};
+
+/// The main orders of arachnids.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum ArachnidOrder {
+    // Araneae: spin silk, and nearly all have venomous fangs.
+    #[default]
+    Spider,
+    // Scorpiones: pincers, and a venomous sting on the tail.
+    Scorpion,
+    // Acari: ticks and mites, tiny and often parasitic.
+    Mite,
+    // Opiliones: harvestmen, with one fused body and very long legs; no silk or venom.
+    Harvestman,
+}
+
+impl ArachnidOrder {
+    pub fn spins_silk(&self) -> bool {
+        matches!(self, ArachnidOrder::Spider)
+    }
+
+    // Whether the order as a whole is venomous; individual species can differ.
+    pub fn is_venomous(&self) -> bool {
+        matches!(self, ArachnidOrder::Spider | ArachnidOrder::Scorpion)
+    }
+}
+
+impl Display for ArachnidOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArachnidOrder::Spider => write!(f, "spider"),
+            ArachnidOrder::Scorpion => write!(f, "scorpion"),
+            ArachnidOrder::Mite => write!(f, "mite"),
+            ArachnidOrder::Harvestman => write!(f, "harvestman"),
+        }
+    }
+}
+
+/// The main classes of molluscs, which differ in body plan.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum MolluscClass {
+    // Snails and slugs: a muscular foot, tentacles, and usually a coiled shell.
+    #[default]
+    Gastropod,
+    // Clams, mussels and oysters: a hinged two-part shell and no head.
+    Bivalve,
+    // Octopuses, squid and cuttlefish: a ring of arms, and at most an internal shell.
+    Cephalopod,
+}
+
+impl Display for MolluscClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MolluscClass::Gastropod => write!(f, "gastropod"),
+            MolluscClass::Bivalve => write!(f, "bivalve"),
+            MolluscClass::Cephalopod => write!(f, "cephalopod"),
+        }
+    }
+}
@@ -0,0 +1,325 @@
+use biology::{Nomenclature, Organism, Sex};
+use biology::nomenclature::{common_aliases, common_display_name};
+use biology::anatomy::appendage::{Appendage, AppendageKind};
+use biology::anatomy::leg_measurements::LegMeasurements;
+use biology::anatomy::limb_status::LimbStatus;
+use biology::anatomy::wing_measurements::WingMeasurements;
+use biology::characteristics::structural::Anatomy;
+use biology::characteristics::locomotion::{Locomotion, Mobility};
+use biology::characteristics::presentation::Summarizable;
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::habitat::HabitatPreference;
+use biology::patterns::presentation_patterns::{BasicSummary, DetailedSummary};
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::vitals::blood_pressure::BloodPressure;
+use biology::vitals::live_vitals::LiveVitals;
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::{Celsius, RespiratoryOrgan, BPM};
+use core_shared::{Direction, Identifier, Kilograms, MetersPerSecond, Millimeters};
+use crate::invertebrates::builder::InsectBuilder;
+
+const SIDES: [Direction; 2] = [Direction::Left, Direction::Right];
+
+/// An insect - six legs, a pair of antennae and, usually, two pairs of wings.
+///
+/// Flies and mosquitoes have a single pair (`with_wing_pairs(1)`); ant
+/// workers, fleas and lice have none (`wingless`). Like birds, insects that
+/// can fly move as `Locomotion::Aerial`, and fall back to `locomotion` when
+/// they cannot. Insects breathe through tracheae and take the temperature
+/// of the air around them.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let honeybee = Insect::builder("Apis mellifera")
+///     .common_name("Western honey bee")
+///     .wing_measurements(WingMeasurements { length: Centimeters(1), chord: Centimeters(1) })
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Insect {
+    pub id: Identifier,
+    pub species: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub common_name: Option<String>,
+    // An individual's own name or tag code.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
+    pub vitals: VitalStatus,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sex: Sex,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub body_mass: Option<Kilograms>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub top_speed: Option<MetersPerSecond>,
+    // How it moves when not flying; `Aquatic` for water beetles.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub locomotion: Locomotion,
+    // Six legs, then the wings, then the antennae.
+    pub appendages: Vec<Appendage>,
+    // Where it can live; `None` for anywhere.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub habitat: Option<HabitatPreference>,
+}
+
+impl Insect {
+    // A locust-sized insect with two pairs of wings.
+    pub fn new(species: String) -> Self {
+        InsectBuilder::new(&species).assemble()
+    }
+
+    pub fn builder(species: &str) -> InsectBuilder {
+        InsectBuilder::new(species)
+    }
+
+    // Insects have no blood pressure to speak of: an open circulation, and a tubular heart.
+    pub fn default_vitals() -> LiveVitals {
+        LiveVitals::new(BloodPressure(0, 0), Celsius(25), BPM(100), 30)
+            .with_respiratory_organ(RespiratoryOrgan::Tracheae)
+    }
+
+    pub(crate) fn body_plan(legs: LegMeasurements, wing_pairs: u32, wings: WingMeasurements, antennae: Millimeters) -> Vec<Appendage> {
+        let legs = (0..3).flat_map(|_| SIDES).map(|side| Appendage::Leg {
+            side,
+            measurements: legs,
+            status: LimbStatus::Intact,
+        });
+        let wings = (0..wing_pairs.min(2)).flat_map(|_| SIDES).map(|side| Appendage::Wing {
+            side,
+            measurements: wings,
+            status: LimbStatus::Intact,
+        });
+        let antennae = SIDES.into_iter().map(|side| Appendage::Antenna {
+            side,
+            length: antennae,
+            status: LimbStatus::Intact,
+        });
+        legs.chain(wings).chain(antennae).collect()
+    }
+
+    // The measurements of the first appendage of a kind, to keep when rebuilding another.
+    fn leg_measurements(&self) -> LegMeasurements {
+        self.appendages
+            .iter()
+            .find_map(|appendage| match appendage {
+                Appendage::Leg { measurements, .. } => Some(*measurements),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    fn wing_measurements(&self) -> WingMeasurements {
+        self.appendages
+            .iter()
+            .find_map(|appendage| match appendage {
+                Appendage::Wing { measurements, .. } => Some(*measurements),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    fn antenna_length(&self) -> Millimeters {
+        self.appendages
+            .iter()
+            .find_map(|appendage| match appendage {
+                Appendage::Antenna { length, .. } => Some(*length),
+                _ => None,
+            })
+            .unwrap_or(Millimeters(0))
+    }
+
+    pub fn wing_pairs(&self) -> u32 {
+        let (attached, missing) = self.count_appendages(AppendageKind::Wing);
+        (attached + missing) / 2
+    }
+
+    pub fn with_locomotion(mut self, locomotion: Locomotion) -> Self {
+        self.locomotion = locomotion;
+        self
+    }
+
+    // 2 for most insects, 1 for flies and mosquitoes, 0 for the wingless; at most 2.
+    pub fn with_wing_pairs(mut self, pairs: u32) -> Self {
+        self.appendages = Self::body_plan(self.leg_measurements(), pairs, self.wing_measurements(), self.antenna_length());
+        self
+    }
+
+    pub fn wingless(self) -> Self {
+        self.with_wing_pairs(0)
+    }
+
+    pub fn with_leg_measurements(mut self, measurements: LegMeasurements) -> Self {
+        self.appendages = Self::body_plan(measurements, self.wing_pairs(), self.wing_measurements(), self.antenna_length());
+        self
+    }
+
+    pub fn with_wing_measurements(mut self, measurements: WingMeasurements) -> Self {
+        self.appendages = Self::body_plan(self.leg_measurements(), self.wing_pairs(), measurements, self.antenna_length());
+        self
+    }
+
+    pub fn with_antenna_length(mut self, length: Millimeters) -> Self {
+        self.appendages = Self::body_plan(self.leg_measurements(), self.wing_pairs(), self.wing_measurements(), length);
+        self
+    }
+
+    // Flight needs at least one pair of wings, all of them intact.
+    pub fn can_fly(&self) -> bool {
+        let (attached, _) = self.count_appendages(AppendageKind::Wing);
+        let wings_intact = self
+            .appendages
+            .iter()
+            .filter(|appendage| appendage.kind() == AppendageKind::Wing)
+            .all(|wing| *wing.status() == LimbStatus::Intact);
+        attached >= 2 && wings_intact
+    }
+}
+
+impl Nomenclature for Insect {
+    fn display_name(&self) -> String {
+        common_display_name(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+
+    fn aliases(&self) -> Vec<String> {
+        common_aliases(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+}
+
+impl AnatomyAnalyzer for Insect {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
+    }
+}
+
+impl Anatomy for Insect {
+    fn describe_anatomy(&self) -> String {
+        format!(
+            "{} Head, thorax and abdomen in a chitin exoskeleton; breathes through tracheae and is cold-blooded.",
+            self.describe_limb_status()
+        )
+    }
+}
+
+impl Mobility for Insect {
+    fn describe_locomotion(&self) -> String {
+        let on_foot = match self.locomotion {
+            Locomotion::Aquatic | Locomotion::Amphibious => "swims with oar-like legs",
+            Locomotion::Fossorial => "digs with its forelegs",
+            _ => "walks on six legs",
+        };
+        match self.wing_pairs() {
+            0 => format!("Wingless; {}.", on_foot),
+            _ if !self.can_fly() => format!("Grounded by damaged wings; {}.", on_foot),
+            1 => format!("Flies on a single pair of wings; {}.", on_foot),
+            _ => format!("Flies on two pairs of wings; {}.", on_foot),
+        }
+    }
+
+    fn locomotion(&self) -> Locomotion {
+        if self.can_fly() {
+            Locomotion::Aerial
+        } else {
+            self.locomotion
+        }
+    }
+}
+
+impl Organism for Insect {
+    fn id(&self) -> Identifier {
+        self.id
+    }
+
+    fn species(&self) -> String {
+        self.species.clone()
+    }
+
+    fn vitals(&self) -> &VitalStatus {
+        &self.vitals
+    }
+
+    fn vitals_mut(&mut self) -> &mut VitalStatus {
+        &mut self.vitals
+    }
+
+    fn sex(&self) -> Sex {
+        self.sex
+    }
+
+    fn body_mass(&self) -> Option<Kilograms> {
+        self.body_mass
+    }
+
+    fn top_speed(&self) -> Option<MetersPerSecond> {
+        self.top_speed
+    }
+
+    fn tolerates(&self, environment: &Environment) -> bool {
+        self.habitat.as_ref().is_none_or(|habitat| habitat.allows(environment))
+    }
+
+    fn body_temperature_in(&self, environment: &Environment) -> Option<Celsius> {
+        Some(Celsius::from_degrees(environment.ambient_temperature()))
+    }
+}
+
+impl BasicSummary for Insect {
+    fn get_name(&self) -> String {
+        self.display_name()
+    }
+
+    fn get_gender(&self) -> &Sex {
+        &self.sex
+    }
+}
+
+impl DetailedSummary for Insect {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
+    }
+}
+
+impl Summarizable for Insect {
+    fn summary(&self) -> String {
+        format!(
+            "--- Insect: {} ({}) ---\nID: {}\nAnatomy: {}\nMobility: {}\n\n {}",
+            self.display_name(),
+            self.species,
+            self.id,
+            self.describe_anatomy(),
+            self.describe_locomotion(),
+            self.generate_summary()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core_shared::Centimeters;
+
+    #[test]
+    fn test_measurements_survive_a_change_of_wings() {
+        let beetle = Insect::new("Lucanus cervus".to_string())
+            .with_antenna_length(Millimeters(12))
+            .with_wing_measurements(WingMeasurements { length: Centimeters(3), chord: Centimeters(1) })
+            .with_wing_pairs(1);
+
+        assert_eq!(beetle.wing_pairs(), 1);
+        assert_eq!(beetle.antenna_length(), Millimeters(12));
+        assert_eq!(beetle.wing_measurements().length, Centimeters(3));
+        assert_eq!(beetle.count_limbs(), (0, 0, 6, 0));
+    }
+
+    #[test]
+    fn test_a_torn_wing_grounds_an_insect() {
+        let mut moth = Insect::new("Biston betularia".to_string());
+        if let Some(wing) = moth.appendages.iter_mut().find(|appendage| appendage.kind() == AppendageKind::Wing) {
+            *wing.status_mut() = LimbStatus::Injured("Torn".to_string());
+        }
+
+        assert!(!moth.can_fly());
+        assert_eq!(moth.locomotion(), Locomotion::Terrestrial);
+        assert!(moth.describe_locomotion().starts_with("Grounded"));
+    }
+}
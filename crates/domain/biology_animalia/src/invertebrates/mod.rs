@@ -0,0 +1,11 @@
+pub mod arachnid;
+pub mod builder;
+pub mod features;
+pub mod insect;
+pub mod mollusc;
+
+pub use arachnid::Arachnid;
+pub use builder::{ArachnidBuilder, InsectBuilder, MolluscBuilder};
+pub use features::{ArachnidOrder, MolluscClass};
+pub use insect::Insect;
+pub use mollusc::Mollusc;
@@ -0,0 +1,266 @@
+use biology::{Nomenclature, Organism, Sex};
+use biology::nomenclature::{common_aliases, common_display_name};
+use biology::anatomy::appendage::Appendage;
+use biology::anatomy::limb_status::LimbStatus;
+use biology::characteristics::structural::Anatomy;
+use biology::characteristics::locomotion::{Locomotion, Mobility};
+use biology::characteristics::presentation::Summarizable;
+use biology::ecosystem::environment::{Environment, Terrain};
+use biology::ecosystem::habitat::HabitatPreference;
+use biology::patterns::presentation_patterns::{BasicSummary, DetailedSummary};
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::vitals::blood_pressure::BloodPressure;
+use biology::vitals::live_vitals::LiveVitals;
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::{Celsius, RespiratoryOrgan, BPM};
+use core_shared::{Direction, Identifier, Kilograms, MetersPerSecond, Millimeters};
+use crate::invertebrates::features::MolluscClass;
+use crate::invertebrates::builder::MolluscBuilder;
+
+/// A mollusc - a soft-bodied invertebrate, usually with a shell.
+///
+/// The `class` sets the body plan, locomotion and breathing:
+///
+/// | Class | Appendages | Shell | Moves | Breathes |
+/// |-------|------------|-------|-------|----------|
+/// | `Gastropod` | 4 tentacles | Coiled | `Terrestrial` | A lung |
+/// | `Bivalve` | None | Two hinged valves | `Sessile` | Gills |
+/// | `Cephalopod` | 8 arms | None | `Aquatic` | Gills |
+///
+/// Bivalves and cephalopods live only in water: cephalopods in the `Ocean`,
+/// bivalves there or in `Wetlands`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let octopus = Mollusc::builder("Octopus vulgaris")
+///     .common_name("Common octopus")
+///     .class(MolluscClass::Cephalopod)
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mollusc {
+    pub id: Identifier,
+    pub species: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub common_name: Option<String>,
+    // An individual's own name or tag code.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
+    pub vitals: VitalStatus,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sex: Sex,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub body_mass: Option<Kilograms>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub top_speed: Option<MetersPerSecond>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub locomotion: Locomotion,
+    pub appendages: Vec<Appendage>,
+    pub class: MolluscClass,
+    // False for slugs and octopuses.
+    pub has_shell: bool,
+    // Where it can live, within what its body allows; `None` for wherever that is.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub habitat: Option<HabitatPreference>,
+}
+
+impl Mollusc {
+    // A land snail with a shell.
+    pub fn new(species: String) -> Self {
+        MolluscBuilder::new(&species).assemble()
+    }
+
+    pub fn builder(species: &str) -> MolluscBuilder {
+        MolluscBuilder::new(species)
+    }
+
+    pub(crate) fn vitals_for(class: MolluscClass) -> LiveVitals {
+        let vitals = LiveVitals::new(BloodPressure(0, 0), Celsius(18), BPM(30), 10);
+        match class {
+            MolluscClass::Gastropod => vitals,
+            MolluscClass::Bivalve | MolluscClass::Cephalopod => {
+                vitals.with_respiratory_organ(RespiratoryOrgan::Gills)
+            }
+        }
+    }
+
+    pub(crate) fn locomotion_for(class: MolluscClass) -> Locomotion {
+        match class {
+            MolluscClass::Gastropod => Locomotion::Terrestrial,
+            MolluscClass::Bivalve => Locomotion::Sessile,
+            MolluscClass::Cephalopod => Locomotion::Aquatic,
+        }
+    }
+
+    // A snail's long eye stalks and short feelers, or a cephalopod's eight arms.
+    pub(crate) fn tentacles_for(class: MolluscClass) -> Vec<Appendage> {
+        let lengths: &[u32] = match class {
+            MolluscClass::Gastropod => &[20, 5],
+            MolluscClass::Bivalve => &[],
+            MolluscClass::Cephalopod => &[300, 300, 300, 300],
+        };
+        lengths
+            .iter()
+            .flat_map(|&length| {
+                [Direction::Left, Direction::Right].map(|side| Appendage::Tentacle {
+                    side,
+                    length: Millimeters(length),
+                    status: LimbStatus::Intact,
+                })
+            })
+            .collect()
+    }
+
+    // Sets the body plan, shell, locomotion and breathing usual for the class.
+    pub fn with_class(mut self, class: MolluscClass) -> Self {
+        self.class = class;
+        self.appendages = Self::tentacles_for(class);
+        self.locomotion = Self::locomotion_for(class);
+        self.has_shell = class != MolluscClass::Cephalopod;
+        self.vitals = VitalStatus::Alive(Self::vitals_for(class));
+        self
+    }
+
+    // `Aquatic` for sea snails and pond snails.
+    pub fn with_locomotion(mut self, locomotion: Locomotion) -> Self {
+        self.locomotion = locomotion;
+        self
+    }
+
+    // Slugs are shell-less gastropods; nautiluses are shelled cephalopods.
+    pub fn with_shell(mut self, has_shell: bool) -> Self {
+        self.has_shell = has_shell;
+        self
+    }
+}
+
+impl Nomenclature for Mollusc {
+    fn display_name(&self) -> String {
+        common_display_name(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+
+    fn aliases(&self) -> Vec<String> {
+        common_aliases(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+}
+
+impl AnatomyAnalyzer for Mollusc {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
+    }
+}
+
+impl Anatomy for Mollusc {
+    fn describe_anatomy(&self) -> String {
+        let shell = match (self.class, self.has_shell) {
+            (_, false) => "with no shell",
+            (MolluscClass::Gastropod, true) => "in a coiled shell",
+            (MolluscClass::Bivalve, true) => "inside a hinged two-part shell",
+            (MolluscClass::Cephalopod, true) => "in a chambered shell",
+        };
+        let breathing = match self.class {
+            MolluscClass::Gastropod => "breathes through a simple lung",
+            MolluscClass::Bivalve => "filters its food through its gills",
+            MolluscClass::Cephalopod => "breathes through gills and sees with keen eyes",
+        };
+        format!(
+            "{} Soft-bodied {} {}; {}.",
+            self.describe_limb_status(),
+            self.class,
+            shell,
+            breathing
+        )
+    }
+}
+
+impl Mobility for Mollusc {
+    fn describe_locomotion(&self) -> String {
+        match self.class {
+            MolluscClass::Gastropod => "Glides slowly on a muscular foot over a trail of mucus.".to_string(),
+            MolluscClass::Bivalve if self.locomotion == Locomotion::Sessile => {
+                "Stays anchored in place, filtering the water.".to_string()
+            }
+            MolluscClass::Bivalve => "Digs and shuffles with its muscular foot.".to_string(),
+            MolluscClass::Cephalopod => "Jets through the water from its siphon, and crawls on its arms.".to_string(),
+        }
+    }
+
+    fn locomotion(&self) -> Locomotion {
+        self.locomotion
+    }
+}
+
+impl Organism for Mollusc {
+    fn id(&self) -> Identifier {
+        self.id
+    }
+
+    fn species(&self) -> String {
+        self.species.clone()
+    }
+
+    fn vitals(&self) -> &VitalStatus {
+        &self.vitals
+    }
+
+    fn vitals_mut(&mut self) -> &mut VitalStatus {
+        &mut self.vitals
+    }
+
+    fn sex(&self) -> Sex {
+        self.sex
+    }
+
+    fn body_mass(&self) -> Option<Kilograms> {
+        self.body_mass
+    }
+
+    fn top_speed(&self) -> Option<MetersPerSecond> {
+        self.top_speed
+    }
+
+    fn tolerates(&self, environment: &Environment) -> bool {
+        let suits_body = match self.class {
+            MolluscClass::Gastropod => true,
+            MolluscClass::Bivalve => matches!(environment.terrain, Terrain::Ocean | Terrain::Wetlands),
+            MolluscClass::Cephalopod => matches!(environment.terrain, Terrain::Ocean),
+        };
+        suits_body && self.habitat.as_ref().is_none_or(|habitat| habitat.allows(environment))
+    }
+
+    fn body_temperature_in(&self, environment: &Environment) -> Option<Celsius> {
+        Some(Celsius::from_degrees(environment.ambient_temperature()))
+    }
+}
+
+impl BasicSummary for Mollusc {
+    fn get_name(&self) -> String {
+        self.display_name()
+    }
+
+    fn get_gender(&self) -> &Sex {
+        &self.sex
+    }
+}
+
+impl DetailedSummary for Mollusc {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
+    }
+}
+
+impl Summarizable for Mollusc {
+    fn summary(&self) -> String {
+        format!(
+            "--- Mollusc: {} ({}) ---\nID: {}\nAnatomy: {}\nMobility: {}\n\n {}",
+            self.display_name(),
+            self.species,
+            self.id,
+            self.describe_anatomy(),
+            self.describe_locomotion(),
+            self.generate_summary()
+        )
+    }
+}
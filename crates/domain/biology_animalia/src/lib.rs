@@ -6,10 +6,30 @@ pub mod mammals;
 pub mod birds;
 pub mod reptiles;
 pub mod fish;
+pub mod amphibians;
+pub mod invertebrates;
+
+// Reproductive strategies and offspring construction
+pub mod reproduction;
+
+// Loci and their phenotypes
+pub mod genetics;
+
+// Family trees exported for genealogy software
+pub mod genealogy;
+
+// Versioned documents (requires the `serde` feature)
+#[cfg(feature = "serde")]
+pub mod persistence;
 
 // Re-export generic types at top level (Option C: both paths work)
-pub use animal::Animal;
-pub use mammals::Mammal;
+pub use animal::{Animal, AnimalBuilder};
+pub use mammals::{Mammal, MammalBuilder};
+pub use birds::{Bird, BirdBuilder};
+pub use fish::{Fish, FishBuilder};
+pub use reptiles::{Reptile, ReptileBuilder};
+pub use amphibians::{Amphibian, AmphibianBuilder, Metamorphosis};
+pub use invertebrates::{Arachnid, ArachnidBuilder, Insect, InsectBuilder, Mollusc, MolluscBuilder};
 
 // Re-export specific types for convenience (Option C: both paths work)
-pub use mammals::primates::{Human, HumanBuilder, Moniker, NameParts};
+pub use mammals::primates::{Human, HumanBuilder, Moniker, NameKind, NameParts, NameRecord};
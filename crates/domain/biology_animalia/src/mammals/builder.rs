@@ -0,0 +1,112 @@
+use biology::anatomy::appendage::AppendageKind;
+use biology::builder::{BuildError, BuildProblem, OrganismBuilder, OrganismBuilderExt};
+use biology::characteristics::locomotion::Locomotion;
+use biology::genetics::Genome;
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::vitals::vital_status::VitalStatus;
+use crate::mammals::mammal::Mammal;
+use crate::mammals::profiles::MammalProfile;
+
+/// Builds a `Mammal` with the shared `OrganismBuilder` settings, plus
+/// locomotion, fur and a genome.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let rex = Mammal::builder("Canis familiaris")
+///     .profile(&profiles::DOG)
+///     .name("Rex")
+///     .sex(Sex::Male)
+///     .build()?;
+/// ```
+pub struct MammalBuilder {
+    organism: OrganismBuilder,
+    locomotion: Locomotion,
+    has_fur: bool,
+    genome: Option<Genome>,
+}
+
+impl MammalBuilder {
+    pub fn new(species: &str) -> Self {
+        Self {
+            organism: OrganismBuilder::new(species),
+            locomotion: Locomotion::Terrestrial,
+            has_fur: true,
+            genome: None,
+        }
+    }
+
+    // Fills in a typical adult of the profiled species; later setters override it.
+    pub fn profile(self, profile: &MammalProfile) -> Self {
+        self.common_name(profile.common_name)
+            .vitals(VitalStatus::Alive(profile.resting_vitals()))
+            .body_mass(profile.body_mass)
+            .top_speed(profile.top_speed)
+            .appendages(profile.body_plan.appendages())
+            .locomotion(profile.locomotion)
+            .fur(profile.has_fur)
+    }
+
+    pub fn locomotion(mut self, locomotion: Locomotion) -> Self {
+        self.locomotion = locomotion;
+        self
+    }
+
+    pub fn fur(mut self, has_fur: bool) -> Self {
+        self.has_fur = has_fur;
+        self
+    }
+
+    pub fn genome(mut self, genome: Genome) -> Self {
+        self.genome = Some(genome);
+        self
+    }
+
+    // Everything wrong with the settings: the shared checks, then the mammal's own.
+    pub fn problems(&self) -> Vec<BuildProblem> {
+        let mut problems = self.organism.problems();
+        let (wings, _) = self.organism.count_appendages(AppendageKind::Wing);
+        if self.locomotion == Locomotion::Aerial && wings < 2 {
+            problems.push(BuildProblem::Invalid {
+                field: "locomotion",
+                reason: format!("an aerial mammal needs two wings, not {}", wings),
+            });
+        }
+        problems
+    }
+
+    pub fn build(self) -> Result<Mammal, BuildError> {
+        BuildError::check(self.problems())?;
+        Ok(self.assemble())
+    }
+
+    // The mammal without any checks, e.g. for a newborn.
+    pub(crate) fn assemble(self) -> Mammal {
+        let parts = self.organism.into_parts();
+        let mut mammal = Mammal {
+            id: parts.id,
+            species: parts.species,
+            common_name: parts.common_name,
+            name: parts.name,
+            vitals: parts.vitals,
+            sex: parts.sex,
+            body_mass: parts.body_mass,
+            top_speed: parts.top_speed,
+            locomotion: self.locomotion,
+            has_fur: self.has_fur,
+            genome: None,
+            appendages: parts.appendages,
+            habitat: parts.habitat,
+        };
+        if let Some(genome) = self.genome {
+            mammal = mammal.with_genome(genome);
+        }
+        mammal
+    }
+}
+
+impl OrganismBuilderExt for MammalBuilder {
+    fn organism_builder(&mut self) -> &mut OrganismBuilder {
+        &mut self.organism
+    }
+}
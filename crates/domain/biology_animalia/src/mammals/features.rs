@@ -0,0 +1,32 @@
+use std::fmt::{Display, Formatter};
+
+/// What a mammal mainly eats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Diet {
+    // Grasses and leaves: horses, cattle.
+    Herbivore,
+    // Fruit and nectar: fruit bats.
+    Frugivore,
+    // Insects: most small bats, shrews.
+    Insectivore,
+    // Meat: cats.
+    Carnivore,
+    // A bit of everything: dogs, bears, humans.
+    Omnivore,
+    // Krill and small fish strained from the water: baleen whales.
+    FilterFeeder,
+}
+
+impl Display for Diet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Diet::Herbivore => write!(f, "herbivore, grazing and browsing plants"),
+            Diet::Frugivore => write!(f, "frugivore, eating fruit and nectar"),
+            Diet::Insectivore => write!(f, "insectivore"),
+            Diet::Carnivore => write!(f, "carnivore"),
+            Diet::Omnivore => write!(f, "omnivore"),
+            Diet::FilterFeeder => write!(f, "filter feeder, straining krill and small fish"),
+        }
+    }
+}
@@ -1,22 +1,61 @@
-use biology::Nomenclature;
+use biology::{Nomenclature, Organism, Sex};
+use biology::nomenclature::{common_aliases, common_display_name};
+use biology::anatomy::appendage::{Appendage, AppendageKind};
 use biology::characteristics::structural::Anatomy;
-use biology::characteristics::locomotion::Mobility;
+use biology::characteristics::locomotion::{Locomotion, Mobility};
 use biology::characteristics::presentation::Summarizable;
+use biology::ecosystem::environment::{Environment, Terrain};
+use biology::ecosystem::habitat::HabitatPreference;
+use biology::genetics::Genome;
+use biology::patterns::locomotion_patterns::QuadrupedalMobility;
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
 use biology::vitals::vital_status::VitalStatus;
 use biology::vitals::live_vitals::LiveVitals;
-use core_shared::Identifier;
+use core_shared::{Identifier, Kilograms, MetersPerSecond};
+use crate::genetics;
+use crate::mammals::builder::MammalBuilder;
+use crate::mammals::profiles::{self, MammalProfile};
 
 /// A generic mammal - more specific than Animal, demonstrates class-level characteristics.
 ///
 /// Mammals are warm-blooded vertebrates with hair/fur and mammary glands.
 /// This struct adds mammalian-specific traits while remaining simpler than
 /// detailed implementations like Human.
-#[derive(Debug)]
+///
+/// `Mammal::new` gives a bare mammal with no limbs; `Mammal::from_profile`
+/// builds a typical adult of a profiled species (`profiles::DOG`,
+/// `profiles::HUMPBACK_WHALE`, ...) with its body plan, size, resting
+/// vitals and locomotion. Mammals of a profiled species also get its diet,
+/// lifespan and vital reference ranges through `profile()`. `Mammal::builder`
+/// checks its settings, and can also give a mammal a name and a habitat.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Mammal {
     pub id: Identifier,
     pub species: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub common_name: Option<String>,
+    // An individual's own name, e.g. a pet's.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
     pub vitals: VitalStatus,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sex: Sex,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub body_mass: Option<Kilograms>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub top_speed: Option<MetersPerSecond>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub locomotion: Locomotion,
     pub has_fur: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub genome: Option<Genome>,
+    // Empty unless built from a profile or given limbs.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub appendages: Vec<Appendage>,
+    // Where it can live; `None` leaves it to the locomotion.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub habitat: Option<HabitatPreference>,
 }
 
 impl Mammal {
@@ -24,53 +63,215 @@ impl Mammal {
         Self {
             id: Identifier::new(),
             species,
+            common_name: None,
+            name: None,
             vitals: VitalStatus::Alive(LiveVitals::default()),
+            sex: Sex::Unknown,
+            body_mass: None,
+            top_speed: None,
+            locomotion: Locomotion::Terrestrial,
             has_fur: true, // Most mammals have fur
+            genome: None,
+            appendages: Vec::new(),
+            habitat: None,
+        }
+    }
+
+    pub fn builder(species: &str) -> MammalBuilder {
+        MammalBuilder::new(species)
+    }
+
+    // A typical adult of the profiled species, at rest.
+    pub fn from_profile(profile: &MammalProfile) -> Self {
+        Self::new(profile.species.to_string())
+            .with_common_name(profile.common_name)
+            .with_vitals(VitalStatus::Alive(profile.resting_vitals()))
+            .with_body_mass(profile.body_mass)
+            .with_top_speed(profile.top_speed)
+            .with_locomotion(profile.locomotion)
+            .with_fur(profile.has_fur)
+            .with_appendages(profile.body_plan.appendages())
+    }
+
+    // The profile for this mammal's species, if it has one.
+    pub fn profile(&self) -> Option<&'static MammalProfile> {
+        profiles::profile_for(&self.species)
+    }
+
+    // Readings outside the species' reference ranges; empty if it has no profile or is dead.
+    pub fn vital_deviations(&self) -> Vec<String> {
+        match (&self.vitals, self.profile()) {
+            (VitalStatus::Alive(vitals), Some(profile)) => profile.ranges.deviations(vitals),
+            _ => Vec::new(),
         }
     }
 
+    pub fn with_common_name(mut self, common_name: &str) -> Self {
+        self.common_name = Some(common_name.to_string());
+        self
+    }
+
     pub fn with_vitals(mut self, vitals: VitalStatus) -> Self {
         self.vitals = vitals;
         self
     }
 
+    pub fn with_sex(mut self, sex: Sex) -> Self {
+        self.sex = sex;
+        self
+    }
+
+    pub fn with_body_mass(mut self, body_mass: Kilograms) -> Self {
+        self.body_mass = Some(body_mass);
+        self
+    }
+
+    pub fn with_top_speed(mut self, top_speed: MetersPerSecond) -> Self {
+        self.top_speed = Some(top_speed);
+        self
+    }
+
+    pub fn with_locomotion(mut self, locomotion: Locomotion) -> Self {
+        self.locomotion = locomotion;
+        self
+    }
+
+    pub fn with_appendages(mut self, appendages: Vec<Appendage>) -> Self {
+        self.appendages = appendages;
+        self
+    }
+
     pub fn with_fur(mut self, has_fur: bool) -> Self {
         self.has_fur = has_fur;
         self
     }
+
+    // Sets the genome and expresses it: the fur locus decides `has_fur`.
+    pub fn with_genome(mut self, genome: Genome) -> Self {
+        if let Some(has_fur) = genetics::has_fur(&genome) {
+            self.has_fur = has_fur;
+        }
+        self.genome = Some(genome);
+        self
+    }
 }
 
 impl Nomenclature for Mammal {
     fn display_name(&self) -> String {
-        self.species.clone()
+        common_display_name(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+
+    fn aliases(&self) -> Vec<String> {
+        common_aliases(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+}
+
+impl AnatomyAnalyzer for Mammal {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
     }
 }
 
 impl Anatomy for Mammal {
     fn describe_anatomy(&self) -> String {
         let fur_status = if self.has_fur { "with fur" } else { "hairless" };
-        format!(
+        let description = format!(
             "A {} - warm-blooded mammal {} and mammary glands.",
             self.species, fur_status
-        )
+        );
+        if self.appendages.is_empty() {
+            description
+        } else {
+            format!("{} {}", self.describe_limb_status(), description)
+        }
     }
 }
 
+impl QuadrupedalMobility for Mammal {}
+
 impl Mobility for Mammal {
     fn describe_locomotion(&self) -> String {
-        "Mammalian locomotion (walking, running, or swimming).".to_string()
+        let (legs, missing) = self.count_appendages(AppendageKind::Leg);
+        match self.locomotion {
+            Locomotion::Aerial if self.count_appendages(AppendageKind::Wing).0 == 2 => {
+                "Flies on membranous wings; clambers and hangs by its hind feet.".to_string()
+            }
+            Locomotion::Aquatic if self.count_appendages(AppendageKind::Fin).0 > 0 => {
+                "Swims with beats of its tail fluke, steering with its flippers.".to_string()
+            }
+            Locomotion::Terrestrial if legs + missing == 4 => self.describe_quadrupedal_movement(),
+            _ => "Mammalian locomotion (walking, running, or swimming).".to_string(),
+        }
+    }
+
+    fn locomotion(&self) -> Locomotion {
+        self.locomotion
+    }
+}
+
+impl Organism for Mammal {
+    fn id(&self) -> Identifier {
+        self.id
+    }
+
+    fn species(&self) -> String {
+        self.species.clone()
+    }
+
+    fn vitals(&self) -> &VitalStatus {
+        &self.vitals
+    }
+
+    fn vitals_mut(&mut self) -> &mut VitalStatus {
+        &mut self.vitals
+    }
+
+    fn sex(&self) -> Sex {
+        self.sex
+    }
+
+    fn body_mass(&self) -> Option<Kilograms> {
+        self.body_mass
+    }
+
+    fn top_speed(&self) -> Option<MetersPerSecond> {
+        self.top_speed
+    }
+
+    fn lifespan(&self) -> Option<u32> {
+        self.profile().map(|profile| profile.lifespan)
+    }
+
+    fn genome(&self) -> Option<&Genome> {
+        self.genome.as_ref()
+    }
+
+    // Its habitat if it has one; otherwise aquatic mammals such as whales and dolphins need water.
+    fn tolerates(&self, environment: &Environment) -> bool {
+        match &self.habitat {
+            Some(habitat) => habitat.allows(environment),
+            None => self.locomotion != Locomotion::Aquatic || matches!(environment.terrain, Terrain::Ocean | Terrain::Wetlands),
+        }
     }
 }
 
 impl Summarizable for Mammal {
     fn summary(&self) -> String {
-        format!(
+        let mut summary = format!(
             "--- Generic Mammal: {} ---\nID: {}\nAnatomy: {}\nMobility: {}",
             self.species,
             self.id,
             self.describe_anatomy(),
             self.describe_locomotion()
-        )
+        );
+        if let Some(profile) = self.profile() {
+            summary.push_str(&format!(
+                "\nDiet: {}\nLifespan: about {} years",
+                profile.diet,
+                profile.lifespan_years()
+            ));
+        }
+        summary
     }
 }
 
@@ -95,6 +296,18 @@ mod tests {
         assert_eq!(mammal.has_fur, false);
     }
 
+    #[test]
+    fn test_mammal_genome_decides_fur() {
+        let hairless = Mammal::new("Cavia porcellus".to_string())
+            .with_genome(Genome::new().with("HR", "hr", "hr"));
+        let carrier = Mammal::new("Cavia porcellus".to_string())
+            .with_genome(Genome::new().with("HR", "Hr", "hr"));
+
+        assert!(!hairless.has_fur);
+        assert!(carrier.has_fur);
+        assert!(hairless.genome().is_some());
+    }
+
     #[test]
     fn test_mammal_nomenclature() {
         let mammal = Mammal::new("Elephant".to_string());
@@ -128,6 +341,18 @@ mod tests {
         assert!(mobility.contains("Mammalian locomotion"));
     }
 
+    #[test]
+    fn test_mammal_from_profile() {
+        let dog = Mammal::from_profile(&profiles::DOG);
+
+        assert_eq!(dog.display_name(), "Domestic dog");
+        assert_eq!(dog.count_limbs(), (0, 0, 4, 0));
+        assert_eq!(dog.describe_locomotion(), "Walks and runs on four legs.");
+        assert!(dog.vital_deviations().is_empty());
+        assert_eq!(dog.profile().map(|profile| profile.lifespan_years()), Some(12));
+        assert!(Mammal::new("Canis lupus".to_string()).profile().is_none());
+    }
+
     #[test]
     fn test_mammal_builder_pattern() {
         let mammal = Mammal::new("Test Mammal".to_string())
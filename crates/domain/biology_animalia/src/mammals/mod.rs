@@ -1,6 +1,12 @@
+pub mod builder;
+pub mod features;
 pub mod mammal;
 pub mod primates;
+pub mod profiles;
 
 // Re-export generic and specific types
+pub use builder::MammalBuilder;
+pub use features::Diet;
 pub use mammal::Mammal;
-pub use primates::{Human, HumanBuilder, Moniker, NameParts};
+pub use primates::{Human, HumanBuilder, Moniker, NameKind, NameParts, NameRecord};
+pub use profiles::{BodyPlan, MammalProfile};
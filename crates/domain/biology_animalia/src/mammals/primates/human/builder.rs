@@ -1,16 +1,56 @@
-use biology::anatomy::appendage::Appendage;
-use crate::{Human, Moniker, NameParts};
+use biology::anatomy::appendage::{Appendage, AppendageKind};
+use crate::{genetics, Human, Moniker, NameParts, NameRecord};
 use biology::anatomy::arm_measurements::ArmMeasurements;
 use biology::anatomy::leg_measurements::LegMeasurements;
 use biology::anatomy::limb_status::LimbStatus;
+use biology::builder::{BuildError, BuildProblem, OrganismBuilder, OrganismBuilderExt};
+use biology::genetics::Genome;
+use biology::medical::MedicalRecord;
+use biology::vitals::blood_pressure::BloodPressure;
+use biology::vitals::blood_type::BloodType;
 use biology::vitals::live_vitals::LiveVitals;
+use biology::vitals::reference_ranges::ReferenceRanges;
 use biology::vitals::vital_status::VitalStatus;
-use core_shared::{Direction, Identifier};
+use biology::vitals::vital_types::{Celsius, BPM};
+use core_shared::Direction;
 use biology::Sex;
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
 use biology::templates::anatomy::AnatomyTemplate;
 
+// The furthest from normal a living person has been recorded; anything beyond is a typo.
+const SURVIVABLE: ReferenceRanges = ReferenceRanges {
+    pulse: (BPM(20), BPM(300)),
+    respiration: (2, 80),
+    temperature: (Celsius(14), Celsius(46)),
+};
+
+// Systolic pressure, in mmHg, with the same meaning.
+const SURVIVABLE_SYSTOLIC: (u32, u32) = (50, 300);
+
+/// Builds a `Human` with the shared `OrganismBuilder` settings, plus
+/// structured names, limbs, blood type, genome and medical record.
+///
+/// The species is always "Homo sapiens", and a common name is not kept. `name` takes structured
+/// `NameParts`; a plain name given through `OrganismBuilderExt::name` is
+/// parsed into the legal name when no other name is set. Appendages, once
+/// set, replace the two arms and two legs, even when there are none.
+///
+/// `build` never fails; `try_build` first checks the settings and returns
+/// a `BuildError` listing everything wrong with them: blank names, a common
+/// name, vitals no living person could have, and appendages a human cannot
+/// have.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let person = Human::builder()
+///     .first_name("Ada")
+///     .last_name("Lovelace")
+///     .absent_limb(Direction::Left, AppendageKind::Arm)
+///     .try_build()?;
+/// ```
 pub struct HumanBuilder {
-    id: Identifier,
+    organism: OrganismBuilder,
     name_prefix: Option<String>,
     name_first: Option<String>,
     name_middle: Option<String>,
@@ -18,16 +58,28 @@ pub struct HumanBuilder {
     name_suffix: Option<String>,
     name_moniker: Option<NameParts>,
     name_designation: Option<String>,
-    sex: Sex,
-    vitals: VitalStatus,
+    aliases: Vec<NameRecord>,
     arm_measurements: ArmMeasurements,
     leg_measurements: LegMeasurements,
+    // Replaces the two arms and two legs when set.
+    appendages: Option<Vec<Appendage>>,
+    // Statuses applied to single limbs at build time.
+    limb_statuses: Vec<(Direction, AppendageKind, LimbStatus)>,
+    blood_type: Option<BloodType>,
+    genome: Option<Genome>,
+    medical_record: MedicalRecord,
+}
+
+impl Default for HumanBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl HumanBuilder {
     pub fn new() -> Self {
         Self {
-            id: Identifier::new(),
+            organism: OrganismBuilder::new("Homo sapiens").vitals(VitalStatus::Alive(Self::resting_vitals())),
             name_prefix: None,
             name_first: None,
             name_middle: None,
@@ -35,13 +87,22 @@ impl HumanBuilder {
             name_suffix: None,
             name_moniker: None,
             name_designation: None,
-            sex: Sex::Unknown,
+            aliases: Vec::new(),
             arm_measurements: ArmMeasurements::default(),
             leg_measurements: LegMeasurements::default(),
-            vitals: VitalStatus::Alive(LiveVitals::default()),
+            appendages: None,
+            limb_statuses: Vec::new(),
+            blood_type: None,
+            genome: None,
+            medical_record: MedicalRecord::default(),
         }
     }
 
+    // A healthy adult at rest.
+    pub fn resting_vitals() -> LiveVitals {
+        LiveVitals::new(BloodPressure(120, 80), Celsius(37), BPM(72), 16)
+    }
+
     pub fn designation(mut self, des: String) -> Self {
         self.name_designation = Some(des);
         self
@@ -52,13 +113,50 @@ impl HumanBuilder {
         self
     }
 
+    // The parts below fill in or override the matching parts of `name`.
+    pub fn name_prefix(mut self, prefix: &str) -> Self {
+        self.name_prefix = Some(prefix.to_string());
+        self
+    }
+
+    pub fn first_name(mut self, first: &str) -> Self {
+        self.name_first = Some(first.to_string());
+        self
+    }
+
+    pub fn middle_name(mut self, middle: &str) -> Self {
+        self.name_middle = Some(middle.to_string());
+        self
+    }
+
+    pub fn last_name(mut self, last: &str) -> Self {
+        self.name_last = Some(last.to_string());
+        self
+    }
+
+    pub fn name_suffix(mut self, suffix: &str) -> Self {
+        self.name_suffix = Some(suffix.to_string());
+        self
+    }
+
+    pub fn preferred_name(mut self, name: NameParts) -> Self {
+        self.aliases.push(NameRecord::preferred(name));
+        self
+    }
+
+    // Adds any other name on record, such as a former name with its date range.
+    pub fn alias(mut self, record: NameRecord) -> Self {
+        self.aliases.push(record);
+        self
+    }
+
     pub fn sex(mut self, sex: Sex) -> Self {
-        self.sex = sex;
+        self.organism = self.organism.sex(sex);
         self
     }
 
     pub fn vitals(mut self, vital_status: VitalStatus) -> Self {
-        self.vitals = vital_status;
+        self.organism = self.organism.vitals(vital_status);
         self
     }
 
@@ -75,57 +173,265 @@ impl HumanBuilder {
         self
     }
 
-    pub fn with_vitals(mut self, vitals: VitalStatus) -> Self {
-        self.vitals = vitals;
+    // A method to override just the leg measurements.
+    pub fn with_leg_measurements(mut self, measurements: LegMeasurements) -> Self {
+        self.leg_measurements = measurements;
         self
     }
 
-    pub fn build(self) -> Human {
-        let name = if let Some(des) = self.name_designation {
-            // If a designation was provided, it wins.
-            Moniker::Designation(des)
-        } else if let Some(mon) = self.name_moniker {
-            Moniker::Name(mon)
-        } else {
-            // Otherwise, assemble a structured name from the parts.
-            Moniker::Name(NameParts {
-                prefix: self.name_prefix,
-                first: self.name_first,
-                middle: self.name_middle,
-                last: self.name_last,
-                suffix: self.name_suffix,
-            })
-        };
-
-        let appendages = vec![
-            Appendage::Arm {
-                side: Direction::Left,
-                measurements: self.arm_measurements, // Use the configured measurements
-                status: LimbStatus::Intact,
-            },
-            Appendage::Arm {
-                side: Direction::Right,
-                measurements: self.arm_measurements,
-                status: LimbStatus::Intact,
-            },
-            Appendage::Leg {
-                side: Direction::Left,
-                measurements: self.leg_measurements,
-                status: LimbStatus::Intact,
-            },
-            Appendage::Leg {
-                side: Direction::Right,
-                measurements: self.leg_measurements,
-                status: LimbStatus::Intact,
-            },
+    // Replaces the two arms and two legs, e.g. with a set measured one limb at a time.
+    pub fn appendages(mut self, appendages: Vec<Appendage>) -> Self {
+        self.appendages = Some(appendages);
+        self
+    }
+
+    // Sets one limb's status from birth, e.g. a prosthetic leg.
+    pub fn limb_status(mut self, side: Direction, kind: AppendageKind, status: LimbStatus) -> Self {
+        self.limb_statuses.push((side, kind, status));
+        self
+    }
+
+    // A limb that never formed: a congenital limb difference.
+    pub fn absent_limb(self, side: Direction, kind: AppendageKind) -> Self {
+        self.limb_status(side, kind, LimbStatus::Absent)
+    }
+
+    pub fn with_vitals(self, vitals: VitalStatus) -> Self {
+        self.vitals(vitals)
+    }
+
+    pub fn blood_type(mut self, blood_type: BloodType) -> Self {
+        self.blood_type = Some(blood_type);
+        self
+    }
+
+    // Expressed at build time: it decides the blood type and scales the measurements by stature.
+    pub fn genome(mut self, genome: Genome) -> Self {
+        self.genome = Some(genome);
+        self
+    }
+
+    // The history so far; a deceased person's report is filed in it at build time.
+    pub fn medical_record(mut self, record: MedicalRecord) -> Self {
+        self.medical_record = record;
+        self
+    }
+
+    pub fn date_of_birth(mut self, date: &str) -> Self {
+        self.medical_record.date_of_birth = Some(date.to_string());
+        self
+    }
+
+    // Everything wrong with the settings, shared and human; empty if `try_build` would succeed.
+    pub fn problems(&self) -> Vec<BuildProblem> {
+        let mut problems = Vec::new();
+        let names = [
+            ("name prefix", &self.name_prefix),
+            ("first name", &self.name_first),
+            ("middle name", &self.name_middle),
+            ("last name", &self.name_last),
+            ("name suffix", &self.name_suffix),
+            ("designation", &self.name_designation),
         ];
+        for (which, name) in names {
+            if name.as_ref().is_some_and(|name| name.trim().is_empty()) {
+                problems.push(BuildProblem::BlankName(which));
+            }
+        }
+
+        problems.extend(self.organism.problems());
+        if self.organism.has_common_name() {
+            problems.push(BuildProblem::Invalid {
+                field: "common name",
+                reason: "a human has no common name; use `name` or `preferred_name`".to_string(),
+            });
+        }
+        if let VitalStatus::Alive(vitals) = self.organism.vital_status() {
+            let systolic = vitals.blood_pressure.0;
+            let (low, high) = SURVIVABLE_SYSTOLIC;
+            if systolic < low || systolic > high {
+                problems.push(BuildProblem::ImplausibleVitals(format!(
+                    "Systolic pressure of {} mmHg is outside {}-{} mmHg",
+                    systolic, low, high
+                )));
+            }
+            problems.extend(SURVIVABLE.deviations(vitals).into_iter().map(BuildProblem::ImplausibleVitals));
+        }
+
+        problems.extend(self.appendage_problems());
+        if let Some(date) = &self.medical_record.date_of_birth {
+            // A valid date of birth makes the person zero years old on that day.
+            if self.medical_record.age_on(date).is_none() {
+                problems.push(BuildProblem::Invalid {
+                    field: "date of birth",
+                    reason: format!("{} is not an ISO 8601 date", date),
+                });
+            }
+        }
+        problems
+    }
+
+    // Limbs a human cannot have, and limb statuses with no limb to apply to.
+    fn appendage_problems(&self) -> Vec<BuildProblem> {
+        let appendages = self.limbs(self.arm_measurements, self.leg_measurements);
+        let mut reasons = Vec::new();
+        for appendage in &appendages {
+            let kind = appendage.kind();
+            if !matches!(kind, AppendageKind::Arm | AppendageKind::Leg) {
+                let reason = format!("a human has no {}", kind.plural());
+                if !reasons.contains(&reason) {
+                    reasons.push(reason);
+                }
+            }
+        }
+        for side in [Direction::Left, Direction::Right] {
+            for kind in [AppendageKind::Arm, AppendageKind::Leg] {
+                let count = appendages
+                    .iter()
+                    .filter(|appendage| appendage.kind() == kind && appendage.side() == Some(side))
+                    .count();
+                if count > 1 {
+                    reasons.push(format!("{} {} {}s where a human has one", count, side_name(side), kind.name()));
+                }
+            }
+        }
+        for (side, kind, _) in &self.limb_statuses {
+            if !appendages.iter().any(|appendage| appendage.kind() == *kind && appendage.side() == Some(*side)) {
+                reasons.push(format!("there is no {} {} to set the status of", side_name(*side), kind.name()));
+            }
+        }
+
+        reasons
+            .into_iter()
+            .map(|reason| BuildProblem::Invalid { field: "appendages", reason })
+            .collect()
+    }
+
+    // The configured appendages, or two arms and two legs, with the limb statuses applied.
+    // Appendages given through `OrganismBuilderExt` count as configured unless there are none.
+    fn limbs(&self, arm_measurements: ArmMeasurements, leg_measurements: LegMeasurements) -> Vec<Appendage> {
+        let shared = self.organism.get_appendages();
+        let configured = self.appendages.clone().or_else(|| (!shared.is_empty()).then(|| shared.clone()));
+        let mut appendages = configured.unwrap_or_else(|| {
+            vec![
+                Appendage::Arm {
+                    side: Direction::Left,
+                    measurements: arm_measurements, // Use the configured measurements
+                    status: LimbStatus::Intact,
+                },
+                Appendage::Arm {
+                    side: Direction::Right,
+                    measurements: arm_measurements,
+                    status: LimbStatus::Intact,
+                },
+                Appendage::Leg {
+                    side: Direction::Left,
+                    measurements: leg_measurements,
+                    status: LimbStatus::Intact,
+                },
+                Appendage::Leg {
+                    side: Direction::Right,
+                    measurements: leg_measurements,
+                    status: LimbStatus::Intact,
+                },
+            ]
+        });
+        for (side, kind, status) in &self.limb_statuses {
+            if let Some(limb) = appendages
+                .iter_mut()
+                .find(|appendage| appendage.kind() == *kind && appendage.side() == Some(*side))
+            {
+                *limb.status_mut() = status.clone();
+            }
+        }
+        appendages
+    }
+
+    // The legal name: `name`, with any parts set on their own taking precedence.
+    fn legal_name(&self) -> Option<NameParts> {
+        let parts = [&self.name_prefix, &self.name_first, &self.name_middle, &self.name_last, &self.name_suffix];
+        if self.name_moniker.is_none() && parts.iter().all(|part| part.is_none()) {
+            return None;
+        }
+        let mut name = self.name_moniker.clone().unwrap_or_default();
+        for (part, setting) in [
+            (&mut name.prefix, &self.name_prefix),
+            (&mut name.first, &self.name_first),
+            (&mut name.middle, &self.name_middle),
+            (&mut name.last, &self.name_last),
+            (&mut name.suffix, &self.name_suffix),
+        ] {
+            if setting.is_some() {
+                part.clone_from(setting);
+            }
+        }
+        Some(name)
+    }
+
+    // Checks everything first, reporting every problem at once.
+    pub fn try_build(self) -> Result<Human, BuildError> {
+        BuildError::check(self.problems())?;
+        Ok(self.build())
+    }
+
+    // Builds without any checks; see `try_build`.
+    pub fn build(self) -> Human {
+        let legal = self.legal_name();
+        let (mut arm_measurements, mut leg_measurements) = (self.arm_measurements, self.leg_measurements);
+        let mut blood_type = self.blood_type;
+        if let Some(genome) = &self.genome {
+            let stature = genetics::stature(genome);
+            arm_measurements = arm_measurements.scaled(stature);
+            leg_measurements = leg_measurements.scaled(stature);
+            blood_type = genetics::blood_type(genome).or(blood_type);
+        }
+        let appendages = self.limbs(arm_measurements, leg_measurements);
+        let parts = self.organism.into_parts();
+
+        // The legal name and designation are both kept on record; neither replaces the other.
+        let mut aliases = Vec::new();
+        if let Some(legal) = legal.or_else(|| parts.name.as_deref().map(NameParts::from)) {
+            aliases.push(NameRecord::legal(legal));
+        }
+        if let Some(des) = &self.name_designation {
+            aliases.push(NameRecord::designation(des.clone()));
+        }
+        aliases.extend(self.aliases.iter().cloned());
+
+        // With no name at all, the person is shown with an empty name.
+        let name = Human::primary_name(&aliases).unwrap_or_else(|| Moniker::Name(NameParts::default()));
+
+        let mut medical_record = self.medical_record;
+        if let (VitalStatus::Deceased(report), false) = (&parts.vitals, medical_record.is_closed()) {
+            medical_record.close(report.clone());
+        }
 
         Human {
-            id: self.id,
+            id: parts.id,
             name,
-            sex: self.sex,
-            vitals: self.vitals,
+            aliases,
+            sex: parts.sex,
+            vitals: parts.vitals,
+            body_mass: parts.body_mass,
+            top_speed: parts.top_speed,
+            habitat: parts.habitat,
             appendages,
+            blood_type,
+            genome: self.genome,
+            medical_record,
         }
     }
 }
+
+impl OrganismBuilderExt for HumanBuilder {
+    fn organism_builder(&mut self) -> &mut OrganismBuilder {
+        &mut self.organism
+    }
+}
+
+fn side_name(side: Direction) -> &'static str {
+    match side {
+        Direction::Left => "left",
+        Direction::Right => "right",
+    }
+}
@@ -1,16 +1,37 @@
-use crate::{HumanBuilder, Moniker};
-use biology::{Nomenclature, Sex};
+use crate::{HumanBuilder, Moniker, NameKind, NameRecord};
+use biology::{Nomenclature, Sex, Summarizable};
 use biology::anatomy::appendage::Appendage;
 use biology::anatomy::limb_status::LimbStatus;
+use biology::genetics::Genome;
+use biology::medical::{Encounter, MedicalRecord};
+use biology::vitals::blood_type::BloodType;
 use biology::vitals::vital_status::VitalStatus;
-use core_shared::{Direction, Identifier};
+use biology::ecosystem::habitat::HabitatPreference;
+use core_shared::{Direction, Identifier, Kilograms, MetersPerSecond};
 
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Human {
     pub id: Identifier,
     pub name: Moniker,
+    // Every name on record, including the one shown as `name`.
+    pub aliases: Vec<NameRecord>,
     pub sex: Sex,
     pub appendages: Vec<Appendage>,
     pub vitals: VitalStatus,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub body_mass: Option<Kilograms>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub top_speed: Option<MetersPerSecond>,
+    // Where the person can live; `None` for anywhere.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub habitat: Option<HabitatPreference>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub blood_type: Option<BloodType>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub genome: Option<Genome>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub medical_record: MedicalRecord,
 }
 
 impl Human {
@@ -19,6 +40,112 @@ impl Human {
        HumanBuilder::new()
     }
 
+    // Records another name and re-evaluates which one is displayed.
+    pub fn add_name(&mut self, record: NameRecord) {
+        self.aliases.push(record);
+        if let Some(primary) = Self::primary_name(&self.aliases) {
+            self.name = primary;
+        }
+    }
+
+    // Designations win (case records are anonymised), then preferred names, then legal names.
+    pub(crate) fn primary_name(records: &[NameRecord]) -> Option<Moniker> {
+        [NameKind::Designation, NameKind::Preferred, NameKind::Legal]
+            .iter()
+            .find_map(|kind| {
+                records
+                    .iter()
+                    .rev()
+                    .find(|record| record.kind == *kind && record.is_current())
+            })
+            .map(|record| record.moniker.clone())
+    }
+
+    pub fn legal_name(&self) -> Option<&Moniker> {
+        self.current_name_of_kind(NameKind::Legal)
+    }
+
+    pub fn preferred_name(&self) -> Option<&Moniker> {
+        self.current_name_of_kind(NameKind::Preferred)
+    }
+
+    pub fn former_names(&self) -> Vec<&NameRecord> {
+        self.aliases
+            .iter()
+            .filter(|record| record.kind == NameKind::Former || !record.is_current())
+            .collect()
+    }
+
+    pub fn designations(&self) -> Vec<&str> {
+        self.aliases
+            .iter()
+            .filter_map(|record| match &record.moniker {
+                Moniker::Designation(designation) if record.kind == NameKind::Designation => {
+                    Some(designation.as_str())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    // The names that were in use on the given ISO 8601 date.
+    pub fn names_on(&self, date: &str) -> Vec<&NameRecord> {
+        self.aliases
+            .iter()
+            .filter(|record| record.was_valid_on(date))
+            .collect()
+    }
+
+    fn current_name_of_kind(&self, kind: NameKind) -> Option<&Moniker> {
+        self.aliases
+            .iter()
+            .rev()
+            .find(|record| record.kind == kind && record.is_current())
+            .map(|record| &record.moniker)
+    }
+
+    // Whole years on the given ISO 8601 date, if the date of birth is on record.
+    pub fn age_on(&self, date: &str) -> Option<u32> {
+        self.medical_record.age_on(date)
+    }
+
+    // Files the encounter; vitals taken at the newest one become the current vitals.
+    pub fn record_encounter(&mut self, encounter: Encounter) {
+        let newest = self
+            .medical_record
+            .encounters()
+            .last()
+            .is_none_or(|latest| latest.timestamp <= encounter.timestamp);
+        if let (true, Some(vitals), VitalStatus::Alive(_)) = (newest, &encounter.vitals, &self.vitals) {
+            self.vitals = VitalStatus::Alive(vitals.clone());
+        }
+        self.medical_record.record_encounter(encounter);
+    }
+
+    // A chart for the ward: who the patient is and how they are, then the medical record.
+    pub fn medical_summary(&self) -> String {
+        let blood_type = self.blood_type.map_or_else(|| "Unknown".to_string(), |blood_type| blood_type.to_string());
+        let status = match &self.vitals {
+            VitalStatus::Alive(vitals) => format!(
+                "Alive; BP {}/{}, {} °C, {} BPM, {}",
+                vitals.blood_pressure.0,
+                vitals.blood_pressure.1,
+                vitals.temperature.0,
+                vitals.pulse.0,
+                vitals.describe_respiration()
+            ),
+            VitalStatus::Deceased(_) => "Deceased".to_string(),
+        };
+        format!(
+            "--- Patient: {} ---\nSex: {}\nBlood type: {}\nStatus: {}\n{}",
+            self.name.display_name(),
+            self.sex,
+            blood_type,
+            status,
+            self.medical_record.summary()
+        )
+    }
+
     #[allow(unused_variables)]
     pub fn set_limb_status(
         &mut self,
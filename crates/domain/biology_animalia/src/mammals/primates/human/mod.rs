@@ -1,13 +1,17 @@
 pub mod builder;
+#[allow(clippy::module_inception)]
 pub mod human;
 pub mod moniker;
 pub mod nomenclature;
 pub mod anatomy;
 pub mod mobility;
+pub mod organism;
 pub mod summarizable;
 pub mod name_parts;
+pub mod name_record;
 
 pub use builder::HumanBuilder;
 pub use human::Human;
 pub use moniker::Moniker;
 pub use name_parts::NameParts;
+pub use name_record::{NameKind, NameRecord};
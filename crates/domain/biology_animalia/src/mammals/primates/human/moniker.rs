@@ -1,8 +1,10 @@
 use crate::NameParts;
 use biology::Nomenclature;
+use biology::nomenclature::conventions::{NameFormatting, NameStyle, NamingConvention};
 use biology::nomenclature::nomenclature::NomenclatureComponents;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
 pub enum Moniker {
     Name(NameParts),
     Designation(String),
@@ -10,28 +12,7 @@ pub enum Moniker {
 
 impl Nomenclature for Moniker {
     fn display_name(&self) -> String {
-        match self {
-            Moniker::Name(name) => {
-                let mut parts = vec![];
-                if let Some(prefix) = &name.prefix {
-                    parts.push(prefix.clone());
-                }
-                if let Some(first) = &name.first {
-                    parts.push(first.clone());
-                }
-                if let Some(middle) = &name.middle {
-                    parts.push(middle.clone());
-                }
-                if let Some(last) = &name.last {
-                    parts.push(last.clone());
-                }
-                if let Some(suffix) = &name.suffix {
-                    parts.push(suffix.clone());
-                }
-                parts.join(" ")
-            }
-            Moniker::Designation(title) => title.clone(),
-        }
+        self.format_name(NameStyle::Formal)
     }
 }
 
@@ -71,4 +52,28 @@ impl NomenclatureComponents for Moniker {
             Moniker::Designation(_) => None,
         }
     }
+
+    fn second_last_name(&self) -> Option<String> {
+        match self {
+            Moniker::Name(name) => name.second_last_name(),
+            Moniker::Designation(_) => None,
+        }
+    }
+}
+
+impl NameFormatting for Moniker {
+    fn naming_convention(&self) -> NamingConvention {
+        match self {
+            Moniker::Name(name) => name.naming_convention(),
+            Moniker::Designation(_) => NamingConvention::default(),
+        }
+    }
+
+    fn format_name(&self, style: NameStyle) -> String {
+        match self {
+            Moniker::Name(name) => name.format_name(style),
+            // Designations are opaque labels, so every style renders them verbatim.
+            Moniker::Designation(title) => title.clone(),
+        }
+    }
 }
@@ -1,12 +1,17 @@
+use biology::nomenclature::conventions::{NameFormatting, NamingConvention};
 use biology::nomenclature::nomenclature::NomenclatureComponents;
 
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
 pub struct NameParts {
     pub prefix: Option<String>,
     pub first: Option<String>,
     pub middle: Option<String>,
     pub last: Option<String>,
+    // The maternal surname for double-surname conventions.
+    pub second_last: Option<String>,
     pub suffix: Option<String>,
+    pub convention: NamingConvention,
 }
 
 impl NameParts {
@@ -20,26 +25,42 @@ impl NameParts {
     }
 
     pub fn from(full_name: &str) -> Self {
-        let parts: Vec<&str> = full_name.split_whitespace().collect();
-        match parts.len() {
-            0 => Self::default(),
-            1 => Self {
-                first: Some(parts[0].to_string()),
-                ..Default::default()
-            },
-            2 => Self {
-                first: Some(parts[0].to_string()),
-                last: Some(parts[1].to_string()),
-                ..Default::default()
-            },
-            3 => Self {
-                first: Some(parts[0].to_string()),
-                middle: Some(parts[1].to_string()),
-                last: Some(parts[2].to_string()),
-                ..Default::default()
-            },
-            _ => Self::default(),
+        Self::parse(full_name, NamingConvention::GivenNameFirst)
+    }
+
+    // Splits a full name written in the convention's natural order.
+    pub fn parse(full_name: &str, convention: NamingConvention) -> Self {
+        let mut parts: Vec<&str> = full_name.split_whitespace().collect();
+        if convention == NamingConvention::FamilyNameFirst && !parts.is_empty() {
+            // "Mao Zedong" holds the same parts as "Zedong Mao", just reordered.
+            parts.rotate_left(1);
         }
+
+        // Double surnames keep the last two parts as surnames; every other
+        // convention has one. Given names between first and surname(s) are
+        // joined into `middle` so no part of a long name is lost.
+        let surnames = match (convention, parts.len()) {
+            (_, 0 | 1) => 0,
+            (NamingConvention::DoubleSurname, 3..) => 2,
+            _ => 1,
+        };
+
+        let mut name = Self::default();
+        if let Some((first, rest)) = parts.split_first() {
+            let (middle, surname) = rest.split_at(rest.len() - surnames);
+            name.first = Some(first.to_string());
+            name.middle = (!middle.is_empty()).then(|| middle.join(" "));
+            name.last = surname.first().map(|part| part.to_string());
+            name.second_last = surname.get(1).map(|part| part.to_string());
+        }
+
+        name.convention = convention;
+        name
+    }
+
+    pub fn with_convention(mut self, convention: NamingConvention) -> Self {
+        self.convention = convention;
+        self
     }
 }
 
@@ -63,4 +84,14 @@ impl NomenclatureComponents for NameParts {
     fn suffix_name(&self) -> Option<String> {
         self.suffix.clone()
     }
+
+    fn second_last_name(&self) -> Option<String> {
+        self.second_last.clone()
+    }
+}
+
+impl NameFormatting for NameParts {
+    fn naming_convention(&self) -> NamingConvention {
+        self.convention
+    }
 }
@@ -0,0 +1,72 @@
+use crate::{Moniker, NameParts};
+
+/// The role a name plays in a person's record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum NameKind {
+    Legal,
+    Preferred,
+    Former,
+    Designation,
+}
+
+/// A single name a person is (or was) known by.
+///
+/// Dates are ISO 8601 strings (`"2019-06-01"`), matching `PostMortemReport`.
+/// A record without `valid_until` is still in use.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NameRecord {
+    pub kind: NameKind,
+    pub moniker: Moniker,
+    pub valid_from: Option<String>,
+    pub valid_until: Option<String>,
+}
+
+impl NameRecord {
+    pub fn new(kind: NameKind, moniker: Moniker) -> Self {
+        Self {
+            kind,
+            moniker,
+            valid_from: None,
+            valid_until: None,
+        }
+    }
+
+    pub fn legal(name: NameParts) -> Self {
+        Self::new(NameKind::Legal, Moniker::Name(name))
+    }
+
+    pub fn preferred(name: NameParts) -> Self {
+        Self::new(NameKind::Preferred, Moniker::Name(name))
+    }
+
+    pub fn designation(designation: String) -> Self {
+        Self::new(NameKind::Designation, Moniker::Designation(designation))
+    }
+
+    pub fn former(name: NameParts, valid_from: Option<String>, valid_until: String) -> Self {
+        Self {
+            kind: NameKind::Former,
+            moniker: Moniker::Name(name),
+            valid_from,
+            valid_until: Some(valid_until),
+        }
+    }
+
+    pub fn with_valid_from(mut self, date: String) -> Self {
+        self.valid_from = Some(date);
+        self
+    }
+
+    pub fn is_current(&self) -> bool {
+        self.valid_until.is_none()
+    }
+
+    // Whether the record was in use on the given ISO 8601 date.
+    pub fn was_valid_on(&self, date: &str) -> bool {
+        let started = self.valid_from.as_deref().is_none_or(|from| from <= date);
+        let not_ended = self.valid_until.as_deref().is_none_or(|until| date < until);
+        started && not_ended
+    }
+}
@@ -1,11 +1,23 @@
 use crate::{Human};
 use biology::Nomenclature;
+use biology::nomenclature::conventions::{NameFormatting, NameStyle, NamingConvention};
 use biology::nomenclature::nomenclature::NomenclatureComponents;
 
 impl Nomenclature for Human {
     fn display_name(&self) -> String {
         self.name.display_name()
     }
+
+    fn aliases(&self) -> Vec<String> {
+        let mut aliases = vec![self.name.display_name()];
+        for record in &self.aliases {
+            let alias = record.moniker.display_name();
+            if !aliases.contains(&alias) {
+                aliases.push(alias);
+            }
+        }
+        aliases
+    }
 }
 
 impl NomenclatureComponents for Human {
@@ -29,4 +41,18 @@ impl NomenclatureComponents for Human {
     fn suffix_name(&self) -> Option<String> {
         self.name.suffix_name()
     }
-}
\ No newline at end of file
+
+    fn second_last_name(&self) -> Option<String> {
+        self.name.second_last_name()
+    }
+}
+
+impl NameFormatting for Human {
+    fn naming_convention(&self) -> NamingConvention {
+        self.name.naming_convention()
+    }
+
+    fn format_name(&self, style: NameStyle) -> String {
+        self.name.format_name(style)
+    }
+}
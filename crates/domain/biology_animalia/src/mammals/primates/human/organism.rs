@@ -0,0 +1,51 @@
+use biology::{Organism, Sex};
+use biology::genetics::Genome;
+use biology::vitals::post_mortem_report::PostMortemReport;
+use biology::vitals::vital_status::VitalStatus;
+use biology::ecosystem::environment::Environment;
+use core_shared::{Identifier, Kilograms, MetersPerSecond};
+use crate::Human;
+
+impl Organism for Human {
+    fn id(&self) -> Identifier {
+        self.id
+    }
+
+    fn species(&self) -> String {
+        "Homo sapiens".to_string()
+    }
+
+    fn vitals(&self) -> &VitalStatus {
+        &self.vitals
+    }
+
+    fn vitals_mut(&mut self) -> &mut VitalStatus {
+        &mut self.vitals
+    }
+
+    fn sex(&self) -> Sex {
+        self.sex
+    }
+
+    fn body_mass(&self) -> Option<Kilograms> {
+        self.body_mass
+    }
+
+    fn top_speed(&self) -> Option<MetersPerSecond> {
+        self.top_speed
+    }
+
+    fn tolerates(&self, environment: &Environment) -> bool {
+        self.habitat.as_ref().is_none_or(|habitat| habitat.allows(environment))
+    }
+
+    fn genome(&self) -> Option<&Genome> {
+        self.genome.as_ref()
+    }
+
+    // Marks the person deceased and closes the medical record with the same report.
+    fn record_death(&mut self, report: PostMortemReport) {
+        self.vitals = VitalStatus::Deceased(report.clone());
+        self.medical_record.close(report);
+    }
+}
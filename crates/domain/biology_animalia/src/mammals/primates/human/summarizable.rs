@@ -24,9 +24,16 @@ impl DetailedSummary for Human {
 impl Summarizable for Human {
     fn summary(&self) -> String {
         println!("\n--- {} Body ---", self.name.display_name());
+        let other_names = self.aliases().split_off(1);
+        let also_known_as = if other_names.is_empty() {
+            String::new()
+        } else {
+            format!("Also known as: {}\n", other_names.join("; "))
+        };
         format!(
-            "--- Human: {} ---\nAnatomy: {}\nMobility: {}\n\n {}",
+            "--- Human: {} ---\n{}Anatomy: {}\nMobility: {}\n\n {}",
             self.name.display_name(),
+            also_known_as,
             self.describe_anatomy(),
             self.describe_locomotion(),
             self.generate_summary()
@@ -1,4 +1,4 @@
 pub mod human;
 
 // Re-export for convenience
-pub use human::{Human, HumanBuilder, Moniker, NameParts};
+pub use human::{Human, HumanBuilder, Moniker, NameKind, NameParts, NameRecord};
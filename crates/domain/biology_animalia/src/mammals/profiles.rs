@@ -0,0 +1,220 @@
+// Species profiles for common domestic and wild mammals.
+// Figures are rough adult averages for modelling; lifespans are in days, the ticks `Mortality` counts, and reach it through `Organism::lifespan`.
+
+use biology::anatomy::appendage::{Appendage, FinPosition};
+use biology::anatomy::fin_measurements::FinMeasurements;
+use biology::anatomy::leg_measurements::LegMeasurements;
+use biology::anatomy::limb_status::LimbStatus;
+use biology::anatomy::wing_measurements::WingMeasurements;
+use biology::characteristics::locomotion::Locomotion;
+use biology::vitals::blood_pressure::BloodPressure;
+use biology::vitals::live_vitals::LiveVitals;
+use biology::vitals::reference_ranges::ReferenceRanges;
+use biology::vitals::vital_types::{Celsius, BPM};
+use core_shared::{Centimeters, Direction, Kilograms, MetersPerSecond};
+use crate::mammals::features::Diet;
+
+const SIDES: [Direction; 2] = [Direction::Left, Direction::Right];
+
+/// The limbs a mammal is built with.
+#[derive(Debug, Clone, Copy)]
+pub enum BodyPlan {
+    // Four legs, fore and hind alike.
+    Quadruped(LegMeasurements),
+    // Membranous wings stretched over the forelimbs, and two hind legs: bats.
+    Winged { wings: WingMeasurements, legs: LegMeasurements },
+    // Forelimbs as flippers and a tail fluke, with no hind legs: whales and dolphins.
+    Flippered { flippers: FinMeasurements, fluke: FinMeasurements },
+}
+
+impl BodyPlan {
+    pub fn appendages(&self) -> Vec<Appendage> {
+        let leg = |side, measurements| Appendage::Leg { side, measurements, status: LimbStatus::Intact };
+        match *self {
+            BodyPlan::Quadruped(legs) => SIDES.into_iter().chain(SIDES).map(|side| leg(side, legs)).collect(),
+            BodyPlan::Winged { wings, legs } => SIDES
+                .into_iter()
+                .map(|side| Appendage::Wing { side, measurements: wings, status: LimbStatus::Intact })
+                .chain(SIDES.into_iter().map(|side| leg(side, legs)))
+                .collect(),
+            BodyPlan::Flippered { flippers, fluke } => SIDES
+                .into_iter()
+                .map(|side| Appendage::Fin {
+                    position: FinPosition::Pectoral(side),
+                    measurements: flippers,
+                    status: LimbStatus::Intact,
+                })
+                .chain([Appendage::Fin {
+                    position: FinPosition::Caudal,
+                    measurements: fluke,
+                    status: LimbStatus::Intact,
+                }])
+                .collect(),
+        }
+    }
+}
+
+/// Everything `Mammal::from_profile` needs to build a typical adult of a species.
+///
+/// Profiles are plain data, like reproductive strategies, so adding a
+/// species means adding a constant here and listing it in `PROFILES`.
+#[derive(Debug, Clone, Copy)]
+pub struct MammalProfile {
+    pub species: &'static str,
+    pub common_name: &'static str,
+    pub body_plan: BodyPlan,
+    pub locomotion: Locomotion,
+    pub has_fur: bool,
+    pub diet: Diet,
+    pub body_mass: Kilograms,
+    pub top_speed: MetersPerSecond,
+    // A typical resting reading; it is not range-checked.
+    pub blood_pressure: BloodPressure,
+    pub ranges: ReferenceRanges,
+    // Typical lifespan in days.
+    pub lifespan: u32,
+}
+
+impl MammalProfile {
+    // The species itself, or any of its subspecies ("Equus caballus przewalskii").
+    pub fn applies_to(&self, species: &str) -> bool {
+        species == self.species || species.strip_prefix(self.species).is_some_and(|rest| rest.starts_with(' '))
+    }
+
+    // A healthy animal at rest: the middle of every reference range.
+    pub fn resting_vitals(&self) -> LiveVitals {
+        let (pulse, respiration, temperature) = self.ranges.midpoint();
+        LiveVitals::new(self.blood_pressure, temperature, pulse, respiration)
+    }
+
+    pub fn lifespan_years(&self) -> u32 {
+        self.lifespan / 365
+    }
+}
+
+/// Canis familiaris - a medium-sized dog, about the build of a labrador.
+pub const DOG: MammalProfile = MammalProfile {
+    species: "Canis familiaris",
+    common_name: "Domestic dog",
+    body_plan: BodyPlan::Quadruped(LegMeasurements { inseam: Centimeters(30), quad: Centimeters(25) }),
+    locomotion: Locomotion::Terrestrial,
+    has_fur: true,
+    diet: Diet::Omnivore,
+    body_mass: Kilograms(30),
+    top_speed: MetersPerSecond(13),
+    blood_pressure: BloodPressure(130, 75),
+    ranges: ReferenceRanges {
+        pulse: (BPM(60), BPM(140)),
+        respiration: (10, 30),
+        temperature: (Celsius(38), Celsius(39)),
+    },
+    lifespan: 4380,
+};
+
+/// Felis catus - small, with a fast heart.
+pub const CAT: MammalProfile = MammalProfile {
+    species: "Felis catus",
+    common_name: "Domestic cat",
+    body_plan: BodyPlan::Quadruped(LegMeasurements { inseam: Centimeters(20), quad: Centimeters(12) }),
+    locomotion: Locomotion::Terrestrial,
+    has_fur: true,
+    diet: Diet::Carnivore,
+    body_mass: Kilograms(4),
+    top_speed: MetersPerSecond(13),
+    blood_pressure: BloodPressure(125, 80),
+    ranges: ReferenceRanges {
+        pulse: (BPM(140), BPM(220)),
+        respiration: (20, 30),
+        temperature: (Celsius(38), Celsius(39)),
+    },
+    lifespan: 5475,
+};
+
+/// Equus caballus - a riding horse; a large heart beats slowly.
+pub const HORSE: MammalProfile = MammalProfile {
+    species: "Equus caballus",
+    common_name: "Horse",
+    body_plan: BodyPlan::Quadruped(LegMeasurements { inseam: Centimeters(90), quad: Centimeters(60) }),
+    locomotion: Locomotion::Terrestrial,
+    has_fur: true,
+    diet: Diet::Herbivore,
+    body_mass: Kilograms(500),
+    top_speed: MetersPerSecond(19),
+    blood_pressure: BloodPressure(115, 70),
+    ranges: ReferenceRanges {
+        pulse: (BPM(28), BPM(44)),
+        respiration: (8, 16),
+        temperature: (Celsius(37), Celsius(38)),
+    },
+    lifespan: 10220,
+};
+
+/// Bos taurus - a beef or dairy cow.
+pub const CATTLE: MammalProfile = MammalProfile {
+    species: "Bos taurus",
+    common_name: "Cattle",
+    body_plan: BodyPlan::Quadruped(LegMeasurements { inseam: Centimeters(70), quad: Centimeters(55) }),
+    locomotion: Locomotion::Terrestrial,
+    has_fur: true,
+    diet: Diet::Herbivore,
+    body_mass: Kilograms(600),
+    top_speed: MetersPerSecond(11),
+    blood_pressure: BloodPressure(140, 90),
+    ranges: ReferenceRanges {
+        pulse: (BPM(48), BPM(84)),
+        respiration: (26, 50),
+        temperature: (Celsius(38), Celsius(39)),
+    },
+    lifespan: 7300,
+};
+
+/// Pteropus vampyrus - the large flying fox, one of the biggest bats, with a 1.5 m wingspan.
+pub const LARGE_FLYING_FOX: MammalProfile = MammalProfile {
+    species: "Pteropus vampyrus",
+    common_name: "Large flying fox",
+    body_plan: BodyPlan::Winged {
+        wings: WingMeasurements { length: Centimeters(70), chord: Centimeters(30) },
+        legs: LegMeasurements { inseam: Centimeters(8), quad: Centimeters(3) },
+    },
+    locomotion: Locomotion::Aerial,
+    has_fur: true,
+    diet: Diet::Frugivore,
+    body_mass: Kilograms(1),
+    top_speed: MetersPerSecond(10),
+    blood_pressure: BloodPressure(110, 70),
+    ranges: ReferenceRanges {
+        pulse: (BPM(200), BPM(400)),
+        respiration: (30, 60),
+        temperature: (Celsius(35), Celsius(38)),
+    },
+    lifespan: 5475,
+};
+
+/// Megaptera novaeangliae - the humpback whale; its pulse slows to a few beats a minute on a dive.
+pub const HUMPBACK_WHALE: MammalProfile = MammalProfile {
+    species: "Megaptera novaeangliae",
+    common_name: "Humpback whale",
+    body_plan: BodyPlan::Flippered {
+        flippers: FinMeasurements { length: Centimeters(460), base: Centimeters(100) },
+        fluke: FinMeasurements { length: Centimeters(550), base: Centimeters(120) },
+    },
+    locomotion: Locomotion::Aquatic,
+    has_fur: false,
+    diet: Diet::FilterFeeder,
+    body_mass: Kilograms(30000),
+    top_speed: MetersPerSecond(7),
+    blood_pressure: BloodPressure(120, 80),
+    ranges: ReferenceRanges {
+        pulse: (BPM(4), BPM(35)),
+        respiration: (1, 4),
+        temperature: (Celsius(36), Celsius(37)),
+    },
+    lifespan: 18250,
+};
+
+pub const PROFILES: &[MammalProfile] = &[DOG, CAT, HORSE, CATTLE, LARGE_FLYING_FOX, HUMPBACK_WHALE];
+
+// The profile for a species, if there is one.
+pub fn profile_for(species: &str) -> Option<&'static MammalProfile> {
+    PROFILES.iter().find(|profile| profile.applies_to(species))
+}
@@ -0,0 +1,145 @@
+//! Migration chains for organism and population documents.
+//!
+//! Each chain lists one step per schema version bump, oldest first.
+//! Never edit a released step; append a new one instead.
+
+use biology::ecosystem::environment::{Climate, Terrain};
+use biology::ecosystem::resources::{ResourceKind, Resources};
+use biology::persistence::{MigrationStep, PersistenceError};
+use serde_json::Value;
+
+pub const ORGANISM_MIGRATIONS: &[MigrationStep] = &[MigrationStep {
+    from: 0,
+    description: "Tag bare Animal, Mammal and Human values with their organism type",
+    apply: tag_bare_organism,
+}];
+
+// Populations could not be serialized before documents were versioned, so the chain starts at 1.
+pub const POPULATION_MIGRATIONS: &[MigrationStep] = &[
+    MigrationStep {
+        from: 1,
+        description: "Replace interaction member indices with member identifiers",
+        apply: index_ids_to_identifiers,
+    },
+    MigrationStep {
+        from: 2,
+        description: "Type competition resources and stock the environment with resource pools",
+        apply: type_resources,
+    },
+];
+
+const INTERACTION_ID_FIELDS: [&str; 8] = [
+    "predator_id",
+    "prey_id",
+    "competitor1_id",
+    "competitor2_id",
+    "participant1_id",
+    "participant2_id",
+    "parasite_id",
+    "host_id",
+];
+
+// Before version 1, organisms were saved as their bare struct, so the type
+// has to be recognised from the fields only that struct has. Humans are the
+// only organisms saved without a species.
+fn tag_bare_organism(mut data: Value) -> Result<Value, PersistenceError> {
+    let fields = data.as_object_mut().ok_or_else(|| PersistenceError::Migration {
+        from: 0,
+        reason: "organism is not a JSON object".to_string(),
+    })?;
+
+    let organism_type = if !fields.contains_key("species") && fields.contains_key("name") {
+        "human"
+    } else if fields.contains_key("has_fur") {
+        "mammal"
+    } else if fields.contains_key("species") {
+        "animal"
+    } else {
+        return Err(PersistenceError::Migration {
+            from: 0,
+            reason: "unrecognised organism fields".to_string(),
+        });
+    };
+
+    fields.insert("type".to_string(), Value::from(organism_type));
+    Ok(data)
+}
+
+// Version 1 interactions referenced members by their position in `members`.
+// Indices outside the member list never pointed at anyone, and the population
+// now rejects dangling references, so those interactions are dropped.
+fn index_ids_to_identifiers(mut data: Value) -> Result<Value, PersistenceError> {
+    let malformed = |reason: &str| PersistenceError::Migration {
+        from: 1,
+        reason: reason.to_string(),
+    };
+
+    let member_ids: Vec<Value> = data
+        .get("members")
+        .and_then(Value::as_array)
+        .ok_or_else(|| malformed("population has no member list"))?
+        .iter()
+        .map(|member| member.get("id").cloned().unwrap_or(Value::Null))
+        .collect();
+
+    let interactions = data
+        .get_mut("interactions")
+        .and_then(Value::as_array_mut)
+        .ok_or_else(|| malformed("population has no interaction list"))?;
+
+    interactions.retain_mut(|interaction| {
+        let Some(fields) = interaction.as_object_mut() else {
+            return false;
+        };
+        INTERACTION_ID_FIELDS.iter().all(|field| match fields.get_mut(*field) {
+            Some(value) => match value.as_u64().and_then(|index| member_ids.get(index as usize)) {
+                Some(id) if !id.is_null() => {
+                    *value = id.clone();
+                    true
+                }
+                _ => false,
+            },
+            None => true,
+        })
+    });
+
+    Ok(data)
+}
+
+// Version 2 named competition resources in free text and had no resource
+// pools. Labels are matched by keyword ("Water sources" becomes water);
+// anything unrecognised was almost always food, so it becomes food biomass.
+// The environment gets the typical pools for its climate and terrain.
+fn type_resources(mut data: Value) -> Result<Value, PersistenceError> {
+    let malformed = |reason: String| PersistenceError::Migration { from: 2, reason };
+
+    let environment = data
+        .get_mut("environment")
+        .and_then(Value::as_object_mut)
+        .ok_or_else(|| malformed("population has no environment".to_string()))?;
+    if !environment.contains_key("resources") {
+        let climate: Climate = serde_json::from_value(environment.get("climate").cloned().unwrap_or(Value::Null))
+            .map_err(|error| malformed(format!("unreadable climate: {}", error)))?;
+        let terrain: Terrain = serde_json::from_value(environment.get("terrain").cloned().unwrap_or(Value::Null))
+            .map_err(|error| malformed(format!("unreadable terrain: {}", error)))?;
+        let resources = serde_json::to_value(Resources::for_habitat(&climate, &terrain))?;
+        environment.insert("resources".to_string(), resources);
+    }
+
+    let interactions = data
+        .get_mut("interactions")
+        .and_then(Value::as_array_mut)
+        .ok_or_else(|| malformed("population has no interaction list".to_string()))?;
+    for interaction in interactions {
+        if interaction.get("type").and_then(Value::as_str) != Some("competition") {
+            continue;
+        }
+        if let Some(resource) = interaction.get_mut("resource") {
+            let label = resource.as_str().unwrap_or_default();
+            let kind = ResourceKind::from_label(label).unwrap_or(ResourceKind::FoodBiomass);
+            *resource = serde_json::to_value(kind)?;
+        }
+    }
+
+    Ok(data)
+}
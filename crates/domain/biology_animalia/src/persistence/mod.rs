@@ -0,0 +1,12 @@
+//! Versioned documents for organisms and populations.
+//!
+//! Available with the `serde` feature. Builds on `biology::persistence`:
+//! the records here are the document kinds, and `migrations` holds the
+//! upgrade chain for each of them.
+
+pub mod migrations;
+pub mod organism_record;
+pub mod population_record;
+
+pub use organism_record::OrganismRecord;
+pub use population_record::PopulationRecord;
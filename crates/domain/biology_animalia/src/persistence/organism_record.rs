@@ -0,0 +1,158 @@
+use std::any::Any;
+use biology::Organism;
+use biology::persistence::{MigrationStep, Versioned};
+use serde::{Deserialize, Serialize};
+use crate::persistence::migrations::ORGANISM_MIGRATIONS;
+use crate::{Amphibian, Animal, Arachnid, Bird, Fish, Human, Insect, Mammal, Mollusc, Reptile};
+#[cfg(feature = "plantae")]
+use biology_plantae::Plant;
+
+/// Any organism this crate can save, tagged with its concrete type.
+///
+/// With the `plantae` feature it also saves `biology_plantae::Plant`, so a
+/// meadow's clover is kept along with the rabbits that graze it.
+///
+/// ```json
+/// { "type": "mammal", "id": "...", "species": "Felis catus", "vitals": { ... }, "has_fur": true }
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OrganismRecord {
+    Animal(Animal),
+    Mammal(Mammal),
+    // Boxed: a human with a medical record dwarfs every other variant.
+    Human(Box<Human>),
+    Bird(Bird),
+    Fish(Fish),
+    Reptile(Reptile),
+    Amphibian(Amphibian),
+    Insect(Insect),
+    Arachnid(Arachnid),
+    Mollusc(Mollusc),
+    #[cfg(feature = "plantae")]
+    Plant(Plant),
+}
+
+impl OrganismRecord {
+    // Copies a population member into a record, if it is a type this crate knows how to save.
+    pub fn from_organism(organism: &dyn Organism) -> Option<Self> {
+        let organism: &dyn Any = organism;
+        #[cfg(feature = "plantae")]
+        if let Some(plant) = organism.downcast_ref::<Plant>() {
+            return Some(OrganismRecord::Plant(plant.clone()));
+        }
+        if let Some(human) = organism.downcast_ref::<Human>() {
+            Some(OrganismRecord::Human(Box::new(human.clone())))
+        } else if let Some(mammal) = organism.downcast_ref::<Mammal>() {
+            Some(OrganismRecord::Mammal(mammal.clone()))
+        } else if let Some(bird) = organism.downcast_ref::<Bird>() {
+            Some(OrganismRecord::Bird(bird.clone()))
+        } else if let Some(fish) = organism.downcast_ref::<Fish>() {
+            Some(OrganismRecord::Fish(fish.clone()))
+        } else if let Some(reptile) = organism.downcast_ref::<Reptile>() {
+            Some(OrganismRecord::Reptile(reptile.clone()))
+        } else if let Some(amphibian) = organism.downcast_ref::<Amphibian>() {
+            Some(OrganismRecord::Amphibian(amphibian.clone()))
+        } else if let Some(insect) = organism.downcast_ref::<Insect>() {
+            Some(OrganismRecord::Insect(insect.clone()))
+        } else if let Some(arachnid) = organism.downcast_ref::<Arachnid>() {
+            Some(OrganismRecord::Arachnid(arachnid.clone()))
+        } else if let Some(mollusc) = organism.downcast_ref::<Mollusc>() {
+            Some(OrganismRecord::Mollusc(mollusc.clone()))
+        } else {
+            organism
+                .downcast_ref::<Animal>()
+                .map(|animal| OrganismRecord::Animal(animal.clone()))
+        }
+    }
+
+    pub fn into_organism(self) -> Box<dyn Organism> {
+        match self {
+            OrganismRecord::Animal(animal) => Box::new(animal),
+            OrganismRecord::Mammal(mammal) => Box::new(mammal),
+            OrganismRecord::Human(human) => human,
+            OrganismRecord::Bird(bird) => Box::new(bird),
+            OrganismRecord::Fish(fish) => Box::new(fish),
+            OrganismRecord::Reptile(reptile) => Box::new(reptile),
+            OrganismRecord::Amphibian(amphibian) => Box::new(amphibian),
+            OrganismRecord::Insect(insect) => Box::new(insect),
+            OrganismRecord::Arachnid(arachnid) => Box::new(arachnid),
+            OrganismRecord::Mollusc(mollusc) => Box::new(mollusc),
+            #[cfg(feature = "plantae")]
+            OrganismRecord::Plant(plant) => Box::new(plant),
+        }
+    }
+}
+
+impl Versioned for OrganismRecord {
+    const KIND: &'static str = "organism";
+    const SCHEMA_VERSION: u32 = 1;
+    const MIGRATIONS: &'static [MigrationStep] = ORGANISM_MIGRATIONS;
+}
+
+impl From<Animal> for OrganismRecord {
+    fn from(animal: Animal) -> Self {
+        OrganismRecord::Animal(animal)
+    }
+}
+
+impl From<Mammal> for OrganismRecord {
+    fn from(mammal: Mammal) -> Self {
+        OrganismRecord::Mammal(mammal)
+    }
+}
+
+impl From<Human> for OrganismRecord {
+    fn from(human: Human) -> Self {
+        OrganismRecord::Human(Box::new(human))
+    }
+}
+
+impl From<Bird> for OrganismRecord {
+    fn from(bird: Bird) -> Self {
+        OrganismRecord::Bird(bird)
+    }
+}
+
+impl From<Fish> for OrganismRecord {
+    fn from(fish: Fish) -> Self {
+        OrganismRecord::Fish(fish)
+    }
+}
+
+impl From<Reptile> for OrganismRecord {
+    fn from(reptile: Reptile) -> Self {
+        OrganismRecord::Reptile(reptile)
+    }
+}
+
+impl From<Amphibian> for OrganismRecord {
+    fn from(amphibian: Amphibian) -> Self {
+        OrganismRecord::Amphibian(amphibian)
+    }
+}
+
+impl From<Insect> for OrganismRecord {
+    fn from(insect: Insect) -> Self {
+        OrganismRecord::Insect(insect)
+    }
+}
+
+impl From<Arachnid> for OrganismRecord {
+    fn from(arachnid: Arachnid) -> Self {
+        OrganismRecord::Arachnid(arachnid)
+    }
+}
+
+impl From<Mollusc> for OrganismRecord {
+    fn from(mollusc: Mollusc) -> Self {
+        OrganismRecord::Mollusc(mollusc)
+    }
+}
+
+#[cfg(feature = "plantae")]
+impl From<Plant> for OrganismRecord {
+    fn from(plant: Plant) -> Self {
+        OrganismRecord::Plant(plant)
+    }
+}
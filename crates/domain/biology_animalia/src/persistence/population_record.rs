@@ -0,0 +1,96 @@
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::interactions::Interaction;
+use biology::ecosystem::landscape::{Landscape, Position};
+use biology::ecosystem::population::{Population, PopulationError};
+use biology::persistence::{MigrationStep, PersistenceError, Versioned};
+use core_shared::Identifier;
+use serde::{Deserialize, Serialize};
+use crate::persistence::migrations::POPULATION_MIGRATIONS;
+use crate::persistence::organism_record::OrganismRecord;
+
+/// An owned snapshot of a population: its environment, members,
+/// interactions and parent links, and the landscape they are spread over,
+/// if any.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PopulationRecord {
+    pub environment: Environment,
+    pub members: Vec<OrganismRecord>,
+    pub interactions: Vec<Interaction>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub landscape: Option<Landscape>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub positions: Vec<(Identifier, Position)>,
+    // Each member's parents, mother first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parents: Vec<(Identifier, Vec<Identifier>)>,
+}
+
+impl PopulationRecord {
+    pub fn new(environment: Environment) -> Self {
+        Self {
+            environment,
+            members: Vec::new(),
+            interactions: Vec::new(),
+            landscape: None,
+            positions: Vec::new(),
+            parents: Vec::new(),
+        }
+    }
+
+    // Rebuilds the population, checking every interaction, position and parent link against the members.
+    pub fn into_population(self) -> Result<Population, PersistenceError> {
+        let invalid = |error: PopulationError| {
+            PersistenceError::Invalid {
+                reason: error.to_string(),
+            }
+        };
+
+        let mut population = Population::new(self.environment);
+        if let Some(landscape) = self.landscape {
+            population = population.with_landscape(landscape);
+        }
+        for member in self.members {
+            population.insert_boxed(member.into_organism()).map_err(invalid)?;
+        }
+        for interaction in self.interactions {
+            population.add_interaction(interaction).map_err(invalid)?;
+        }
+        for (id, position) in self.positions {
+            population.place(id, position).map_err(invalid)?;
+        }
+        for (child, parents) in self.parents {
+            population.set_parents(child, &parents).map_err(invalid)?;
+        }
+        Ok(population)
+    }
+}
+
+impl TryFrom<&Population> for PopulationRecord {
+    type Error = PersistenceError;
+
+    fn try_from(population: &Population) -> Result<Self, Self::Error> {
+        let members = population
+            .members()
+            .map(|organism| {
+                OrganismRecord::from_organism(organism).ok_or_else(|| PersistenceError::Invalid {
+                    reason: format!("{} ({}) cannot be saved", organism.display_name(), organism.species()),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            environment: population.environment.clone(),
+            members,
+            interactions: population.interactions().to_vec(),
+            landscape: population.landscape().cloned(),
+            positions: population.positions(),
+            parents: population.parentage(),
+        })
+    }
+}
+
+impl Versioned for PopulationRecord {
+    const KIND: &'static str = "population";
+    const SCHEMA_VERSION: u32 = 3;
+    const MIGRATIONS: &'static [MigrationStep] = POPULATION_MIGRATIONS;
+}
@@ -0,0 +1,379 @@
+// How animals reproduce, and how their young are built.
+// Figures are rough species averages for modelling, with ticks as days.
+
+use std::any::Any;
+use biology::nomenclature::conventions::NamingConvention;
+use biology::reproduction::{Birth, Development, MatingSystem, ReproductiveStrategy};
+use biology::{Organism, Sex};
+use biology::builder::OrganismBuilderExt;
+use crate::{Amphibian, Animal, Arachnid, Bird, Fish, Human, HumanBuilder, Insect, Mammal, Moniker, Mollusc, NameParts, Reptile};
+
+/// Homo sapiens - one child at a time after nine months.
+pub const HUMAN: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Homo sapiens",
+    mating: MatingSystem::Sexual,
+    development: Development::Gestation,
+    period: 280,
+    litter: (1, 1),
+    maturity: 5475,
+    interval: 730,
+};
+
+/// Canis latrans - one litter a year, born in spring dens.
+pub const COYOTE: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Canis latrans",
+    mating: MatingSystem::Sexual,
+    development: Development::Gestation,
+    period: 63,
+    litter: (4, 7),
+    maturity: 365,
+    interval: 365,
+};
+
+/// Vulpes vulpes - one litter of kits a year.
+pub const RED_FOX: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Vulpes vulpes",
+    mating: MatingSystem::Sexual,
+    development: Development::Gestation,
+    period: 52,
+    litter: (4, 6),
+    maturity: 300,
+    interval: 365,
+};
+
+/// Lepus californicus - several litters of leverets a year.
+pub const BLACK_TAILED_JACKRABBIT: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Lepus californicus",
+    mating: MatingSystem::Sexual,
+    development: Development::Gestation,
+    period: 42,
+    litter: (2, 4),
+    maturity: 210,
+    interval: 30,
+};
+
+/// Mus musculus - large litters, and mates again within days of giving birth.
+pub const HOUSE_MOUSE: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Mus musculus",
+    mating: MatingSystem::Sexual,
+    development: Development::Gestation,
+    period: 20,
+    litter: (5, 8),
+    maturity: 42,
+    interval: 3,
+};
+
+/// Gallus gallus - a clutch of eggs, hatching after three weeks.
+pub const CHICKEN: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Gallus gallus",
+    mating: MatingSystem::Sexual,
+    development: Development::Incubation,
+    period: 21,
+    litter: (8, 12),
+    maturity: 150,
+    interval: 30,
+};
+
+/// Struthio camelus - each hen lays into a shared nest scrape, and the pair incubates.
+pub const OSTRICH: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Struthio camelus",
+    mating: MatingSystem::Sexual,
+    development: Development::Incubation,
+    period: 42,
+    litter: (7, 10),
+    maturity: 1095,
+    interval: 365,
+};
+
+/// Aptenodytes forsteri - a single egg, kept on the father's feet through the winter.
+pub const EMPEROR_PENGUIN: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Aptenodytes forsteri",
+    mating: MatingSystem::Sexual,
+    development: Development::Incubation,
+    period: 64,
+    litter: (1, 1),
+    maturity: 1460,
+    interval: 365,
+};
+
+/// Anas platyrhynchos - one large clutch each spring.
+pub const MALLARD: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Anas platyrhynchos",
+    mating: MatingSystem::Sexual,
+    development: Development::Incubation,
+    period: 28,
+    litter: (8, 13),
+    maturity: 365,
+    interval: 365,
+};
+
+/// Passer domesticus - small clutches, and several broods a summer.
+pub const HOUSE_SPARROW: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Passer domesticus",
+    mating: MatingSystem::Sexual,
+    development: Development::Incubation,
+    period: 12,
+    litter: (3, 5),
+    maturity: 365,
+    interval: 20,
+};
+
+/// Salmo trutta - spawns in gravel redds in autumn; the eggs hatch over the winter.
+pub const BROWN_TROUT: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Salmo trutta",
+    mating: MatingSystem::Sexual,
+    development: Development::Incubation,
+    period: 90,
+    litter: (500, 1500),
+    maturity: 1095,
+    interval: 365,
+};
+
+/// Chelonia mydas - large clutches buried on the beach, every other year.
+pub const GREEN_SEA_TURTLE: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Chelonia mydas",
+    mating: MatingSystem::Sexual,
+    development: Development::Incubation,
+    period: 60,
+    litter: (80, 120),
+    maturity: 9125,
+    interval: 730,
+};
+
+/// Iguana iguana - a clutch buried in a sandy burrow, hatching after three months.
+pub const GREEN_IGUANA: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Iguana iguana",
+    mating: MatingSystem::Sexual,
+    development: Development::Incubation,
+    period: 90,
+    litter: (20, 45),
+    maturity: 1095,
+    interval: 365,
+};
+
+/// Thamnophis sirtalis - bears live young in late summer, after mating out of brumation.
+pub const COMMON_GARTER_SNAKE: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Thamnophis sirtalis",
+    mating: MatingSystem::Sexual,
+    development: Development::Gestation,
+    period: 90,
+    litter: (10, 40),
+    maturity: 730,
+    interval: 365,
+};
+
+/// Rana temporaria - spawns a mass of eggs in spring ponds; tadpoles hatch within weeks.
+pub const COMMON_FROG: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Rana temporaria",
+    mating: MatingSystem::Sexual,
+    development: Development::Incubation,
+    period: 21,
+    litter: (1000, 2000),
+    maturity: 730,
+    interval: 365,
+};
+
+/// Cornu aspersum - hermaphrodites; after mating both snails lay eggs.
+pub const GARDEN_SNAIL: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Cornu aspersum",
+    mating: MatingSystem::Hermaphroditic,
+    development: Development::Incubation,
+    period: 20,
+    litter: (40, 80),
+    maturity: 365,
+    interval: 30,
+};
+
+/// Acyrthosiphon pisum - in summer, females bear live young without mating.
+pub const PEA_APHID: ReproductiveStrategy = ReproductiveStrategy {
+    species: "Acyrthosiphon pisum",
+    mating: MatingSystem::Asexual,
+    development: Development::Gestation,
+    period: 1,
+    litter: (3, 10),
+    maturity: 7,
+    interval: 0,
+};
+
+pub const STRATEGIES: &[ReproductiveStrategy] = &[
+    HUMAN,
+    COYOTE,
+    RED_FOX,
+    BLACK_TAILED_JACKRABBIT,
+    HOUSE_MOUSE,
+    CHICKEN,
+    OSTRICH,
+    EMPEROR_PENGUIN,
+    MALLARD,
+    HOUSE_SPARROW,
+    BROWN_TROUT,
+    GREEN_SEA_TURTLE,
+    GREEN_IGUANA,
+    COMMON_GARTER_SNAKE,
+    COMMON_FROG,
+    GARDEN_SNAIL,
+    PEA_APHID,
+];
+
+/// Builds a newborn of the mother's type, for the `Reproduction` rule.
+///
+/// Humans take their family name from their parents, following the
+/// naming convention of the parent it passes down from. Every other newborn
+/// keeps its mother's species and common name; mammals and animals also
+/// keep her locomotion, and mammals her fur and the body plan of a profiled
+/// species. Birds hatch with her plumage, beak and flight, fish with her
+/// water, scales and habitat, and reptiles
+/// with her scales, body plan, temperatures, shedding and way of bearing
+/// young. Amphibians hatch as larvae of their mother's order; insects keep
+/// her wings and locomotion, arachnids her order and venom, and molluscs her
+/// class and shell. An inherited genome is expressed instead where it has
+/// the loci: blood type and stature for humans, fur for mammals.
+///
+/// Each newborn is assembled by its type's builder without the builder's
+/// checks, which its mother has already passed. Newborns have no body mass
+/// or top speed recorded, and take the id in `birth`.
+pub fn offspring(birth: &Birth) -> Option<Box<dyn Organism>> {
+    let mother: &dyn Any = birth.mother;
+    let father: Option<&dyn Any> = birth.father.map(|father| father as &dyn Any);
+
+    if let Some(mother) = mother.downcast_ref::<Human>() {
+        let father = father.and_then(|father| father.downcast_ref::<Human>());
+        let mut builder = HumanBuilder::new().id(birth.id).sex(birth.sex);
+        if let Some(name) = family_name(mother, father, birth.sex) {
+            builder = builder.name(name);
+        }
+        if let Some(genome) = birth.genome.clone() {
+            builder = builder.genome(genome);
+        }
+        Some(Box::new(builder.build()))
+    } else if let Some(mother) = mother.downcast_ref::<Mammal>() {
+        let mut young = inherit(Mammal::builder(&mother.species), birth, &mother.common_name)
+            .locomotion(mother.locomotion)
+            .fur(mother.has_fur);
+        if let Some(profile) = mother.profile() {
+            young = young.appendages(profile.body_plan.appendages());
+        }
+        if let Some(genome) = birth.genome.clone() {
+            young = young.genome(genome);
+        }
+        Some(Box::new(young.assemble()))
+    } else if let Some(mother) = mother.downcast_ref::<Bird>() {
+        let chick = inherit(Bird::builder(&mother.species), birth, &mother.common_name)
+            .plumage(mother.plumage.clone())
+            .beak(mother.beak)
+            .flight(mother.flight)
+            .locomotion(mother.locomotion);
+        Some(Box::new(chick.assemble()))
+    } else if let Some(mother) = mother.downcast_ref::<Fish>() {
+        let mut fry = inherit(Fish::builder(&mother.species), birth, &mother.common_name)
+            .water(mother.water)
+            .scales(mother.scales);
+        if let Some(habitat) = mother.habitat.clone() {
+            fry = fry.habitat(habitat);
+        }
+        Some(Box::new(fry.assemble()))
+    } else if let Some(mother) = mother.downcast_ref::<Reptile>() {
+        let mut young = inherit(Reptile::builder(&mother.species), birth, &mother.common_name)
+            .locomotion(mother.locomotion)
+            .scales(mother.scales)
+            .temperatures(mother.preferred_temperature, mother.brumation_temperature)
+            .shed_interval(mother.shedding.interval_days);
+        if mother.is_limbless() {
+            young = young.limbless();
+        }
+        if mother.live_bearing {
+            young = young.live_birth();
+        }
+        Some(Box::new(young.assemble()))
+    } else if let Some(mother) = mother.downcast_ref::<Amphibian>() {
+        let larva = inherit(Amphibian::builder(&mother.species), birth, &mother.common_name)
+            .order(mother.order)
+            .larval_period(mother.larval_period)
+            .larva();
+        Some(Box::new(larva.assemble()))
+    } else if let Some(mother) = mother.downcast_ref::<Insect>() {
+        let nymph = inherit(Insect::builder(&mother.species), birth, &mother.common_name)
+            .locomotion(mother.locomotion)
+            .wing_pairs(mother.wing_pairs());
+        Some(Box::new(nymph.assemble()))
+    } else if let Some(mother) = mother.downcast_ref::<Arachnid>() {
+        let spiderling = inherit(Arachnid::builder(&mother.species), birth, &mother.common_name)
+            .order(mother.order)
+            .venom(mother.venomous);
+        Some(Box::new(spiderling.assemble()))
+    } else if let Some(mother) = mother.downcast_ref::<Mollusc>() {
+        let young = inherit(Mollusc::builder(&mother.species), birth, &mother.common_name)
+            .class(mother.class)
+            .locomotion(mother.locomotion)
+            .shell(mother.has_shell);
+        Some(Box::new(young.assemble()))
+    } else {
+        mother.downcast_ref::<Animal>().map(|mother| {
+            let young = inherit(Animal::builder(&mother.species), birth, &mother.common_name).locomotion(mother.locomotion);
+            Box::new(young.assemble()) as Box<dyn Organism>
+        })
+    }
+}
+
+// What every newborn takes from its birth and its mother: the id, sex and common name.
+fn inherit<B: OrganismBuilderExt>(builder: B, birth: &Birth, common_name: &Option<String>) -> B {
+    let builder = builder.id(birth.id).sex(birth.sex);
+    match common_name {
+        Some(common_name) => builder.common_name(common_name),
+        None => builder,
+    }
+}
+
+// The surname(s) a newborn is given; the first name is left for the parents to choose.
+fn family_name(mother: &Human, father: Option<&Human>, sex: Sex) -> Option<NameParts> {
+    let parts = |human: &Human| match human.legal_name() {
+        Some(Moniker::Name(parts)) => Some(parts.clone()),
+        _ => None,
+    };
+    let mother = parts(mother);
+    let father = father.and_then(parts);
+    let from = father.clone().or_else(|| mother.clone())?;
+
+    let mut name = NameParts {
+        convention: from.convention,
+        ..NameParts::default()
+    };
+    match from.convention {
+        NamingConvention::GivenNameFirst | NamingConvention::FamilyNameFirst => {
+            name.last = from.last;
+        }
+        NamingConvention::DoubleSurname => {
+            // The father's first surname, then the mother's first surname.
+            name.last = father.as_ref().and_then(|father| father.last.clone()).or(from.last);
+            name.second_last = mother.and_then(|mother| mother.last).filter(|_| father.is_some());
+        }
+        NamingConvention::Patronymic => {
+            // From the parent's given name: Jón → Jónsson, Jónsdóttir, or Jónsbur.
+            let ending = match sex {
+                Sex::Male => "sson",
+                Sex::Female => "sdóttir",
+                Sex::Unknown => "sbur",
+            };
+            name.last = from.first.map(|first| format!("{}{}", first, ending));
+        }
+    }
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strategies_cover_their_species() {
+        assert!(COYOTE.applies_to("Canis latrans"));
+        assert!(!COYOTE.applies_to("Canis lupus"));
+        assert!(STRATEGIES.iter().all(|strategy| strategy.litter.0 <= strategy.litter.1));
+        assert_eq!(CHICKEN.development, Development::Incubation);
+        assert_eq!(COMMON_GARTER_SNAKE.development, Development::Gestation);
+        assert_eq!(COMMON_FROG.development, Development::Incubation);
+        assert_eq!(BROWN_TROUT.development, Development::Incubation);
+        assert!(GARDEN_SNAIL.can_bear(Sex::Unknown));
+        assert!(!HUMAN.can_bear(Sex::Male));
+    }
+}
@@ -0,0 +1,154 @@
+use biology::anatomy::appendage::AppendageKind;
+use biology::anatomy::leg_measurements::LegMeasurements;
+use biology::builder::{BuildError, BuildProblem, OrganismBuilder, OrganismBuilderExt};
+use biology::characteristics::locomotion::Locomotion;
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::Celsius;
+use crate::reptiles::features::{Scales, Shedding};
+use crate::reptiles::reptile::Reptile;
+
+/// Builds a `Reptile` with the shared `OrganismBuilder` settings, plus its
+/// legs, scales, temperatures, moult and breeding.
+///
+/// Unless `appendages` replaces them, the reptile gets four legs of the
+/// configured measurements, or none if `limbless`. `build` reports
+/// appendages other than legs, more than four legs, and a brumation
+/// temperature at or above the preferred one.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let adder = Reptile::builder("Vipera berus")
+///     .common_name("Common European adder")
+///     .limbless()
+///     .live_birth()
+///     .build()?;
+/// ```
+pub struct ReptileBuilder {
+    organism: OrganismBuilder,
+    locomotion: Locomotion,
+    leg_measurements: LegMeasurements,
+    limbless: bool,
+    scales: Scales,
+    preferred_temperature: Celsius,
+    brumation_temperature: Celsius,
+    shedding: Shedding,
+    live_bearing: bool,
+}
+
+impl ReptileBuilder {
+    pub fn new(species: &str) -> Self {
+        Self {
+            organism: OrganismBuilder::new(species).vitals(VitalStatus::Alive(Reptile::default_vitals())),
+            locomotion: Locomotion::Terrestrial,
+            leg_measurements: Reptile::default_legs(),
+            limbless: false,
+            scales: Scales::default(),
+            preferred_temperature: Celsius(30),
+            brumation_temperature: Celsius(10),
+            shedding: Shedding::default(),
+            live_bearing: false,
+        }
+    }
+
+    // `Amphibious` for turtles and crocodiles, `Fossorial` for burrowers.
+    pub fn locomotion(mut self, locomotion: Locomotion) -> Self {
+        self.locomotion = locomotion;
+        self
+    }
+
+    pub fn leg_measurements(mut self, measurements: LegMeasurements) -> Self {
+        self.leg_measurements = measurements;
+        self
+    }
+
+    // No legs at all: snakes and legless lizards.
+    pub fn limbless(mut self) -> Self {
+        self.limbless = true;
+        self
+    }
+
+    pub fn scales(mut self, scales: Scales) -> Self {
+        self.scales = scales;
+        self
+    }
+
+    pub fn temperatures(mut self, preferred: Celsius, brumation: Celsius) -> Self {
+        self.preferred_temperature = preferred;
+        self.brumation_temperature = brumation;
+        self
+    }
+
+    pub fn shed_interval(mut self, days: u32) -> Self {
+        self.shedding = Shedding::every(days);
+        self
+    }
+
+    pub fn live_birth(mut self) -> Self {
+        self.live_bearing = true;
+        self
+    }
+
+    // Everything wrong with the settings: the shared checks, then the reptile's own.
+    pub fn problems(&self) -> Vec<BuildProblem> {
+        let mut problems = self.organism.problems();
+        problems.extend(self.organism.unexpected_appendages(&[AppendageKind::Leg], "a reptile"));
+        let (attached, missing) = self.organism.count_appendages(AppendageKind::Leg);
+        if attached + missing > 4 {
+            problems.push(BuildProblem::Invalid {
+                field: "appendages",
+                reason: format!("a reptile has at most four legs, not {}", attached + missing),
+            });
+        }
+        if self.brumation_temperature.0 >= self.preferred_temperature.0 {
+            problems.push(BuildProblem::Invalid {
+                field: "temperatures",
+                reason: format!(
+                    "it would brumate at {} °C, no colder than the {} °C it basks to reach",
+                    self.brumation_temperature.0, self.preferred_temperature.0
+                ),
+            });
+        }
+        problems
+    }
+
+    pub fn build(self) -> Result<Reptile, BuildError> {
+        BuildError::check(self.problems())?;
+        Ok(self.assemble())
+    }
+
+    // The reptile without any checks, for `Reptile::new`.
+    pub(crate) fn assemble(self) -> Reptile {
+        let parts = self.organism.into_parts();
+        let appendages = match (parts.appendages.is_empty(), self.limbless) {
+            (false, _) => parts.appendages,
+            (true, false) => Reptile::legs(self.leg_measurements),
+            (true, true) => Vec::new(),
+        };
+        Reptile {
+            id: parts.id,
+            species: parts.species,
+            common_name: parts.common_name,
+            name: parts.name,
+            vitals: parts.vitals,
+            sex: parts.sex,
+            body_mass: parts.body_mass,
+            top_speed: parts.top_speed,
+            locomotion: self.locomotion,
+            appendages,
+            scales: self.scales,
+            preferred_temperature: self.preferred_temperature,
+            brumation_temperature: self.brumation_temperature,
+            shedding: self.shedding,
+            live_bearing: self.live_bearing,
+            habitat: parts.habitat,
+        }
+    }
+}
+
+impl OrganismBuilderExt for ReptileBuilder {
+    fn organism_builder(&mut self) -> &mut OrganismBuilder {
+        &mut self.organism
+    }
+}
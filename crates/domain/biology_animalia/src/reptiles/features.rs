@@ -0,0 +1,130 @@
+use std::fmt::{Display, Formatter};
+
+/// Scale type, which follows a reptile's lineage and habits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Scales {
+    // Glossy and overlapping: skinks, pythons.
+    Smooth,
+    // Ridged down the middle: most lizards and vipers.
+    #[default]
+    Keeled,
+    // Tiny, bead-like scales: geckos, Gila monsters.
+    Granular,
+    // Large bony plates: turtles, crocodiles.
+    Scutes,
+}
+
+impl Display for Scales {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Scales::Smooth => write!(f, "covered in smooth, overlapping scales"),
+            Scales::Keeled => write!(f, "covered in keeled, ridged scales"),
+            Scales::Granular => write!(f, "covered in tiny granular scales"),
+            Scales::Scutes => write!(f, "armoured with bony scutes"),
+        }
+    }
+}
+
+/// What a reptile is doing about the temperature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Activity {
+    // Warm enough to hunt and move about.
+    Active,
+    // Lying in the sun to warm up to its preferred temperature.
+    Basking,
+    // Torpid in a burrow or crevice through the cold months.
+    Brumating,
+}
+
+impl Display for Activity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Activity::Active => write!(f, "active"),
+            Activity::Basking => write!(f, "basking"),
+            Activity::Brumating => write!(f, "brumating"),
+        }
+    }
+}
+
+/// The moult cycle: reptiles outgrow their skin and shed it every few weeks.
+///
+/// A saved cycle must shed at least once a day and not be past its own
+/// interval; one that is fails to load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "SavedShedding")
+)]
+pub struct Shedding {
+    // Days between sheds.
+    pub interval_days: u32,
+    pub days_since_shed: u32,
+}
+
+// A moult cycle as it was saved, before it is checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SavedShedding {
+    interval_days: u32,
+    days_since_shed: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SavedShedding> for Shedding {
+    type Error = String;
+
+    fn try_from(saved: SavedShedding) -> Result<Self, Self::Error> {
+        if saved.interval_days == 0 {
+            return Err("a reptile cannot shed every 0 days".to_string());
+        }
+        if saved.days_since_shed >= saved.interval_days {
+            return Err(format!(
+                "{} days since the last shed is past the {}-day interval",
+                saved.days_since_shed, saved.interval_days
+            ));
+        }
+        Ok(Self {
+            interval_days: saved.interval_days,
+            days_since_shed: saved.days_since_shed,
+        })
+    }
+}
+
+impl Default for Shedding {
+    // About every six weeks, as for a young snake or lizard.
+    fn default() -> Self {
+        Self::every(42)
+    }
+}
+
+impl Shedding {
+    // The last days before a shed, when the old skin dulls and a snake's eyes cloud over.
+    pub const PRE_SHED_DAYS: u32 = 7;
+
+    pub fn every(interval_days: u32) -> Self {
+        Self {
+            interval_days: interval_days.max(1),
+            days_since_shed: 0,
+        }
+    }
+
+    // Moves the cycle on and returns how many sheds happened.
+    // The fields are public, so an interval of 0 counts as daily rather than dividing by it.
+    pub fn advance(&mut self, days: u32) -> u32 {
+        let interval = self.interval_days.max(1);
+        let total = self.days_since_shed.saturating_add(days);
+        self.days_since_shed = total % interval;
+        total / interval
+    }
+
+    pub fn days_until_shed(&self) -> u32 {
+        self.interval_days.saturating_sub(self.days_since_shed)
+    }
+
+    pub fn is_due(&self) -> bool {
+        self.days_until_shed() <= Self::PRE_SHED_DAYS
+    }
+}
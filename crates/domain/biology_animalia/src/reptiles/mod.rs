@@ -1,4 +1,7 @@
 pub mod reptile;
+pub mod builder;
+pub mod features;
 
-// Re-export when implemented
-// pub use reptile::Reptile;
+pub use builder::ReptileBuilder;
+pub use reptile::Reptile;
+pub use features::{Activity, Scales, Shedding};
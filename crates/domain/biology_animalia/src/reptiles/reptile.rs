@@ -1,6 +1,389 @@
-// TODO: Implement generic Reptile struct
-// Reptilian characteristics:
-// - Cold-blooded
-// - Scales
-// - Egg-laying (most species)
-// - Crawling/slithering locomotion
+use biology::{Nomenclature, Organism, Sex};
+use biology::nomenclature::{common_aliases, common_display_name};
+use biology::anatomy::appendage::{Appendage, AppendageKind};
+use biology::anatomy::leg_measurements::LegMeasurements;
+use biology::anatomy::limb_status::LimbStatus;
+use biology::characteristics::structural::Anatomy;
+use biology::characteristics::locomotion::{Locomotion, Mobility};
+use biology::characteristics::presentation::Summarizable;
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::habitat::HabitatPreference;
+use biology::patterns::presentation_patterns::{BasicSummary, DetailedSummary};
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::vitals::blood_pressure::BloodPressure;
+use biology::vitals::live_vitals::LiveVitals;
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::{Celsius, BPM};
+use core_shared::{Centimeters, Direction, Identifier, Kilograms, MetersPerSecond};
+use crate::reptiles::features::{Activity, Scales, Shedding};
+use crate::reptiles::builder::ReptileBuilder;
+
+/// A reptile - a scaly, cold-blooded vertebrate.
+///
+/// Reptiles are ectotherms: their body temperature is not a fixed vital
+/// sign but follows the air around them. Below `brumation_temperature` they
+/// shut down for the cold season; below `preferred_temperature` they bask,
+/// which lifts their body up to `BASKING_GAIN` degrees above the air, and
+/// above it they are active at the air's temperature. `thermoregulate`
+/// applies this to the vitals, and the simulation's `Weather` rule does so
+/// every tick through `Organism::body_temperature_in`.
+///
+/// Reptiles start with four sprawling legs; `limbless` makes a snake.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let adder = Reptile::builder("Vipera berus")
+///     .common_name("Common European adder")
+///     .limbless()
+///     .live_birth()
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Reptile {
+    pub id: Identifier,
+    pub species: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub common_name: Option<String>,
+    // An individual's own name or tag code.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
+    pub vitals: VitalStatus,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sex: Sex,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub body_mass: Option<Kilograms>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub top_speed: Option<MetersPerSecond>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub locomotion: Locomotion,
+    // Four legs, or none at all for snakes and legless lizards.
+    pub appendages: Vec<Appendage>,
+    pub scales: Scales,
+    // The body temperature it basks to reach.
+    pub preferred_temperature: Celsius,
+    // Below this air temperature it brumates.
+    pub brumation_temperature: Celsius,
+    pub shedding: Shedding,
+    // Bears live young (boas, garter snakes) rather than laying eggs.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub live_bearing: bool,
+    // Where it can live; `None` for anywhere.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub habitat: Option<HabitatPreference>,
+}
+
+impl Reptile {
+    // How far above the air temperature sunshine can warm a basking reptile.
+    pub const BASKING_GAIN: f32 = 10.0;
+
+    // A four-legged, egg-laying lizard that prefers 30 °C and brumates below 10 °C.
+    pub fn new(species: String) -> Self {
+        ReptileBuilder::new(&species).assemble()
+    }
+
+    pub fn builder(species: &str) -> ReptileBuilder {
+        ReptileBuilder::new(species)
+    }
+
+    // A resting lizard: slow heart, slow breathing, warmed to 25 °C.
+    pub fn default_vitals() -> LiveVitals {
+        LiveVitals::new(BloodPressure(40, 30), Celsius(25), BPM(40), 12)
+    }
+
+    // A green iguana's legs: leg length and thigh girth.
+    pub(crate) fn default_legs() -> LegMeasurements {
+        LegMeasurements {
+            inseam: Centimeters(10),
+            quad: Centimeters(4),
+        }
+    }
+
+    pub(crate) fn legs(measurements: LegMeasurements) -> Vec<Appendage> {
+        [Direction::Left, Direction::Right, Direction::Left, Direction::Right]
+            .into_iter()
+            .map(|side| Appendage::Leg {
+                side,
+                measurements,
+                status: LimbStatus::Intact,
+            })
+            .collect()
+    }
+
+    // `Amphibious` for turtles and crocodiles, `Fossorial` for burrowers.
+    pub fn with_locomotion(mut self, locomotion: Locomotion) -> Self {
+        self.locomotion = locomotion;
+        self
+    }
+
+    pub fn with_scales(mut self, scales: Scales) -> Self {
+        self.scales = scales;
+        self
+    }
+
+    pub fn with_leg_measurements(mut self, measurements: LegMeasurements) -> Self {
+        self.appendages = Self::legs(measurements);
+        self
+    }
+
+    // No legs at all: snakes and legless lizards.
+    pub fn limbless(mut self) -> Self {
+        self.appendages.clear();
+        self
+    }
+
+    pub fn with_temperatures(mut self, preferred: Celsius, brumation: Celsius) -> Self {
+        self.preferred_temperature = preferred;
+        self.brumation_temperature = brumation;
+        self
+    }
+
+    pub fn with_shed_interval(mut self, days: u32) -> Self {
+        self.shedding = Shedding::every(days);
+        self
+    }
+
+    pub fn with_live_birth(mut self) -> Self {
+        self.live_bearing = true;
+        self
+    }
+
+    // Limbless by body plan, not by injury: a lizard that lost its legs still lists them as amputated.
+    pub fn is_limbless(&self) -> bool {
+        !self.appendages.iter().any(|appendage| appendage.kind() == AppendageKind::Leg)
+    }
+
+    pub fn lays_eggs(&self) -> bool {
+        !self.live_bearing
+    }
+
+    pub fn activity(&self, environment: &Environment) -> Activity {
+        let ambient = environment.ambient_temperature();
+        if ambient < self.brumation_temperature.0 as f32 {
+            Activity::Brumating
+        } else if ambient < self.preferred_temperature.0 as f32 {
+            Activity::Basking
+        } else {
+            Activity::Active
+        }
+    }
+
+    // The air's temperature, or warmer while basking, but never above the preferred temperature by basking alone.
+    pub fn body_temperature(&self, environment: &Environment) -> Celsius {
+        let ambient = environment.ambient_temperature();
+        let body = match self.activity(environment) {
+            Activity::Basking => (ambient + Self::BASKING_GAIN).min(self.preferred_temperature.0 as f32),
+            Activity::Active | Activity::Brumating => ambient,
+        };
+        Celsius::from_degrees(body)
+    }
+
+    // Sets the body temperature from the environment.
+    pub fn thermoregulate(&mut self, environment: &Environment) {
+        let temperature = self.body_temperature(environment);
+        if let VitalStatus::Alive(vitals) = &mut self.vitals {
+            vitals.temperature = temperature;
+        }
+    }
+
+    // Moves the moult cycle on; returns how many times the skin was shed.
+    pub fn pass_days(&mut self, days: u32) -> u32 {
+        self.shedding.advance(days)
+    }
+
+    pub fn describe_shedding(&self) -> String {
+        let how = if self.is_limbless() {
+            "in one piece"
+        } else if self.scales == Scales::Scutes {
+            "scute by scute"
+        } else {
+            "in patches"
+        };
+        if self.shedding.is_due() {
+            format!("Dull and ready to shed {}, within {} days.", how, self.shedding.days_until_shed())
+        } else {
+            format!("Sheds {} every {} days; next in {} days.", how, self.shedding.interval_days, self.shedding.days_until_shed())
+        }
+    }
+}
+
+impl Nomenclature for Reptile {
+    fn display_name(&self) -> String {
+        common_display_name(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+
+    fn aliases(&self) -> Vec<String> {
+        common_aliases(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+}
+
+impl AnatomyAnalyzer for Reptile {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
+    }
+}
+
+impl Anatomy for Reptile {
+    fn describe_anatomy(&self) -> String {
+        let young = if self.live_bearing { "bears live young" } else { "lays eggs" };
+        format!(
+            "{} Its body is {}; cold-blooded, it basks to reach {}°C, and it {}.",
+            self.describe_limb_status(),
+            self.scales,
+            self.preferred_temperature.0,
+            young
+        )
+    }
+}
+
+impl Mobility for Reptile {
+    fn describe_locomotion(&self) -> String {
+        let (legs, _) = self.count_appendages(AppendageKind::Leg);
+        let on_land = if self.is_limbless() {
+            "Slithers by throwing its legless body into waves"
+        } else if legs == 0 {
+            "Drags itself along, having lost its legs"
+        } else {
+            "Crawls and scurries on sprawling legs"
+        };
+        match self.locomotion {
+            Locomotion::Aquatic | Locomotion::Amphibious => format!("{}, and swims with sweeps of its tail.", on_land),
+            Locomotion::Fossorial => format!("{}, and burrows into loose ground.", on_land),
+            _ => format!("{}.", on_land),
+        }
+    }
+
+    fn locomotion(&self) -> Locomotion {
+        self.locomotion
+    }
+}
+
+impl Organism for Reptile {
+    fn id(&self) -> Identifier {
+        self.id
+    }
+
+    fn species(&self) -> String {
+        self.species.clone()
+    }
+
+    fn vitals(&self) -> &VitalStatus {
+        &self.vitals
+    }
+
+    fn vitals_mut(&mut self) -> &mut VitalStatus {
+        &mut self.vitals
+    }
+
+    fn sex(&self) -> Sex {
+        self.sex
+    }
+
+    fn body_mass(&self) -> Option<Kilograms> {
+        self.body_mass
+    }
+
+    fn top_speed(&self) -> Option<MetersPerSecond> {
+        self.top_speed
+    }
+
+    fn tolerates(&self, environment: &Environment) -> bool {
+        self.habitat.as_ref().is_none_or(|habitat| habitat.allows(environment))
+    }
+
+    fn body_temperature_in(&self, environment: &Environment) -> Option<Celsius> {
+        Some(self.body_temperature(environment))
+    }
+}
+
+impl BasicSummary for Reptile {
+    fn get_name(&self) -> String {
+        self.display_name()
+    }
+
+    fn get_gender(&self) -> &Sex {
+        &self.sex
+    }
+}
+
+impl DetailedSummary for Reptile {
+    fn get_appendages(&self) -> &Vec<Appendage> {
+        &self.appendages
+    }
+}
+
+impl Summarizable for Reptile {
+    fn summary(&self) -> String {
+        format!(
+            "--- Reptile: {} ({}) ---\nID: {}\nAnatomy: {}\nMobility: {}\nSkin: {}\n\n {}",
+            self.display_name(),
+            self.species,
+            self.id,
+            self.describe_anatomy(),
+            self.describe_locomotion(),
+            self.describe_shedding(),
+            self.generate_summary()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biology::ecosystem::environment::{Climate, Terrain};
+
+    fn air(temperature: u32) -> Environment {
+        let climate = Climate::Custom {
+            avg_temp: Celsius(temperature),
+            rainfall_mm: 500,
+        };
+        Environment::new(climate, Terrain::Plains, Centimeters(0), 0.3)
+    }
+
+    #[test]
+    fn test_reptile_creation() {
+        let lizard = Reptile::new("Lacerta agilis".to_string());
+
+        assert_eq!(lizard.count_limbs(), (0, 0, 4, 0));
+        assert!(!lizard.is_limbless());
+        assert!(lizard.lays_eggs());
+        assert!(lizard.describe_anatomy().starts_with("A quadrupedal form"));
+    }
+
+    #[test]
+    fn test_body_temperature_follows_the_air() {
+        let lizard = Reptile::new("Lacerta agilis".to_string());
+
+        assert_eq!(lizard.activity(&air(5)), Activity::Brumating);
+        assert_eq!(lizard.body_temperature(&air(5)), Celsius(5));
+        assert_eq!(lizard.activity(&air(15)), Activity::Basking);
+        assert_eq!(lizard.body_temperature(&air(15)), Celsius(25));
+        assert_eq!(lizard.body_temperature(&air(25)), Celsius(30));
+        assert_eq!(lizard.activity(&air(34)), Activity::Active);
+        assert_eq!(lizard.body_temperature(&air(34)), Celsius(34));
+    }
+
+    #[test]
+    fn test_shedding_cycle() {
+        let mut snake = Reptile::new("Natrix natrix".to_string()).limbless().with_shed_interval(30);
+
+        assert_eq!(snake.pass_days(20), 0);
+        assert!(!snake.shedding.is_due());
+        assert_eq!(snake.pass_days(5), 0);
+        assert!(snake.describe_shedding().contains("ready to shed in one piece"));
+        assert_eq!(snake.pass_days(65), 3);
+        assert_eq!(snake.shedding.days_since_shed, 0);
+    }
+
+    #[test]
+    fn test_shedding_survives_odd_settings() {
+        let mut shedding = Shedding { interval_days: 0, days_since_shed: 5 };
+        assert_eq!(shedding.days_until_shed(), 0);
+        assert_eq!(shedding.advance(2), 7);
+
+        let mut shedding = Shedding::every(30);
+        shedding.advance(29);
+        assert_eq!(shedding.advance(u32::MAX), u32::MAX / 30);
+    }
+}
@@ -0,0 +1,139 @@
+use biology::{Nomenclature, Organism, Sex, Summarizable};
+use biology::builder::OrganismBuilderExt;
+use biology::anatomy::appendage::AppendageKind;
+use biology::characteristics::locomotion::{Locomotion, Mobility};
+use biology::characteristics::structural::Anatomy;
+use biology::ecosystem::environment::{Climate, Environment, Terrain};
+use biology::ecosystem::population::Population;
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::simulation::{MemberState, Reproduction, Simulation, Weather};
+use biology::vitals::vital_status::VitalStatus;
+use biology_animalia::amphibians::{AmphibianOrder, LifeStage, Metamorphosis};
+use biology_animalia::reproduction::{offspring, COMMON_FROG, STRATEGIES};
+use biology_animalia::Amphibian;
+use core_shared::Centimeters;
+
+const JULY: u32 = 196;
+
+fn pond() -> Environment {
+    Environment::new(Climate::Temperate, Terrain::Wetlands, Centimeters(0), 0.8)
+}
+
+fn common_frog(sex: Sex) -> Amphibian {
+    Amphibian::builder("Rana temporaria")
+        .common_name("Common frog")
+        .sex(sex)
+        .larval_period(70)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_tadpoles_are_finned_and_breathe_through_gills() {
+    let tadpole = common_frog(Sex::Female).as_larva();
+    let VitalStatus::Alive(vitals) = &tadpole.vitals else {
+        panic!("tadpole should be alive");
+    };
+
+    assert_eq!(vitals.describe_respiration(), "80 gill beats/min");
+    assert_eq!(tadpole.count_limbs(), (0, 0, 0, 0));
+    assert!(tadpole.describe_anatomy().starts_with("A finned form with 1 attached fins."));
+    assert!(tadpole.describe_anatomy().contains("frog tadpole breathing through gills, 70 days from metamorphosis"));
+    assert!(tadpole.describe_locomotion().contains("finned tail"));
+}
+
+#[test]
+fn test_metamorphosis_grows_legs_and_lungs() {
+    let mut frog = common_frog(Sex::Male).as_larva();
+    assert!(!frog.grow(30));
+    assert!(frog.grow(40));
+    assert!(!frog.grow(1));
+
+    assert_eq!(frog.stage, LifeStage::Adult);
+    assert_eq!(frog.count_appendages(AppendageKind::Leg), (4, 0));
+    assert!(frog.describe_anatomy().starts_with("A quadrupedal form with 4 attached legs."));
+    assert!(frog.describe_locomotion().starts_with("Hops and leaps"));
+    assert!(matches!(&frog.vitals, VitalStatus::Alive(vitals) if vitals.describe_respiration() == "20 breaths/min"));
+
+    let newt = Amphibian::new("Triturus cristatus".to_string()).with_order(AmphibianOrder::Salamander);
+    assert_eq!(newt.locomotion(), Locomotion::Amphibious);
+    assert!(newt.summary().contains("--- Amphibian: Triturus cristatus (Triturus cristatus) ---"));
+    assert!(newt.answers_to("triturus cristatus"));
+}
+
+#[test]
+fn test_larvae_need_wetlands() {
+    let forest = Environment::new(Climate::Temperate, Terrain::Forest, Centimeters(0), 0.6);
+    let ocean = Environment::new(Climate::Temperate, Terrain::Ocean, Centimeters(0), 1.0);
+    let tadpole = common_frog(Sex::Female).as_larva();
+    let frog = common_frog(Sex::Female);
+
+    assert!(tadpole.tolerates(&pond()));
+    assert!(!tadpole.tolerates(&forest));
+    assert!(frog.tolerates(&forest));
+    assert!(!frog.tolerates(&ocean));
+
+    let mut population = Population::new(forest);
+    let tadpole_id = population.insert(tadpole).unwrap();
+    population.insert(frog).unwrap();
+    assert_eq!(population.unsuited(), [tadpole_id]);
+}
+
+#[test]
+fn test_frogs_take_the_temperature_of_the_pond() {
+    let mut population = Population::new(pond());
+    let frog_id = population.insert(common_frog(Sex::Female)).unwrap();
+    let mut simulation = Simulation::new(population, 3).with_rule(Weather::new().with_start_day(JULY));
+    simulation.run(1);
+
+    let frog = simulation.population().get(frog_id).unwrap();
+    let VitalStatus::Alive(vitals) = frog.vitals() else {
+        panic!("frog should be alive");
+    };
+    assert_eq!(frog.body_temperature_in(&simulation.population().environment), Some(vitals.temperature));
+}
+
+#[test]
+fn test_frog_spawn_hatches_into_tadpoles() {
+    let (male, female) = (common_frog(Sex::Male), common_frog(Sex::Female));
+    let (male_id, female_id) = (male.id, female.id);
+    let mut population = Population::new(pond());
+    population.insert(male).unwrap();
+    population.insert(female).unwrap();
+
+    let adult = COMMON_FROG.maturity;
+    let mut simulation = Simulation::new(population, 7)
+        .with_state(male_id, MemberState::new(adult, 1.0))
+        .with_state(female_id, MemberState::new(adult, 1.0))
+        .with_rule(Reproduction::new(STRATEGIES, offspring).with_mating_chance(1.0));
+    simulation.run(COMMON_FROG.period as u64 + 2);
+
+    let population = simulation.population();
+    let tadpoles = population.children_of(female_id);
+    assert!(tadpoles.len() >= COMMON_FROG.litter.0 as usize);
+    for id in tadpoles {
+        let tadpole = population.get_as::<Amphibian>(id).unwrap();
+        assert!(tadpole.is_larva());
+        assert_eq!(tadpole.days_to_metamorphosis, 70);
+        assert_eq!(tadpole.common_name.as_deref(), Some("Common frog"));
+    }
+}
+
+#[test]
+fn test_tadpoles_metamorphose_as_the_days_pass() {
+    let tadpole = Amphibian::builder("Rana temporaria").larval_period(3).larva().build().unwrap();
+    let tadpole_id = tadpole.id;
+    let mut population = Population::new(pond());
+    population.insert(tadpole).unwrap();
+    let mut simulation = Simulation::new(population, 3).with_rule(Metamorphosis);
+
+    simulation.run(2);
+    let tadpole = simulation.population().get_as::<Amphibian>(tadpole_id).unwrap();
+    assert!(tadpole.is_larva());
+    assert_eq!(tadpole.days_to_metamorphosis, 1);
+
+    simulation.run(1);
+    let frog = simulation.population().get_as::<Amphibian>(tadpole_id).unwrap();
+    assert_eq!(frog.stage, LifeStage::Adult);
+    assert_eq!(frog.count_appendages(AppendageKind::Leg), (4, 0));
+}
@@ -0,0 +1,124 @@
+use biology::{Nomenclature, Sex, Summarizable};
+use biology::anatomy::appendage::AppendageKind;
+use biology::builder::OrganismBuilderExt;
+use biology::anatomy::limb_status::LimbStatus;
+use biology::anatomy::wing_measurements::WingMeasurements;
+use biology::characteristics::locomotion::{Locomotion, Mobility};
+use biology::characteristics::structural::Anatomy;
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::population::Population;
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::simulation::{MemberState, Reproduction, Simulation};
+use biology_animalia::birds::{Beak, Flight, Plumage, PlumagePattern};
+use biology_animalia::reproduction::{offspring, MALLARD, STRATEGIES};
+use biology_animalia::Bird;
+use core_shared::{Centimeters, Direction};
+
+fn ostrich() -> Bird {
+    Bird::builder("Struthio camelus")
+        .common_name("Common ostrich")
+        .flight(Flight::Flightless)
+        .wing_measurements(WingMeasurements { length: Centimeters(90), chord: Centimeters(40) })
+        .build()
+        .unwrap()
+}
+
+fn emperor_penguin() -> Bird {
+    Bird::builder("Aptenodytes forsteri")
+        .common_name("Emperor penguin")
+        .plumage(Plumage::new(&["black", "white", "yellow"], PlumagePattern::Countershaded))
+        .flight(Flight::Flightless)
+        .locomotion(Locomotion::Amphibious)
+        .build()
+        .unwrap()
+}
+
+fn mallard(sex: Sex) -> Bird {
+    Bird::builder("Anas platyrhynchos")
+        .common_name("Mallard")
+        .sex(sex)
+        .plumage(Plumage::new(&["green", "brown", "grey"], PlumagePattern::Iridescent))
+        .beak(Beak::Filtering)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_flight_drives_locomotion() {
+    let sparrow = Bird::builder("Passer domesticus").beak(Beak::Conical).build().unwrap();
+    let albatross = Bird::builder("Diomedea exulans").flight(Flight::Soaring).build().unwrap();
+
+    assert_eq!(sparrow.locomotion(), Locomotion::Aerial);
+    assert!(sparrow.describe_locomotion().contains("Flies"));
+    assert_eq!(albatross.locomotion(), Locomotion::Aerial);
+    assert!(albatross.describe_locomotion().contains("Soars"));
+
+    assert!(!ostrich().can_fly());
+    assert_eq!(ostrich().locomotion(), Locomotion::Terrestrial);
+    assert!(ostrich().describe_locomotion().contains("Flightless; walks and runs"));
+
+    assert_eq!(emperor_penguin().locomotion(), Locomotion::Amphibious);
+    assert!(emperor_penguin().describe_locomotion().contains("swims"));
+}
+
+#[test]
+fn test_a_lost_wing_grounds_a_flying_bird() {
+    let mut duck = mallard(Sex::Male);
+    duck.set_wing_status(Direction::Right, LimbStatus::Amputated);
+
+    assert!(!duck.can_fly());
+    assert_eq!(duck.locomotion(), Locomotion::Terrestrial);
+    assert_eq!(duck.count_appendages(AppendageKind::Wing), (1, 1));
+    assert!(duck.describe_anatomy().contains("It is noted that 1 wings and 0 legs are missing."));
+}
+
+#[test]
+fn test_bird_anatomy() {
+    let penguin = emperor_penguin();
+    let anatomy = penguin.describe_anatomy();
+
+    assert!(anatomy.starts_with("A bipedal form with 2 attached wings and 2 attached legs."));
+    assert!(anatomy.contains("black, white and yellow countershaded plumage"));
+    assert!(anatomy.contains("egg-laying"));
+    assert!(mallard(Sex::Female).describe_anatomy().contains("filtering beak for plankton"));
+    assert_eq!(penguin.count_limbs(), (0, 0, 2, 0));
+}
+
+#[test]
+fn test_bird_summary_and_names() {
+    let bird = Bird::builder("Ara macao").common_name("Scarlet macaw").name("Rio").sex(Sex::Male).build().unwrap();
+    let summary = bird.summary();
+
+    assert_eq!(bird.display_name(), "Rio");
+    assert!(bird.answers_to("Ara macao"));
+    assert!(summary.contains("--- Bird: Rio (Ara macao) ---"));
+    assert!(summary.contains("Left Wing (Intact): Length: 32cm, Chord: 12cm"));
+    assert!(summary.contains("Mobility: Flies"));
+    assert_eq!(Bird::new("Columba livia".to_string()).display_name(), "Columba livia");
+}
+
+#[test]
+fn test_ducks_lay_eggs_that_hatch_into_ducklings() {
+    let (drake, hen) = (mallard(Sex::Male), mallard(Sex::Female));
+    let (drake_id, hen_id) = (drake.id, hen.id);
+    let mut population = Population::new(Environment::default());
+    population.insert(drake).unwrap();
+    population.insert(hen).unwrap();
+
+    let mut simulation = Simulation::new(population, 11)
+        .with_state(drake_id, MemberState::new(400, 1.0))
+        .with_state(hen_id, MemberState::new(400, 1.0))
+        .with_rule(Reproduction::new(STRATEGIES, offspring).with_mating_chance(1.0));
+    simulation.run(MALLARD.period as u64 + 2);
+
+    let population = simulation.population();
+    let ducklings = population.children_of(hen_id);
+    assert!(ducklings.len() >= MALLARD.litter.0 as usize);
+    for duckling in ducklings {
+        let duckling = population.get_as::<Bird>(duckling).unwrap();
+        assert_eq!(duckling.common_name.as_deref(), Some("Mallard"));
+        assert_eq!(duckling.beak, Beak::Filtering);
+        assert_eq!(duckling.plumage.pattern, PlumagePattern::Iridescent);
+        assert_eq!(population.parents_of(duckling.id), [hen_id, drake_id]);
+    }
+}
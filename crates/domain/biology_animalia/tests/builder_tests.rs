@@ -0,0 +1,258 @@
+use biology::{Nomenclature, Organism, Sex};
+use biology::anatomy::appendage::{Appendage, FinPosition};
+use biology::anatomy::fin_measurements::FinMeasurements;
+use biology::anatomy::limb_status::LimbStatus;
+use biology::builder::{BuildError, BuildProblem, OrganismBuilder, OrganismBuilderExt};
+use biology::characteristics::locomotion::Locomotion;
+use biology::ecosystem::environment::{Climate, Environment, Terrain};
+use biology::ecosystem::habitat::HabitatPreference;
+use biology::ecosystem::population::Population;
+use biology::genetics::Genome;
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::templates::regional_defaults::AVG_NORTH_AMERICAN_MALE;
+use biology::vitals::blood_pressure::BloodPressure;
+use biology::vitals::live_vitals::LiveVitals;
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::{Celsius, BPM};
+use biology::nomenclature::nomenclature::NomenclatureComponents;
+use biology_animalia::birds::Flight;
+use biology_animalia::invertebrates::MolluscClass;
+use biology_animalia::mammals::profiles::{DOG, LARGE_FLYING_FOX};
+use biology_animalia::{Amphibian, Animal, Arachnid, Bird, Fish, Human, Insect, Mammal, Mollusc, Reptile};
+use biology_plantae::{Plant, PlantForm};
+use core_shared::{Centimeters, Identifier, Kilograms, MetersPerSecond};
+
+fn environment(climate: Climate, terrain: Terrain) -> Environment {
+    Environment::new(climate, terrain, Centimeters(0), 0.5)
+}
+
+#[test]
+fn test_organism_builder_builds_the_shared_parts() {
+    let id = Identifier::new();
+    let parts = OrganismBuilder::new("Pan troglodytes")
+        .id(id)
+        .common_name("Chimpanzee")
+        .name("Ai")
+        .sex(Sex::Female)
+        .body_mass(Kilograms(45))
+        .anatomy_template(&AVG_NORTH_AMERICAN_MALE.scaled(0.7))
+        .build()
+        .unwrap();
+
+    assert_eq!(parts.id, id);
+    assert_eq!(parts.name.as_deref(), Some("Ai"));
+    assert_eq!(parts.appendages.len(), 4);
+    assert!(parts.habitat.is_none());
+}
+
+#[test]
+fn test_build_lists_every_problem() {
+    let error = OrganismBuilder::new("  ")
+        .common_name("")
+        .vitals(VitalStatus::Alive(LiveVitals::new(BloodPressure(70, 90), Celsius(37), BPM(60), 12)))
+        .build()
+        .unwrap_err();
+
+    assert_eq!(
+        error.problems,
+        [
+            BuildProblem::MissingSpecies,
+            BuildProblem::BlankName("common name"),
+            BuildProblem::ImplausibleVitals("diastolic pressure 90 is not below systolic 70".to_string()),
+        ]
+    );
+    assert_eq!(
+        error.to_string(),
+        "cannot build: no species given; the common name is blank; implausible vitals: diastolic pressure 90 is not below systolic 70"
+    );
+    assert_eq!(BuildError::check(Vec::new()), Ok(()));
+}
+
+#[test]
+fn test_animals_and_mammals_extend_the_builder() {
+    let lizard = Animal::builder("Podarcis muralis")
+        .common_name("Common wall lizard")
+        .locomotion(Locomotion::Fossorial)
+        .build()
+        .unwrap();
+    assert_eq!(lizard.display_name(), "Common wall lizard");
+    assert_eq!(lizard.locomotion, Locomotion::Fossorial);
+
+    let rex = Mammal::builder(DOG.species)
+        .profile(&DOG)
+        .name("Rex")
+        .sex(Sex::Male)
+        .genome(Genome::new().with("HR", "hr", "hr"))
+        .build()
+        .unwrap();
+    assert_eq!(rex.display_name(), "Rex");
+    assert!(rex.answers_to("Domestic dog"));
+    assert_eq!(rex.count_limbs(), (0, 0, 4, 0));
+    assert!(rex.vital_deviations().is_empty());
+    assert!(!rex.has_fur);
+}
+
+#[test]
+fn test_mammal_builder_checks_its_own_fields() {
+    let error = Mammal::builder("Glaucomys volans")
+        .locomotion(Locomotion::Aerial)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        error.problems,
+        [BuildProblem::Invalid {
+            field: "locomotion",
+            reason: "an aerial mammal needs two wings, not 0".to_string(),
+        }]
+    );
+
+    let bat = Mammal::builder(LARGE_FLYING_FOX.species).profile(&LARGE_FLYING_FOX).build();
+    assert!(bat.is_ok());
+}
+
+#[test]
+fn test_habitat_preferences_decide_tolerance() {
+    let marmot = Mammal::builder("Marmota marmota")
+        .habitat(HabitatPreference::on(&[Terrain::Mountains, Terrain::Plains]).between(-20.0, 25.0))
+        .build()
+        .unwrap();
+
+    assert!(marmot.tolerates(&environment(Climate::Temperate, Terrain::Mountains)));
+    assert!(!marmot.tolerates(&environment(Climate::Temperate, Terrain::Forest)));
+    assert!(!marmot.tolerates(&environment(Climate::Tropical, Terrain::Plains)));
+    assert!(HabitatPreference::anywhere().allows(&environment(Climate::Arctic, Terrain::Ocean)));
+
+    let mut population = Population::new(environment(Climate::Temperate, Terrain::Urban));
+    let marmot_id = population.insert(marmot).unwrap();
+    population.insert(Animal::builder("Columba livia").build().unwrap()).unwrap();
+    assert_eq!(population.unsuited(), [marmot_id]);
+}
+
+#[test]
+fn test_every_animal_type_extends_the_builder() {
+    let pond = HabitatPreference::on(&[Terrain::Wetlands]);
+    let wetlands = environment(Climate::Temperate, Terrain::Wetlands);
+    let plains = environment(Climate::Temperate, Terrain::Plains);
+
+    let heron = Bird::builder("Ardea cinerea").name("Grey").sex(Sex::Female).habitat(pond.clone()).build().unwrap();
+    let trout = Fish::builder("Salmo trutta").body_mass(Kilograms(2)).build().unwrap();
+    let terrapin = Reptile::builder("Emys orbicularis").habitat(pond.clone()).build().unwrap();
+    let tadpole = Amphibian::builder("Rana temporaria").larva().build().unwrap();
+    let dragonfly = Insect::builder("Anax imperator").habitat(pond.clone()).build().unwrap();
+    let raft_spider = Arachnid::builder("Dolomedes fimbriatus").habitat(pond).build().unwrap();
+    let mussel = Mollusc::builder("Anodonta cygnea").class(MolluscClass::Bivalve).build().unwrap();
+
+    assert_eq!(heron.display_name(), "Grey");
+    assert_eq!(heron.count_limbs(), (0, 0, 2, 0));
+    assert_eq!(trout.body_mass(), Some(Kilograms(2)));
+    assert_eq!(trout.appendages.len(), 7);
+    assert!(tadpole.is_larva());
+    assert_eq!(tadpole.days_to_metamorphosis, tadpole.larval_period);
+    assert_eq!(dragonfly.wing_pairs(), 2);
+    assert_eq!(raft_spider.count_limbs(), (0, 0, 8, 0));
+    assert_eq!(mussel.locomotion, Locomotion::Sessile);
+    let members: [&dyn Organism; 7] = [&heron, &trout, &terrapin, &tadpole, &dragonfly, &raft_spider, &mussel];
+    assert!(members.iter().all(|member| member.tolerates(&wetlands)));
+    assert!(members.iter().all(|member| !member.tolerates(&plains)));
+}
+
+#[test]
+fn test_type_builders_check_their_own_fields() {
+    let invalid = |problems: Vec<BuildProblem>| -> Vec<&'static str> {
+        problems
+            .into_iter()
+            .map(|problem| match problem {
+                BuildProblem::Invalid { field, .. } => field,
+                other => panic!("expected a type's own problem, not {:?}", other),
+            })
+            .collect()
+    };
+    let fin = Appendage::Fin {
+        position: FinPosition::Caudal,
+        measurements: FinMeasurements::default(),
+        status: LimbStatus::Intact,
+    };
+
+    let finned_pigeon = Bird::builder("Columba livia").appendage(fin.clone());
+    assert_eq!(invalid(finned_pigeon.build().unwrap_err().problems), ["appendages", "flight"]);
+    let gecko = Reptile::builder("Tarentola mauritanica").temperatures(Celsius(10), Celsius(20));
+    assert_eq!(invalid(gecko.build().unwrap_err().problems), ["temperatures"]);
+    let newt = Amphibian::builder("Lissotriton vulgaris").larva().larval_period(0);
+    assert_eq!(invalid(newt.build().unwrap_err().problems), ["larval period"]);
+    let housefly = Insect::builder("Musca domestica").wing_pairs(3);
+    assert_eq!(invalid(housefly.build().unwrap_err().problems), ["wing pairs"]);
+    // A fin is no arachnid's, and leaves it with no legs.
+    let finned_spider = Arachnid::builder("Araneus diadematus").appendage(fin.clone());
+    assert_eq!(invalid(finned_spider.build().unwrap_err().problems), ["appendages", "appendages"]);
+    let clam = Mollusc::builder("Mya arenaria").class(MolluscClass::Bivalve).shell(false);
+    assert_eq!(invalid(clam.build().unwrap_err().problems), ["shell"]);
+
+    assert!(Fish::builder("Salmo trutta").appendage(fin).build().is_ok());
+    assert!(Bird::builder("Struthio camelus").flight(Flight::Flightless).build().is_ok());
+}
+
+#[test]
+fn test_human_builder_takes_the_shared_settings() {
+    // `HumanBuilder::name` takes structured parts; the shared setter takes a plain name.
+    let jane = OrganismBuilderExt::name(Human::builder(), "Jane Ann Smith")
+        .sex(Sex::Female)
+        .body_mass(Kilograms(60))
+        .habitat(HabitatPreference::on(&[Terrain::Urban]))
+        .try_build()
+        .unwrap();
+
+    assert_eq!(jane.species(), "Homo sapiens");
+    assert_eq!(jane.display_name(), "Jane Ann Smith");
+    assert_eq!(jane.last_name().as_deref(), Some("Smith"));
+    assert_eq!(jane.body_mass(), Some(Kilograms(60)));
+    assert!(!jane.tolerates(&environment(Climate::Arctic, Terrain::Ocean)));
+    assert_eq!(jane.count_limbs(), (2, 0, 2, 0));
+
+    let Err(error) = OrganismBuilderExt::name(Human::builder(), "  ").try_build() else {
+        panic!("a human with a blank name was built");
+    };
+    assert_eq!(error.problems, [BuildProblem::BlankName("name")]);
+
+    // A common name would be dropped, so it is reported rather than ignored.
+    let Err(error) = Human::builder().common_name("Human").try_build() else {
+        panic!("a human with a common name was built");
+    };
+    assert!(matches!(&error.problems[..], [BuildProblem::Invalid { field: "common name", .. }]));
+
+    // An empty set of appendages is kept, not replaced by arms and legs.
+    let limbless = Human::builder().appendages(Vec::new()).try_build().unwrap();
+    assert_eq!(limbless.count_limbs(), (0, 0, 0, 0));
+}
+
+#[test]
+fn test_plant_builder_checks_what_a_plant_cannot_have() {
+    let oak = Plant::builder("Quercus robur")
+        .common_name("English oak")
+        .form(PlantForm::Tree)
+        .body_mass(Kilograms(500))
+        .habitat(HabitatPreference::on(&[Terrain::Forest]))
+        .build()
+        .unwrap();
+
+    assert_eq!(oak.display_name(), "English oak");
+    assert_eq!(oak.body_mass(), Some(Kilograms(500)));
+    assert!((oak.maturity() - 0.25).abs() < 1e-6);
+    assert!(!oak.tolerates(&environment(Climate::Temperate, Terrain::Plains)));
+
+    let error = Plant::builder("Poa pratensis")
+        .form(PlantForm::Grass)
+        .sex(Sex::Male)
+        .top_speed(MetersPerSecond(1))
+        .body_mass(Kilograms(1))
+        .build()
+        .unwrap_err();
+    let fields: Vec<_> = error
+        .problems
+        .iter()
+        .map(|problem| match problem {
+            BuildProblem::Invalid { field, .. } => *field,
+            other => panic!("expected a plant's own problem, not {:?}", other),
+        })
+        .collect();
+    assert_eq!(fields, ["sex", "top speed", "body mass"]);
+}
@@ -0,0 +1,243 @@
+use std::any::Any;
+use std::collections::HashMap;
+use biology::{Organism, Sex};
+use biology::demography::{
+    CarryingCapacity, Census, DEFAULT_NEEDS, LifeTable, Lifespan, Logistic, PeriodRates,
+};
+use biology::ecosystem::environment::{Climate, Environment, Terrain};
+use biology::ecosystem::landscape::{Landscape, Topology};
+use biology::ecosystem::population::Population;
+use biology::ecosystem::resources::{ResourceKind, ResourcePool};
+use biology::ecosystem::weather::{Conditions, WeatherEvent};
+use biology::simulation::{Aging, Births, Mortality, Simulation};
+use biology::vitals::post_mortem_report::PostMortemReport;
+use biology::vitals::vital_status::VitalStatus;
+use biology_animalia::{HumanBuilder, Mammal};
+use core_shared::{Centimeters, Identifier};
+
+fn same_species(parent: &dyn Organism, id: Identifier) -> Option<Box<dyn Organism>> {
+    let parent: &dyn Any = parent;
+    let mammal = parent.downcast_ref::<Mammal>()?;
+    let mut newborn = Mammal::new(mammal.species.clone());
+    newborn.id = id;
+    Some(Box::new(newborn))
+}
+
+fn deceased() -> VitalStatus {
+    VitalStatus::Deceased(PostMortemReport::new("Natural causes".to_string(), "2024-01-01".to_string(), vec![]))
+}
+
+fn lifespans(ages: &[u32], died: bool) -> Vec<Lifespan> {
+    ages.iter().map(|&age| Lifespan { age, died }).collect()
+}
+
+// A village of given sex and age; `alive: false` members are recorded at their age of death.
+fn village(members: &[(Sex, u32, bool)]) -> Census {
+    let mut population = Population::new(Environment::default());
+    let mut ages = HashMap::new();
+    for &(sex, age, alive) in members {
+        let mut builder = HumanBuilder::new().sex(sex);
+        if !alive {
+            builder = builder.vitals(deceased());
+        }
+        let id = population.insert(builder.build()).unwrap();
+        ages.insert(id, age);
+    }
+    Census::of_population(&population, |id: Identifier| ages.get(&id).copied())
+}
+
+#[test]
+fn test_census_counts_the_living() {
+    let census = village(&[
+        (Sex::Male, 4, true),
+        (Sex::Male, 12, true),
+        (Sex::Female, 8, true),
+        (Sex::Female, 15, true),
+        (Sex::Female, 31, true),
+        (Sex::Unknown, 2, true),
+        (Sex::Male, 70, false),
+    ]);
+
+    assert_eq!(census.size(), 7);
+    assert_eq!(census.living(), 6);
+    assert_eq!(census.count_living(Sex::Male), 2);
+    assert_eq!(census.sex_ratio(), Some(2.0 / 3.0));
+    assert_eq!(census.mean_age(), Some(12.0));
+    assert_eq!(census.of_species("Homo sapiens").size(), 7);
+    assert_eq!(census.of_species("Canis latrans").size(), 0);
+}
+
+#[test]
+fn test_age_pyramid() {
+    let census = village(&[
+        (Sex::Male, 4, true),
+        (Sex::Male, 12, true),
+        (Sex::Female, 8, true),
+        (Sex::Female, 15, true),
+        (Sex::Female, 31, true),
+        (Sex::Unknown, 2, true),
+        (Sex::Male, 70, false),
+    ]);
+    let pyramid = census.pyramid(10);
+
+    // The dead are left out: four bands up to the 31-year-old.
+    assert_eq!(pyramid.total(), 6);
+    let bands: Vec<_> = pyramid.bands().iter().map(|band| (band.from, band.male, band.female, band.unknown)).collect();
+    assert_eq!(bands, vec![(0, 1, 1, 1), (10, 1, 1, 0), (20, 0, 0, 0), (30, 0, 1, 0)]);
+
+    let chart = pyramid.render(2);
+    let lines: Vec<&str> = chart.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], "30-39   |##");
+    assert_eq!(lines[3], "  0-9 ##|##  (+1)");
+}
+
+#[test]
+fn test_life_table_from_deaths() {
+    // One death at each age from 0 to 3: a quarter of the cohort per tick.
+    let table = LifeTable::from_lifespans(&lifespans(&[0, 1, 2, 3], true), 1);
+
+    let survival: Vec<f64> = table.survival_curve().iter().map(|&(_, survivorship)| survivorship).collect();
+    assert_eq!(survival, vec![1.0, 0.75, 0.5, 0.25]);
+    assert_eq!(table.rows()[3].mortality, 1.0);
+
+    // Dying halfway through an interval on average: 0.5, 1.5, 2.5 and 3.5 ticks.
+    assert!((table.life_expectancy() - 2.0).abs() < 1e-9);
+    assert!((table.rows()[2].life_expectancy - 1.0).abs() < 1e-9);
+    assert_eq!(table.median_lifespan(), Some(2));
+}
+
+#[test]
+fn test_life_table_censors_the_living() {
+    let mut cohort = lifespans(&[1, 1], true);
+    cohort.extend(lifespans(&[1, 1], false));
+    let table = LifeTable::from_lifespans(&cohort, 1);
+
+    // Two deaths among three members exposed for the whole interval.
+    let row = table.rows()[1];
+    assert_eq!((row.at_risk, row.deaths, row.censored), (4, 2, 2));
+    assert!((row.mortality - 2.0 / 3.0).abs() < 1e-9);
+    assert!(LifeTable::from_lifespans(&[], 5).rows().is_empty());
+}
+
+#[test]
+fn test_statistics_from_a_simulation_run() {
+    let mut population = Population::new(Environment::default());
+    for _ in 0..20 {
+        population.insert(Mammal::new("Lepus californicus".to_string())).unwrap();
+    }
+    let mut simulation = Simulation::new(population, 11)
+        .with_rule(Aging)
+        .with_rule(Births::new(same_species).with_chance(0.05).with_maturity(10).with_energy_cost(0.0))
+        .with_rule(Mortality { lifespan: 60, background_chance: 0.01 });
+
+    let reports = simulation.run(100);
+    let periods = PeriodRates::from_reports(&reports, 20, 25);
+
+    assert_eq!(periods.len(), 4);
+    assert_eq!((periods[0].from_tick, periods[0].to_tick), (1, 25));
+    assert_eq!(periods[0].start, 20);
+    for pair in periods.windows(2) {
+        assert_eq!(pair[0].end, pair[1].start);
+    }
+    let births: usize = periods.iter().map(|period| period.births).sum();
+    let deaths: usize = periods.iter().map(|period| period.deaths).sum();
+    assert_eq!(20 + births - deaths, reports.last().unwrap().alive);
+    // The founders all die of old age at 60 ticks.
+    assert!(periods[2].causes.get("Old age").copied().unwrap_or(0) > 0);
+    assert!(periods.iter().all(|period| period.birth_rate() >= 0.0 && period.death_rate() >= 0.0));
+
+    let census = Census::of_simulation(&simulation);
+    assert_eq!(census.living(), reports.last().unwrap().alive);
+    assert_eq!(census.size(), 20 + births);
+    assert!(census.entries().iter().all(|entry| entry.age.is_some_and(|age| age <= 60)));
+
+    let table = census.life_table(10);
+    assert!(table.life_expectancy() > 0.0);
+    assert!(table.survival_curve().windows(2).all(|pair| pair[1].1 <= pair[0].1));
+}
+
+#[test]
+fn test_carrying_capacity_from_resources() {
+    let mut environment = Environment::default();
+    environment.resources.insert(ResourceKind::Water, ResourcePool::new(50.0, 5.0));
+    environment.resources.insert(ResourceKind::FoodBiomass, ResourcePool::new(100.0, 20.0));
+
+    let capacity = CarryingCapacity::estimate(&environment, &DEFAULT_NEEDS).unwrap();
+    assert_eq!(capacity.members, 5.0);
+    assert_eq!(capacity.limiting, ResourceKind::Water);
+
+    // Big eaters: each needs ten times the food.
+    let grazers = CarryingCapacity::estimate(&environment, &[(ResourceKind::FoodBiomass, 10.0), (ResourceKind::Water, 1.0)]).unwrap();
+    assert_eq!((grazers.members, grazers.limiting), (2.0, ResourceKind::FoodBiomass));
+
+    // Nothing to drink at all.
+    let bare = Environment::default().with_resources(Default::default());
+    assert_eq!(CarryingCapacity::estimate(&bare, &DEFAULT_NEEDS).unwrap().members, 0.0);
+    assert!(CarryingCapacity::estimate(&environment, &[]).is_none());
+}
+
+#[test]
+fn test_carrying_capacity_follows_the_weather() {
+    let environment = Environment::new(Climate::Temperate, Terrain::Plains, Centimeters(0), 0.5);
+    let usual = CarryingCapacity::estimate(&environment, &DEFAULT_NEEDS).unwrap().members;
+
+    let mut drought = environment.clone();
+    drought.weather = Some(Conditions::for_climate(&Climate::Temperate, 196, None, Some(WeatherEvent::Drought)));
+    let mut winter = environment.clone();
+    winter.weather = Some(Conditions::for_climate(&Climate::Temperate, 15, None, None));
+
+    assert!(CarryingCapacity::estimate(&drought, &DEFAULT_NEEDS).unwrap().members < usual);
+    let lean = CarryingCapacity::estimate(&winter, &DEFAULT_NEEDS).unwrap();
+    assert!(lean.members < usual);
+    assert_eq!(lean.limiting, ResourceKind::FoodBiomass);
+}
+
+#[test]
+fn test_carrying_capacity_of_a_landscape() {
+    let environment = Environment::default();
+    let cell = CarryingCapacity::estimate(&environment, &DEFAULT_NEEDS).unwrap();
+    let population = Population::new(environment.clone()).with_landscape(Landscape::new(3, 2, Topology::Square, environment));
+
+    let whole = CarryingCapacity::of_population(&population, &DEFAULT_NEEDS).unwrap();
+    assert!((whole.members - 6.0 * cell.members).abs() < 1e-9);
+    assert_eq!(whole.limiting, cell.limiting);
+}
+
+#[test]
+fn test_logistic_growth() {
+    let model = Logistic::new(0.5, 100.0);
+
+    assert_eq!(model.size_at(10.0, 0.0), 10.0);
+    assert_eq!(model.rate(50.0), 12.5);
+    assert_eq!(model.rate(100.0), 0.0);
+    assert!((model.size_at(100.0, 10.0) - 100.0).abs() < 1e-9);
+    assert!(model.size_at(10.0, 40.0) > 99.9);
+
+    let trajectory = model.trajectory(10.0, 1.0, 30);
+    assert_eq!(trajectory.len(), 31);
+    assert!(trajectory.windows(2).all(|pair| pair[1] > pair[0]));
+
+    let half = model.time_to(10.0, 50.0).unwrap();
+    assert!((model.size_at(10.0, half) - 50.0).abs() < 1e-9);
+    assert_eq!(model.time_to(10.0, 100.0), None);
+    assert_eq!(model.time_to(60.0, 50.0), None);
+}
+
+#[test]
+fn test_fitting_logistic_growth_to_counts() {
+    // Counts from the discrete model are exactly linear in per-capita growth.
+    let mut sizes = vec![5.0];
+    for _ in 0..30 {
+        let n: f64 = *sizes.last().unwrap();
+        sizes.push(n + 0.3 * n * (1.0 - n / 200.0));
+    }
+    let fitted = Logistic::fit(&sizes, 1.0).unwrap();
+    assert!((fitted.growth_rate - 0.3).abs() < 1e-6);
+    assert!((fitted.capacity - 200.0).abs() < 1e-3);
+
+    // Unchecked growth has no capacity to find.
+    let exponential: Vec<f64> = (0..10).map(|step| 2f64.powi(step)).collect();
+    assert_eq!(Logistic::fit(&exponential, 1.0), None);
+    assert_eq!(Logistic::fit(&[10.0], 1.0), None);
+}
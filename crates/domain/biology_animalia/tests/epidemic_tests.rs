@@ -0,0 +1,183 @@
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::interactions::Interaction;
+use biology::ecosystem::population::Population;
+use biology::epidemiology::pathogens::{PLASMODIUM_FALCIPARUM, RABIES};
+use biology::epidemiology::{Compartment, Pathogen, PathogenKind, Seir, SeirCounts};
+use biology::simulation::{CauseOfDeath, Epidemic, Simulation, SimulationEvent};
+use biology::vitals::blood_pressure::BloodPressure;
+use biology::vitals::live_vitals::LiveVitals;
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::{Celsius, BPM};
+use biology_animalia::{Animal, Human, Mammal};
+use core_shared::Identifier;
+
+// Certain to spread on contact and harmless in the end, to make outcomes predictable.
+const TEST_FEVER: Pathogen = Pathogen {
+    name: "Test fever",
+    kind: PathogenKind::Viral,
+    hosts: &["Canis"],
+    vectors: &[],
+    transmission: 1.0,
+    incubation: 2,
+    infectious_period: 3,
+    lethality: 0.0,
+    fever: 2,
+    tachycardia: 30,
+    virulence: 0.0,
+    lasting_immunity: true,
+};
+
+fn healthy() -> VitalStatus {
+    VitalStatus::Alive(LiveVitals::new(BloodPressure(120, 80), Celsius(38), BPM(90), 20))
+}
+
+fn pack(size: usize) -> (Population, Vec<Identifier>) {
+    let mut population = Population::new(Environment::default());
+    let ids = (0..size)
+        .map(|_| {
+            population
+                .insert(Mammal::new("Canis latrans".to_string()).with_vitals(healthy()))
+                .unwrap()
+        })
+        .collect();
+    (population, ids)
+}
+
+fn vitals_of(simulation: &Simulation, id: Identifier) -> LiveVitals {
+    match simulation.population().get_as::<Mammal>(id).unwrap().vitals.clone() {
+        VitalStatus::Alive(vitals) => vitals,
+        VitalStatus::Deceased(_) => panic!("expected a living member"),
+    }
+}
+
+#[test]
+fn test_course_of_infection() {
+    let (population, ids) = pack(2);
+    let (patient_zero, contact) = (ids[0], ids[1]);
+    let mut simulation = Simulation::new(population, 1)
+        .with_rule(Epidemic::new(TEST_FEVER).with_contact_rate(4).with_case(patient_zero));
+
+    let report = simulation.step();
+    assert!(report.events.contains(&SimulationEvent::Infected { id: contact, pathogen: "Test fever".to_string() }));
+    let epidemic = simulation.rule::<Epidemic>().unwrap();
+    assert_eq!(epidemic.compartment(patient_zero), Compartment::Infectious);
+    assert_eq!(epidemic.compartment(contact), Compartment::Exposed);
+    assert_eq!(epidemic.counts(), SeirCounts::new(0.0, 1.0, 1.0, 0.0, 0.0));
+
+    // Symptoms: fever and a racing pulse while infectious.
+    let sick = vitals_of(&simulation, patient_zero);
+    assert_eq!(sick.temperature, Celsius(40));
+    assert_eq!(sick.pulse, BPM(120));
+
+    simulation.run(2);
+    assert_eq!(simulation.rule::<Epidemic>().unwrap().compartment(contact), Compartment::Infectious);
+
+    simulation.run(3);
+    let epidemic = simulation.rule::<Epidemic>().unwrap();
+    assert_eq!(epidemic.compartment(patient_zero), Compartment::Recovered);
+    assert_eq!(epidemic.compartment(contact), Compartment::Recovered);
+    assert_eq!(epidemic.history().len(), 6);
+    assert_eq!(vitals_of(&simulation, patient_zero).temperature, Celsius(38));
+    assert_eq!(vitals_of(&simulation, contact).pulse, BPM(90));
+}
+
+#[test]
+fn test_lethal_infections_end_in_death() {
+    let (population, ids) = pack(1);
+    let lethal = Pathogen { lethality: 1.0, ..TEST_FEVER };
+    let mut simulation = Simulation::new(population, 1).with_rule(Epidemic::new(lethal).with_case(ids[0]));
+
+    let reports = simulation.run(3);
+
+    assert!(reports[2].events.contains(&SimulationEvent::Died {
+        id: ids[0],
+        cause: CauseOfDeath::Disease { pathogen: "Test fever".to_string() },
+    }));
+    let member = simulation.population().get_as::<Mammal>(ids[0]).unwrap();
+    assert!(matches!(&member.vitals, VitalStatus::Deceased(report) if report.cause_of_death == "Disease (Test fever)"));
+    assert_eq!(simulation.rule::<Epidemic>().unwrap().counts().dead, 1.0);
+}
+
+#[test]
+fn test_other_species_are_not_susceptible() {
+    let (mut population, ids) = pack(1);
+    let hare = population.insert(Animal::new("Lepus californicus".to_string())).unwrap();
+    let mut simulation =
+        Simulation::new(population, 1).with_rule(Epidemic::new(TEST_FEVER).with_contact_rate(10).with_case(ids[0]));
+
+    simulation.run(5);
+
+    let epidemic = simulation.rule::<Epidemic>().unwrap();
+    assert_eq!(epidemic.compartment(hare), Compartment::Susceptible);
+    assert_eq!(epidemic.counts().total(), 1.0);
+}
+
+#[test]
+fn test_immunity_can_wane() {
+    let (population, ids) = pack(1);
+    let seasonal = Pathogen { lasting_immunity: false, ..TEST_FEVER };
+    let mut simulation = Simulation::new(population, 1).with_rule(Epidemic::new(seasonal).with_case(ids[0]));
+
+    let reports = simulation.run(3);
+
+    assert!(reports[2].events.contains(&SimulationEvent::Recovered { id: ids[0], pathogen: "Test fever".to_string() }));
+    assert_eq!(simulation.rule::<Epidemic>().unwrap().compartment(ids[0]), Compartment::Susceptible);
+}
+
+#[test]
+fn test_vector_borne_spread_follows_bites() {
+    let mut population = Population::new(Environment::default());
+    let villagers: Vec<Identifier> = (0..3)
+        .map(|_| population.insert(Human::builder().build()).unwrap())
+        .collect();
+    let mosquito = population.insert(Animal::new("Anopheles gambiae".to_string())).unwrap();
+    for &host_id in &villagers[..2] {
+        population
+            .add_interaction(Interaction::Parasitism { parasite_id: mosquito, host_id })
+            .unwrap();
+    }
+    let malaria = Pathogen { transmission: 1.0, ..PLASMODIUM_FALCIPARUM };
+    let mut simulation = Simulation::new(population, 2)
+        .with_rule(Epidemic::new(malaria).with_contact_rate(10).with_case(villagers[0]));
+
+    simulation.run(15);
+
+    let epidemic = simulation.rule::<Epidemic>().unwrap();
+    assert_ne!(epidemic.compartment(mosquito), Compartment::Susceptible);
+    assert_ne!(epidemic.compartment(villagers[1]), Compartment::Susceptible);
+    // Never bitten, so never infected, however many people it meets.
+    assert_eq!(epidemic.compartment(villagers[2]), Compartment::Susceptible);
+    // The mosquito carries the parasite but is not a case.
+    assert_eq!(epidemic.counts().total(), 3.0);
+}
+
+#[test]
+fn test_same_seed_same_outbreak() {
+    let outbreak = |seed| {
+        let (population, ids) = pack(40);
+        let mut simulation =
+            Simulation::new(population, seed).with_rule(Epidemic::new(RABIES).with_contact_rate(3).with_case(ids[0]));
+        simulation.run(120);
+        simulation.rule::<Epidemic>().unwrap().history().to_vec()
+    };
+
+    assert_eq!(outbreak(5), outbreak(5));
+}
+
+#[test]
+fn test_seir_model() {
+    let model = Seir::for_pathogen(&TEST_FEVER, 1.5);
+
+    assert!((model.r0() - 4.5).abs() < 1e-9);
+    assert!((model.herd_immunity_threshold() - (1.0 - 1.0 / 4.5)).abs() < 1e-9);
+
+    let trajectory = model.trajectory(SeirCounts::new(999.0, 0.0, 1.0, 0.0, 0.0), 0.1, 2000);
+    let end = trajectory.last().unwrap();
+    assert!((end.total() - 1000.0).abs() < 1e-6);
+    // With R0 well above 1, nearly everyone catches it.
+    assert!(end.ever_infected() > 900.0);
+
+    let fizzle = Seir::new(0.1, 0.5, 0.5, 0.0);
+    let end = *fizzle.trajectory(SeirCounts::new(999.0, 0.0, 1.0, 0.0, 0.0), 0.1, 2000).last().unwrap();
+    assert!(end.ever_infected() < 5.0);
+}
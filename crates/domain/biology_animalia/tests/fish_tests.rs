@@ -0,0 +1,173 @@
+use biology::{Nomenclature, Organism, Sex, Summarizable};
+use biology::builder::OrganismBuilderExt;
+use biology::anatomy::appendage::{AppendageKind, FinPosition};
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::anatomy::fin_measurements::FinMeasurements;
+use biology::anatomy::limb_status::LimbStatus;
+use biology::characteristics::locomotion::{Locomotion, Mobility};
+use biology::characteristics::structural::Anatomy;
+use biology::ecosystem::environment::{Climate, Environment, Terrain};
+use biology::ecosystem::habitat::HabitatPreference;
+use biology::ecosystem::landscape::{Landscape, Position, Topology};
+use biology::ecosystem::population::Population;
+use biology::ecosystem::resources::{ResourceKind, ResourcePool, Resources};
+use biology::simulation::{MemberState, Migration, Reproduction, Simulation};
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::RespiratoryOrgan;
+use biology_animalia::fish::{Scales, WaterType};
+use biology_animalia::reproduction::{offspring, BROWN_TROUT, STRATEGIES};
+use biology_animalia::Fish;
+use core_shared::{Centimeters, Direction};
+
+fn water(terrain: Terrain, food: f64) -> Environment {
+    let mut resources = Resources::default();
+    resources.insert(ResourceKind::FoodBiomass, ResourcePool::new(food, 0.0));
+    Environment::new(Climate::Temperate, terrain, Centimeters(0), 0.9).with_resources(resources)
+}
+
+fn brown_trout() -> Fish {
+    Fish::builder("Salmo trutta")
+        .common_name("Brown trout")
+        .scales(Scales::Cycloid)
+        .build()
+        .unwrap()
+}
+
+fn atlantic_salmon() -> Fish {
+    Fish::builder("Salmo salar")
+        .common_name("Atlantic salmon")
+        .water(WaterType::Euryhaline)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_fish_anatomy_and_summary() {
+    let shark = Fish::builder("Carcharodon carcharias")
+        .common_name("Great white shark")
+        .name("Deep Blue")
+        .sex(Sex::Female)
+        .scales(Scales::Placoid)
+        .water(WaterType::Saltwater)
+        .fin_measurements(FinMeasurements { length: Centimeters(60), base: Centimeters(40) })
+        .build()
+        .unwrap();
+
+    let anatomy = shark.describe_anatomy();
+    assert!(anatomy.starts_with("A finned form with 7 attached fins."));
+    assert!(anatomy.contains("placoid denticles"));
+    assert!(anatomy.contains("living in salt water"));
+
+    let summary = shark.summary();
+    assert!(summary.contains("--- Fish: Deep Blue (Carcharodon carcharias) ---"));
+    assert!(summary.contains("Respiration: 70 gill beats/min"));
+    assert!(summary.contains("Left Pectoral Fin (Intact): Length: 60cm, Base: 40cm"));
+    assert!(summary.contains("Caudal Fin (Intact): Length: 120cm, Base: 80cm"));
+    assert!(shark.answers_to("great white shark"));
+}
+
+#[test]
+fn test_fish_breathe_through_gills_and_swim() {
+    let trout = brown_trout();
+
+    assert_eq!(trout.locomotion(), Locomotion::Aquatic);
+    assert!(trout.describe_locomotion().contains("tail fin"));
+    match trout.vitals() {
+        VitalStatus::Alive(vitals) => {
+            assert_eq!(vitals.respiratory_organ, RespiratoryOrgan::Gills);
+            assert_eq!(vitals.describe_respiration(), "70 gill beats/min");
+        }
+        VitalStatus::Deceased(_) => panic!("a new trout is alive"),
+    }
+}
+
+#[test]
+fn test_a_torn_fin_is_still_attached() {
+    let mut trout = brown_trout();
+    trout.set_fin_status(FinPosition::Pectoral(Direction::Left), LimbStatus::Injured("Torn".to_string()));
+    trout.set_fin_status(FinPosition::Dorsal, LimbStatus::Amputated);
+
+    assert_eq!(trout.count_appendages(AppendageKind::Fin), (6, 1));
+    assert!(trout.describe_anatomy().contains("It is noted that 1 fins are missing."));
+    assert!(trout.has_tail_fin());
+}
+
+#[test]
+fn test_fish_only_live_in_matching_water() {
+    let mut lake = Population::new(water(Terrain::Wetlands, 50.0));
+    let trout_id = lake.insert(brown_trout()).unwrap();
+    let salmon_id = lake.insert(atlantic_salmon()).unwrap();
+    let cod_id = lake
+        .insert(Fish::new("Gadus morhua".to_string()).with_water(WaterType::Saltwater))
+        .unwrap();
+
+    assert_eq!(lake.unsuited(), vec![cod_id]);
+    assert!(lake.get(trout_id).unwrap().tolerates(&water(Terrain::Wetlands, 0.0)));
+    assert!(!lake.get(trout_id).unwrap().tolerates(&water(Terrain::Ocean, 0.0)));
+    assert!(lake.get(salmon_id).unwrap().tolerates(&water(Terrain::Ocean, 0.0)));
+    assert!(!lake.get(salmon_id).unwrap().tolerates(&water(Terrain::Plains, 0.0)));
+}
+
+#[test]
+fn test_migration_keeps_freshwater_fish_out_of_the_sea() {
+    // A poor river mouth next to a rich sea.
+    let landscape = Landscape::from_fn(2, 1, Topology::Square, |position| {
+        if position.x == 0 {
+            water(Terrain::Wetlands, 10.0)
+        } else {
+            water(Terrain::Ocean, 100.0)
+        }
+    });
+    let mut population = Population::new(water(Terrain::Wetlands, 10.0)).with_landscape(landscape);
+    let trout_id = population.insert(brown_trout()).unwrap();
+    let salmon_id = population.insert(atlantic_salmon()).unwrap();
+    for id in [trout_id, salmon_id] {
+        population.place(id, Position::new(0, 0)).unwrap();
+    }
+
+    let mut simulation = Simulation::new(population, 1).with_rule(Migration::default());
+    simulation.run(2);
+
+    let population = simulation.population();
+    assert_eq!(population.position(trout_id), Some(Position::new(0, 0)));
+    assert_eq!(population.position(salmon_id), Some(Position::new(1, 0)));
+    assert!(population.unsuited().is_empty());
+}
+
+#[test]
+fn test_trout_spawn_hatches_into_fry() {
+    let habitat = HabitatPreference::on(&[Terrain::Wetlands]);
+    let trout = |sex| {
+        Fish::builder("Salmo trutta")
+            .common_name("Brown trout")
+            .sex(sex)
+            .scales(Scales::Cycloid)
+            .water(WaterType::Freshwater)
+            .habitat(habitat.clone())
+            .build()
+            .unwrap()
+    };
+    let (male, female) = (trout(Sex::Male), trout(Sex::Female));
+    let (male_id, female_id) = (male.id, female.id);
+    let mut population = Population::new(water(Terrain::Wetlands, 50.0));
+    population.insert(male).unwrap();
+    population.insert(female).unwrap();
+
+    let adult = BROWN_TROUT.maturity;
+    let mut simulation = Simulation::new(population, 5)
+        .with_state(male_id, MemberState::new(adult, 1.0))
+        .with_state(female_id, MemberState::new(adult, 1.0))
+        .with_rule(Reproduction::new(STRATEGIES, offspring).with_mating_chance(1.0));
+    simulation.run(BROWN_TROUT.period as u64 + 2);
+
+    let population = simulation.population();
+    let fry = population.children_of(female_id);
+    assert!(fry.len() >= BROWN_TROUT.litter.0 as usize);
+    for id in fry {
+        let fry = population.get_as::<Fish>(id).unwrap();
+        assert_eq!(fry.water, WaterType::Freshwater);
+        assert_eq!(fry.scales, Scales::Cycloid);
+        assert_eq!(fry.habitat, Some(habitat.clone()));
+        assert_eq!(fry.common_name.as_deref(), Some("Brown trout"));
+    }
+}
@@ -0,0 +1,205 @@
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::food_web::{FoodWeb, LinkKind};
+use biology::ecosystem::interactions::Interaction;
+use biology::ecosystem::population::Population;
+use biology::ecosystem::resources::ResourceKind;
+use biology_animalia::{Animal, Mammal};
+use core_shared::Identifier;
+
+fn member(population: &mut Population, species: &str) -> Identifier {
+    population.insert(Mammal::new(species.to_string())).unwrap()
+}
+
+fn predation(population: &mut Population, predator_id: Identifier, prey_id: Identifier) {
+    population
+        .add_interaction(Interaction::Predation { predator_id, prey_id })
+        .unwrap();
+}
+
+fn mutualism(population: &mut Population, participant1_id: Identifier, participant2_id: Identifier) {
+    population
+        .add_interaction(Interaction::Mutualism {
+            participant1_id,
+            participant2_id,
+            benefit: "Pollination".to_string(),
+        })
+        .unwrap();
+}
+
+// grass <- rabbit <- fox <- eagle, and the eagle also takes rabbits.
+fn meadow() -> Population {
+    let mut population = Population::new(Environment::default());
+    let grass = population.insert(Animal::new("Poa pratensis".to_string())).unwrap();
+    let rabbit = member(&mut population, "Oryctolagus cuniculus");
+    let second_rabbit = member(&mut population, "Oryctolagus cuniculus");
+    let fox = member(&mut population, "Vulpes vulpes");
+    let eagle = member(&mut population, "Aquila chrysaetos");
+
+    predation(&mut population, rabbit, grass);
+    predation(&mut population, second_rabbit, grass);
+    predation(&mut population, fox, rabbit);
+    predation(&mut population, eagle, fox);
+    predation(&mut population, eagle, second_rabbit);
+    population
+}
+
+#[test]
+fn test_links_are_species_level_and_point_at_the_consumer() {
+    let web = FoodWeb::from_population(&meadow());
+
+    assert_eq!(
+        web.species(),
+        ["Poa pratensis", "Oryctolagus cuniculus", "Vulpes vulpes", "Aquila chrysaetos"]
+    );
+    assert_eq!(web.links().len(), 4);
+
+    let grazing = &web.links()[0];
+    assert_eq!(grazing.from, "Poa pratensis");
+    assert_eq!(grazing.to, "Oryctolagus cuniculus");
+    assert_eq!(grazing.kind, LinkKind::Predation);
+    assert_eq!(grazing.weight, 2);
+
+    assert_eq!(web.resources_of("Aquila chrysaetos"), ["Vulpes vulpes", "Oryctolagus cuniculus"]);
+    assert_eq!(web.consumers_of("Oryctolagus cuniculus"), ["Vulpes vulpes", "Aquila chrysaetos"]);
+}
+
+#[test]
+fn test_trophic_levels() {
+    let levels = FoodWeb::from_population(&meadow()).trophic_levels();
+
+    assert_eq!(levels["Poa pratensis"], 1.0);
+    assert_eq!(levels["Oryctolagus cuniculus"], 2.0);
+    assert_eq!(levels["Vulpes vulpes"], 3.0);
+    // Half fox (3), half rabbit (2).
+    assert!((levels["Aquila chrysaetos"] - 3.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_trophic_levels_of_feeding_loops() {
+    // Pike eat perch and perch eat young pike; the perch also eat insects.
+    let mut web = FoodWeb::new();
+    web.add_link("Chironomus riparius", "Perca fluviatilis", LinkKind::Predation);
+    web.add_link("Esox lucius", "Perca fluviatilis", LinkKind::Predation);
+    web.add_link("Perca fluviatilis", "Esox lucius", LinkKind::Predation);
+
+    // perch = 1 + (1 + pike) / 2 and pike = 1 + perch.
+    let levels = web.trophic_levels();
+    assert!((levels["Perca fluviatilis"] - 4.0).abs() < 1e-9);
+    assert!((levels["Esox lucius"] - 5.0).abs() < 1e-9);
+
+    // A closed loop eats nothing from outside, so it stays at the base,
+    // however many unrelated species share the web.
+    let mut web = FoodWeb::new();
+    web.add_link("Species P", "Species Q", LinkKind::Predation);
+    web.add_link("Species Q", "Species P", LinkKind::Predation);
+    assert_eq!(web.trophic_levels()["Species P"], 1.0);
+    for i in 0..10 {
+        web.add_species(&format!("Bystander {}", i));
+    }
+    let levels = web.trophic_levels();
+    assert_eq!(levels["Species P"], 1.0);
+    assert_eq!(levels["Species Q"], 1.0);
+}
+
+#[test]
+fn test_connectance() {
+    let web = FoodWeb::from_population(&meadow());
+
+    // 4 distinct feeding links among 4 species.
+    assert!((web.connectance() - 4.0 / 16.0).abs() < 1e-12);
+    assert_eq!(FoodWeb::new().connectance(), 0.0);
+}
+
+#[test]
+fn test_keystone_species_causes_the_largest_cascade() {
+    let web = FoodWeb::from_population(&meadow());
+    let ranking = web.keystone_ranking();
+
+    // Without grass the rabbits starve, then the fox, then the eagle.
+    assert_eq!(ranking[0].species, "Poa pratensis");
+    assert_eq!(ranking[0].secondary_extinctions, 3);
+    // The eagle eats rabbits too, so losing the fox starves nobody.
+    let fox = ranking.iter().find(|score| score.species == "Vulpes vulpes").unwrap();
+    assert_eq!(fox.secondary_extinctions, 0);
+    assert_eq!(web.keystone_species().as_deref(), Some("Poa pratensis"));
+}
+
+#[test]
+fn test_no_keystone_without_feeding_links() {
+    let mut population = Population::new(Environment::default());
+    let coyote = member(&mut population, "Canis latrans");
+    let fox = member(&mut population, "Vulpes vulpes");
+    population
+        .add_interaction(Interaction::Competition {
+            competitor1_id: coyote,
+            competitor2_id: fox,
+            resource: ResourceKind::Water,
+        })
+        .unwrap();
+
+    let web = FoodWeb::from_population(&population);
+    assert_eq!(web.keystone_species(), None);
+    assert_eq!(web.connectance(), 0.0);
+    assert_eq!(web.trophic_levels()["Canis latrans"], 1.0);
+}
+
+#[test]
+fn test_mutualism_cycles() {
+    let mut population = Population::new(Environment::default());
+    let bee = member(&mut population, "Apis mellifera");
+    let clover = member(&mut population, "Trifolium repens");
+    let rhizobium = member(&mut population, "Rhizobium leguminosarum");
+    let fungus = member(&mut population, "Glomus intraradices");
+
+    // A loop of three, plus a pair hanging off it.
+    mutualism(&mut population, bee, clover);
+    mutualism(&mut population, clover, rhizobium);
+    mutualism(&mut population, rhizobium, bee);
+    mutualism(&mut population, clover, fungus);
+    mutualism(&mut population, fungus, clover);
+
+    let web = FoodWeb::from_population(&population);
+    assert_eq!(web.links().len(), 4);
+    assert_eq!(
+        web.mutualism_cycles(),
+        vec![vec![
+            "Apis mellifera".to_string(),
+            "Trifolium repens".to_string(),
+            "Rhizobium leguminosarum".to_string(),
+        ]]
+    );
+}
+
+#[test]
+fn test_mutualism_pairs_are_not_cycles() {
+    let mut population = Population::new(Environment::default());
+    let bee = member(&mut population, "Apis mellifera");
+    let clover = member(&mut population, "Trifolium repens");
+    mutualism(&mut population, bee, clover);
+
+    assert!(FoodWeb::from_population(&population).mutualism_cycles().is_empty());
+}
+
+#[test]
+fn test_dot_export() {
+    let dot = FoodWeb::from_population(&meadow()).to_dot();
+
+    assert!(dot.starts_with("digraph food_web {"));
+    assert!(dot.contains("\"Vulpes vulpes\" [label=\"Vulpes vulpes\\nTL 3.00\"];"));
+    assert!(dot.contains("\"Poa pratensis\" -> \"Oryctolagus cuniculus\" [label=\"predation\", penwidth=2"));
+    assert!(dot.trim_end().ends_with('}'));
+}
+
+#[test]
+fn test_graphml_export_escapes_names() {
+    let mut web = FoodWeb::new();
+    web.add_link("Algae <benthic>", "Snail & co", LinkKind::Predation);
+    web.add_link("Snail & co", "Algae <benthic>", LinkKind::Mutualism);
+
+    let xml = web.to_graphml();
+    assert!(xml.starts_with("<?xml"));
+    assert!(xml.contains("<node id=\"Algae &lt;benthic&gt;\">"));
+    assert!(xml.contains("source=\"Algae &lt;benthic&gt;\" target=\"Snail &amp; co\" directed=\"true\""));
+    assert!(xml.contains("directed=\"false\"><data key=\"kind\">mutualism</data>"));
+    assert!(xml.trim_end().ends_with("</graphml>"));
+}
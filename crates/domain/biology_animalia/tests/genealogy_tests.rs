@@ -0,0 +1,188 @@
+use biology::Sex;
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::population::Population;
+use biology::genealogy::{Individual, Pedigree, Relative};
+use biology::simulation::{MemberState, Reproduction, Simulation};
+use biology_animalia::genealogy::to_gedcom;
+use biology_animalia::reproduction::{offspring, STRATEGIES};
+use biology_animalia::{HumanBuilder, Mammal, NameParts};
+use core_shared::Identifier;
+
+fn goat(population: &mut Population, sex: Sex, parents: &[Identifier]) -> Identifier {
+    let id = population
+        .insert(Mammal::new("Capra hircus".to_string()).with_sex(sex))
+        .unwrap();
+    if !parents.is_empty() {
+        population.set_parents(id, parents).unwrap();
+    }
+    id
+}
+
+fn close(actual: f64, expected: f64) -> bool {
+    (actual - expected).abs() < 1e-9
+}
+
+#[test]
+fn test_ancestors_and_descendants() {
+    let mut population = Population::new(Environment::default());
+    let grandmother = goat(&mut population, Sex::Female, &[]);
+    let grandfather = goat(&mut population, Sex::Male, &[]);
+    let mother = goat(&mut population, Sex::Female, &[grandmother, grandfather]);
+    let father = goat(&mut population, Sex::Male, &[]);
+    let kid = goat(&mut population, Sex::Male, &[mother, father]);
+    let pedigree = Pedigree::from_population(&population);
+
+    assert_eq!(
+        pedigree.ancestors(kid, 1),
+        vec![Relative { id: mother, generation: 1 }, Relative { id: father, generation: 1 }]
+    );
+    assert_eq!(pedigree.ancestors(kid, 5).len(), 4);
+    assert_eq!(pedigree.ancestors(kid, 0), vec![]);
+    assert_eq!(
+        pedigree.descendants(grandfather, 2),
+        vec![Relative { id: mother, generation: 1 }, Relative { id: kid, generation: 2 }]
+    );
+    assert_eq!(pedigree.mother_of(kid), Some(mother));
+    assert_eq!(pedigree.father_of(kid), Some(father));
+    assert_eq!(pedigree.founders(), vec![grandmother, grandfather, father]);
+    assert!(pedigree.is_ancestor(grandmother, kid));
+    assert!(!pedigree.is_ancestor(kid, grandmother));
+}
+
+#[test]
+fn test_common_ancestors() {
+    let mut population = Population::new(Environment::default());
+    let doe = goat(&mut population, Sex::Female, &[]);
+    let buck = goat(&mut population, Sex::Male, &[]);
+    let sister = goat(&mut population, Sex::Female, &[doe, buck]);
+    let brother = goat(&mut population, Sex::Male, &[doe, buck]);
+    let stranger = goat(&mut population, Sex::Male, &[]);
+    let niece = goat(&mut population, Sex::Female, &[sister, stranger]);
+    let pedigree = Pedigree::from_population(&population);
+
+    assert_eq!(pedigree.common_ancestors(sister, brother), vec![doe, buck]);
+    assert_eq!(pedigree.common_ancestors(niece, brother), vec![doe, buck]);
+    // A parent is an ancestor its child shares with it.
+    assert_eq!(pedigree.common_ancestors(niece, sister), vec![sister, doe, buck]);
+    assert_eq!(pedigree.most_recent_common_ancestors(niece, sister), vec![sister]);
+    assert!(pedigree.common_ancestors(stranger, brother).is_empty());
+}
+
+#[test]
+fn test_kinship_and_inbreeding() {
+    let mut population = Population::new(Environment::default());
+    let doe = goat(&mut population, Sex::Female, &[]);
+    let buck = goat(&mut population, Sex::Male, &[]);
+    let other_buck = goat(&mut population, Sex::Male, &[]);
+    let sister = goat(&mut population, Sex::Female, &[doe, buck]);
+    let brother = goat(&mut population, Sex::Male, &[doe, buck]);
+    let half_brother = goat(&mut population, Sex::Male, &[doe, other_buck]);
+    let inbred = goat(&mut population, Sex::Male, &[sister, brother]);
+    let pedigree = Pedigree::from_population(&population);
+
+    assert!(close(pedigree.kinship(doe, doe), 0.5));
+    assert!(close(pedigree.kinship(doe, buck), 0.0));
+    assert!(close(pedigree.kinship(doe, sister), 0.25));
+    assert!(close(pedigree.kinship(sister, brother), 0.25));
+    assert!(close(pedigree.kinship(sister, half_brother), 0.125));
+    assert!(close(pedigree.relatedness(sister, brother), 0.5));
+
+    // The young of full siblings: F = 0.25, and more closely related to their parents.
+    assert!(close(pedigree.inbreeding(inbred), 0.25));
+    assert!(close(pedigree.inbreeding(sister), 0.0));
+    assert!(close(pedigree.kinship(inbred, inbred), 0.625));
+    assert!(close(pedigree.kinship(inbred, sister), 0.375));
+}
+
+#[test]
+fn test_clones_and_unknown_parents() {
+    let mut pedigree = Pedigree::new();
+    let individual = |sex: Sex| Individual {
+        id: Identifier::new(),
+        species: "Acyrthosiphon pisum".to_string(),
+        sex,
+        name: "Pea aphid".to_string(),
+        alive: true,
+    };
+    let (founder, clone, unrecorded) = (individual(Sex::Female), individual(Sex::Female), Identifier::new());
+    let (founder_id, clone_id) = (founder.id, clone.id);
+    pedigree.add(founder, &[unrecorded]);
+    pedigree.add(clone, &[founder_id]);
+
+    // A clone is as related to everyone as its one parent.
+    assert!(close(pedigree.kinship(founder_id, clone_id), 0.5));
+    assert!(close(pedigree.inbreeding(clone_id), 0.0));
+    assert_eq!(pedigree.ancestors(clone_id, 2).len(), 2);
+    assert_eq!(pedigree.size(), 2);
+}
+
+#[test]
+fn test_studbook() {
+    let mut population = Population::new(Environment::default());
+    let doe = goat(&mut population, Sex::Female, &[]);
+    let buck = goat(&mut population, Sex::Male, &[]);
+    let kid = goat(&mut population, Sex::Unknown, &[doe, buck]);
+    let studbook = Pedigree::from_population(&population).to_studbook();
+    let rows: Vec<Vec<&str>> = studbook.lines().map(|line| line.split('\t').collect()).collect();
+
+    assert_eq!(rows.len(), 4);
+    assert_eq!(rows[0], ["Stud", "Sex", "Sire", "Dam", "Status", "F", "Species", "Name", "Identifier"]);
+    assert_eq!(&rows[1][..6], ["1", "F", "UNK", "UNK", "Alive", "0.0000"]);
+    assert_eq!(&rows[3][..6], ["3", "U", "2", "1", "Alive", "0.0000"]);
+    assert_eq!(rows[3][6], "Capra hircus");
+    assert_eq!(rows[3][8], String::from(kid));
+}
+
+#[test]
+fn test_gedcom() {
+    let mut population = Population::new(Environment::default());
+    let mother = population
+        .insert(HumanBuilder::new().name(NameParts::from("Ada Smith")).sex(Sex::Female).build())
+        .unwrap();
+    let father = population
+        .insert(HumanBuilder::new().name(NameParts::from("Bo Smith")).sex(Sex::Male).build())
+        .unwrap();
+    let child = population
+        .insert(HumanBuilder::new().name(NameParts::from("Cy Smith")).sex(Sex::Male).build())
+        .unwrap();
+    let cat = population.insert(Mammal::new("Felis catus".to_string())).unwrap();
+    population.set_parents(child, &[mother, father]).unwrap();
+    population.set_parents(cat, &[mother]).unwrap();
+
+    let gedcom = to_gedcom(&population);
+    let lines: Vec<&str> = gedcom.lines().collect();
+
+    assert_eq!(lines.first(), Some(&"0 HEAD"));
+    assert_eq!(lines.last(), Some(&"0 TRLR"));
+    assert!(gedcom.contains("0 @I1@ INDI\n1 NAME Ada /Smith/\n2 GIVN Ada\n2 SURN Smith\n1 SEX F\n"));
+    assert!(gedcom.contains(&format!("1 REFN {}\n1 FAMC @F1@\n", String::from(child))));
+    assert!(gedcom.contains("0 @F1@ FAM\n1 HUSB @I2@\n1 WIFE @I1@\n1 CHIL @I3@\n"));
+    // Only humans are written.
+    assert_eq!(lines.iter().filter(|line| line.ends_with(" INDI")).count(), 3);
+    assert_eq!(lines.iter().filter(|line| line.ends_with(" FAM")).count(), 1);
+}
+
+#[test]
+fn test_pedigree_of_a_simulated_run() {
+    let mut population = Population::new(Environment::default());
+    let female = goat(&mut population, Sex::Female, &[]);
+    let male = population
+        .insert(Mammal::new("Canis latrans".to_string()).with_sex(Sex::Male))
+        .unwrap();
+    let mother = population
+        .insert(Mammal::new("Canis latrans".to_string()).with_sex(Sex::Female))
+        .unwrap();
+    let mut simulation = Simulation::new(population, 3)
+        .with_state(female, MemberState::new(400, 1.0))
+        .with_state(male, MemberState::new(400, 1.0))
+        .with_state(mother, MemberState::new(400, 1.0))
+        .with_rule(Reproduction::new(STRATEGIES, offspring).with_mating_chance(1.0));
+    simulation.run(64);
+
+    let pedigree = Pedigree::from_population(simulation.population());
+    let pups = pedigree.children_of(mother);
+    assert!(!pups.is_empty());
+    assert_eq!(pedigree.descendants(male, 3).len(), pups.len());
+    assert!(close(pedigree.kinship(pups[0], pups[pups.len() - 1]), if pups.len() > 1 { 0.25 } else { 0.5 }));
+    assert!(pedigree.children_of(female).is_empty());
+}
@@ -0,0 +1,201 @@
+use biology::Sex;
+use biology::anatomy::appendage::Appendage;
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::population::Population;
+use biology::genetics::{Genome, Genotype, Heredity};
+use biology::simulation::{MemberState, Reproduction, Simulation};
+use biology::templates::regional_defaults;
+use biology::vitals::blood_type::{BloodGroup, BloodType};
+use biology_animalia::genetics::{self, ABO, FUR, HEIGHT_1, HUMAN_LOCI, MAMMAL_LOCI, RH, STATURE};
+use biology_animalia::reproduction::{offspring, STRATEGIES};
+use biology_animalia::{Human, HumanBuilder, Mammal, NameParts};
+use core_shared::{Centimeters, SeededRng};
+
+fn tall(genome: Genome) -> Genome {
+    genome.with("HGT1", "+", "+").with("HGT2", "+", "+").with("HGT3", "+", "+")
+}
+
+fn span(human: &Human) -> Centimeters {
+    human
+        .appendages
+        .iter()
+        .find_map(|appendage| match appendage {
+            Appendage::Arm { measurements, .. } => Some(measurements.span),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_dominance() {
+    // Complete: O is masked by A.
+    assert_eq!(ABO.expressed(&Genotype::new("O", "A")), vec!["A"]);
+    assert_eq!(ABO.expressed(&Genotype::new("O", "O")), vec!["O"]);
+    // Codominant: A and B both show.
+    assert_eq!(ABO.expressed(&Genotype::new("B", "A")), vec!["A", "B"]);
+    assert_eq!(RH.value(&Genotype::new("d", "D")), 1.0);
+
+    // Incomplete: a heterozygote lands in between.
+    assert_eq!(HEIGHT_1.value(&Genotype::new("+", "+")), 0.04);
+    assert_eq!(HEIGHT_1.value(&Genotype::new("+", "-")), 0.0);
+    assert!(Genotype::new("+", "+").is_homozygous());
+
+    // Symbols the locus does not know are not expressed.
+    assert!(ABO.expressed(&Genotype::new("X", "Y")).is_empty());
+}
+
+#[test]
+fn test_polygenic_stature() {
+    let short = Genome::new().with("HGT1", "-", "-").with("HGT2", "-", "-").with("HGT3", "-", "-");
+    let mixed = Genome::new().with("HGT1", "+", "+").with("HGT2", "+", "-").with("HGT3", "-", "-");
+
+    assert!((STATURE.value(&tall(Genome::new())) - 1.12).abs() < 1e-9);
+    assert!((STATURE.value(&short) - 0.88).abs() < 1e-9);
+    assert!((STATURE.value(&mixed) - 1.0).abs() < 1e-9);
+    assert_eq!(genetics::stature(&Genome::new()), 1.0);
+}
+
+#[test]
+fn test_mendelian_ratios() {
+    let carrier = Genome::new().with("HR", "Hr", "hr");
+    let mut rng = SeededRng::new(5);
+    let mut counts = [0; 3];
+    for _ in 0..4000 {
+        let child = Genome::inherit(&carrier, Some(&carrier), MAMMAL_LOCI, 0.0, &mut rng);
+        let genotype = child.genotype("HR").unwrap();
+        let dominant = [&genotype.first, &genotype.second].into_iter().filter(|symbol| *symbol == "Hr").count();
+        counts[dominant] += 1;
+    }
+
+    // 1 : 2 : 1 genotypes, so a quarter of the young are hairless.
+    let share = |count: usize| count as f64 / 4000.0;
+    assert!((share(counts[0]) - 0.25).abs() < 0.03);
+    assert!((share(counts[1]) - 0.5).abs() < 0.03);
+    assert!((share(counts[2]) - 0.25).abs() < 0.03);
+}
+
+#[test]
+fn test_clones_and_mutation() {
+    let mother = Genome::new().with("HR", "Hr", "hr").with("XYZ", "x", "y");
+    let mut rng = SeededRng::new(1);
+
+    assert_eq!(Genome::inherit(&mother, None, MAMMAL_LOCI, 0.0, &mut rng), mother);
+
+    // At a two-allele locus every mutation swaps the allele; unknown loci never mutate.
+    let mutant = Genome::inherit(&mother, None, MAMMAL_LOCI, 1.0, &mut rng);
+    assert_eq!(mutant.genotype("HR"), Some(&Genotype::new("hr", "Hr")));
+    assert_eq!(mutant.genotype("XYZ"), mother.genotype("XYZ"));
+
+    let heredity = Heredity::new(MAMMAL_LOCI).with_mutation_rate(1.0);
+    let homozygous = Genome::new().with("HR", "hr", "hr");
+    assert_eq!(heredity.inherit(&homozygous, Some(&homozygous), &mut rng).genotype("HR"), Some(&Genotype::new("Hr", "Hr")));
+}
+
+#[test]
+fn test_random_founders() {
+    let mut rng = SeededRng::new(9);
+    let genome = Genome::random(HUMAN_LOCI, &mut rng);
+
+    assert_eq!(genome.loci().count(), HUMAN_LOCI.len());
+    assert!(genetics::blood_type(&genome).is_some());
+    assert!(genome.loci().all(|(name, genotype)| {
+        let locus = HUMAN_LOCI.iter().find(|locus| locus.name == name).unwrap();
+        locus.allele(&genotype.first).is_some() && locus.allele(&genotype.second).is_some()
+    }));
+}
+
+#[test]
+fn test_genome_maps_to_phenotype() {
+    let genome = tall(Genome::new().with("ABO", "A", "O").with("RHD", "d", "d"));
+    let human = HumanBuilder::new()
+        .with_anatomy_template(&regional_defaults::AVG_NORTH_AMERICAN_MALE)
+        .genome(genome.clone())
+        .build();
+
+    assert_eq!(human.blood_type, Some(BloodType::new(BloodGroup::A, false)));
+    assert_eq!(span(&human), Centimeters(196)); // 175 cm × 1.12
+    assert_eq!(human.genome, Some(genome));
+
+    // Without a genome, the builder's values are used as given.
+    let plain = HumanBuilder::new()
+        .with_anatomy_template(&regional_defaults::AVG_NORTH_AMERICAN_MALE)
+        .blood_type(BloodType::new(BloodGroup::O, true))
+        .build();
+    assert_eq!(plain.blood_type.unwrap().to_string(), "O+");
+    assert_eq!(span(&plain), Centimeters(175));
+
+    let hairless = Mammal::new("Cavia porcellus".to_string()).with_genome(Genome::new().with("HR", "hr", "hr"));
+    assert!(!hairless.has_fur);
+    assert_eq!(genetics::has_fur(&Genome::new()), None);
+}
+
+#[test]
+fn test_reproduction_passes_genomes_on() {
+    let mother = HumanBuilder::new()
+        .name(NameParts::from("Ada Smith"))
+        .sex(Sex::Female)
+        .genome(Genome::new().with("ABO", "A", "B").with("RHD", "d", "d"))
+        .build();
+    let father = HumanBuilder::new()
+        .name(NameParts::from("Bo Smith"))
+        .sex(Sex::Male)
+        .genome(Genome::new().with("ABO", "O", "O").with("RHD", "d", "d"))
+        .build();
+    let (mother_id, father_id) = (mother.id, father.id);
+
+    let mut population = Population::new(Environment::default());
+    population.insert(mother).unwrap();
+    population.insert(father).unwrap();
+    let mut simulation = Simulation::new(population, 4)
+        .with_state(mother_id, MemberState::new(9000, 1.0))
+        .with_state(father_id, MemberState::new(9000, 1.0))
+        .with_rule(
+            Reproduction::new(STRATEGIES, offspring)
+                .with_mating_chance(1.0)
+                .with_heredity(Heredity::new(HUMAN_LOCI).with_mutation_rate(0.0)),
+        );
+    simulation.run(281);
+
+    let population = simulation.population();
+    let children = population.children_of(mother_id);
+    assert_eq!(children.len(), 1);
+    let child = population.get_as::<Human>(children[0]).unwrap();
+    // An AB mother and an OO father have A or B children, never AB or O.
+    let blood_type = child.blood_type.unwrap();
+    assert!(matches!(blood_type.group, BloodGroup::A | BloodGroup::B));
+    assert!(!blood_type.rh_positive);
+    let abo = child.genome.as_ref().unwrap().genotype("ABO").unwrap();
+    assert!(abo.contains("O"));
+}
+
+#[test]
+fn test_mammals_without_genomes_inherit_the_old_way() {
+    let mother = Mammal::new("Canis latrans".to_string()).with_sex(Sex::Female).with_fur(false);
+    let father = Mammal::new("Canis latrans".to_string())
+        .with_sex(Sex::Male)
+        .with_genome(Genome::new().with(FUR.name, "Hr", "Hr"));
+    let (mother_id, father_id) = (mother.id, father.id);
+
+    let mut population = Population::new(Environment::default());
+    population.insert(mother).unwrap();
+    population.insert(father).unwrap();
+    let mut simulation = Simulation::new(population, 4)
+        .with_state(mother_id, MemberState::new(400, 1.0))
+        .with_state(father_id, MemberState::new(400, 1.0))
+        .with_rule(
+            Reproduction::new(STRATEGIES, offspring)
+                .with_mating_chance(1.0)
+                .with_heredity(Heredity::new(MAMMAL_LOCI)),
+        );
+    simulation.run(64);
+
+    // The mother has no genome to pass on, so the pups take after her.
+    let population = simulation.population();
+    let pups = population.children_of(mother_id);
+    assert!(!pups.is_empty());
+    for pup in pups {
+        let pup = population.get_as::<Mammal>(pup).unwrap();
+        assert!(!pup.has_fur);
+        assert!(pup.genome.is_none());
+    }
+}
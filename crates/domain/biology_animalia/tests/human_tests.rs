@@ -1,4 +1,10 @@
 use biology::{Nomenclature, Sex};
+use biology::anatomy::appendage::{Appendage, AppendageKind};
+use biology::anatomy::leg_measurements::LegMeasurements;
+use biology::anatomy::limb_status::LimbStatus;
+use biology::anatomy::wing_measurements::WingMeasurements;
+use biology::builder::BuildProblem;
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
 use biology::characteristics::structural::Anatomy;
 use biology::characteristics::locomotion::Mobility;
 use biology::characteristics::presentation::Summarizable;
@@ -7,7 +13,11 @@ use biology::vitals::blood_pressure::BloodPressure;
 use biology::vitals::live_vitals::LiveVitals;
 use biology::vitals::vital_status::VitalStatus;
 use biology::vitals::vital_types::{Celsius, BPM};
-use biology_animalia::{Human, NameParts};
+use biology::ecosystem::environment::Environment;
+use biology::nomenclature::nomenclature::NomenclatureComponents;
+use biology::ecosystem::population::Population;
+use biology_animalia::{Animal, Human, Moniker, NameKind, NameParts, NameRecord};
+use core_shared::{Centimeters, Direction};
 
 #[test]
 fn test_human_creation_with_builder() {
@@ -135,3 +145,147 @@ fn test_human_unique_identifiers() {
     // IDs should be unique (just verify they're different via string representation)
     assert_ne!(format!("{}", human1.id), format!("{}", human2.id));
 }
+
+#[test]
+fn test_human_keeps_name_and_designation() {
+    let human = Human::builder()
+        .name(NameParts::from("Jane Smith"))
+        .designation("Case #12345".to_string())
+        .build();
+
+    assert_eq!(human.display_name(), "Case #12345");
+    assert_eq!(human.legal_name().unwrap().display_name(), "Jane Smith");
+    assert_eq!(human.designations(), vec!["Case #12345"]);
+}
+
+#[test]
+fn test_human_preferred_name_is_displayed() {
+    let human = Human::builder()
+        .name(NameParts::from("Robert James Smith"))
+        .preferred_name(NameParts::from("Bob Smith"))
+        .build();
+
+    assert_eq!(human.display_name(), "Bob Smith");
+    assert_eq!(human.legal_name().unwrap().display_name(), "Robert James Smith");
+}
+
+#[test]
+fn test_human_name_history() {
+    let mut human = Human::builder()
+        .name(NameParts::from("Jane Smith"))
+        .alias(
+            NameRecord::former(NameParts::from("Jane Doe"), None, "2015-06-20".to_string()),
+        )
+        .build();
+    human.add_name(NameRecord::designation("Case #777".to_string()));
+
+    let former = human.former_names();
+    assert_eq!(former.len(), 1);
+    assert_eq!(former[0].kind, NameKind::Former);
+    assert!(matches!(&former[0].moniker, Moniker::Name(name) if name.last.as_deref() == Some("Doe")));
+
+    assert_eq!(human.names_on("2010-01-01").len(), 3);
+    assert_eq!(human.names_on("2020-01-01").len(), 2);
+    assert_eq!(human.display_name(), "Case #777");
+    assert!(human.answers_to("jane doe"));
+    assert!(human.summary().contains("Also known as"));
+}
+
+#[test]
+fn test_population_search_by_alias() {
+    let human = Human::builder()
+        .name(NameParts::from("Jane Smith"))
+        .designation("Case #12345".to_string())
+        .alias(NameRecord::former(NameParts::from("Jane Doe"), None, "2015-06-20".to_string()))
+        .build();
+    let animal = Animal::new("Canis lupus".to_string());
+
+    let mut population = Population::new(Environment::default());
+    population.insert(human).unwrap();
+    population.insert(animal).unwrap();
+
+    assert_eq!(population.find_by_alias("Jane Doe").len(), 1);
+    assert_eq!(population.find_by_alias("case #12345").len(), 1);
+    assert_eq!(population.find_by_alias("Canis lupus").len(), 1);
+    assert!(population.find_by_alias("John Roe").is_empty());
+}
+
+#[test]
+fn test_name_parts_are_kept_alongside_a_designation() {
+    let human = Human::builder()
+        .name_prefix("Dr.")
+        .first_name("Jane")
+        .last_name("Smith")
+        .name_suffix("PhD")
+        .designation("Case #12345".to_string())
+        .try_build()
+        .unwrap();
+
+    assert_eq!(human.display_name(), "Case #12345");
+    let legal = human.legal_name().unwrap();
+    assert_eq!(legal.first_name().as_deref(), Some("Jane"));
+    assert_eq!(legal.prefix_name().as_deref(), Some("Dr."));
+    assert_eq!(legal.suffix_name().as_deref(), Some("PhD"));
+}
+
+#[test]
+fn test_name_parts_override_the_structured_name() {
+    let human = Human::builder()
+        .name(NameParts::from("Jane Ann Smith"))
+        .last_name("Jones")
+        .build();
+
+    assert_eq!(human.last_name().as_deref(), Some("Jones"));
+    assert_eq!(human.middle_name().as_deref(), Some("Ann"));
+}
+
+#[test]
+fn test_try_build_lists_every_problem() {
+    let Err(error) = Human::builder()
+        .first_name(" ")
+        .with_vitals(VitalStatus::Alive(LiveVitals::new(BloodPressure(80, 120), Celsius(60), BPM(72), 16)))
+        .appendages(vec![Appendage::Wing {
+            side: Direction::Left,
+            measurements: WingMeasurements::default(),
+            status: LimbStatus::Intact,
+        }])
+        .absent_limb(Direction::Right, AppendageKind::Leg)
+        .try_build()
+    else {
+        panic!("an impossible human was built");
+    };
+
+    assert_eq!(error.problems.len(), 5);
+    assert_eq!(error.problems[0], BuildProblem::BlankName("first name"));
+    assert!(error.to_string().contains("diastolic pressure 120 is not below systolic 80"));
+    assert!(error.to_string().contains("Temperature of 60 °C is above 14-46 °C"));
+    assert!(error.to_string().contains("a human has no wings"));
+    assert!(error.to_string().contains("there is no right leg"));
+}
+
+#[test]
+fn test_default_builder_passes_validation() {
+    let human = Human::builder().name(NameParts::from("John Doe")).try_build().unwrap();
+
+    assert!(matches!(human.vitals, VitalStatus::Alive(ref vitals) if vitals.pulse == BPM(72)));
+    assert_eq!(human.count_limbs(), (2, 0, 2, 0));
+}
+
+#[test]
+fn test_congenital_limb_difference() {
+    let legs = LegMeasurements { inseam: Centimeters(70), quad: Centimeters(50) };
+    let human = Human::builder()
+        .name(NameParts::from("Sam Lee"))
+        .with_leg_measurements(legs)
+        .absent_limb(Direction::Left, AppendageKind::Arm)
+        .limb_status(Direction::Right, AppendageKind::Leg, LimbStatus::Prosthetic)
+        .try_build()
+        .unwrap();
+
+    assert_eq!(human.count_limbs(), (1, 1, 2, 0));
+    assert!(human.appendages.iter().any(|appendage| matches!(
+        appendage,
+        Appendage::Leg { side: Direction::Left, measurements, .. } if measurements.inseam == Centimeters(70)
+    )));
+    assert!(human.describe_anatomy().contains("1 arms and 0 legs are missing"));
+}
@@ -0,0 +1,134 @@
+use biology::{Organism, Sex, Summarizable};
+use biology::builder::OrganismBuilderExt;
+use biology::anatomy::appendage::AppendageKind;
+use biology::characteristics::locomotion::{Locomotion, Mobility};
+use biology::characteristics::structural::Anatomy;
+use biology::ecosystem::environment::{Climate, Environment, Terrain};
+use biology::ecosystem::population::Population;
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::simulation::{MemberState, Reproduction, Simulation};
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::Celsius;
+use biology_animalia::invertebrates::{ArachnidOrder, MolluscClass};
+use biology_animalia::reproduction::{offspring, GARDEN_SNAIL, PEA_APHID, STRATEGIES};
+use biology_animalia::{Arachnid, Insect, Mollusc};
+use core_shared::Centimeters;
+
+fn environment(terrain: Terrain) -> Environment {
+    Environment::new(Climate::Temperate, terrain, Centimeters(0), 0.6)
+}
+
+#[test]
+fn test_insects_have_six_legs_wings_and_antennae() {
+    let bee = Insect::builder("Apis mellifera").common_name("Western honey bee").build().unwrap();
+
+    assert_eq!(bee.count_limbs(), (0, 0, 6, 0));
+    assert_eq!(bee.count_appendages(AppendageKind::Wing), (4, 0));
+    assert_eq!(bee.count_appendages(AppendageKind::Antenna), (2, 0));
+    assert!(bee.describe_anatomy().starts_with(
+        "A six-legged form with 6 attached legs, 4 attached wings and 2 attached antennae."
+    ));
+    assert!(matches!(&bee.vitals, VitalStatus::Alive(vitals) if vitals.describe_respiration().ends_with("abdominal pumps/min")));
+    assert!(bee.summary().contains("--- Insect: Western honey bee (Apis mellifera) ---"));
+    assert_eq!(bee.locomotion(), Locomotion::Aerial);
+}
+
+#[test]
+fn test_wingless_insects_walk() {
+    let ant = Insect::new("Formica rufa".to_string()).wingless();
+    let fly = Insect::new("Musca domestica".to_string()).with_wing_pairs(1);
+
+    assert_eq!(ant.count_appendages(AppendageKind::Wing), (0, 0));
+    assert!(!ant.can_fly());
+    assert_eq!(ant.locomotion(), Locomotion::Terrestrial);
+    assert!(ant.describe_locomotion().starts_with("Wingless"));
+
+    assert_eq!(fly.wing_pairs(), 1);
+    assert_eq!(fly.locomotion(), Locomotion::Aerial);
+    assert!(fly.describe_locomotion().contains("a single pair of wings"));
+}
+
+#[test]
+fn test_arachnids_have_eight_legs() {
+    let spider = Arachnid::new("Araneus diadematus".to_string());
+    let scorpion = Arachnid::new("Androctonus australis".to_string()).with_order(ArachnidOrder::Scorpion);
+
+    assert_eq!(spider.count_limbs(), (0, 0, 8, 0));
+    assert_eq!(spider.count_appendages(AppendageKind::Antenna), (0, 0));
+    assert!(spider.describe_anatomy().starts_with("An eight-legged form with 8 attached legs."));
+    assert!(spider.spins_silk());
+    assert!(!scorpion.spins_silk());
+    assert!(scorpion.venomous);
+    assert_eq!(scorpion.locomotion(), Locomotion::Terrestrial);
+}
+
+#[test]
+fn test_molluscs_by_class() {
+    let snail = Mollusc::new("Cornu aspersum".to_string());
+    let mussel = Mollusc::new("Mytilus edulis".to_string()).with_class(MolluscClass::Bivalve);
+    let octopus = Mollusc::new("Octopus vulgaris".to_string()).with_class(MolluscClass::Cephalopod);
+
+    assert_eq!(snail.count_appendages(AppendageKind::Tentacle), (4, 0));
+    assert!(snail.describe_anatomy().starts_with("A tentacled form with 4 attached tentacles."));
+    assert!(snail.describe_anatomy().contains("coiled shell"));
+    assert!(mussel.describe_anatomy().starts_with("A legless form without appendages."));
+    assert_eq!(mussel.locomotion(), Locomotion::Sessile);
+    assert_eq!(octopus.count_appendages(AppendageKind::Tentacle), (8, 0));
+    assert!(!octopus.has_shell);
+    assert_eq!(octopus.locomotion(), Locomotion::Aquatic);
+
+    let (forest, wetlands, ocean) = (environment(Terrain::Forest), environment(Terrain::Wetlands), environment(Terrain::Ocean));
+    assert!(snail.tolerates(&forest));
+    assert!(mussel.tolerates(&wetlands) && !mussel.tolerates(&forest));
+    assert!(octopus.tolerates(&ocean) && !octopus.tolerates(&wetlands));
+}
+
+#[test]
+fn test_invertebrates_are_cold_blooded() {
+    let desert = Environment::new(Climate::Desert, Terrain::Plains, Centimeters(0), 0.1);
+    let ambient = Some(Celsius::from_degrees(desert.ambient_temperature()));
+
+    assert_eq!(Insect::new("Apis mellifera".to_string()).body_temperature_in(&desert), ambient);
+    assert_eq!(Arachnid::new("Hadrurus arizonensis".to_string()).body_temperature_in(&desert), ambient);
+    assert_eq!(Mollusc::new("Cornu aspersum".to_string()).body_temperature_in(&desert), ambient);
+}
+
+#[test]
+fn test_garden_snails_both_lay_eggs() {
+    let (first, second) = (Mollusc::new("Cornu aspersum".to_string()), Mollusc::new("Cornu aspersum".to_string()));
+    let (first_id, second_id) = (first.id, second.id);
+    let mut population = Population::new(environment(Terrain::Forest));
+    population.insert(first).unwrap();
+    population.insert(second).unwrap();
+
+    let mut simulation = Simulation::new(population, 9)
+        .with_state(first_id, MemberState::new(GARDEN_SNAIL.maturity, 1.0))
+        .with_state(second_id, MemberState::new(GARDEN_SNAIL.maturity, 1.0))
+        .with_rule(Reproduction::new(STRATEGIES, offspring).with_mating_chance(1.0));
+    simulation.run(GARDEN_SNAIL.period as u64 + 2);
+
+    let population = simulation.population();
+    for parent in [first_id, second_id] {
+        let young = population.children_of(parent);
+        assert!(!young.is_empty());
+        assert!(young.iter().all(|&id| population.get_as::<Mollusc>(id).unwrap().class == MolluscClass::Gastropod));
+    }
+}
+
+#[test]
+fn test_aphids_bear_young_without_mating() {
+    let mother = Insect::builder("Acyrthosiphon pisum").sex(Sex::Female).wingless().build().unwrap();
+    let mother_id = mother.id;
+    let mut population = Population::new(environment(Terrain::Plains));
+    population.insert(mother).unwrap();
+
+    let mut simulation = Simulation::new(population, 4)
+        .with_state(mother_id, MemberState::new(PEA_APHID.maturity, 1.0))
+        .with_rule(Reproduction::new(STRATEGIES, offspring).with_mating_chance(1.0));
+    simulation.run(PEA_APHID.period as u64 + 2);
+
+    let population = simulation.population();
+    let nymphs = population.children_of(mother_id);
+    assert!(nymphs.len() >= PEA_APHID.litter.0 as usize);
+    assert!(nymphs.iter().all(|&id| population.get_as::<Insect>(id).unwrap().wing_pairs() == 0));
+}
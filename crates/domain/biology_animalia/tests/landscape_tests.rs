@@ -0,0 +1,210 @@
+use biology::characteristics::Locomotion;
+use biology::ecosystem::environment::{Climate, Environment, Terrain};
+use biology::ecosystem::landscape::{movement_cost, Landscape, Position, Topology};
+use biology::ecosystem::population::{Population, PopulationError};
+use biology::ecosystem::resources::{ResourceKind, ResourcePool, Resources};
+use biology::simulation::{Births, Migration, Simulation, SimulationEvent};
+use biology::Organism;
+use biology_animalia::{Animal, Mammal};
+use core_shared::{Centimeters, Identifier};
+
+fn plains() -> Environment {
+    Environment::new(Climate::Temperate, Terrain::Plains, Centimeters(0), 0.5)
+}
+
+fn cell(terrain: Terrain) -> Environment {
+    Environment::new(Climate::Temperate, terrain, Centimeters(0), 0.5)
+}
+
+// A 3x3 square landscape with a 1000 m mountain ridge down the middle
+// column, broken by a pass in the bottom row.
+fn ridge() -> Landscape {
+    Landscape::from_fn(3, 3, Topology::Square, |position| {
+        if position.x == 1 && position.y < 2 {
+            let mut mountain = cell(Terrain::Mountains);
+            mountain.altitude = Centimeters(100_000);
+            mountain
+        } else {
+            cell(Terrain::Plains)
+        }
+    })
+}
+
+fn stocked(food: f64) -> Environment {
+    let mut resources = Resources::default();
+    resources.insert(ResourceKind::FoodBiomass, ResourcePool::new(food, 0.0));
+    plains().with_resources(resources)
+}
+
+#[test]
+fn test_square_and_hex_neighbours() {
+    let square = Landscape::new(3, 3, Topology::Square, plains());
+    assert_eq!(
+        square.neighbours(Position::new(1, 1)),
+        vec![Position::new(1, 0), Position::new(2, 1), Position::new(1, 2), Position::new(0, 1)]
+    );
+    assert_eq!(square.neighbours(Position::new(0, 0)).len(), 2);
+
+    let hex = Landscape::new(4, 4, Topology::Hex, plains());
+    assert_eq!(hex.neighbours(Position::new(1, 1)).len(), 6);
+    assert_eq!(hex.neighbours(Position::new(1, 2)).len(), 6);
+    // Odd rows lean right: (2, 0) touches (1, 1), but (0, 0) does not.
+    assert!(hex.neighbours(Position::new(1, 1)).contains(&Position::new(2, 0)));
+    assert!(!hex.neighbours(Position::new(1, 1)).contains(&Position::new(0, 0)));
+    for neighbour in hex.neighbours(Position::new(1, 2)) {
+        assert_eq!(hex.distance(Position::new(1, 2), neighbour), 1);
+    }
+    assert_eq!(hex.distance(Position::new(0, 0), Position::new(3, 3)), 5);
+    assert_eq!(square.distance(Position::new(0, 0), Position::new(2, 2)), 4);
+}
+
+#[test]
+fn test_movement_cost_depends_on_terrain_and_locomotion() {
+    assert_eq!(movement_cost(&Terrain::Plains, Locomotion::Terrestrial), Some(1.0));
+    assert_eq!(movement_cost(&Terrain::Mountains, Locomotion::Terrestrial), Some(4.0));
+    assert_eq!(movement_cost(&Terrain::Ocean, Locomotion::Terrestrial), None);
+    assert_eq!(movement_cost(&Terrain::Ocean, Locomotion::Aquatic), Some(1.0));
+    assert_eq!(movement_cost(&Terrain::Plains, Locomotion::Aquatic), None);
+    assert_eq!(movement_cost(&Terrain::Mountains, Locomotion::Aerial), Some(1.5));
+    assert_eq!(movement_cost(&Terrain::Plains, Locomotion::Sessile), None);
+}
+
+#[test]
+fn test_climbing_costs_extra() {
+    let mut landscape = Landscape::new(2, 1, Topology::Square, plains());
+    landscape.cell_mut(Position::new(1, 0)).unwrap().altitude = Centimeters(50_000);
+
+    let up = landscape.step_cost(Position::new(0, 0), Position::new(1, 0), Locomotion::Terrestrial);
+    let down = landscape.step_cost(Position::new(1, 0), Position::new(0, 0), Locomotion::Terrestrial);
+    assert_eq!(up, Some(6.0));
+    assert_eq!(down, Some(1.0));
+    // Not neighbours.
+    assert_eq!(landscape.step_cost(Position::new(0, 0), Position::new(0, 0), Locomotion::Terrestrial), None);
+}
+
+#[test]
+fn test_route_goes_round_the_ridge() {
+    let landscape = ridge();
+    let walk = landscape
+        .route(Position::new(0, 0), Position::new(2, 0), Locomotion::Terrestrial)
+        .unwrap();
+
+    // Down to the pass, across and back up is cheaper than over the top.
+    assert_eq!(walk.steps.first(), Some(&Position::new(0, 0)));
+    assert_eq!(walk.steps.last(), Some(&Position::new(2, 0)));
+    assert!(walk.steps.contains(&Position::new(1, 2)));
+    assert_eq!(walk.cost, 6.0);
+
+    // Fliers climb at half the cost, but over the top would still be 1.5 + 5 + 1.
+    let flight = landscape
+        .route(Position::new(0, 0), Position::new(2, 0), Locomotion::Aerial)
+        .unwrap();
+    assert_eq!(flight.steps.len(), 7);
+    assert_eq!(flight.cost, 6.0);
+
+    assert!(landscape.route(Position::new(0, 0), Position::new(2, 0), Locomotion::Aquatic).is_none());
+}
+
+#[test]
+fn test_placing_members() {
+    let mut population = Population::new(plains());
+    let coyote_id = population.insert(Mammal::new("Canis latrans".to_string())).unwrap();
+    assert_eq!(
+        population.place(coyote_id, Position::new(0, 0)),
+        Err(PopulationError::OutOfBounds(Position::new(0, 0)))
+    );
+
+    let mut population = Population::new(plains()).with_landscape(ridge());
+    let coyote_id = population.insert(Mammal::new("Canis latrans".to_string())).unwrap();
+    let fox_id = population.insert(Mammal::new("Vulpes vulpes".to_string())).unwrap();
+
+    population.place(coyote_id, Position::new(1, 0)).unwrap();
+    assert_eq!(
+        population.place(fox_id, Position::new(3, 0)),
+        Err(PopulationError::OutOfBounds(Position::new(3, 0)))
+    );
+    assert!(matches!(population.environment_of(coyote_id).terrain, Terrain::Mountains));
+    assert!(matches!(population.environment_of(fox_id).terrain, Terrain::Plains));
+    assert_eq!(population.members_at(Position::new(1, 0)), vec![coyote_id]);
+    assert_eq!(population.positions(), vec![(coyote_id, Position::new(1, 0))]);
+
+    population.remove(coyote_id);
+    assert_eq!(population.position(coyote_id), None);
+    assert!(population.members_at(Position::new(1, 0)).is_empty());
+}
+
+#[test]
+fn test_migration_seeks_the_better_cell() {
+    let landscape = Landscape::from_fn(3, 1, Topology::Square, |position| stocked(10.0 + 40.0 * position.x as f64));
+    let mut population = Population::new(plains()).with_landscape(landscape);
+    let deer_id = population.insert(Mammal::new("Odocoileus virginianus".to_string())).unwrap();
+    population.place(deer_id, Position::new(0, 0)).unwrap();
+
+    let mut simulation = Simulation::new(population, 1).with_rule(Migration::default());
+    let reports = simulation.run(3);
+
+    assert_eq!(
+        reports[0].events,
+        vec![SimulationEvent::Moved {
+            id: deer_id,
+            from: Position::new(0, 0),
+            to: Position::new(1, 0),
+        }]
+    );
+    assert_eq!(simulation.population().position(deer_id), Some(Position::new(2, 0)));
+    assert!(reports[2].events.is_empty());
+    assert!(simulation.state(deer_id).unwrap().energy < 1.0);
+}
+
+#[test]
+fn test_migration_respects_locomotion() {
+    let landscape = Landscape::from_fn(2, 1, Topology::Square, |position| {
+        if position.x == 0 {
+            stocked(10.0)
+        } else {
+            let mut sea = stocked(100.0);
+            sea.terrain = Terrain::Ocean;
+            sea
+        }
+    });
+    let mut population = Population::new(plains()).with_landscape(landscape);
+    let hare_id = population.insert(Mammal::new("Lepus europaeus".to_string())).unwrap();
+    let gull_id = population
+        .insert(Animal::new("Larus argentatus".to_string()).with_locomotion(Locomotion::Aerial))
+        .unwrap();
+    let lichen_id = population
+        .insert(Animal::new("Xanthoria parietina".to_string()).with_locomotion(Locomotion::Sessile))
+        .unwrap();
+    for id in [hare_id, gull_id, lichen_id] {
+        population.place(id, Position::new(0, 0)).unwrap();
+    }
+    assert_eq!(population.get(gull_id).unwrap().locomotion(), Locomotion::Aerial);
+
+    let mut simulation = Simulation::new(population, 1).with_rule(Migration::default());
+    simulation.step();
+
+    assert_eq!(simulation.population().position(hare_id), Some(Position::new(0, 0)));
+    assert_eq!(simulation.population().position(gull_id), Some(Position::new(1, 0)));
+    assert_eq!(simulation.population().position(lichen_id), Some(Position::new(0, 0)));
+}
+
+#[test]
+fn test_newborns_start_in_their_parents_cell() {
+    fn offspring(parent: &dyn Organism, id: Identifier) -> Option<Box<dyn Organism>> {
+        let mut fawn = Mammal::new(parent.species());
+        fawn.id = id;
+        Some(Box::new(fawn))
+    }
+
+    let mut population = Population::new(plains()).with_landscape(ridge());
+    let doe_id = population.insert(Mammal::new("Odocoileus virginianus".to_string())).unwrap();
+    population.place(doe_id, Position::new(2, 2)).unwrap();
+
+    let mut simulation = Simulation::new(population, 1).with_rule(Births::new(offspring).with_chance(1.0).with_maturity(0));
+    let report = simulation.step();
+
+    let Some(SimulationEvent::Born { id, .. }) = report.events.first() else {
+        panic!("expected a birth");
+    };
+    assert_eq!(simulation.population().position(*id), Some(Position::new(2, 2)));
+}
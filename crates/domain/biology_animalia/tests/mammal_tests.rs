@@ -0,0 +1,130 @@
+use biology::{Nomenclature, Organism, Summarizable};
+use biology::anatomy::appendage::AppendageKind;
+use biology::characteristics::locomotion::{Locomotion, Mobility};
+use biology::characteristics::structural::Anatomy;
+use biology::ecosystem::environment::{Climate, Environment, Terrain};
+use biology::ecosystem::population::Population;
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::simulation::{Aging, MemberState, Mortality, Simulation};
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::{Celsius, BPM};
+use biology_animalia::mammals::Diet;
+use biology_animalia::mammals::profiles::{CAT, CATTLE, DOG, HORSE, HUMPBACK_WHALE, LARGE_FLYING_FOX, PROFILES};
+use biology_animalia::Mammal;
+use core_shared::Centimeters;
+
+#[test]
+fn test_profiles_build_healthy_adults() {
+    for profile in PROFILES {
+        let mammal = Mammal::from_profile(profile);
+
+        assert_eq!(mammal.species, profile.species);
+        assert_eq!(mammal.display_name(), profile.common_name);
+        assert!(mammal.answers_to(profile.species));
+        assert_eq!(mammal.body_mass, Some(profile.body_mass));
+        assert_eq!(mammal.locomotion(), profile.locomotion);
+        assert!(mammal.vital_deviations().is_empty(), "{} should be healthy", profile.common_name);
+        assert!(profile.ranges.pulse.0.0 <= profile.ranges.pulse.1.0);
+    }
+}
+
+#[test]
+fn test_domestic_mammals_are_quadrupeds() {
+    for profile in [DOG, CAT, HORSE, CATTLE] {
+        let mammal = Mammal::from_profile(&profile);
+
+        assert_eq!(mammal.count_limbs(), (0, 0, 4, 0));
+        assert!(mammal.describe_anatomy().starts_with("A quadrupedal form with 4 attached legs."));
+        assert_eq!(mammal.describe_locomotion(), "Walks and runs on four legs.");
+    }
+    assert_eq!(HORSE.diet, Diet::Herbivore);
+    assert_eq!(CAT.diet, Diet::Carnivore);
+}
+
+#[test]
+fn test_bats_fly_and_whales_swim() {
+    let bat = Mammal::from_profile(&LARGE_FLYING_FOX);
+    let whale = Mammal::from_profile(&HUMPBACK_WHALE);
+
+    assert_eq!(bat.locomotion(), Locomotion::Aerial);
+    assert_eq!(bat.count_appendages(AppendageKind::Wing), (2, 0));
+    assert!(bat.describe_anatomy().starts_with("A bipedal form with 2 attached wings and 2 attached legs."));
+    assert!(bat.describe_locomotion().starts_with("Flies on membranous wings"));
+
+    assert_eq!(whale.locomotion(), Locomotion::Aquatic);
+    assert!(!whale.has_fur);
+    assert!(whale.describe_anatomy().starts_with("A finned form with 3 attached fins."));
+    assert!(whale.describe_locomotion().contains("tail fluke"));
+}
+
+#[test]
+fn test_whales_need_water() {
+    let ocean = Environment::new(Climate::Temperate, Terrain::Ocean, Centimeters(0), 1.0);
+    let plains = Environment::new(Climate::Temperate, Terrain::Plains, Centimeters(0), 0.4);
+    let whale = Mammal::from_profile(&HUMPBACK_WHALE);
+
+    assert!(whale.tolerates(&ocean));
+    assert!(!whale.tolerates(&plains));
+    assert!(Mammal::from_profile(&DOG).tolerates(&plains));
+
+    let mut population = Population::new(plains);
+    let whale_id = population.insert(whale).unwrap();
+    population.insert(Mammal::from_profile(&CATTLE)).unwrap();
+    assert_eq!(population.unsuited(), [whale_id]);
+}
+
+#[test]
+fn test_vitals_are_checked_against_the_species() {
+    let mut cat = Mammal::from_profile(&CAT);
+    let mut horse = Mammal::from_profile(&HORSE);
+    for mammal in [&mut cat, &mut horse] {
+        if let VitalStatus::Alive(vitals) = &mut mammal.vitals {
+            vitals.pulse = BPM(40);
+            vitals.temperature = Celsius(41);
+        }
+    }
+
+    assert_eq!(
+        cat.vital_deviations(),
+        ["Pulse of 40 BPM is below 140-220 BPM", "Temperature of 41 °C is above 38-39 °C"]
+    );
+    assert_eq!(horse.vital_deviations(), ["Temperature of 41 °C is above 37-38 °C"]);
+    assert!(Mammal::new("Canis lupus".to_string()).vital_deviations().is_empty());
+}
+
+#[test]
+fn test_profile_lifespans_drive_mortality() {
+    let dog = Mammal::from_profile(&DOG);
+    let summary = dog.summary();
+    assert!(summary.contains("Diet: omnivore"));
+    assert!(summary.contains("Lifespan: about 12 years"));
+
+    // Dogs and horses in one population each die at their own species' age.
+    let horse = Mammal::from_profile(&HORSE);
+    let stray = Mammal::new("Canis lupus".to_string());
+    let (dog_id, horse_id, stray_id) = (dog.id, horse.id, stray.id);
+    assert_eq!(dog.lifespan(), Some(DOG.lifespan));
+    assert_eq!(stray.lifespan(), None);
+
+    let mut population = Population::new(Environment::default());
+    population.insert(dog).unwrap();
+    population.insert(horse).unwrap();
+    population.insert(stray).unwrap();
+    let mut simulation = Simulation::new(population, 1)
+        .with_state(dog_id, MemberState::new(DOG.lifespan - 1, 1.0))
+        .with_state(horse_id, MemberState::new(DOG.lifespan - 1, 1.0))
+        .with_state(stray_id, MemberState::new(DOG.lifespan - 1, 1.0))
+        .with_rule(Aging)
+        .with_rule(Mortality { lifespan: DOG.lifespan + 10, background_chance: 0.0 });
+    simulation.run(2);
+
+    let alive = |id| simulation.population().get(id).unwrap().is_alive();
+    assert!(!alive(dog_id));
+    assert!(alive(horse_id));
+    // Without a profile, the rule's own lifespan applies.
+    assert!(alive(stray_id));
+
+    simulation.run(10);
+    assert!(!simulation.population().get(stray_id).unwrap().is_alive());
+    assert!(simulation.population().get(horse_id).unwrap().is_alive());
+}
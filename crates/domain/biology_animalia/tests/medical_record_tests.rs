@@ -0,0 +1,163 @@
+use biology::{Organism, Summarizable};
+use biology::builder::BuildProblem;
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::population::Population;
+use biology::medical::{Allergy, Condition, Encounter, EncounterKind, MedicalRecord, Medication, Procedure, Severity};
+use biology::simulation::{MemberState, Mortality, Simulation};
+use biology::vitals::blood_pressure::BloodPressure;
+use biology::vitals::blood_type::{BloodGroup, BloodType};
+use biology::vitals::live_vitals::LiveVitals;
+use biology::vitals::post_mortem_report::PostMortemReport;
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::{Celsius, BPM};
+use biology_animalia::{Human, NameParts};
+
+fn vitals(pulse: u32, temperature: u32) -> LiveVitals {
+    LiveVitals::new(BloodPressure(118, 76), Celsius(temperature), BPM(pulse), 16)
+}
+
+fn patient() -> Human {
+    let mut record = MedicalRecord::new().with_date_of_birth("1990-04-12");
+    record.add_allergy(Allergy::new("Penicillin", "Hives", Severity::Moderate));
+    record.diagnose(Condition::new("Asthma").diagnosed_on("2001-05-01"));
+    record.diagnose(Condition::new("Fractured wrist").diagnosed_on("2012-08-03").resolved_on("2012-10-01"));
+    record.prescribe(Medication::new("Salbutamol", "100 mcg", "as needed").started_on("2001-05-01"));
+    record.add_procedure(Procedure::new("Appendectomy", "2015-03-02").with_notes("Laparoscopic"));
+
+    Human::builder()
+        .name(NameParts::from("Jane Smith"))
+        .blood_type(BloodType::new(BloodGroup::A, true))
+        .medical_record(record)
+        .try_build()
+        .unwrap()
+}
+
+#[test]
+fn test_age_from_date_of_birth() {
+    let human = patient();
+
+    assert_eq!(human.age_on("2024-04-11"), Some(33));
+    assert_eq!(human.age_on("2024-04-12T08:00"), Some(34));
+    assert_eq!(human.age_on("1989-01-01"), None);
+    assert_eq!(human.age_on("2024-13-45"), None);
+    assert_eq!(human.age_on("2024-00-10"), None);
+    assert_eq!(Human::builder().build().age_on("2024-01-01"), None);
+}
+
+#[test]
+fn test_encounters_are_kept_in_order_and_update_vitals() {
+    let mut human = patient();
+    human.record_encounter(
+        Encounter::new("2024-03-01T09:30", EncounterKind::Checkup, "Annual physical").with_vitals(vitals(70, 37)),
+    );
+    human.record_encounter(
+        Encounter::new("2024-06-10T22:15", EncounterKind::Emergency, "Asthma attack")
+            .with_clinician("Dr. Patel")
+            .with_vitals(vitals(118, 38)),
+    );
+    // Filed late, so it does not replace the newer reading.
+    human.record_encounter(Encounter::new("2024-01-15", EncounterKind::FollowUp, "Inhaler review").with_vitals(vitals(64, 37)));
+
+    let record = &human.medical_record;
+    let timestamps: Vec<&str> = record.encounters().iter().map(|encounter| encounter.timestamp.as_str()).collect();
+    assert_eq!(timestamps, ["2024-01-15", "2024-03-01T09:30", "2024-06-10T22:15"]);
+    assert_eq!(record.vitals_history().len(), 3);
+    assert_eq!(record.latest_vitals().unwrap().pulse, BPM(118));
+    assert!(matches!(&human.vitals, VitalStatus::Alive(vitals) if vitals.pulse == BPM(118)));
+    assert_eq!(record.encounters_between("2024-03-01", "2024-06-01").len(), 1);
+}
+
+#[test]
+fn test_allergies_conditions_and_medications() {
+    let mut human = patient();
+    human.medical_record.prescribe(Medication::new("Penicillin V", "500 mg", "four times daily"));
+
+    let record = &human.medical_record;
+    assert!(record.is_allergic_to("penicillin"));
+    assert_eq!(record.active_conditions().len(), 1);
+    assert_eq!(record.current_medications().len(), 2);
+    assert_eq!(
+        record.allergy_warnings(),
+        ["Penicillin V is prescribed despite a moderate allergy to Penicillin"]
+    );
+}
+
+#[test]
+fn test_death_closes_the_record() {
+    let mut human = patient();
+    let report = PostMortemReport::new(
+        "Status asthmaticus".to_string(),
+        "2030-02-14".to_string(),
+        vec!["Mucus plugging of the airways".to_string()],
+    );
+    human.record_death(report);
+
+    assert!(matches!(human.vitals, VitalStatus::Deceased(_)));
+    assert!(human.medical_record.is_closed());
+    let last = human.medical_record.encounters().last().unwrap();
+    assert_eq!(last.kind, EncounterKind::PostMortem);
+    assert_eq!(last.notes, ["Mucus plugging of the airways"]);
+    assert!(human.medical_summary().contains("Deceased 2030-02-14: Status asthmaticus"));
+}
+
+#[test]
+fn test_dying_in_a_simulation_closes_the_record() {
+    let human = patient();
+    let id = human.id;
+    let mut population = Population::new(Environment::default());
+    population.insert(human).unwrap();
+    let mut simulation = Simulation::new(population, 1)
+        .with_state(id, MemberState::new(0, 0.0))
+        .with_rule(Mortality::default());
+
+    simulation.step();
+
+    let human = simulation.population().get_as::<Human>(id).unwrap();
+    assert!(human.medical_record.is_closed());
+    assert!(human.medical_summary().contains("Deceased Tick 1: Starvation"));
+}
+
+#[test]
+fn test_a_deceased_person_is_built_with_a_closed_record() {
+    let report = PostMortemReport::new("Unknown".to_string(), "2020-01-01".to_string(), Vec::new());
+    let human = Human::builder().vitals(VitalStatus::Deceased(report)).build();
+
+    assert!(human.medical_record.is_closed());
+    assert_eq!(human.medical_record.encounters().len(), 1);
+}
+
+#[test]
+fn test_medical_summary() {
+    let mut human = patient();
+    human.record_encounter(
+        Encounter::new("2024-03-01T09:30", EncounterKind::Checkup, "Annual physical").with_vitals(vitals(70, 37)),
+    );
+    let summary = human.medical_summary();
+
+    assert!(summary.starts_with("--- Patient: Jane Smith ---\nSex: Unknown\nBlood type: A+\n"));
+    assert!(summary.contains("Status: Alive; BP 118/76, 37 °C, 70 BPM, 16 breaths/min"));
+    assert!(summary.contains("Date of birth: 1990-04-12"));
+    assert!(summary.contains("  - Penicillin (moderate: Hives)"));
+    assert!(summary.contains("  - Asthma (since 2001-05-01)"));
+    assert!(summary.contains("  - Fractured wrist (2012-08-03 to 2012-10-01)"));
+    assert!(summary.contains("  - Salbutamol 100 mcg, as needed"));
+    assert!(summary.contains("  - 2015-03-02 Appendectomy - Laparoscopic"));
+    assert!(summary.contains("  - 2024-03-01T09:30 Checkup: Annual physical; BP 118/76"));
+    assert!(MedicalRecord::new().summary().contains("Allergies: None recorded"));
+}
+
+#[test]
+fn test_invalid_date_of_birth_is_reported() {
+    let Err(error) = Human::builder().date_of_birth("12/04/1990").try_build() else {
+        panic!("an invalid date of birth was accepted");
+    };
+
+    assert_eq!(
+        error.problems,
+        [BuildProblem::Invalid {
+            field: "date of birth",
+            reason: "12/04/1990 is not an ISO 8601 date".to_string(),
+        }]
+    );
+    assert!(Human::builder().date_of_birth("1990-13-45").try_build().is_err());
+}
@@ -0,0 +1,95 @@
+use biology::Nomenclature;
+use biology::nomenclature::conventions::{NameFormatting, NameStyle, NamingConvention};
+use biology::nomenclature::nomenclature::NomenclatureComponents;
+use biology_animalia::{Human, Moniker, NameParts};
+
+#[test]
+fn test_western_name_styles() {
+    let name = NameParts {
+        prefix: Some("Dr.".to_string()),
+        suffix: Some("Jr.".to_string()),
+        ..NameParts::from("Jane Marie Smith")
+    };
+
+    assert_eq!(name.format_name(NameStyle::Formal), "Dr. Jane Marie Smith Jr.");
+    assert_eq!(name.format_name(NameStyle::Informal), "Jane");
+    assert_eq!(name.format_name(NameStyle::SortKey), "Smith, Jane Marie, Jr.");
+    assert_eq!(name.format_name(NameStyle::Initials), "J. M. S.");
+}
+
+#[test]
+fn test_family_name_first_parsing_and_formatting() {
+    let name = NameParts::parse("Mao Zedong", NamingConvention::FamilyNameFirst);
+
+    assert_eq!(name.last_name(), Some("Mao".to_string()));
+    assert_eq!(name.first_name(), Some("Zedong".to_string()));
+    assert_eq!(name.format_name(NameStyle::Formal), "Mao Zedong");
+    assert_eq!(name.format_name(NameStyle::SortKey), "Mao, Zedong");
+    assert_eq!(name.format_name(NameStyle::Initials), "M. Z.");
+}
+
+#[test]
+fn test_patronymic_sorts_by_given_name() {
+    let name = NameParts::parse("Björk Guðmundsdóttir", NamingConvention::Patronymic);
+
+    assert_eq!(name.format_name(NameStyle::Formal), "Björk Guðmundsdóttir");
+    assert_eq!(name.format_name(NameStyle::SortKey), "Björk Guðmundsdóttir");
+    assert_eq!(name.format_name(NameStyle::Informal), "Björk");
+}
+
+#[test]
+fn test_double_surname() {
+    let name = NameParts::parse("Gabriel García Márquez", NamingConvention::DoubleSurname);
+
+    assert_eq!(name.last_name(), Some("García".to_string()));
+    assert_eq!(name.second_last_name(), Some("Márquez".to_string()));
+    assert_eq!(name.format_name(NameStyle::Formal), "Gabriel García Márquez");
+    assert_eq!(name.format_name(NameStyle::SortKey), "García Márquez, Gabriel");
+    assert_eq!(name.format_name(NameStyle::Initials), "G. G. M.");
+}
+
+#[test]
+fn test_convention_for_locale() {
+    assert_eq!(NamingConvention::for_locale("ja-JP"), NamingConvention::FamilyNameFirst);
+    assert_eq!(NamingConvention::for_locale("zh_CN"), NamingConvention::FamilyNameFirst);
+    assert_eq!(NamingConvention::for_locale("is"), NamingConvention::Patronymic);
+    assert_eq!(NamingConvention::for_locale("es-MX"), NamingConvention::DoubleSurname);
+    assert_eq!(NamingConvention::for_locale("en-US"), NamingConvention::GivenNameFirst);
+    assert_eq!(NamingConvention::for_locale(""), NamingConvention::GivenNameFirst);
+}
+
+#[test]
+fn test_human_display_name_follows_convention() {
+    let human = Human::builder()
+        .name(NameParts::parse("Yamada Taro", NamingConvention::FamilyNameFirst))
+        .build();
+
+    assert_eq!(human.display_name(), "Yamada Taro");
+    assert_eq!(human.format_name(NameStyle::Informal), "Taro");
+}
+
+#[test]
+fn test_designation_ignores_style() {
+    let moniker = Moniker::Designation("Case #12345".to_string());
+
+    assert_eq!(moniker.format_name(NameStyle::SortKey), "Case #12345");
+    assert_eq!(moniker.format_name(NameStyle::Initials), "Case #12345");
+}
+
+#[test]
+fn test_parse_keeps_extra_given_names_in_middle() {
+    let name = NameParts::parse("John Ronald Reuel Tolkien", NamingConvention::GivenNameFirst);
+
+    assert_eq!(name.first_name(), Some("John".to_string()));
+    assert_eq!(name.middle_name(), Some("Ronald Reuel".to_string()));
+    assert_eq!(name.last_name(), Some("Tolkien".to_string()));
+
+    let name = NameParts::parse(
+        "Gabriel José de la Concordia García Márquez",
+        NamingConvention::DoubleSurname,
+    );
+
+    assert_eq!(name.middle_name(), Some("José de la Concordia".to_string()));
+    assert_eq!(name.last_name(), Some("García".to_string()));
+    assert_eq!(name.second_last_name(), Some("Márquez".to_string()));
+}
@@ -0,0 +1,493 @@
+#![cfg(feature = "serde")]
+
+use biology::{Nomenclature, Sex};
+use biology::characteristics::Locomotion;
+use biology::ecosystem::environment::{Climate, Environment, Terrain};
+use biology::ecosystem::interactions::Interaction;
+use biology::ecosystem::landscape::{Landscape, Position, Topology};
+use biology::ecosystem::population::Population;
+use biology::genetics::Genome;
+use biology::medical::{Allergy, Encounter, EncounterKind, MedicalRecord, Severity};
+use biology::vitals::live_vitals::LiveVitals;
+use biology::ecosystem::resources::{ResourceKind, Resources};
+use biology::persistence::{PersistenceError, Versioned};
+use biology::templates::regional_defaults;
+use biology_animalia::persistence::{OrganismRecord, PopulationRecord};
+use biology_animalia::birds::{Flight, Plumage, PlumagePattern};
+use biology_animalia::fish::{Scales, WaterType};
+use biology_animalia::amphibians::AmphibianOrder;
+use biology_animalia::invertebrates::{ArachnidOrder, MolluscClass};
+use biology_animalia::mammals::profiles::HUMPBACK_WHALE;
+use biology_animalia::reptiles::Scales as ReptileScales;
+#[cfg(feature = "plantae")]
+use biology::builder::OrganismBuilderExt;
+#[cfg(feature = "plantae")]
+use biology_plantae::{Plant, PlantForm};
+use biology_animalia::{Amphibian, Animal, Arachnid, Bird, Fish, Human, Insect, Mammal, Mollusc, NameParts, Reptile};
+use core_shared::{Centimeters, Identifier};
+use serde_json::{json, Value};
+
+fn sample_human() -> Human {
+    Human::builder()
+        .name(NameParts::from("Jane Smith"))
+        .sex(Sex::Female)
+        .with_anatomy_template(&regional_defaults::AVG_NORTH_AMERICAN_FEMALE)
+        .build()
+}
+
+#[test]
+fn test_organism_document_envelope() {
+    let json = OrganismRecord::from(sample_human()).to_json().unwrap();
+    let value: Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(value["format"], "blocks_of_life");
+    assert_eq!(value["kind"], "organism");
+    assert_eq!(value["schema_version"], OrganismRecord::SCHEMA_VERSION);
+    assert_eq!(value["data"]["type"], "human");
+}
+
+#[test]
+fn test_organism_round_trip() {
+    let human = sample_human();
+    let id = human.id;
+
+    let json = OrganismRecord::from(human).to_json().unwrap();
+    let restored = OrganismRecord::from_json(&json).unwrap();
+
+    match restored {
+        OrganismRecord::Human(human) => {
+            assert_eq!(human.id, id);
+            assert_eq!(human.display_name(), "Jane Smith");
+        }
+        _ => panic!("expected a human"),
+    }
+}
+
+#[test]
+fn test_unversioned_organisms_are_migrated() {
+    // Written before documents had an envelope: just the bare struct.
+    let bare_human = serde_json::to_string(&sample_human()).unwrap();
+    let bare_mammal = serde_json::to_string(&Mammal::new("Felis catus".to_string())).unwrap();
+    let bare_animal = serde_json::to_string(&Animal::new("Aves genericus".to_string())).unwrap();
+
+    assert!(matches!(OrganismRecord::from_json(&bare_human), Ok(OrganismRecord::Human(_))));
+    assert!(matches!(OrganismRecord::from_json(&bare_mammal), Ok(OrganismRecord::Mammal(_))));
+    assert!(matches!(OrganismRecord::from_json(&bare_animal), Ok(OrganismRecord::Animal(_))));
+}
+
+#[test]
+fn test_medical_record_round_trip() {
+    let mut record = MedicalRecord::new().with_date_of_birth("1990-04-12");
+    record.add_allergy(Allergy::new("Latex", "Rash", Severity::Mild));
+    let mut human = Human::builder().name(NameParts::from("Jane Smith")).medical_record(record).build();
+    human.record_encounter(Encounter::new("2024-03-01", EncounterKind::Checkup, "Annual physical").with_vitals(LiveVitals::default()));
+
+    let json = OrganismRecord::from(human).to_json().unwrap();
+    match OrganismRecord::from_json(&json).unwrap() {
+        OrganismRecord::Human(human) => {
+            assert_eq!(human.age_on("2024-03-01"), Some(33));
+            assert!(human.medical_record.is_allergic_to("latex"));
+            assert!(human.medical_record.latest_vitals().is_some());
+        }
+        _ => panic!("expected a human"),
+    }
+}
+
+#[test]
+fn test_profiled_mammal_round_trip() {
+    let whale = Mammal::from_profile(&HUMPBACK_WHALE);
+
+    let json = OrganismRecord::from(whale.clone()).to_json().unwrap();
+    match OrganismRecord::from_json(&json).unwrap() {
+        OrganismRecord::Mammal(mammal) => {
+            assert_eq!(mammal.display_name(), "Humpback whale");
+            assert_eq!(mammal.appendages.len(), whale.appendages.len());
+            assert!(mammal.profile().is_some());
+        }
+        _ => panic!("expected a mammal"),
+    }
+}
+
+#[test]
+fn test_newer_documents_are_rejected() {
+    let json = json!({
+        "format": "blocks_of_life",
+        "kind": "organism",
+        "schema_version": OrganismRecord::SCHEMA_VERSION + 1,
+        "data": {}
+    })
+    .to_string();
+
+    assert!(matches!(
+        OrganismRecord::from_json(&json),
+        Err(PersistenceError::UnsupportedVersion { .. })
+    ));
+}
+
+#[test]
+fn test_wrong_document_kind_is_rejected() {
+    let json = PopulationRecord::new(Environment::default()).to_json().unwrap();
+
+    assert!(matches!(
+        OrganismRecord::from_json(&json),
+        Err(PersistenceError::WrongKind { expected: "organism", .. })
+    ));
+}
+
+#[test]
+fn test_population_round_trip() {
+    let human = sample_human();
+    let cat = Mammal::new("Felis catus".to_string());
+    let (human_id, cat_id) = (human.id, cat.id);
+
+    let mut population = Population::new(Environment::default());
+    population.insert(human).unwrap();
+    population.insert(cat).unwrap();
+    population
+        .add_interaction(Interaction::Predation {
+            predator_id: cat_id,
+            prey_id: human_id,
+        })
+        .unwrap();
+
+    let json = PopulationRecord::try_from(&population).unwrap().to_json().unwrap();
+    let restored = PopulationRecord::from_json(&json).unwrap().into_population().unwrap();
+
+    assert_eq!(restored.size(), 2);
+    assert_eq!(restored.get_as::<Human>(human_id).unwrap().display_name(), "Jane Smith");
+    assert_eq!(restored.interactions_involving(cat_id).count(), 1);
+    assert!(restored.landscape().is_none());
+}
+
+#[test]
+fn test_landscape_and_positions_round_trip() {
+    let hills = Environment::new(Climate::Temperate, Terrain::Mountains, Centimeters(80000), 0.4);
+    let landscape = Landscape::from_fn(2, 2, Topology::Hex, |position| {
+        if position.y == 0 {
+            hills.clone()
+        } else {
+            Environment::default()
+        }
+    });
+    let goat = Mammal::new("Capra hircus".to_string()).with_locomotion(Locomotion::Terrestrial);
+    let goat_id = goat.id;
+
+    let mut population = Population::new(Environment::default()).with_landscape(landscape);
+    population.insert(goat).unwrap();
+    population.place(goat_id, Position::new(1, 0)).unwrap();
+
+    let json = PopulationRecord::try_from(&population).unwrap().to_json().unwrap();
+    let restored = PopulationRecord::from_json(&json).unwrap().into_population().unwrap();
+
+    let landscape = restored.landscape().unwrap();
+    assert_eq!((landscape.width(), landscape.height(), landscape.topology()), (2, 2, Topology::Hex));
+    assert_eq!(restored.position(goat_id), Some(Position::new(1, 0)));
+    assert!(matches!(restored.environment_of(goat_id).terrain, Terrain::Mountains));
+}
+
+#[test]
+fn test_landscapes_that_do_not_fill_their_grid_are_rejected() {
+    let population = Population::new(Environment::default())
+        .with_landscape(Landscape::new(2, 2, Topology::Square, Environment::default()));
+    let json = PopulationRecord::try_from(&population).unwrap().to_json().unwrap();
+    let saved: Value = serde_json::from_str(&json).unwrap();
+
+    let mut missing_cell = saved.clone();
+    missing_cell["data"]["landscape"]["cells"].as_array_mut().unwrap().pop();
+    let Err(error) = PopulationRecord::from_json(&missing_cell.to_string()) else {
+        panic!("a landscape with a missing cell was loaded");
+    };
+    assert!(error.to_string().contains("3 cells do not fill a 2 × 2 landscape"));
+
+    let mut no_width = saved;
+    no_width["data"]["landscape"]["width"] = json!(0);
+    assert!(PopulationRecord::from_json(&no_width.to_string()).is_err());
+}
+
+#[test]
+fn test_parent_links_round_trip() {
+    let doe = Mammal::new("Capra hircus".to_string()).with_sex(Sex::Female);
+    let buck = Mammal::new("Capra hircus".to_string()).with_sex(Sex::Male);
+    let kid = Mammal::new("Capra hircus".to_string()).with_sex(Sex::Male);
+    let (doe_id, buck_id, kid_id) = (doe.id, buck.id, kid.id);
+
+    let mut population = Population::new(Environment::default());
+    for goat in [doe, buck, kid] {
+        population.insert(goat).unwrap();
+    }
+    population.set_parents(kid_id, &[doe_id, buck_id]).unwrap();
+
+    let json = PopulationRecord::try_from(&population).unwrap().to_json().unwrap();
+    let restored = PopulationRecord::from_json(&json).unwrap().into_population().unwrap();
+
+    assert_eq!(restored.parents_of(kid_id), [doe_id, buck_id]);
+    assert_eq!(restored.children_of(buck_id), vec![kid_id]);
+    assert_eq!(restored.get(kid_id).unwrap().sex(), Sex::Male);
+}
+
+#[test]
+fn test_bird_round_trip() {
+    let penguin = Bird::builder("Aptenodytes forsteri")
+        .plumage(Plumage::new(&["black", "white"], PlumagePattern::Countershaded))
+        .flight(Flight::Flightless)
+        .locomotion(Locomotion::Amphibious)
+        .build()
+        .unwrap();
+
+    let json = OrganismRecord::from(penguin.clone()).to_json().unwrap();
+    assert_eq!(serde_json::from_str::<Value>(&json).unwrap()["data"]["type"], "bird");
+
+    match OrganismRecord::from_json(&json).unwrap() {
+        OrganismRecord::Bird(bird) => {
+            assert_eq!(bird.id, penguin.id);
+            assert_eq!(bird.plumage, penguin.plumage);
+            assert_eq!(bird.flight, Flight::Flightless);
+            assert_eq!(bird.appendages.len(), 4);
+        }
+        _ => panic!("expected a bird"),
+    }
+}
+
+#[test]
+fn test_fish_round_trip() {
+    let sturgeon = Fish::new("Acipenser sturio".to_string())
+        .with_scales(Scales::Ganoid)
+        .with_water(WaterType::Euryhaline);
+
+    let json = OrganismRecord::from(sturgeon.clone()).to_json().unwrap();
+    let document: Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(document["data"]["type"], "fish");
+    assert_eq!(document["data"]["vitals"]["alive"]["respiratory_organ"], "gills");
+
+    match OrganismRecord::from_json(&json).unwrap() {
+        OrganismRecord::Fish(fish) => {
+            assert_eq!(fish.id, sturgeon.id);
+            assert_eq!(fish.scales, Scales::Ganoid);
+            assert_eq!(fish.water, WaterType::Euryhaline);
+            assert_eq!(fish.appendages.len(), 7);
+        }
+        _ => panic!("expected a fish"),
+    }
+}
+
+#[test]
+fn test_reptile_round_trip() {
+    let mut python = Reptile::new("Python regius".to_string())
+        .with_scales(ReptileScales::Smooth)
+        .limbless()
+        .with_shed_interval(50);
+    python.pass_days(12);
+
+    let json = OrganismRecord::from(python.clone()).to_json().unwrap();
+    assert_eq!(serde_json::from_str::<Value>(&json).unwrap()["data"]["type"], "reptile");
+
+    match OrganismRecord::from_json(&json).unwrap() {
+        OrganismRecord::Reptile(reptile) => {
+            assert_eq!(reptile.id, python.id);
+            assert_eq!(reptile.scales, ReptileScales::Smooth);
+            assert!(reptile.is_limbless());
+            assert_eq!(reptile.shedding, python.shedding);
+            assert!(reptile.lays_eggs());
+        }
+        _ => panic!("expected a reptile"),
+    }
+}
+
+#[test]
+fn test_impossible_shedding_cycles_are_rejected() {
+    let json = OrganismRecord::from(Reptile::new("Python regius".to_string())).to_json().unwrap();
+    let saved: Value = serde_json::from_str(&json).unwrap();
+
+    let mut never = saved.clone();
+    never["data"]["shedding"]["interval_days"] = json!(0);
+    assert!(OrganismRecord::from_json(&never.to_string()).is_err());
+
+    let mut overdue = saved;
+    overdue["data"]["shedding"] = json!({ "interval_days": 30, "days_since_shed": 45 });
+    let Err(error) = OrganismRecord::from_json(&overdue.to_string()) else {
+        panic!("an overdue shedding cycle was loaded");
+    };
+    assert!(error.to_string().contains("past the 30-day interval"));
+}
+
+#[test]
+fn test_amphibian_round_trip() {
+    let mut axolotl = Amphibian::new("Ambystoma mexicanum".to_string())
+        .with_order(AmphibianOrder::Salamander)
+        .as_larva();
+    axolotl.grow(20);
+
+    let json = OrganismRecord::from(axolotl.clone()).to_json().unwrap();
+    assert_eq!(serde_json::from_str::<Value>(&json).unwrap()["data"]["type"], "amphibian");
+
+    match OrganismRecord::from_json(&json).unwrap() {
+        OrganismRecord::Amphibian(amphibian) => {
+            assert_eq!(amphibian.order, AmphibianOrder::Salamander);
+            assert!(amphibian.is_larva());
+            assert_eq!(amphibian.days_to_metamorphosis, axolotl.days_to_metamorphosis);
+        }
+        _ => panic!("expected an amphibian"),
+    }
+}
+
+#[test]
+fn test_invertebrates_round_trip() {
+    let ant = Insect::new("Formica rufa".to_string()).wingless();
+    let scorpion = Arachnid::new("Pandinus imperator".to_string()).with_order(ArachnidOrder::Scorpion);
+    let octopus = Mollusc::new("Octopus vulgaris".to_string()).with_class(MolluscClass::Cephalopod);
+
+    let json = OrganismRecord::from(ant.clone()).to_json().unwrap();
+    assert!(matches!(
+        OrganismRecord::from_json(&json).unwrap(),
+        OrganismRecord::Insect(insect) if insect.id == ant.id && insect.wing_pairs() == 0
+    ));
+
+    let json = OrganismRecord::from(scorpion).to_json().unwrap();
+    assert_eq!(serde_json::from_str::<Value>(&json).unwrap()["data"]["order"], "scorpion");
+    assert!(matches!(
+        OrganismRecord::from_json(&json).unwrap(),
+        OrganismRecord::Arachnid(arachnid) if arachnid.order == ArachnidOrder::Scorpion
+    ));
+
+    let json = OrganismRecord::from(octopus).to_json().unwrap();
+    assert!(matches!(
+        OrganismRecord::from_json(&json).unwrap(),
+        OrganismRecord::Mollusc(mollusc) if mollusc.class == MolluscClass::Cephalopod && !mollusc.has_shell
+    ));
+}
+
+#[cfg(feature = "plantae")]
+#[test]
+fn test_plant_round_trip() {
+    let clover = Plant::builder("Trifolium repens")
+        .common_name("White clover")
+        .form(PlantForm::Herb)
+        .maturity(0.5)
+        .build()
+        .unwrap();
+    let rabbit = Mammal::new("Oryctolagus cuniculus".to_string());
+    let (clover_id, rabbit_id) = (clover.id, rabbit.id);
+
+    let json = OrganismRecord::from(clover.clone()).to_json().unwrap();
+    assert_eq!(serde_json::from_str::<Value>(&json).unwrap()["data"]["type"], "plant");
+    assert!(matches!(
+        OrganismRecord::from_json(&json).unwrap(),
+        OrganismRecord::Plant(plant) if plant.id == clover_id && plant.biomass == clover.biomass
+    ));
+
+    // A grazed meadow saves as a whole.
+    let mut meadow = Population::new(Environment::default());
+    meadow.insert(clover).unwrap();
+    meadow.insert(rabbit).unwrap();
+    meadow
+        .add_interaction(Interaction::Predation { predator_id: rabbit_id, prey_id: clover_id })
+        .unwrap();
+
+    let json = PopulationRecord::try_from(&meadow).unwrap().to_json().unwrap();
+    let restored = PopulationRecord::from_json(&json).unwrap().into_population().unwrap();
+
+    let plant = restored.get_as::<Plant>(clover_id).unwrap();
+    assert_eq!(plant.display_name(), "White clover");
+    assert_eq!(plant.form, PlantForm::Herb);
+    assert_eq!(restored.interactions_involving(clover_id).count(), 1);
+}
+
+#[test]
+fn test_genomes_round_trip() {
+    let genome = Genome::new().with("HR", "Hr", "hr");
+    let goat = Mammal::new("Capra hircus".to_string()).with_genome(genome.clone());
+    let goat_id = goat.id;
+
+    let mut population = Population::new(Environment::default());
+    population.insert(goat).unwrap();
+
+    let json = PopulationRecord::try_from(&population).unwrap().to_json().unwrap();
+    let restored = PopulationRecord::from_json(&json).unwrap().into_population().unwrap();
+
+    assert_eq!(restored.get(goat_id).unwrap().genome(), Some(&genome));
+}
+
+#[test]
+fn test_version_1_population_indices_are_migrated() {
+    let human = sample_human();
+    let cat = Mammal::new("Felis catus".to_string());
+    let (human_id, cat_id) = (human.id, cat.id);
+
+    // Version 1 referenced members by index, and allowed indices that pointed nowhere.
+    let json = json!({
+        "format": "blocks_of_life",
+        "kind": "population",
+        "schema_version": 1,
+        "data": {
+            "environment": serde_json::to_value(Environment::default()).unwrap(),
+            "members": [
+                serde_json::to_value(OrganismRecord::from(human)).unwrap(),
+                serde_json::to_value(OrganismRecord::from(cat)).unwrap(),
+            ],
+            "interactions": [
+                { "type": "predation", "predator_id": 1, "prey_id": 0 },
+                { "type": "predation", "predator_id": 0, "prey_id": 10 },
+            ]
+        }
+    })
+    .to_string();
+
+    let population = PopulationRecord::from_json(&json).unwrap().into_population().unwrap();
+
+    assert_eq!(population.interactions().len(), 1);
+    assert_eq!(population.interactions()[0].participants(), (cat_id, human_id));
+}
+
+#[test]
+fn test_dangling_interactions_are_rejected() {
+    let mut record = PopulationRecord::new(Environment::default());
+    record.members.push(sample_human().into());
+    record.interactions.push(Interaction::Parasitism {
+        parasite_id: Identifier::new(),
+        host_id: Identifier::new(),
+    });
+
+    assert!(matches!(record.into_population(), Err(PersistenceError::Invalid { .. })));
+}
+
+#[test]
+fn test_version_2_competition_resources_are_typed() {
+    let human = sample_human();
+    let cat = Mammal::new("Felis catus".to_string());
+    let (human_id, cat_id) = (human.id, cat.id);
+
+    // Version 2 named resources in free text and had no resource pools.
+    let json = json!({
+        "format": "blocks_of_life",
+        "kind": "population",
+        "schema_version": 2,
+        "data": {
+            "environment": { "climate": "desert", "terrain": "plains", "altitude": 0, "humidity": 0.1 },
+            "members": [
+                serde_json::to_value(OrganismRecord::from(human)).unwrap(),
+                serde_json::to_value(OrganismRecord::from(cat)).unwrap(),
+            ],
+            "interactions": [
+                { "type": "competition", "competitor1_id": human_id, "competitor2_id": cat_id, "resource": "Water sources" },
+                { "type": "competition", "competitor1_id": cat_id, "competitor2_id": human_id, "resource": "Attention" },
+            ]
+        }
+    })
+    .to_string();
+
+    let population = PopulationRecord::from_json(&json).unwrap().into_population().unwrap();
+
+    let resources: Vec<ResourceKind> = population
+        .interactions()
+        .iter()
+        .filter_map(|interaction| match interaction {
+            Interaction::Competition { resource, .. } => Some(*resource),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(resources, vec![ResourceKind::Water, ResourceKind::FoodBiomass]);
+    assert_eq!(
+        population.environment.resources,
+        Resources::for_habitat(&Climate::Desert, &Terrain::Plains)
+    );
+}
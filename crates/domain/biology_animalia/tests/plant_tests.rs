@@ -0,0 +1,99 @@
+use biology::Organism;
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::food_web::FoodWeb;
+use biology::ecosystem::interactions::Interaction;
+use biology::ecosystem::population::Population;
+use biology::ecosystem::weather::WeatherEvent;
+use biology::simulation::{
+    CauseOfDeath, MemberState, Metabolism, Mortality, Predation, Simulation, SimulationEvent, Weather,
+};
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::BPM;
+use biology_animalia::Mammal;
+use biology_plantae::{Grazing, Growth, Plant, PlantForm};
+use core_shared::Identifier;
+
+// Clover, and a rabbit that eats it.
+fn pasture() -> (Population, Identifier, Identifier) {
+    let mut population = Population::new(Environment::default());
+    let clover = population
+        .insert(Plant::new("Trifolium repens".to_string()).with_maturity(0.5))
+        .unwrap();
+    let rabbit = population.insert(Mammal::new("Oryctolagus cuniculus".to_string())).unwrap();
+    population
+        .add_interaction(Interaction::Predation { predator_id: rabbit, prey_id: clover })
+        .unwrap();
+    (population, clover, rabbit)
+}
+
+#[test]
+fn test_plants_are_the_food_webs_producers() {
+    let (mut population, _, rabbit) = pasture();
+    let fox = population.insert(Mammal::new("Vulpes vulpes".to_string())).unwrap();
+    population
+        .add_interaction(Interaction::Predation { predator_id: fox, prey_id: rabbit })
+        .unwrap();
+    let web = FoodWeb::from_population(&population);
+
+    assert_eq!(web.producers(), ["Trifolium repens"]);
+    assert_eq!(web.primary_consumers(), ["Oryctolagus cuniculus"]);
+    assert_eq!(web.trophic_levels()["Trifolium repens"], 1.0);
+    assert!(web.to_dot().contains("\"Trifolium repens\" [label=\"Trifolium repens\\nTL 1.00\", shape=box];"));
+}
+
+#[test]
+fn test_plants_are_grazed_not_hunted() {
+    let (population, clover, rabbit) = pasture();
+    let before = population.get_as::<Plant>(clover).unwrap().biomass;
+    let mut simulation = Simulation::new(population, 3)
+        .with_state(rabbit, MemberState::new(0, 0.2))
+        .with_rule(Predation { search_rate: 100.0, base_capture: 1.0, ..Predation::default() })
+        .with_rule(Grazing { chance: 1.0, ..Grazing::default() });
+
+    let report = simulation.step();
+
+    let eaten = simulation.population().get_as::<Plant>(clover).unwrap();
+    assert!(eaten.is_alive());
+    assert!((eaten.biomass - before * 0.9).abs() < 0.01);
+    assert!(report.events.contains(&SimulationEvent::Interacted {
+        interaction: Interaction::Predation { predator_id: rabbit, prey_id: clover },
+    }));
+    assert!((simulation.state(rabbit).unwrap().energy - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn test_growth_rule_grows_plants_and_withers_the_grazed_out() {
+    let mut population = Population::new(Environment::default());
+    let oak = population
+        .insert(Plant::new("Quercus robur".to_string()).with_form(PlantForm::Tree))
+        .unwrap();
+    let stump = population
+        .insert(Plant::new("Quercus robur".to_string()).with_maturity(0.0))
+        .unwrap();
+    let before = population.get_as::<Plant>(oak).unwrap().biomass;
+    let mut simulation = Simulation::new(population, 3).with_rule(Growth);
+
+    let report = simulation.step();
+
+    assert!(simulation.population().get_as::<Plant>(oak).unwrap().biomass > before);
+    assert!(report.events.contains(&SimulationEvent::Died { id: stump, cause: CauseOfDeath::Starvation }));
+}
+
+#[test]
+fn test_plants_live_through_metabolism_and_weather() {
+    let (population, clover, rabbit) = pasture();
+    let mut simulation = Simulation::new(population, 3)
+        .with_rule(Weather::new().with_start_day(15).with_event(WeatherEvent::Blizzard))
+        .with_rule(Growth)
+        .with_rule(Metabolism { upkeep: 0.5, foraging: 0.0 })
+        .with_rule(Mortality::default());
+
+    simulation.run(3);
+
+    // A blizzard neither chills the clover's pulse nor spends its energy.
+    let clover = simulation.population().get_as::<Plant>(clover).unwrap();
+    assert!(clover.is_alive());
+    assert!(matches!(&clover.vitals, VitalStatus::Alive(vitals) if vitals.pulse == BPM(0)));
+    assert_eq!(simulation.state(clover.id).unwrap().energy, 1.0);
+    assert!(!simulation.population().get(rabbit).unwrap().is_alive());
+}
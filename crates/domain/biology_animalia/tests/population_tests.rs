@@ -0,0 +1,98 @@
+use biology::{Nomenclature, Sex};
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::interactions::Interaction;
+use biology::ecosystem::population::{Population, PopulationError};
+use biology::ecosystem::resources::ResourceKind;
+use biology_animalia::{Animal, Human, Mammal, NameParts};
+use core_shared::Identifier;
+
+fn desert() -> Population {
+    Population::new(Environment::default())
+}
+
+#[test]
+fn test_insert_and_lookup() {
+    let mut population = desert();
+    let coyote = Mammal::new("Canis latrans".to_string());
+    let human = Human::builder()
+        .name(NameParts::from("Jane Smith"))
+        .sex(Sex::Female)
+        .build();
+
+    let coyote_id = population.insert(coyote).unwrap();
+    let human_id = population.insert(human).unwrap();
+
+    assert_eq!(population.size(), 2);
+    assert_eq!(population.get(coyote_id).unwrap().species(), "Canis latrans");
+    assert_eq!(population.get(human_id).unwrap().sex(), Sex::Female);
+    assert_eq!(population.get_as::<Human>(human_id).unwrap().display_name(), "Jane Smith");
+    assert!(population.get_as::<Mammal>(human_id).is_none());
+    assert_eq!(population.ids(), vec![coyote_id, human_id]);
+}
+
+#[test]
+fn test_duplicate_members_are_rejected() {
+    let mut population = desert();
+    let animal = Animal::new("Aves genericus".to_string());
+    let copy = animal.clone();
+
+    population.insert(animal).unwrap();
+
+    assert_eq!(population.insert(copy.clone()), Err(PopulationError::DuplicateMember(copy.id)));
+}
+
+#[test]
+fn test_interactions_must_reference_members() {
+    let mut population = desert();
+    let coyote_id = population.insert(Mammal::new("Canis latrans".to_string())).unwrap();
+    let stranger = Identifier::new();
+
+    let result = population.add_interaction(Interaction::Predation {
+        predator_id: coyote_id,
+        prey_id: stranger,
+    });
+
+    assert_eq!(result, Err(PopulationError::UnknownMember(stranger)));
+    assert!(population.interactions().is_empty());
+}
+
+#[test]
+fn test_remove_cascades_interactions() {
+    let mut population = desert();
+    let coyote_id = population.insert(Mammal::new("Canis latrans".to_string())).unwrap();
+    let rabbit_id = population.insert(Mammal::new("Lepus californicus".to_string())).unwrap();
+    let hawk_id = population.insert(Animal::new("Buteo jamaicensis".to_string())).unwrap();
+
+    population
+        .add_interaction(Interaction::Predation { predator_id: coyote_id, prey_id: rabbit_id })
+        .unwrap();
+    population
+        .add_interaction(Interaction::Predation { predator_id: hawk_id, prey_id: rabbit_id })
+        .unwrap();
+    population
+        .add_interaction(Interaction::Competition {
+            competitor1_id: coyote_id,
+            competitor2_id: hawk_id,
+            resource: ResourceKind::FoodBiomass,
+        })
+        .unwrap();
+
+    let removed = population.remove(rabbit_id).unwrap();
+
+    assert_eq!(removed.species(), "Lepus californicus");
+    assert_eq!(population.interactions().len(), 1);
+    assert!(population.get(rabbit_id).is_none());
+    // Later members are still found after the removal shifts them.
+    assert_eq!(population.get(hawk_id).unwrap().species(), "Buteo jamaicensis");
+}
+
+#[test]
+fn test_members_can_be_mutated() {
+    let mut population = desert();
+    let cat_id = population.insert(Mammal::new("Felis catus".to_string())).unwrap();
+
+    population.get_as_mut::<Mammal>(cat_id).unwrap().has_fur = false;
+
+    assert!(!population.get_as::<Mammal>(cat_id).unwrap().has_fur);
+    assert!(population.get(cat_id).unwrap().is_alive());
+}
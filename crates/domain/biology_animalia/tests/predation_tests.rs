@@ -0,0 +1,192 @@
+use biology::ecosystem::environment::{Climate, Environment, Terrain};
+use biology::ecosystem::interactions::Interaction;
+use biology::ecosystem::population::Population;
+use biology::simulation::{
+    CauseOfDeath, LotkaVolterra, MemberState, Predation, PredatorPrey, Simulation, SimulationEvent,
+};
+use biology_animalia::Mammal;
+use core_shared::{Centimeters, Identifier, Kilograms, MetersPerSecond};
+
+fn coyote() -> Mammal {
+    Mammal::new("Canis latrans".to_string())
+        .with_body_mass(Kilograms(14))
+        .with_top_speed(MetersPerSecond(18))
+}
+
+fn jackrabbit() -> Mammal {
+    Mammal::new("Lepus californicus".to_string())
+        .with_body_mass(Kilograms(2))
+        .with_top_speed(MetersPerSecond(20))
+}
+
+fn hunting_ground(environment: Environment, rabbits: usize) -> (Population, Identifier, Vec<Identifier>) {
+    let mut population = Population::new(environment);
+    let coyote_id = population.insert(coyote()).unwrap();
+    let rabbit_ids: Vec<Identifier> = (0..rabbits)
+        .map(|_| population.insert(jackrabbit()).unwrap())
+        .collect();
+    for &prey_id in &rabbit_ids {
+        population
+            .add_interaction(Interaction::Predation { predator_id: coyote_id, prey_id })
+            .unwrap();
+    }
+    (population, coyote_id, rabbit_ids)
+}
+
+#[test]
+fn test_encounters_rise_with_density() {
+    let rule = Predation::default();
+    let plains = Environment::default();
+
+    assert_eq!(rule.encounter_chance(0, &plains), 0.0);
+    assert!(rule.encounter_chance(20, &plains) > rule.encounter_chance(2, &plains));
+}
+
+#[test]
+fn test_cover_hides_prey() {
+    let rule = Predation::default();
+    let forest = Environment::new(Climate::Temperate, Terrain::Forest, Centimeters(0), 0.5);
+    let desert = Environment::new(Climate::Desert, Terrain::Plains, Centimeters(0), 0.5);
+    let plains = Environment::default();
+
+    assert!(rule.encounter_chance(5, &forest) < rule.encounter_chance(5, &plains));
+    assert!(rule.encounter_chance(5, &desert) > rule.encounter_chance(5, &plains));
+}
+
+#[test]
+fn test_capture_depends_on_size_and_speed() {
+    let rule = Predation::default();
+    let bigger_and_slower = rule.capture_chance(&coyote(), &jackrabbit());
+    let slow_prey = jackrabbit().with_top_speed(MetersPerSecond(9));
+    let huge_prey = jackrabbit().with_body_mass(Kilograms(140));
+
+    assert!(rule.capture_chance(&coyote(), &slow_prey) > bigger_and_slower);
+    assert!(rule.capture_chance(&coyote(), &huge_prey) < bigger_and_slower);
+    // Unknown measures count as evenly matched.
+    let unmeasured = Mammal::new("Canis latrans".to_string());
+    assert_eq!(rule.capture_chance(&unmeasured, &unmeasured), rule.base_capture);
+}
+
+#[test]
+fn test_kill_feeds_predator_once_per_tick() {
+    let (population, coyote_id, rabbit_ids) = hunting_ground(Environment::default(), 3);
+    let mut simulation = Simulation::new(population, 9)
+        .with_state(coyote_id, MemberState::new(0, 0.2))
+        .with_rule(Predation { search_rate: 100.0, base_capture: 1.0, ..Predation::default() });
+
+    let report = simulation.step();
+
+    assert_eq!(report.deaths(), 1);
+    let Some(&prey_id) = rabbit_ids.iter().find(|&&id| {
+        report.events.contains(&SimulationEvent::Died {
+            id,
+            cause: CauseOfDeath::Predation { predator_id: coyote_id },
+        })
+    }) else {
+        panic!("expected a rabbit to be killed");
+    };
+    let prey = simulation.population().get(prey_id).unwrap();
+    assert!(!prey.is_alive());
+    // A 2 kg rabbit is a small meal for a 14 kg coyote.
+    let energy = simulation.state(coyote_id).unwrap().energy;
+    assert!(energy > 0.2 && energy < 0.8);
+}
+
+#[test]
+fn test_escapes_tire_both_animals() {
+    let (population, coyote_id, rabbit_ids) = hunting_ground(Environment::default(), 1);
+    let mut simulation = Simulation::new(population, 9)
+        .with_rule(Predation { search_rate: 100.0, base_capture: 0.0, ..Predation::default() });
+
+    let report = simulation.step();
+
+    assert_eq!(report.deaths(), 0);
+    assert_eq!(report.events.len(), 1);
+    assert!(simulation.state(coyote_id).unwrap().energy < 1.0);
+    assert!(simulation.state(rabbit_ids[0]).unwrap().energy < 1.0);
+}
+
+#[test]
+fn test_kills_grow_linearly_with_linked_prey() {
+    // Kills by one coyote in a tick, over many runs; sparse prey keep encounters near-linear.
+    let kills = |rabbits: usize| -> usize {
+        (0..4000)
+            .map(|seed| {
+                let (population, _, _) = hunting_ground(Environment::default(), rabbits);
+                let mut simulation = Simulation::new(population, seed)
+                    .with_rule(Predation { search_rate: 0.2, base_capture: 1.0, ..Predation::default() });
+                simulation.step().deaths()
+            })
+            .sum()
+    };
+
+    // Kills per linked rabbit, relative to two links: about 1 when kills grow linearly
+    // (a little less as encounters saturate); quadratic growth would give 2 and 4.
+    let two = kills(2) as f64 / 2.0;
+    let relative = |rabbits: usize| kills(rabbits) as f64 / rabbits as f64 / two;
+
+    assert!((0.8..1.15).contains(&relative(4)));
+    assert!((0.75..1.15).contains(&relative(8)));
+}
+
+#[test]
+fn test_failed_hunts_cost_one_chase_per_tick() {
+    let (population, coyote_id, _) = hunting_ground(Environment::default(), 20);
+    let mut simulation = Simulation::new(population, 3)
+        .with_rule(Predation { search_rate: 100.0, base_capture: 0.0, ..Predation::default() });
+
+    let report = simulation.step();
+
+    assert_eq!(report.events.len(), 1);
+    assert!((simulation.state(coyote_id).unwrap().energy - 0.95).abs() < 1e-6);
+}
+
+#[test]
+fn test_lotka_volterra_equilibrium_is_stationary() {
+    let model = LotkaVolterra::new(0.1, 0.02, 0.01, 0.1);
+    let equilibrium = model.equilibrium();
+
+    assert_eq!(equilibrium, PredatorPrey::new(10.0, 5.0));
+    let later = model.trajectory(equilibrium, 0.1, 100);
+    assert!((later[100].prey - 10.0).abs() < 1e-9);
+    assert!((later[100].predators - 5.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_lotka_volterra_cycles() {
+    let model = LotkaVolterra::new(0.1, 0.02, 0.01, 0.1);
+    let trajectory = model.trajectory(PredatorPrey::new(20.0, 2.0), 0.1, 2000);
+
+    // Orbits circle the equilibrium: predators overshoot it and the prey later crash below it.
+    assert!(trajectory.iter().any(|sizes| sizes.predators > 5.0));
+    assert!(trajectory.iter().any(|sizes| sizes.prey < 10.0));
+
+    // ...while this quantity is conserved along every orbit.
+    let invariant = |sizes: &PredatorPrey| {
+        model.conversion * sizes.prey - model.predator_death * sizes.prey.ln()
+            + model.predation_rate * sizes.predators
+            - model.prey_growth * sizes.predators.ln()
+    };
+    assert!((invariant(&trajectory[0]) - invariant(&trajectory[2000])).abs() < 1e-6);
+}
+
+#[test]
+fn test_compare_individual_run_with_model() {
+    let (population, _, _) = hunting_ground(Environment::default(), 10);
+    let mut simulation = Simulation::new(population, 21).with_rule(Predation::default());
+
+    let mut observed = vec![PredatorPrey::census(simulation.population(), "Lepus californicus", "Canis latrans")];
+    for _ in 0..20 {
+        simulation.step();
+        observed.push(PredatorPrey::census(simulation.population(), "Lepus californicus", "Canis latrans"));
+    }
+
+    assert_eq!(observed[0], PredatorPrey::new(10.0, 1.0));
+    assert!(observed[20].prey < 10.0);
+
+    // Pure predation with no births and no predator deaths.
+    let model = LotkaVolterra::new(0.0, 0.05, 0.0, 0.0);
+    let deviation = model.deviation(&observed, 1.0);
+    assert_eq!(deviation.predators, 0.0);
+    assert!(deviation.prey.is_finite());
+}
@@ -0,0 +1,297 @@
+use biology::{Organism, Sex};
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::landscape::{Landscape, Position, Topology};
+use biology::ecosystem::population::{Population, PopulationError};
+use biology::nomenclature::conventions::NamingConvention;
+use biology::nomenclature::nomenclature::NomenclatureComponents;
+use biology::reproduction::{Birth, Development};
+use biology::simulation::{MemberState, Reproduction, Simulation, SimulationEvent};
+use biology::vitals::post_mortem_report::PostMortemReport;
+use biology::vitals::vital_status::VitalStatus;
+use biology_animalia::reproduction::{offspring, CHICKEN, COYOTE, GARDEN_SNAIL, PEA_APHID, STRATEGIES};
+use biology_animalia::{Animal, Human, HumanBuilder, Mammal, NameParts};
+use core_shared::Identifier;
+
+fn coyote(sex: Sex) -> Mammal {
+    Mammal::new("Canis latrans".to_string()).with_sex(sex)
+}
+
+fn adult() -> MemberState {
+    MemberState::new(400, 1.0)
+}
+
+// A mature pair that mates on the first tick.
+fn pair(population: Population, female: Identifier, male: Identifier) -> Simulation {
+    Simulation::new(population, 3)
+        .with_state(female, adult())
+        .with_state(male, adult())
+        .with_rule(Reproduction::new(STRATEGIES, offspring).with_mating_chance(1.0))
+}
+
+fn deceased() -> VitalStatus {
+    VitalStatus::Deceased(PostMortemReport::new("Natural causes".to_string(), "Tick 10".to_string(), vec![]))
+}
+
+fn conceptions(reports: &[biology::simulation::TickReport]) -> Vec<(u64, Identifier, Option<Identifier>, u32)> {
+    reports
+        .iter()
+        .flat_map(|report| {
+            report.events.iter().filter_map(move |event| match event {
+                SimulationEvent::Conceived { mother_id, father_id, litter } => {
+                    Some((report.tick, *mother_id, *father_id, *litter))
+                }
+                _ => None,
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn test_compatibility() {
+    let (female, male) = (coyote(Sex::Female), coyote(Sex::Male));
+    let unknown = coyote(Sex::Unknown);
+    let fox = Mammal::new("Vulpes vulpes".to_string()).with_sex(Sex::Male);
+
+    assert!(COYOTE.compatible(&female, &male));
+    assert!(!COYOTE.compatible(&male, &female));
+    assert!(!COYOTE.compatible(&female, &coyote(Sex::Female)));
+    assert!(!COYOTE.compatible(&female, &unknown));
+    assert!(!COYOTE.compatible(&female, &female));
+    assert!(!COYOTE.compatible(&female, &fox));
+    assert!(!COYOTE.compatible(&female, &coyote(Sex::Male).with_vitals(deceased())));
+
+    let snail = || Animal::new("Cornu aspersum".to_string());
+    assert!(GARDEN_SNAIL.compatible(&snail(), &snail()));
+    let aphid = || Animal::new("Acyrthosiphon pisum".to_string());
+    assert!(!PEA_APHID.compatible(&aphid(), &aphid()));
+}
+
+#[test]
+fn test_parent_links() {
+    let mut population = Population::new(Environment::default());
+    let mother = population.insert(coyote(Sex::Female)).unwrap();
+    let father = population.insert(coyote(Sex::Male)).unwrap();
+    let pup = population.insert(coyote(Sex::Female)).unwrap();
+    let stranger = Identifier::new();
+
+    population.set_parents(pup, &[mother, father]).unwrap();
+    assert_eq!(population.parents_of(pup), [mother, father]);
+    assert_eq!(population.children_of(mother), vec![pup]);
+    assert!(population.parents_of(mother).is_empty());
+    assert_eq!(population.parentage(), vec![(pup, vec![mother, father])]);
+
+    assert_eq!(population.set_parents(pup, &[stranger]), Err(PopulationError::UnknownMember(stranger)));
+    assert_eq!(population.set_parents(pup, &[pup]), Err(PopulationError::OwnParent(pup)));
+
+    population.remove(father);
+    assert_eq!(population.parents_of(pup), [mother]);
+}
+
+#[test]
+fn test_mating_gestation_and_birth() {
+    let mut population = Population::new(Environment::default());
+    let female = population.insert(coyote(Sex::Female)).unwrap();
+    let male = population.insert(coyote(Sex::Male)).unwrap();
+    let mut simulation = pair(population, female, male);
+
+    let reports = simulation.run(COYOTE.period as u64 + 1);
+    let conceived = conceptions(&reports);
+    assert_eq!(conceived.len(), 1);
+    let (tick, mother_id, father_id, litter) = conceived[0];
+    assert_eq!((tick, mother_id, father_id), (1, female, Some(male)));
+    assert!((COYOTE.litter.0..=COYOTE.litter.1).contains(&litter));
+
+    // Nothing is born until the gestation period has passed.
+    assert!(reports[..COYOTE.period as usize].iter().all(|report| report.births() == 0));
+    assert_eq!(reports.last().unwrap().births(), litter as usize);
+    assert!(simulation.rule::<Reproduction>().unwrap().pregnancies().is_empty());
+
+    let population = simulation.population();
+    let pups = population.children_of(female);
+    assert_eq!(pups.len(), litter as usize);
+    assert_eq!(population.children_of(male), pups);
+    for pup in pups {
+        assert_eq!(population.parents_of(pup), [female, male]);
+        let pup = population.get_as::<Mammal>(pup).unwrap();
+        assert_eq!(pup.species, "Canis latrans");
+        assert_ne!(pup.sex, Sex::Unknown);
+        assert!(pup.has_fur);
+    }
+
+    // The mother rests for a year before the next litter.
+    let later = simulation.run(100);
+    assert!(conceptions(&later).iter().all(|&(_, mother_id, _, _)| mother_id != female));
+}
+
+#[test]
+fn test_young_are_lost_with_their_mother_but_eggs_hatch() {
+    let mut population = Population::new(Environment::default());
+    let female = population.insert(coyote(Sex::Female)).unwrap();
+    let male = population.insert(coyote(Sex::Male)).unwrap();
+    let hen = population.insert(Animal::new("Gallus gallus".to_string()).with_sex(Sex::Female)).unwrap();
+    let rooster = population.insert(Animal::new("Gallus gallus".to_string()).with_sex(Sex::Male)).unwrap();
+    let mut simulation = pair(population, female, male)
+        .with_state(hen, adult())
+        .with_state(rooster, adult());
+
+    simulation.step();
+    let reproduction = simulation.rule::<Reproduction>().unwrap();
+    assert_eq!(reproduction.pregnancy(hen).unwrap().development, Development::Incubation);
+    assert_eq!(reproduction.pregnancy(female).unwrap().due, 1 + COYOTE.period as u64);
+
+    for mother in [female, hen] {
+        *simulation.population_mut().get_mut(mother).unwrap().vitals_mut() = deceased();
+    }
+    simulation.run(COYOTE.period as u64);
+
+    let population = simulation.population();
+    assert!(population.children_of(female).is_empty());
+    let chicks = population.children_of(hen);
+    assert!(chicks.len() >= CHICKEN.litter.0 as usize);
+    assert!(chicks.iter().all(|&chick| population.get(chick).unwrap().species() == "Gallus gallus"));
+}
+
+#[test]
+fn test_eggs_of_a_removed_hen_are_lost_and_a_removed_rooster_is_left_out() {
+    let mut population = Population::new(Environment::default());
+    let hens = [(); 2].map(|_| population.insert(Animal::new("Gallus gallus".to_string()).with_sex(Sex::Female)).unwrap());
+    let rooster = population.insert(Animal::new("Gallus gallus".to_string()).with_sex(Sex::Male)).unwrap();
+    let mut simulation = Simulation::new(population, 3)
+        .with_state(hens[0], adult())
+        .with_state(hens[1], adult())
+        .with_state(rooster, adult())
+        .with_rule(Reproduction::new(STRATEGIES, offspring).with_mating_chance(1.0));
+
+    simulation.step();
+    simulation.population_mut().remove(hens[1]);
+    simulation.population_mut().remove(rooster);
+    simulation.run(CHICKEN.period as u64);
+
+    let population = simulation.population();
+    let chicks = population.children_of(hens[0]);
+    assert!(chicks.len() >= CHICKEN.litter.0 as usize);
+    assert!(chicks.iter().all(|&chick| population.parents_of(chick) == [hens[0]]));
+}
+
+// A faulty offspring function, whose newborns take their mother's id.
+fn doppelganger(birth: &Birth) -> Option<Box<dyn Organism>> {
+    let mut young = Animal::new(birth.mother.species());
+    young.id = birth.mother.id();
+    Some(Box::new(young))
+}
+
+#[test]
+fn test_newborns_the_population_refuses_are_reported() {
+    let mut population = Population::new(Environment::default());
+    let hen = population.insert(Animal::new("Gallus gallus".to_string()).with_sex(Sex::Female)).unwrap();
+    let rooster = population.insert(Animal::new("Gallus gallus".to_string()).with_sex(Sex::Male)).unwrap();
+    let mut simulation = Simulation::new(population, 3)
+        .with_state(hen, adult())
+        .with_state(rooster, adult())
+        .with_rule(Reproduction::new(STRATEGIES, doppelganger).with_mating_chance(1.0));
+
+    let reports = simulation.run(CHICKEN.period as u64 + 1);
+
+    let failures: Vec<&SimulationEvent> = reports
+        .iter()
+        .flat_map(|report| &report.events)
+        .filter(|event| matches!(event, SimulationEvent::BirthFailed { .. }))
+        .collect();
+    assert_eq!(
+        failures,
+        [&SimulationEvent::BirthFailed { mother_id: hen, reason: format!("{} is already a member", hen) }]
+    );
+    assert!(simulation.population().children_of(hen).is_empty());
+}
+
+#[test]
+fn test_asexual_and_hermaphroditic_reproduction() {
+    let mut population = Population::new(Environment::default());
+    let aphid = population.insert(Animal::new("Acyrthosiphon pisum".to_string()).with_sex(Sex::Female)).unwrap();
+    let first_snail = population.insert(Animal::new("Cornu aspersum".to_string())).unwrap();
+    let second_snail = population.insert(Animal::new("Cornu aspersum".to_string())).unwrap();
+    let mut simulation = pair(population, first_snail, second_snail).with_state(aphid, adult());
+
+    let reports = simulation.run(2);
+    let conceived = conceptions(&reports);
+    assert!(conceived.contains(&(1, aphid, None, conceived[0].3)));
+    // Each snail fertilises the other.
+    assert!(conceived.iter().any(|&(_, mother, father, _)| mother == first_snail && father == Some(second_snail)));
+    assert!(conceived.iter().any(|&(_, mother, father, _)| mother == second_snail && father == Some(first_snail)));
+
+    let population = simulation.population();
+    let nymphs = population.children_of(aphid);
+    assert!(!nymphs.is_empty());
+    assert!(nymphs.iter().all(|&nymph| population.parents_of(nymph) == [aphid]));
+    assert!(nymphs.iter().all(|&nymph| population.get(nymph).unwrap().sex() == Sex::Female));
+}
+
+#[test]
+fn test_mates_must_share_a_cell() {
+    let landscape = Landscape::new(2, 1, Topology::Square, Environment::default());
+    let mut population = Population::new(Environment::default()).with_landscape(landscape);
+    let female = population.insert(coyote(Sex::Female)).unwrap();
+    let male = population.insert(coyote(Sex::Male)).unwrap();
+    population.place(female, Position::new(0, 0)).unwrap();
+    population.place(male, Position::new(1, 0)).unwrap();
+    let mut simulation = pair(population, female, male);
+
+    assert!(conceptions(&simulation.run(5)).is_empty());
+
+    simulation.population_mut().place(male, Position::new(0, 0)).unwrap();
+    simulation.run(COYOTE.period as u64 + 1);
+    let pups = simulation.population().children_of(female);
+    assert!(!pups.is_empty());
+    // Pups are born in their mother's cell.
+    assert!(pups.iter().all(|&pup| simulation.population().position(pup) == Some(Position::new(0, 0))));
+}
+
+#[test]
+fn test_immature_and_exhausted_members_do_not_mate() {
+    let mut population = Population::new(Environment::default());
+    let female = population.insert(coyote(Sex::Female)).unwrap();
+    let male = population.insert(coyote(Sex::Male)).unwrap();
+    let mut young = Simulation::new(population, 3)
+        .with_state(female, MemberState::new(100, 1.0))
+        .with_state(male, adult())
+        .with_rule(Reproduction::new(STRATEGIES, offspring).with_mating_chance(1.0));
+    assert!(conceptions(&young.run(3)).is_empty());
+
+    let mut population = Population::new(Environment::default());
+    let female = population.insert(coyote(Sex::Female)).unwrap();
+    let male = population.insert(coyote(Sex::Male)).unwrap();
+    let mut tired = pair(population, female, male).with_state(female, MemberState::new(400, 0.1));
+    assert!(conceptions(&tired.run(3)).is_empty());
+}
+
+#[test]
+fn test_children_take_family_names() {
+    let parent = |name: &str, convention: NamingConvention, sex: Sex| {
+        HumanBuilder::new().name(NameParts::parse(name, convention)).sex(sex).build()
+    };
+    let child = |mother: &Human, father: Option<&Human>, sex: Sex| {
+        let birth = Birth { id: Identifier::new(), mother, father: father.map(|father| father as &dyn Organism), sex, genome: None };
+        let child = offspring(&birth).unwrap();
+        let child: Box<dyn std::any::Any> = child;
+        *child.downcast::<Human>().unwrap()
+    };
+
+    let mother = parent("Jane Smith", NamingConvention::GivenNameFirst, Sex::Female);
+    let father = parent("John Doe", NamingConvention::GivenNameFirst, Sex::Male);
+    let baby = child(&mother, Some(&father), Sex::Female);
+    assert_eq!(baby.last_name().as_deref(), Some("Doe"));
+    assert_eq!(baby.first_name(), None);
+    assert_eq!(baby.sex, Sex::Female);
+    assert_eq!(child(&mother, None, Sex::Male).last_name().as_deref(), Some("Smith"));
+
+    let madre = parent("Luisa Márquez Iguarán", NamingConvention::DoubleSurname, Sex::Female);
+    let padre = parent("Gabriel García Martínez", NamingConvention::DoubleSurname, Sex::Male);
+    let hijo = child(&madre, Some(&padre), Sex::Male);
+    assert_eq!(hijo.last_name().as_deref(), Some("García"));
+    assert_eq!(hijo.second_last_name().as_deref(), Some("Márquez"));
+
+    let anna = parent("Anna Sigurðardóttir", NamingConvention::Patronymic, Sex::Female);
+    let jon = parent("Jón Einarsson", NamingConvention::Patronymic, Sex::Male);
+    assert_eq!(child(&anna, Some(&jon), Sex::Female).last_name().as_deref(), Some("Jónsdóttir"));
+    assert_eq!(child(&anna, Some(&jon), Sex::Male).last_name().as_deref(), Some("Jónsson"));
+}
@@ -0,0 +1,145 @@
+use biology::{Nomenclature, Organism, Sex, Summarizable};
+use biology::builder::OrganismBuilderExt;
+use biology::anatomy::appendage::AppendageKind;
+use biology::characteristics::locomotion::{Locomotion, Mobility};
+use biology::characteristics::structural::Anatomy;
+use biology::ecosystem::environment::{Climate, Environment, Terrain};
+use biology::ecosystem::population::Population;
+use biology::patterns::structural_patterns::AnatomyAnalyzer;
+use biology::simulation::{MemberState, Reproduction, Simulation, Weather};
+use biology::vitals::blood_pressure::BloodPressure;
+use biology::vitals::live_vitals::LiveVitals;
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::{Celsius, BPM};
+use biology_animalia::reproduction::{offspring, COMMON_GARTER_SNAKE, STRATEGIES};
+use biology_animalia::reptiles::{Activity, Scales};
+use biology_animalia::{Mammal, Reptile};
+use core_shared::{Centimeters, Identifier};
+
+const JANUARY: u32 = 15;
+const JULY: u32 = 196;
+
+fn temperate() -> Population {
+    Population::new(Environment::new(Climate::Temperate, Terrain::Plains, Centimeters(0), 0.5))
+}
+
+fn garter_snake(sex: Sex) -> Reptile {
+    Reptile::builder("Thamnophis sirtalis")
+        .common_name("Common garter snake")
+        .sex(sex)
+        .scales(Scales::Keeled)
+        .limbless()
+        .temperatures(Celsius(28), Celsius(5))
+        .shed_interval(30)
+        .live_birth()
+        .build()
+        .unwrap()
+}
+
+fn temperature(simulation: &Simulation, id: Identifier) -> u32 {
+    let VitalStatus::Alive(vitals) = simulation.population().get(id).unwrap().vitals() else {
+        panic!("member should be alive");
+    };
+    vitals.temperature.0
+}
+
+#[test]
+fn test_snakes_are_limbless() {
+    let snake = garter_snake(Sex::Female);
+
+    assert!(snake.is_limbless());
+    assert_eq!(snake.count_limbs(), (0, 0, 0, 0));
+    assert!(snake.describe_anatomy().starts_with("A legless form without appendages."));
+    assert!(snake.describe_anatomy().contains("bears live young"));
+    assert!(snake.describe_locomotion().starts_with("Slithers"));
+    assert_eq!(snake.locomotion(), Locomotion::Terrestrial);
+}
+
+#[test]
+fn test_turtles_and_lizards() {
+    let turtle = Reptile::builder("Chelonia mydas")
+        .common_name("Green sea turtle")
+        .scales(Scales::Scutes)
+        .locomotion(Locomotion::Amphibious)
+        .build()
+        .unwrap();
+
+    assert_eq!(turtle.count_appendages(AppendageKind::Leg), (4, 0));
+    assert!(turtle.describe_anatomy().contains("armoured with bony scutes"));
+    assert!(turtle.describe_anatomy().contains("lays eggs"));
+    assert!(turtle.describe_locomotion().contains("swims"));
+    assert!(turtle.describe_shedding().contains("scute by scute"));
+
+    let summary = turtle.summary();
+    assert!(summary.contains("--- Reptile: Green sea turtle (Chelonia mydas) ---"));
+    assert!(summary.contains("Skin: Sheds scute by scute every 42 days"));
+    assert!(turtle.answers_to("Chelonia mydas"));
+}
+
+#[test]
+fn test_basking_and_brumation_follow_the_climate() {
+    let lizard = Reptile::new("Podarcis muralis".to_string());
+    let desert = Environment::new(Climate::Desert, Terrain::Plains, Centimeters(0), 0.1);
+    let tundra = Environment::new(Climate::Arctic, Terrain::Plains, Centimeters(0), 0.4);
+
+    assert_eq!(lizard.activity(&temperate().environment), Activity::Basking);
+    assert_eq!(lizard.body_temperature(&temperate().environment), Celsius(21));
+    assert_eq!(lizard.activity(&desert), Activity::Basking);
+    assert_eq!(lizard.body_temperature(&desert), Celsius(30));
+    assert_eq!(lizard.activity(&tundra), Activity::Brumating);
+    assert_eq!(lizard.body_temperature(&tundra), Celsius(0));
+
+    let mut warmed = lizard.clone();
+    warmed.thermoregulate(&desert);
+    assert_eq!(warmed.body_temperature_in(&desert), Some(Celsius(30)));
+    assert!(matches!(warmed.vitals, VitalStatus::Alive(ref vitals) if vitals.temperature == Celsius(30)));
+}
+
+#[test]
+fn test_weather_sets_an_ectotherms_body_temperature() {
+    let run = |start_day: u32| {
+        let mut population = temperate();
+        let lizard_id = population.insert(Reptile::new("Lacerta agilis".to_string())).unwrap();
+        let vole = Mammal::new("Microtus agrestis".to_string())
+            .with_vitals(VitalStatus::Alive(LiveVitals::new(BloodPressure(110, 70), Celsius(38), BPM(400), 90)));
+        let vole_id = population.insert(vole).unwrap();
+        let mut simulation = Simulation::new(population, 3).with_rule(Weather::new().with_start_day(start_day));
+        simulation.run(1);
+        (temperature(&simulation, lizard_id), temperature(&simulation, vole_id))
+    };
+
+    // A 20 °C summer day: the lizard basks up to its preferred 30 °C.
+    assert_eq!(run(JULY), (30, 38));
+    // A 2 °C winter day: it brumates at the temperature of the air, while the vole keeps warm.
+    let (lizard, vole) = run(JANUARY);
+    assert!(lizard <= 3);
+    assert_eq!(vole, 38);
+}
+
+#[test]
+fn test_garter_snakes_bear_live_young() {
+    let (male, female) = (garter_snake(Sex::Male), garter_snake(Sex::Female));
+    let (male_id, female_id) = (male.id, female.id);
+    let mut population = temperate();
+    population.insert(male).unwrap();
+    population.insert(female).unwrap();
+
+    let adult = COMMON_GARTER_SNAKE.maturity;
+    let mut simulation = Simulation::new(population, 5)
+        .with_state(male_id, MemberState::new(adult, 1.0))
+        .with_state(female_id, MemberState::new(adult, 1.0))
+        .with_rule(Reproduction::new(STRATEGIES, offspring).with_mating_chance(1.0));
+    simulation.run(COMMON_GARTER_SNAKE.period as u64 + 2);
+
+    let population = simulation.population();
+    let young = population.children_of(female_id);
+    assert!(young.len() >= COMMON_GARTER_SNAKE.litter.0 as usize);
+    for id in young {
+        let snake = population.get_as::<Reptile>(id).unwrap();
+        assert!(snake.is_limbless());
+        assert!(!snake.lays_eggs());
+        assert_eq!(snake.common_name.as_deref(), Some("Common garter snake"));
+        assert_eq!(snake.preferred_temperature, Celsius(28));
+        assert_eq!(population.parents_of(id), [female_id, male_id]);
+    }
+}
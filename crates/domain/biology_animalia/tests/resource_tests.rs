@@ -0,0 +1,103 @@
+use biology::ecosystem::environment::{Climate, Environment, Terrain};
+use biology::ecosystem::interactions::Interaction;
+use biology::ecosystem::population::Population;
+use biology::ecosystem::resources::{Claim, ResourceKind, ResourcePool, Resources};
+use biology::simulation::{Competition, Metabolism, Mortality, Replenishment, Simulation};
+use biology_animalia::Mammal;
+use core_shared::{Centimeters, Identifier, Kilograms};
+
+fn claim(demand: f64, strength: f64) -> Claim {
+    Claim { claimant: Identifier::new(), demand, strength }
+}
+
+#[test]
+fn test_labels_map_to_kinds() {
+    assert_eq!(ResourceKind::from_label("Water sources"), Some(ResourceKind::Water));
+    assert_eq!(ResourceKind::from_label("Food resources"), Some(ResourceKind::FoodBiomass));
+    assert_eq!(ResourceKind::from_label("Nesting sites"), Some(ResourceKind::Territory));
+    assert_eq!(ResourceKind::from_label("Sunlight"), Some(ResourceKind::Light));
+    assert_eq!(ResourceKind::from_label("Soil nitrogen"), Some(ResourceKind::Nutrients));
+    assert_eq!(ResourceKind::from_label("Attention"), None);
+}
+
+#[test]
+fn test_habitats_differ() {
+    let desert = Resources::for_habitat(&Climate::Desert, &Terrain::Plains);
+    let wetlands = Resources::for_habitat(&Climate::Temperate, &Terrain::Wetlands);
+    let forest = Resources::for_habitat(&Climate::Temperate, &Terrain::Forest);
+
+    assert!(desert.amount(ResourceKind::Water) < wetlands.amount(ResourceKind::Water));
+    assert!(forest.amount(ResourceKind::Light) < desert.amount(ResourceKind::Light));
+    assert_eq!(Environment::default().resources.amount(ResourceKind::FoodBiomass), 100.0);
+}
+
+#[test]
+fn test_pools_replenish_up_to_capacity() {
+    let mut pool = ResourcePool::new(10.0, 3.0);
+
+    assert_eq!(pool.take(8.0), 8.0);
+    pool.replenish();
+    assert_eq!(pool.amount, 5.0);
+    pool.replenish();
+    pool.replenish();
+    assert_eq!(pool.amount, 10.0);
+    assert_eq!(pool.take(20.0), 10.0);
+}
+
+#[test]
+fn test_allocation_follows_strength() {
+    let mut pool = ResourcePool::new(6.0, 0.0);
+
+    let shares = pool.allocate(&[claim(10.0, 2.0), claim(10.0, 1.0)]);
+
+    assert_eq!(shares, vec![4.0, 2.0]);
+    assert_eq!(pool.amount, 0.0);
+}
+
+#[test]
+fn test_allocation_shares_out_leftovers() {
+    let mut pool = ResourcePool::new(10.0, 0.0);
+
+    // The strong claimant only needs 1; the other two split what it leaves.
+    let shares = pool.allocate(&[claim(1.0, 8.0), claim(10.0, 1.0), claim(10.0, 1.0)]);
+
+    assert_eq!(shares, vec![1.0, 4.5, 4.5]);
+    assert_eq!(pool.amount, 0.0);
+
+    let mut plenty = ResourcePool::new(100.0, 0.0);
+    assert_eq!(plenty.allocate(&[claim(3.0, 1.0), claim(2.0, 5.0)]), vec![3.0, 2.0]);
+    assert_eq!(plenty.amount, 95.0);
+}
+
+#[test]
+fn test_weaker_competitor_starves_first() {
+    let oasis = Environment::new(Climate::Desert, Terrain::Plains, Centimeters(0), 0.1);
+    let mut population = Population::new(oasis);
+    let coyote_id = population
+        .insert(Mammal::new("Canis latrans".to_string()).with_body_mass(Kilograms(14)))
+        .unwrap();
+    let fox_id = population
+        .insert(Mammal::new("Vulpes macrotis".to_string()).with_body_mass(Kilograms(2)))
+        .unwrap();
+    population
+        .add_interaction(Interaction::Competition {
+            competitor1_id: coyote_id,
+            competitor2_id: fox_id,
+            resource: ResourceKind::Water,
+        })
+        .unwrap();
+
+    let mut simulation = Simulation::new(population, 3)
+        .with_rule(Replenishment)
+        .with_rule(Competition { demand: 2.0, energy_value: 0.1 })
+        .with_rule(Metabolism { upkeep: 0.05, foraging: 0.0 })
+        .with_rule(Mortality { lifespan: u32::MAX, background_chance: 0.0 });
+    simulation.population_mut().environment.resources.insert(ResourceKind::Water, ResourcePool::new(2.0, 2.0));
+
+    // The fox gets an eighth of its demand: 0.0125 energy a tick against 0.05 upkeep.
+    simulation.run(40);
+
+    let population = simulation.population();
+    assert!(population.get(coyote_id).unwrap().is_alive());
+    assert!(!population.get(fox_id).unwrap().is_alive());
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "serde")]
+
+use biology::{Nomenclature, Sex};
+use biology::anatomy::appendage::Appendage;
+use biology::ecosystem::environment::{Climate, Environment, Terrain};
+use biology::ecosystem::interactions::Interaction;
+use biology::ecosystem::resources::ResourceKind;
+use biology::templates::regional_defaults;
+use biology::vitals::blood_pressure::BloodPressure;
+use biology::vitals::live_vitals::LiveVitals;
+use biology::vitals::post_mortem_report::PostMortemReport;
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::{Celsius, BPM};
+use biology_animalia::{Human, Mammal, NameParts, NameRecord};
+use core_shared::{Centimeters, Identifier};
+use serde_json::json;
+
+#[test]
+fn test_identifier_serializes_as_uuid_string() {
+    let id: Identifier = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+
+    let value = serde_json::to_value(id).unwrap();
+    assert_eq!(value, json!("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+    assert_eq!(serde_json::from_value::<Identifier>(value).unwrap(), id);
+}
+
+#[test]
+fn test_appendage_representation() {
+    let human = Human::builder()
+        .with_anatomy_template(&regional_defaults::AVG_NORTH_AMERICAN_MALE)
+        .build();
+
+    let value = serde_json::to_value(&human.appendages[0]).unwrap();
+    assert_eq!(value["type"], "arm");
+    assert_eq!(value["side"], "left");
+    assert_eq!(value["status"], "intact");
+    assert_eq!(value["measurements"]["span"], 175);
+
+    let appendage: Appendage = serde_json::from_value(value).unwrap();
+    assert!(matches!(appendage, Appendage::Arm { .. }));
+}
+
+#[test]
+fn test_vital_status_representation() {
+    let alive = VitalStatus::Alive(LiveVitals::new(BloodPressure(120, 80), Celsius(37), BPM(72), 16));
+    let value = serde_json::to_value(&alive).unwrap();
+    assert_eq!(value["alive"]["temperature"], 37);
+    assert_eq!(value["alive"]["blood_pressure"], json!([120, 80]));
+
+    let deceased = VitalStatus::Deceased(PostMortemReport::new(
+        "Natural causes".to_string(),
+        "2024-01-01".to_string(),
+        vec![],
+    ));
+    let round_trip: VitalStatus =
+        serde_json::from_str(&serde_json::to_string(&deceased).unwrap()).unwrap();
+    assert!(matches!(round_trip, VitalStatus::Deceased(report) if report.cause_of_death == "Natural causes"));
+}
+
+#[test]
+fn test_environment_and_interaction_round_trip() {
+    let environment = Environment::new(
+        Climate::Custom { avg_temp: Celsius(12), rainfall_mm: 800 },
+        Terrain::Wetlands,
+        Centimeters(1500),
+        0.8,
+    );
+    let value = serde_json::to_value(&environment).unwrap();
+    assert_eq!(value["climate"]["custom"]["rainfall_mm"], 800);
+    assert_eq!(value["terrain"], "wetlands");
+    assert_eq!(value["resources"]["water"]["capacity"], 200.0);
+    let restored: Environment = serde_json::from_value(value).unwrap();
+    assert!(matches!(restored.terrain, Terrain::Wetlands));
+    assert_eq!(restored.resources, environment.resources);
+
+    let interaction = Interaction::Competition {
+        competitor1_id: Identifier::new(),
+        competitor2_id: Identifier::new(),
+        resource: ResourceKind::Water,
+    };
+    let value = serde_json::to_value(&interaction).unwrap();
+    assert_eq!(value["type"], "competition");
+    assert_eq!(value["resource"], "water");
+    let restored: Interaction = serde_json::from_value(value).unwrap();
+    assert_eq!(restored, interaction);
+}
+
+#[test]
+fn test_human_round_trip() {
+    let human = Human::builder()
+        .name(NameParts::from("Jane Smith"))
+        .designation("Case #12345".to_string())
+        .alias(NameRecord::former(NameParts::from("Jane Doe"), None, "2015-06-20".to_string()))
+        .sex(Sex::Female)
+        .with_anatomy_template(&regional_defaults::AVG_NORTH_AMERICAN_FEMALE)
+        .build();
+
+    let json = serde_json::to_string(&human).unwrap();
+    let restored: Human = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored.id, human.id);
+    assert_eq!(restored.display_name(), "Case #12345");
+    assert!(restored.answers_to("Jane Doe"));
+    assert!(matches!(restored.sex, Sex::Female));
+    assert_eq!(restored.appendages.len(), 4);
+}
+
+#[test]
+fn test_mammal_round_trip() {
+    let mammal = Mammal::new("Dolphin".to_string()).with_fur(false);
+
+    let restored: Mammal = serde_json::from_str(&serde_json::to_string(&mammal).unwrap()).unwrap();
+
+    assert_eq!(restored.species, "Dolphin");
+    assert!(!restored.has_fur);
+}
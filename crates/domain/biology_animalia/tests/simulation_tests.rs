@@ -0,0 +1,166 @@
+use std::any::Any;
+use biology::Organism;
+use biology::ecosystem::environment::Environment;
+use biology::ecosystem::interactions::Interaction;
+use biology::ecosystem::population::Population;
+use biology::simulation::{
+    Aging, Births, CauseOfDeath, InteractionEffects, MemberState, Metabolism, Mortality,
+    Predation, Simulation, SimulationEvent,
+};
+use biology_animalia::Mammal;
+use core_shared::Identifier;
+
+fn same_species(parent: &dyn Organism, id: Identifier) -> Option<Box<dyn Organism>> {
+    let parent: &dyn Any = parent;
+    let mammal = parent.downcast_ref::<Mammal>()?;
+    let mut newborn = Mammal::new(mammal.species.clone());
+    newborn.id = id;
+    Some(Box::new(newborn))
+}
+
+fn desert() -> (Population, Identifier, Identifier) {
+    let mut population = Population::new(Environment::default());
+    let coyote_id = population.insert(Mammal::new("Canis latrans".to_string())).unwrap();
+    let rabbit_id = population.insert(Mammal::new("Lepus californicus".to_string())).unwrap();
+    for _ in 0..8 {
+        population.insert(Mammal::new("Lepus californicus".to_string())).unwrap();
+    }
+    population
+        .add_interaction(Interaction::Predation { predator_id: coyote_id, prey_id: rabbit_id })
+        .unwrap();
+    (population, coyote_id, rabbit_id)
+}
+
+fn full_simulation(population: Population, seed: u64) -> Simulation {
+    Simulation::new(population, seed)
+        .with_rule(Aging)
+        .with_rule(Metabolism::default())
+        .with_rule(Predation::default())
+        .with_rule(InteractionEffects::default())
+        .with_rule(Births::new(same_species).with_chance(0.05).with_maturity(10))
+        .with_rule(Mortality::default())
+}
+
+#[test]
+fn test_same_seed_same_history() {
+    // The founders get random ids, so both runs start from the same population.
+    let (population, _, _) = desert();
+    let copy = |population: &Population| {
+        let mut twin = Population::new(Environment::default());
+        for member in population.members() {
+            let mut mammal = Mammal::new(member.species());
+            mammal.id = member.id();
+            twin.insert(mammal).unwrap();
+        }
+        for interaction in population.interactions() {
+            twin.add_interaction(interaction.clone()).unwrap();
+        }
+        twin
+    };
+    let run = |population: Population| {
+        let mut simulation = full_simulation(population, 7);
+        let reports = simulation.run(100);
+        (reports, simulation.population().ids())
+    };
+
+    let (first, first_ids) = run(copy(&population));
+    let (second, second_ids) = run(population);
+
+    assert_eq!(first, second);
+    assert_eq!(first_ids, second_ids);
+    assert_eq!(first.len(), 100);
+    assert!(first.iter().any(|report| report.births() > 0));
+}
+
+#[test]
+fn test_dead_members_keep_only_their_age() {
+    let (population, coyote_id, rabbit_id) = desert();
+    let mut simulation = Simulation::new(population, 1)
+        .with_state(rabbit_id, MemberState::new(0, 0.0))
+        .with_rule(Aging)
+        .with_rule(Mortality { lifespan: 100, background_chance: 0.0 });
+
+    simulation.run(5);
+
+    assert!(simulation.state(rabbit_id).is_none());
+    assert_eq!(simulation.age(rabbit_id), Some(1));
+    assert_eq!(simulation.age(coyote_id), Some(5));
+
+    simulation.population_mut().remove(rabbit_id);
+    simulation.step();
+    assert_eq!(simulation.age(rabbit_id), None);
+}
+
+#[test]
+fn test_aging_counts_ticks() {
+    let (population, coyote_id, _) = desert();
+    let mut simulation = Simulation::new(population, 1).with_rule(Aging);
+
+    simulation.run(30);
+
+    assert_eq!(simulation.tick(), 30);
+    assert_eq!(simulation.state(coyote_id).unwrap().age, 30);
+    assert!(simulation.state(Identifier::new()).is_none());
+}
+
+#[test]
+fn test_old_age_and_starvation() {
+    let (population, coyote_id, rabbit_id) = desert();
+    let mut simulation = Simulation::new(population, 1)
+        .with_state(coyote_id, MemberState::new(99, 1.0))
+        .with_state(rabbit_id, MemberState::new(0, 0.0))
+        .with_rule(Aging)
+        .with_rule(Mortality { lifespan: 100, background_chance: 0.0 });
+
+    let report = simulation.step();
+
+    assert_eq!(report.deaths(), 2);
+    assert!(report.events.contains(&SimulationEvent::Died { id: coyote_id, cause: CauseOfDeath::OldAge }));
+    assert!(report.events.contains(&SimulationEvent::Died { id: rabbit_id, cause: CauseOfDeath::Starvation }));
+    assert_eq!(report.alive, 8);
+
+    // The dead stay in the population with a post-mortem report.
+    let coyote = simulation.population().get(coyote_id).unwrap();
+    assert!(!coyote.is_alive());
+}
+
+#[test]
+fn test_births_add_members() {
+    let (population, _, _) = desert();
+    let founders = population.ids();
+    let mut simulation = Simulation::new(population, 11)
+        .with_rule(Births::new(same_species).with_chance(1.0).with_maturity(0).with_energy_cost(0.7));
+
+    let report = simulation.step();
+    assert_eq!(report.births(), 10);
+    assert_eq!(simulation.population().size(), 20);
+
+    // The founders spent their energy; only the newborns can afford to breed next.
+    let report = simulation.step();
+    assert_eq!(report.births(), 10);
+    for event in &report.events {
+        let SimulationEvent::Born { parent_id, .. } = event else {
+            panic!("expected only births");
+        };
+        assert!(!founders.contains(parent_id));
+    }
+}
+
+#[test]
+fn test_interaction_effects_leave_predation_alone() {
+    let (mut population, coyote_id, rabbit_id) = desert();
+    let tick_id = population.insert(Mammal::new("Dermacentor andersoni".to_string())).unwrap();
+    population
+        .add_interaction(Interaction::Parasitism { parasite_id: tick_id, host_id: coyote_id })
+        .unwrap();
+    let mut simulation = Simulation::new(population, 5)
+        .with_state(tick_id, MemberState::new(0, 0.5))
+        .with_rule(InteractionEffects { encounter_chance: 1.0, energy_transfer: 0.2 });
+
+    let report = simulation.step();
+
+    assert_eq!(report.events.len(), 1);
+    assert!((simulation.state(coyote_id).unwrap().energy - 0.8).abs() < 1e-6);
+    assert!((simulation.state(tick_id).unwrap().energy - 0.7).abs() < 1e-6);
+    assert!(simulation.population().get(rabbit_id).unwrap().is_alive());
+}
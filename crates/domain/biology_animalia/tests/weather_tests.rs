@@ -0,0 +1,214 @@
+use biology::Organism;
+use biology::ecosystem::environment::{Climate, Environment, Terrain};
+use biology::ecosystem::landscape::{Landscape, Position, Topology};
+use biology::ecosystem::population::Population;
+use biology::ecosystem::resources::ResourceKind;
+use biology::ecosystem::weather::{ClimateNormals, Conditions, Season, WeatherEvent};
+use biology::simulation::{Exposure, Replenishment, Simulation, SimulationEvent, Weather};
+use biology::vitals::blood_pressure::BloodPressure;
+use biology::vitals::live_vitals::LiveVitals;
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::{Celsius, BPM};
+use biology_animalia::Mammal;
+use core_shared::Centimeters;
+
+const JANUARY: u32 = 15;
+const JULY: u32 = 196;
+
+fn population(climate: Climate) -> Population {
+    Population::new(Environment::new(climate, Terrain::Plains, Centimeters(0), 0.5))
+}
+
+fn vitals(simulation: &Simulation, member: &Mammal) -> (u32, u32, u32) {
+    let VitalStatus::Alive(vitals) = simulation.population().get(member.id).unwrap().vitals() else {
+        panic!("member should be alive");
+    };
+    (vitals.temperature.0, vitals.pulse.0, vitals.respiration_rate)
+}
+
+#[test]
+fn test_seasons() {
+    assert_eq!(Season::of_day(JANUARY), Season::Winter);
+    assert_eq!(Season::of_day(100), Season::Spring);
+    assert_eq!(Season::of_day(JULY), Season::Summer);
+    assert_eq!(Season::of_day(300), Season::Autumn);
+    assert_eq!(Season::of_day(350), Season::Winter);
+    assert_eq!(Season::of_day(365 + JULY), Season::Summer);
+}
+
+#[test]
+fn test_annual_and_daily_cycles() {
+    let temperate = ClimateNormals::for_climate(&Climate::Temperate);
+    assert_eq!(temperate.daily_mean_temperature(JULY), 20.0);
+    assert!(temperate.daily_mean_temperature(JANUARY) < 3.0);
+
+    let arctic = ClimateNormals::for_climate(&Climate::Arctic);
+    assert!(arctic.daily_mean_temperature(JANUARY) < -25.0);
+
+    let desert = ClimateNormals::for_climate(&Climate::Desert);
+    let afternoon = desert.temperature_at(JULY, 15);
+    let dawn = desert.temperature_at(JULY, 3);
+    assert!((afternoon - dawn - 24.0).abs() < 0.01);
+
+    let tropical = ClimateNormals::for_climate(&Climate::Tropical);
+    assert!(tropical.rainfall(JULY) > 3.0 * tropical.rainfall(JANUARY));
+    let year: f32 = (0..365).map(|day| tropical.rainfall(day)).sum();
+    assert!((year - 2000.0).abs() < 1.0);
+}
+
+#[test]
+fn test_events_override_the_usual_conditions() {
+    let usual = Conditions::for_climate(&Climate::Temperate, 100, None, None);
+    let drought = Conditions::for_climate(&Climate::Temperate, 100, None, Some(WeatherEvent::Drought));
+    let flood = Conditions::for_climate(&Climate::Temperate, 100, None, Some(WeatherEvent::Flood));
+    let blizzard = Conditions::for_climate(&Climate::Temperate, 100, None, Some(WeatherEvent::Blizzard));
+
+    assert_eq!(drought.rainfall, 0.0);
+    assert_eq!(drought.growth(), 0.0);
+    assert!((flood.rainfall - 5.0 * usual.rainfall).abs() < 1e-4);
+    assert_eq!(blizzard.temperature, usual.temperature - 15.0);
+    assert_eq!(blizzard.rainfall, usual.rainfall);
+}
+
+#[test]
+fn test_growth_follows_the_seasons() {
+    let summer = Conditions::for_climate(&Climate::Temperate, JULY, None, None);
+    let arctic_winter = Conditions::for_climate(&Climate::Arctic, JANUARY, None, None);
+
+    assert!(summer.growth() > 0.8);
+    assert_eq!(arctic_winter.growth(), 0.0);
+}
+
+#[test]
+fn test_event_chances_depend_on_climate_and_season() {
+    assert!(WeatherEvent::Blizzard.daily_chance(&Climate::Arctic, Season::Winter) > 0.0);
+    assert_eq!(WeatherEvent::Blizzard.daily_chance(&Climate::Tropical, Season::Winter), 0.0);
+    assert!(WeatherEvent::Flood.daily_chance(&Climate::Tropical, Season::Summer) > 0.0);
+    assert!(WeatherEvent::Drought.daily_chance(&Climate::Desert, Season::Summer) > 0.0);
+}
+
+#[test]
+fn test_drought_drains_water() {
+    let run = |weather: Option<Weather>| {
+        let mut simulation = Simulation::new(population(Climate::Temperate), 7).with_rule(Replenishment);
+        if let Some(weather) = weather {
+            simulation = simulation.with_rule(weather);
+        }
+        let reports = simulation.run(31);
+        let water = simulation.population().environment.resources.amount(ResourceKind::Water);
+        (water, reports, simulation)
+    };
+
+    let (usual, _, _) = run(None);
+    let (dry, reports, simulation) = run(Some(Weather::new().with_start_day(JULY).with_event(WeatherEvent::Drought)));
+
+    assert!(dry < usual * 0.75);
+    assert_eq!(reports[0].events, vec![SimulationEvent::WeatherBegan { event: WeatherEvent::Drought }]);
+    assert!(reports[30].events.contains(&SimulationEvent::WeatherEnded { event: WeatherEvent::Drought }));
+
+    let weather = simulation.rule::<Weather>().unwrap();
+    assert_eq!(weather.history().len(), 31);
+    assert_eq!(weather.history()[29].event, Some(WeatherEvent::Drought));
+    let environment = &simulation.population().environment;
+    assert_eq!(environment.weather, weather.conditions());
+}
+
+#[test]
+fn test_vitals_shift_with_the_weather_and_recover() {
+    fn fever_in_winter(_: &dyn Organism, conditions: &Conditions) -> Exposure {
+        if conditions.season == Season::Winter {
+            Exposure {
+                temperature: 2,
+                pulse: 10,
+                respiration_rate: 4,
+                energy: -0.1,
+            }
+        } else {
+            Exposure::default()
+        }
+    }
+
+    let mut population = population(Climate::Temperate);
+    let wolf = Mammal::new("Canis lupus".to_string());
+    population.insert(wolf.clone()).unwrap();
+    let mut simulation = Simulation::new(population, 1)
+        .with_rule(Weather::new().with_start_day(57).with_response(fever_in_winter));
+    let before = vitals(&simulation, &wolf);
+
+    // 26 and 27 February: the shift is applied once, not once a day.
+    simulation.run(2);
+    assert_eq!(vitals(&simulation, &wolf), (before.0 + 2, before.1 + 10, before.2 + 4));
+    assert!((simulation.state(wolf.id).unwrap().energy - 0.8).abs() < 1e-6);
+
+    // 1 March is spring.
+    simulation.run(2);
+    assert_eq!(vitals(&simulation, &wolf), before);
+}
+
+#[test]
+fn test_default_response_to_arctic_winter() {
+    let mut population = population(Climate::Arctic);
+    let fox = Mammal::new("Vulpes lagopus".to_string()).with_vitals(VitalStatus::Alive(LiveVitals::new(
+        BloodPressure(120, 80),
+        Celsius(39),
+        BPM(100),
+        20,
+    )));
+    population.insert(fox.clone()).unwrap();
+
+    let mut simulation = Simulation::new(population, 1).with_rule(Weather::new().with_start_day(JANUARY));
+    let before = vitals(&simulation, &fox);
+    simulation.step();
+    let after = vitals(&simulation, &fox);
+
+    // About -30 °C: three degrees colder and 15 beats faster.
+    assert_eq!(after.0, before.0 - 3);
+    assert_eq!(after.1, before.1 + 15);
+    assert!(simulation.state(fox.id).unwrap().energy < 1.0);
+}
+
+#[test]
+fn test_hourly_ticks_follow_the_daily_cycle() {
+    let mut simulation = Simulation::new(population(Climate::Desert), 1)
+        .with_rule(Weather::new().with_start_day(JULY).with_ticks_per_day(24));
+    simulation.run(24);
+
+    let history = simulation.rule::<Weather>().unwrap().history();
+    let coldest = history.iter().map(|conditions| conditions.temperature).fold(f32::MAX, f32::min);
+    let hottest = history.iter().map(|conditions| conditions.temperature).fold(f32::MIN, f32::max);
+    assert!(hottest - coldest > 20.0);
+    assert!(history.iter().all(|conditions| conditions.day_of_year == JULY));
+}
+
+#[test]
+fn test_landscape_cells_have_their_own_weather() {
+    let landscape = Landscape::from_fn(2, 1, Topology::Square, |position| {
+        let climate = if position.x == 0 { Climate::Tropical } else { Climate::Arctic };
+        Environment::new(climate, Terrain::Plains, Centimeters(0), 0.5)
+    });
+    let mut simulation = Simulation::new(population(Climate::Temperate).with_landscape(landscape), 1)
+        .with_rule(Weather::new().with_start_day(JANUARY));
+    simulation.step();
+
+    let landscape = simulation.population().landscape().unwrap();
+    let tropical = landscape.cell(Position::new(0, 0)).unwrap().weather.unwrap();
+    let arctic = landscape.cell(Position::new(1, 0)).unwrap().weather.unwrap();
+    assert!(tropical.temperature > 20.0);
+    assert!(arctic.temperature < -20.0);
+}
+
+#[test]
+fn test_weather_is_reproducible() {
+    let events = |seed| {
+        let mut simulation = Simulation::new(population(Climate::Arctic), seed).with_rule(Weather::new());
+        simulation
+            .run(365)
+            .into_iter()
+            .flat_map(|report| report.events)
+            .collect::<Vec<_>>()
+    };
+
+    let first = events(3);
+    assert!(first.iter().any(|event| matches!(event, SimulationEvent::WeatherBegan { event: WeatherEvent::Blizzard })));
+    assert_eq!(first, events(3));
+}
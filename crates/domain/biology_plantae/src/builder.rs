@@ -0,0 +1,128 @@
+use biology::Sex;
+use biology::builder::{BuildError, BuildProblem, OrganismBuilder, OrganismBuilderExt};
+use biology::vitals::vital_status::VitalStatus;
+use crate::parts::{Flowers, PlantForm};
+use crate::plant::Plant;
+
+/// Builds a `Plant` with the shared `OrganismBuilder` settings, plus its
+/// form, how far grown it is, and its flowers.
+///
+/// A body mass, if given, sets how far grown it is in place of `maturity`.
+/// `build` reports settings a plant cannot have: a sex, a top speed,
+/// appendages, a maturity outside 0 to 1, or a body mass beyond its
+/// form's full-grown mass.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let oak = Plant::builder("Quercus robur")
+///     .common_name("English oak")
+///     .form(PlantForm::Tree)
+///     .maturity(0.5)
+///     .habitat(HabitatPreference::on(&[Terrain::Forest]))
+///     .build()?;
+/// ```
+pub struct PlantBuilder {
+    organism: OrganismBuilder,
+    form: PlantForm,
+    maturity: f32,
+    flowers: Option<Flowers>,
+}
+
+impl PlantBuilder {
+    pub fn new(species: &str) -> Self {
+        Self {
+            organism: OrganismBuilder::new(species).vitals(VitalStatus::Alive(Plant::default_vitals())),
+            form: PlantForm::default(),
+            maturity: 0.1,
+            flowers: None,
+        }
+    }
+
+    pub fn form(mut self, form: PlantForm) -> Self {
+        self.form = form;
+        self
+    }
+
+    // Share of the form's full-grown mass, from 0 to 1.
+    pub fn maturity(mut self, maturity: f32) -> Self {
+        self.maturity = maturity;
+        self
+    }
+
+    pub fn flowers(mut self, flowers: Flowers) -> Self {
+        self.flowers = Some(flowers);
+        self
+    }
+
+    // Everything wrong with the settings: the shared checks, then the plant's own.
+    pub fn problems(&self) -> Vec<BuildProblem> {
+        let mut problems = self.organism.problems();
+        problems.extend(self.organism.unexpected_appendages(&[], "a plant"));
+        if !(0.0..=1.0).contains(&self.maturity) {
+            problems.push(BuildProblem::Invalid {
+                field: "maturity",
+                reason: format!("{} is not a share between 0 and 1", self.maturity),
+            });
+        }
+
+        let parts = self.organism.clone().into_parts();
+        if parts.sex != Sex::Unknown {
+            problems.push(BuildProblem::Invalid {
+                field: "sex",
+                reason: "plants are not sexed".to_string(),
+            });
+        }
+        if parts.top_speed.is_some() {
+            problems.push(BuildProblem::Invalid {
+                field: "top speed",
+                reason: "a plant does not move".to_string(),
+            });
+        }
+        if let Some(mass) = parts.body_mass
+            && mass.0 as f32 * 1000.0 > self.form.mature_mass()
+        {
+            problems.push(BuildProblem::Invalid {
+                field: "body mass",
+                reason: format!("{} kg is more than a full-grown {} weighs", mass.0, self.form),
+            });
+        }
+        problems
+    }
+
+    pub fn build(self) -> Result<Plant, BuildError> {
+        BuildError::check(self.problems())?;
+        Ok(self.assemble())
+    }
+
+    // The plant without any checks, for `Plant::new`.
+    pub(crate) fn assemble(self) -> Plant {
+        let parts = self.organism.into_parts();
+        let form = self.form;
+        let maturity = parts
+            .body_mass
+            .map_or(self.maturity, |mass| mass.0 as f32 * 1000.0 / form.mature_mass());
+        let (roots, stem, leaves) = form.mature_parts();
+        let plant = Plant {
+            id: parts.id,
+            species: parts.species,
+            common_name: parts.common_name,
+            name: parts.name,
+            vitals: parts.vitals,
+            form,
+            biomass: 0.0,
+            roots,
+            stem,
+            leaves,
+            flowers: self.flowers,
+            habitat: parts.habitat,
+        };
+        plant.with_maturity(maturity)
+    }
+}
+
+impl OrganismBuilderExt for PlantBuilder {
+    fn organism_builder(&mut self) -> &mut OrganismBuilder {
+        &mut self.organism
+    }
+}
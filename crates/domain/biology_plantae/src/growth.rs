@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+use biology::ecosystem::interactions::Interaction;
+use biology::simulation::engine::TickContext;
+use biology::simulation::events::{CauseOfDeath, SimulationEvent};
+use biology::simulation::rules::Rule;
+use crate::plant::Plant;
+
+// Below this share of its full-grown mass a plant has nothing left to regrow from.
+const WITHERED: f32 = 0.001;
+
+/// Every living plant grows for a day in its cell's environment.
+///
+/// A tick is a day, as for the `Weather` rule; register `Weather` first so
+/// plants grow in the day's conditions. A plant that withers to almost
+/// nothing dies of starvation.
+pub struct Growth;
+
+impl Rule for Growth {
+    fn name(&self) -> &'static str {
+        "growth"
+    }
+
+    fn apply(&mut self, context: &mut TickContext) {
+        for id in context.living() {
+            let environment = context.population.environment_of(id).clone();
+            let Some(plant) = context.population.get_as_mut::<Plant>(id) else {
+                continue;
+            };
+            plant.grow(&environment);
+            if plant.maturity() < WITHERED {
+                context.kill(id, CauseOfDeath::Starvation);
+            }
+        }
+    }
+}
+
+/// Herbivores eating plants along `Interaction::Predation` links.
+///
+/// `Predation` skips producers, so this rule feeds the links it leaves. A
+/// grazer sharing a cell with its plant finds it with `chance`, eats `bite`
+/// of its biomass and gains `energy_gain`; each grazer eats at most once a
+/// tick. Plants regrow rather than die, unless grazed down to nothing.
+pub struct Grazing {
+    pub chance: f64,
+    pub bite: f32,
+    pub energy_gain: f32,
+}
+
+impl Default for Grazing {
+    fn default() -> Self {
+        Self {
+            chance: 0.5,
+            bite: 0.1,
+            energy_gain: 0.3,
+        }
+    }
+}
+
+impl Rule for Grazing {
+    fn name(&self) -> &'static str {
+        "grazing"
+    }
+
+    fn apply(&mut self, context: &mut TickContext) {
+        let mut fed = HashSet::new();
+        let meals: Vec<Interaction> = context
+            .population
+            .interactions()
+            .iter()
+            .filter(|interaction| matches!(interaction, Interaction::Predation { .. }))
+            .cloned()
+            .collect();
+
+        for interaction in meals {
+            let (grazer_id, plant_id) = interaction.participants();
+            if fed.contains(&grazer_id) || !context.is_alive(grazer_id) || !context.is_alive(plant_id) {
+                continue;
+            }
+            if context.population.get_as::<Plant>(plant_id).is_none()
+                || context.population.position(grazer_id) != context.population.position(plant_id)
+            {
+                continue;
+            }
+            if !context.rng.chance(self.chance) {
+                continue;
+            }
+
+            let Some(plant) = context.population.get_as_mut::<Plant>(plant_id) else {
+                continue;
+            };
+            plant.graze(self.bite);
+            let withered = plant.maturity() < WITHERED;
+            context.emit(SimulationEvent::Interacted { interaction });
+            context.state_mut(grazer_id).adjust_energy(self.energy_gain);
+            fed.insert(grazer_id);
+            if withered {
+                context.kill(plant_id, CauseOfDeath::Predation { predator_id: grazer_id });
+            }
+        }
+    }
+}
@@ -1,14 +1,21 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+// Roots, stems, leaves and flowers, and the forms that size them
+pub mod parts;
+
+// How fast leaves make food in an environment
+pub mod photosynthesis;
+
+// The plant itself
+pub mod plant;
+
+// Building a plant with validation
+pub mod builder;
+
+// Simulation rules: growing each day, and being grazed
+pub mod growth;
+
+// Re-export the main types at top level (both paths work)
+pub use parts::{Flowers, Leaves, PlantForm, Roots, Stem};
+pub use photosynthesis::Photosynthesis;
+pub use plant::Plant;
+pub use builder::PlantBuilder;
+pub use growth::{Grazing, Growth};
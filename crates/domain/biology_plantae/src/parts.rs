@@ -0,0 +1,121 @@
+// A plant's structural parts, and the forms that set their full-grown size.
+// Figures are rough averages for modelling, not any one species.
+
+use std::fmt::{Display, Formatter};
+use biology::ecosystem::weather::Season;
+use core_shared::{Centimeters, Millimeters};
+
+/// What kind of plant it is; sets how big it gets and whether its stem is wood.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum PlantForm {
+    Tree,
+    Shrub,
+    #[default]
+    Herb,
+    Grass,
+}
+
+impl PlantForm {
+    // Dry mass of a full-grown plant, in grams.
+    pub fn mature_mass(&self) -> f32 {
+        match self {
+            PlantForm::Tree => 2_000_000.0,
+            PlantForm::Shrub => 20_000.0,
+            PlantForm::Herb => 500.0,
+            PlantForm::Grass => 20.0,
+        }
+    }
+
+    // The roots, stem and leaves of a full-grown plant.
+    pub fn mature_parts(&self) -> (Roots, Stem, Leaves) {
+        let (depth, spread, height, diameter, woody, leaves, area) = match self {
+            PlantForm::Tree => (300, 1500, 2500, 800, true, 200_000, 30),
+            PlantForm::Shrub => (100, 300, 300, 50, true, 5_000, 10),
+            PlantForm::Herb => (50, 40, 150, 20, false, 30, 200),
+            PlantForm::Grass => (30, 20, 40, 3, false, 10, 50),
+        };
+        (
+            Roots {
+                depth: Centimeters(depth),
+                spread: Centimeters(spread),
+            },
+            Stem {
+                height: Centimeters(height),
+                diameter: Millimeters(diameter),
+                woody,
+            },
+            Leaves { count: leaves, area },
+        )
+    }
+}
+
+impl Display for PlantForm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlantForm::Tree => write!(f, "tree"),
+            PlantForm::Shrub => write!(f, "shrub"),
+            PlantForm::Herb => write!(f, "herb"),
+            PlantForm::Grass => write!(f, "grass"),
+        }
+    }
+}
+
+/// The root system: how deep it reaches and how far it spreads.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Roots {
+    pub depth: Centimeters,
+    // Across, at the widest.
+    pub spread: Centimeters,
+}
+
+/// The main stem, or a tree's trunk.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stem {
+    pub height: Centimeters,
+    pub diameter: Millimeters,
+    pub woody: bool,
+}
+
+/// Every leaf on the plant, as a count and the area of one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Leaves {
+    pub count: u32,
+    // One side of one leaf, in cm².
+    pub area: u32,
+}
+
+impl Leaves {
+    // The whole canopy, in m²; what catches the light.
+    pub fn total_area_m2(&self) -> f32 {
+        self.count as f32 * self.area as f32 / 10_000.0
+    }
+}
+
+/// The flowers a plant bears once it is grown enough, in their season.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Flowers {
+    pub count: u32,
+    pub colour: String,
+    // When they open; `None` for plants that flower whenever they can.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub season: Option<Season>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub open: bool,
+}
+
+impl Flowers {
+    // Closed until the plant next grows in their season.
+    pub fn new(count: u32, colour: &str, season: Option<Season>) -> Self {
+        Self {
+            count,
+            colour: colour.to_string(),
+            season,
+            open: false,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use biology::ecosystem::environment::{Climate, Environment};
+use biology::ecosystem::resources::ResourceKind;
+use biology::ecosystem::weather::{growing_warmth, Season};
+
+// Grams of dry matter a square metre of leaf makes on a day with every factor at 1.
+pub const PRODUCTIVITY: f32 = 5.0;
+
+// Share of its own mass a plant burns each day to stay alive, light or not.
+pub const UPKEEP: f32 = 0.0005;
+
+/// How well a plant can photosynthesise in an environment, as three factors from 0 to 1.
+///
+/// `light` is the environment's `ResourceKind::Light` pool against open
+/// temperate plains, shortened by the season's daylight while the `Weather`
+/// rule runs. `water` comes from humidity, and from the day's rain relative
+/// to the usual. `warmth` is `growing_warmth` of the air. The rate is their
+/// product, so whichever is lowest limits it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Photosynthesis {
+    pub light: f32,
+    pub water: f32,
+    pub warmth: f32,
+}
+
+impl Photosynthesis {
+    pub fn in_environment(environment: &Environment) -> Self {
+        let mut light = (environment.resources.amount(ResourceKind::Light) / 100.0).clamp(0.0, 1.0) as f32;
+        let mut water = (0.5 + environment.humidity).clamp(0.0, 1.0);
+        if let Some(conditions) = &environment.weather {
+            light *= daylight(&environment.climate, conditions.season);
+            water *= conditions.wetness().min(1.0) as f32;
+        }
+        Self {
+            light,
+            water,
+            warmth: growing_warmth(environment.ambient_temperature()),
+        }
+    }
+
+    // 0 (none) to 1 (as fast as leaves can).
+    pub fn rate(&self) -> f32 {
+        self.light * self.water * self.warmth
+    }
+
+    // The factor holding the rate back most: "light", "water" or "warmth".
+    pub fn limiting_factor(&self) -> &'static str {
+        if self.light <= self.water && self.light <= self.warmth {
+            "light"
+        } else if self.water <= self.warmth {
+            "water"
+        } else {
+            "warmth"
+        }
+    }
+}
+
+// Day length relative to midsummer: the tropics barely change, the poles go dark in winter.
+fn daylight(climate: &Climate, season: Season) -> f32 {
+    match (climate, season) {
+        (Climate::Tropical, _) | (_, Season::Summer) => 1.0,
+        (Climate::Arctic, Season::Winter) => 0.05,
+        (Climate::Arctic, _) => 0.5,
+        (_, Season::Winter) => 0.45,
+        _ => 0.75,
+    }
+}
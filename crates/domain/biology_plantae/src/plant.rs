@@ -0,0 +1,346 @@
+use biology::{Nomenclature, Organism};
+use biology::nomenclature::{common_aliases, common_display_name};
+use biology::characteristics::locomotion::{Locomotion, Mobility};
+use biology::characteristics::presentation::Summarizable;
+use biology::characteristics::structural::Anatomy;
+use biology::ecosystem::environment::{Environment, Terrain};
+use biology::ecosystem::habitat::HabitatPreference;
+use biology::patterns::locomotion_patterns::SessileMovement;
+use biology::vitals::blood_pressure::BloodPressure;
+use biology::vitals::live_vitals::LiveVitals;
+use biology::vitals::vital_status::VitalStatus;
+use biology::vitals::vital_types::{Celsius, RespiratoryOrgan, BPM};
+use core_shared::{Centimeters, Identifier, Kilograms, Millimeters};
+use crate::builder::PlantBuilder;
+use crate::parts::{Flowers, Leaves, PlantForm, Roots, Stem};
+use crate::photosynthesis::{Photosynthesis, PRODUCTIVITY, UPKEEP};
+
+// Share of its full-grown mass a plant must reach before it flowers.
+const FLOWERING_MATURITY: f32 = 0.5;
+
+/// A plant - roots, a stem, leaves and, once grown enough, flowers.
+///
+/// Its `form` sets how big it gets; the parts are sized from how far
+/// `biomass` has come towards the form's full-grown mass. Each `grow`
+/// is one day: the leaves make food at the `Photosynthesis` rate for the
+/// environment, the plant burns `UPKEEP` of its mass, and flowers open in
+/// their season. Plants stay where they are (`Locomotion::Sessile`), take
+/// the temperature of the air, and are a food web's producers.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let mut meadow_grass = Plant::builder("Poa pratensis")
+///     .common_name("Kentucky bluegrass")
+///     .form(PlantForm::Grass)
+///     .build()?;
+/// let grams = meadow_grass.grow(&environment);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plant {
+    pub id: Identifier,
+    pub species: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub common_name: Option<String>,
+    // An individual's own name or tag code.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
+    pub vitals: VitalStatus,
+    pub form: PlantForm,
+    // Dry mass in grams; up to `form.mature_mass()`.
+    pub biomass: f32,
+    pub roots: Roots,
+    pub stem: Stem,
+    pub leaves: Leaves,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub flowers: Option<Flowers>,
+    // Where it can grow; `None` for anywhere but the open sea.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub habitat: Option<HabitatPreference>,
+}
+
+impl Plant {
+    // A young herb, a tenth grown.
+    pub fn new(species: String) -> Self {
+        PlantBuilder::new(&species).assemble()
+    }
+
+    pub fn builder(species: &str) -> PlantBuilder {
+        PlantBuilder::new(species)
+    }
+
+    // No heart or lungs: the respiration rate counts the share of stomata open.
+    pub fn default_vitals() -> LiveVitals {
+        LiveVitals::new(BloodPressure(0, 0), Celsius(15), BPM(0), 50)
+            .with_respiratory_organ(RespiratoryOrgan::Stomata)
+    }
+
+    // Keeps how far grown it is, resizing the parts for the new form.
+    pub fn with_form(mut self, form: PlantForm) -> Self {
+        let maturity = self.maturity();
+        self.form = form;
+        self.set_maturity(maturity);
+        self
+    }
+
+    // Share of the form's full-grown mass, from 0 to 1.
+    pub fn with_maturity(mut self, maturity: f32) -> Self {
+        self.set_maturity(maturity);
+        self
+    }
+
+    pub fn with_flowers(mut self, flowers: Flowers) -> Self {
+        self.flowers = Some(flowers);
+        self
+    }
+
+    pub fn maturity(&self) -> f32 {
+        (self.biomass / self.form.mature_mass()).clamp(0.0, 1.0)
+    }
+
+    fn set_maturity(&mut self, maturity: f32) {
+        self.biomass = self.form.mature_mass() * maturity.clamp(0.0, 1.0);
+        self.resize();
+    }
+
+    // Sizes the parts for the biomass: lengths grow with the cube root of maturity, leaf count with its square.
+    fn resize(&mut self) {
+        let (roots, stem, leaves) = self.form.mature_parts();
+        let length = self.maturity().cbrt();
+        let scale = |centimeters: Centimeters| Centimeters((centimeters.0 as f32 * length).round() as u32);
+        self.roots = Roots {
+            depth: scale(roots.depth),
+            spread: scale(roots.spread),
+        };
+        self.stem = Stem {
+            height: scale(stem.height),
+            diameter: Millimeters((stem.diameter.0 as f32 * length).round().max(1.0) as u32),
+            woody: stem.woody,
+        };
+        self.leaves = Leaves {
+            count: ((leaves.count as f32 * length * length).round() as u32).max(1),
+            area: leaves.area,
+        };
+    }
+
+    // Grams of dry matter the leaves would make in a day here.
+    pub fn photosynthesise(&self, environment: &Environment) -> f32 {
+        PRODUCTIVITY * self.leaves.total_area_m2() * Photosynthesis::in_environment(environment).rate()
+    }
+
+    /// Grows for one day in the environment; returns the grams gained, or lost.
+    ///
+    /// Biomass stops at the form's full-grown mass. Flowers open once the
+    /// plant is half grown and it is their season (any time without a
+    /// `Weather` rule), and close again out of it. The vitals follow the
+    /// air: its temperature, and stomata open as far as water allows.
+    pub fn grow(&mut self, environment: &Environment) -> f32 {
+        if !self.is_alive() {
+            return 0.0;
+        }
+        let before = self.biomass;
+        let made = self.photosynthesise(environment);
+        self.biomass = (self.biomass + made - self.biomass * UPKEEP).clamp(0.0, self.form.mature_mass());
+        self.resize();
+
+        let season = environment.weather.as_ref().map(|conditions| conditions.season);
+        let mature = self.maturity() >= FLOWERING_MATURITY;
+        if let Some(flowers) = &mut self.flowers {
+            flowers.open = mature && flowers.season.is_none_or(|bloom| season.is_none_or(|now| now == bloom));
+        }
+
+        let water = Photosynthesis::in_environment(environment).water;
+        if let VitalStatus::Alive(vitals) = &mut self.vitals {
+            vitals.temperature = Celsius::from_degrees(environment.ambient_temperature());
+            vitals.respiration_rate = (water * 100.0).round() as u32;
+        }
+        self.biomass - before
+    }
+
+    // Eaten down by a share of its mass; returns the grams taken.
+    pub fn graze(&mut self, share: f32) -> f32 {
+        let eaten = self.biomass * share.clamp(0.0, 1.0);
+        self.biomass -= eaten;
+        self.resize();
+        eaten
+    }
+
+    pub fn is_flowering(&self) -> bool {
+        self.flowers.as_ref().is_some_and(|flowers| flowers.open)
+    }
+}
+
+// "40 cm", or "2.5 m" from a metre up.
+fn length(centimeters: Centimeters) -> String {
+    if centimeters.0 < 100 {
+        format!("{} cm", centimeters.0)
+    } else {
+        format!("{:.1} m", centimeters.0 as f32 / 100.0)
+    }
+}
+
+impl Nomenclature for Plant {
+    fn display_name(&self) -> String {
+        common_display_name(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+
+    fn aliases(&self) -> Vec<String> {
+        common_aliases(self.name.as_deref(), self.common_name.as_deref(), &self.species)
+    }
+}
+
+impl Anatomy for Plant {
+    fn describe_anatomy(&self) -> String {
+        let stem = if self.stem.woody { "woody trunk" } else { "green stem" };
+        let flowers = match &self.flowers {
+            Some(flowers) if flowers.open => format!(" {} {} flowers in bloom.", flowers.count, flowers.colour),
+            _ => String::new(),
+        };
+        format!(
+            "A {} {} with a {} {} mm across; roots reach {} deep and spread {}; {} leaves ({:.2} m²).{}",
+            length(self.stem.height),
+            self.form,
+            stem,
+            self.stem.diameter.0,
+            length(self.roots.depth),
+            length(self.roots.spread),
+            self.leaves.count,
+            self.leaves.total_area_m2(),
+            flowers
+        )
+    }
+}
+
+impl SessileMovement for Plant {}
+
+impl Mobility for Plant {
+    fn describe_locomotion(&self) -> String {
+        self.describe_sessile_movement()
+    }
+
+    fn locomotion(&self) -> Locomotion {
+        Locomotion::Sessile
+    }
+}
+
+impl Organism for Plant {
+    fn id(&self) -> Identifier {
+        self.id
+    }
+
+    fn species(&self) -> String {
+        self.species.clone()
+    }
+
+    fn vitals(&self) -> &VitalStatus {
+        &self.vitals
+    }
+
+    fn vitals_mut(&mut self) -> &mut VitalStatus {
+        &mut self.vitals
+    }
+
+    // Whole kilograms; `None` for a plant under one, rather than a mass of nothing.
+    fn body_mass(&self) -> Option<Kilograms> {
+        (self.biomass >= 1000.0).then(|| Kilograms((self.biomass / 1000.0).round() as u32))
+    }
+
+    fn tolerates(&self, environment: &Environment) -> bool {
+        match &self.habitat {
+            Some(habitat) => habitat.allows(environment),
+            None => !matches!(environment.terrain, Terrain::Ocean),
+        }
+    }
+
+    fn is_producer(&self) -> bool {
+        true
+    }
+
+    fn body_temperature_in(&self, environment: &Environment) -> Option<Celsius> {
+        Some(Celsius::from_degrees(environment.ambient_temperature()))
+    }
+}
+
+impl Summarizable for Plant {
+    fn summary(&self) -> String {
+        format!(
+            "--- Plant: {} ({}) ---\nID: {}\nForm: {}, {:.0}% grown, {:.1} kg\nAnatomy: {}\nMobility: {}",
+            self.display_name(),
+            self.species,
+            self.id,
+            self.form,
+            self.maturity() * 100.0,
+            self.biomass / 1000.0,
+            self.describe_anatomy(),
+            self.describe_locomotion()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biology::ecosystem::environment::Climate;
+    use biology::ecosystem::weather::{Conditions, Season, WeatherEvent};
+
+    fn meadow() -> Environment {
+        Environment::new(Climate::Temperate, Terrain::Plains, Centimeters(0), 0.6)
+    }
+
+    #[test]
+    fn test_parts_grow_with_biomass() {
+        let mut oak = Plant::new("Quercus robur".to_string())
+            .with_form(PlantForm::Tree)
+            .with_maturity(0.2);
+        let (short, narrow) = (oak.stem.height, oak.leaves.count);
+        for _ in 0..30 {
+            oak.grow(&meadow());
+        }
+
+        assert!(oak.stem.height.0 > short.0);
+        assert!(oak.leaves.count > narrow);
+        assert!(oak.stem.woody);
+        assert_eq!(oak.locomotion(), Locomotion::Sessile);
+    }
+
+    #[test]
+    fn test_body_mass_is_unknown_under_a_kilogram() {
+        let seedling = Plant::new("Quercus robur".to_string())
+            .with_form(PlantForm::Tree)
+            .with_maturity(0.0001);
+        let clover = Plant::new("Trifolium repens".to_string()).with_maturity(1.0);
+        let sapling = seedling.clone().with_maturity(0.01);
+
+        assert_eq!(seedling.body_mass(), None);
+        assert_eq!(clover.body_mass(), None);
+        assert_eq!(sapling.body_mass(), Some(Kilograms(20)));
+    }
+
+    #[test]
+    fn test_a_drought_stops_growth() {
+        let mut environment = meadow();
+        environment.weather = Some(Conditions::for_climate(&Climate::Temperate, 180, None, Some(WeatherEvent::Drought)));
+        let mut grass = Plant::new("Poa pratensis".to_string()).with_form(PlantForm::Grass);
+
+        assert_eq!(Photosynthesis::in_environment(&environment).limiting_factor(), "water");
+        assert!(grass.grow(&environment) < 0.0);
+        assert!(matches!(&grass.vitals, VitalStatus::Alive(vitals) if vitals.respiration_rate == 0));
+    }
+
+    #[test]
+    fn test_flowers_open_in_their_season_once_grown() {
+        let mut environment = meadow();
+        let mut poppy = Plant::new("Papaver rhoeas".to_string())
+            .with_maturity(0.8)
+            .with_flowers(Flowers::new(6, "red", Some(Season::Summer)));
+        environment.weather = Some(Conditions::for_climate(&Climate::Temperate, 20, None, None));
+        poppy.grow(&environment);
+        assert!(!poppy.is_flowering());
+
+        environment.weather = Some(Conditions::for_climate(&Climate::Temperate, 180, None, None));
+        poppy.grow(&environment);
+        assert!(poppy.is_flowering());
+        assert!(poppy.describe_anatomy().ends_with("6 red flowers in bloom."));
+    }
+}
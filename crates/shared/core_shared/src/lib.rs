@@ -1,7 +1,9 @@
 pub mod identity;
 pub mod orientation;
+pub mod random;
 pub mod units;
 
 pub use identity::Identifier;
 pub use orientation::Direction;
-pub use units::{Centimeters, Kilograms};
+pub use random::SeededRng;
+pub use units::{Centimeters, Kilograms, MetersPerSecond, Millimeters};